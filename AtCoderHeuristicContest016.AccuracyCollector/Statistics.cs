@@ -1,3 +0,0 @@
-﻿namespace AtCoderHeuristicContest016.AccuracyCollector;
-
-public record Statistics(int M, double ErrorRatio, int Bits, int Redundancy, double ScoreCoef, int TrialCount, int Accepted, double Accuracy, double ExpectedScore);
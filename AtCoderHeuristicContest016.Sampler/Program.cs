@@ -1,12 +1,17 @@
-﻿using AtCoderHeuristicContest016.Sampler;
-using Cysharp.Diagnostics;
-using Newtonsoft.Json.Serialization;
-using Newtonsoft.Json;
-using System.Collections.Concurrent;
-using System.Runtime.Intrinsics.X86;
+﻿using Cysharp.Diagnostics;
 
+// 精度表・混同行列の元になる (送ったグラフ, 答えたグラフ) の組を、(bits, eps) ごとにsamplerで集める
+// 結果はpy/export_accuracy.py, py/export_confusion.pyで表に変換する
+if (args.Length != 2)
+{
+    Console.Error.WriteLine("usage: AtCoderHeuristicContest016.Sampler <redundancy> <score_coef>");
+    return 1;
+}
+
+var redundancy = int.Parse(args[0]);
+var scoreCoef = double.Parse(args[1]);
 var parameters = Enumerable.Range(4, 3).SelectMany(i => Enumerable.Range(0, 41).Select(j => (i, j))).Reverse();
-await Task.WhenAll(BuildAsync("ahc016"), BuildAsync("sampler"));
+await BuildAsync("sampler");
 
 var options = new ParallelOptions
 {
@@ -15,7 +20,6 @@ var options = new ParallelOptions
 
 var directoryPath = @$"data\sampled\{DateTime.Now:yyyyMMdd_HHmmss}";
 Directory.CreateDirectory(directoryPath);
-var bag = new ConcurrentBag<Statistics>();
 
 await Parallel.ForEachAsync(parameters, options, async (param, ct) =>
 {
@@ -23,32 +27,16 @@ await Parallel.ForEachAsync(parameters, options, async (param, ct) =>
     Console.WriteLine($"{bits} {eps}");
     var epsDouble = (double)eps / 100;
 
-    var results = new List<QueryResult>();
-    var output = ProcessX.StartAsync($"sampler.exe -b {bits} -e {epsDouble} -c ahc016.exe");
+    var output = ProcessX.StartAsync($"sampler.exe -b {bits} -e {epsDouble} -r {redundancy} -s {scoreCoef}");
     await using var writer = new StreamWriter(Path.Combine(directoryPath, $"{bits}_{eps:00}.csv"));
 
     await foreach (var s in output)
     {
         writer.WriteLine(s);
-        var pair = s.Split(',').Select(int.Parse).ToArray();
-        results.Add(new QueryResult(pair[0], pair[1]));
     }
-
-    var statistics = new Statistics(bits, epsDouble, results);
-    bag.Add(statistics);
-});
-
-var contractResolver = new DefaultContractResolver
-{
-    NamingStrategy = new SnakeCaseNamingStrategy()
-};
-
-var json = JsonConvert.SerializeObject(bag.OrderBy(s => s.Bits).ThenBy(s => s.ErrorRatio).ToArray(), new JsonSerializerSettings
-{
-    ContractResolver = contractResolver,
 });
 
-await File.WriteAllTextAsync(Path.Combine(directoryPath, "statistics.json"), json);
+return 0;
 
 static async Task BuildAsync(string binName)
 {
@@ -56,4 +44,4 @@ static async Task BuildAsync(string binName)
     var (_, stdOut, stdError) = ProcessX.GetDualAsyncEnumerable(command);
     await Task.WhenAll(stdOut.WaitAsync(), stdError.WaitAsync());
     File.Move(@$"..\target\release\{binName}.exe", @$".\{binName}.exe");
-}
\ No newline at end of file
+}
@@ -1,3 +0,0 @@
-﻿namespace AtCoderHeuristicContest016.Sampler;
-
-public record QueryResult(int GroundTruth, int Answered);
\ No newline at end of file
@@ -1,3 +0,0 @@
-﻿namespace AtCoderHeuristicContest016.Sampler;
-
-public record Statistics(int Bits, double ErrorRatio, List<QueryResult> QueryResults);
\ No newline at end of file
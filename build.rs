@@ -0,0 +1,370 @@
+//! 同型エンコーダの戦略表・精度表・混同行列をCSVから読み込み、定数として埋め込むコードを生成する
+//!
+//! 表はsrc/encoders/isomorphism/tables以下に置く。大きさや値の範囲を検証し、
+//! 不備があればビルドを失敗させる
+
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+const TABLE_DIR: &str = "src/encoders/isomorphism/tables";
+const GRAPH_DIR: &str = "src/encoders/isomorphism/graphs";
+const MIN_M: usize = 10;
+const MAX_M: usize = 100;
+const MAX_EPS: usize = 40;
+/// 精度表・混同行列の用意されているbits
+const TABLE_BITS: [usize; 3] = [4, 5, 6];
+/// 精度表・混同行列を作ったときの、(bits, eps)ごとの1グラフあたりの試行回数
+const SAMPLE_COUNT: u32 = 1000;
+
+/// (bits, redundancy, score_coef)
+type Strategy = (u8, u8, f64);
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", TABLE_DIR);
+    println!("cargo:rerun-if-changed={}", GRAPH_DIR);
+
+    match generate() {
+        Ok(code) => {
+            let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+            fs::write(out_dir.join("isomorphism_tables.rs"), code).unwrap();
+        }
+        Err(message) => {
+            eprintln!("error: {}", message);
+            process::exit(1);
+        }
+    }
+}
+
+fn generate() -> Result<String, String> {
+    let mut code = String::new();
+    writeln!(code, "pub const MIN_M: usize = {};", MIN_M).unwrap();
+    writeln!(code, "pub const SAMPLE_COUNT: u32 = {};", SAMPLE_COUNT).unwrap();
+
+    let strategy = read_strategy(&Path::new(TABLE_DIR).join("strategy.csv"))?;
+    writeln!(
+        code,
+        "/// [m - MIN_M][eps] = (bits, redundancy, score_coef)"
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "pub static STRATEGY_MATRIX: [[(u8, u8, f64); {}]; {}] = [",
+        MAX_EPS + 1,
+        MAX_M - MIN_M + 1
+    )
+    .unwrap();
+
+    for line in strategy.iter() {
+        let line = line
+            .iter()
+            .map(|(bits, redundancy, score_coef)| {
+                format!("({}, {}, {:?})", bits, redundancy, score_coef)
+            })
+            .collect::<Vec<_>>();
+        writeln!(code, "[{}],", line.join(", ")).unwrap();
+    }
+
+    writeln!(code, "];").unwrap();
+
+    let mut accuracy_code = String::new();
+    let mut confusion_code = String::new();
+
+    for &bits in TABLE_BITS.iter() {
+        let graph_count = count_graphs(bits)?;
+        let accuracy = read_accuracy(
+            &Path::new(TABLE_DIR).join(format!("accuracy{}.csv", bits)),
+            graph_count,
+        )?;
+        let confusion = read_confusion(
+            &Path::new(TABLE_DIR).join(format!("confusion{}.csv", bits)),
+            graph_count,
+            &accuracy,
+        )?;
+
+        writeln!(accuracy_code, "&[").unwrap();
+
+        for line in accuracy.iter() {
+            let line = line.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+            writeln!(accuracy_code, "&[{}],", line.join(", ")).unwrap();
+        }
+
+        writeln!(accuracy_code, "],").unwrap();
+
+        let entries = confusion
+            .iter()
+            .map(|&(eps, truth, answered, count)| {
+                format!("({}, {}, {}, {})", eps, truth, answered, count)
+            })
+            .collect::<Vec<_>>();
+        writeln!(confusion_code, "&[{}],", entries.join(", ")).unwrap();
+    }
+
+    writeln!(
+        code,
+        "/// [bits - 4][eps][graph] = SAMPLE_COUNT回中の正解数"
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "pub static ACCURACY_MATRIX: [&[&[u16]]; {}] = [\n{}];",
+        TABLE_BITS.len(),
+        accuracy_code
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "/// [bits - 4] = (eps, 送ったグラフ, 復元したグラフ, 回数) の一覧"
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "pub static CONFUSION_ENTRIES: [&[(u8, u8, u8, u16)]; {}] = [\n{}];",
+        TABLE_BITS.len(),
+        confusion_code
+    )
+    .unwrap();
+
+    Ok(code)
+}
+
+/// [m - MIN_M][eps] = (bits, redundancy, score_coef) の戦略表を読み込む
+fn read_strategy(path: &Path) -> Result<Vec<Vec<Strategy>>, String> {
+    let rows = read_csv(path, &["m", "eps", "bits", "redundancy", "score_coef"])?;
+    let mut matrix = vec![vec![None; MAX_EPS + 1]; MAX_M - MIN_M + 1];
+
+    for (line, row) in rows.iter() {
+        let at = |message: String| format!("{}:{}: {}", path.display(), line, message);
+        let m: usize = parse(&row[0]).map_err(at)?;
+        let eps: usize = parse(&row[1]).map_err(at)?;
+        let bits: u8 = parse(&row[2]).map_err(at)?;
+        let redundancy: u8 = parse(&row[3]).map_err(at)?;
+        let score_coef: f64 = parse(&row[4]).map_err(at)?;
+
+        if !(MIN_M..=MAX_M).contains(&m) || eps > MAX_EPS {
+            return Err(at(format!("(m, eps) = ({}, {}) is out of range", m, eps)));
+        }
+
+        if bits == 0 || redundancy == 0 || !(score_coef.is_finite() && score_coef > 0.0) {
+            return Err(at(
+                "bits, redundancy and score_coef must be positive".to_owned()
+            ));
+        }
+
+        let cell = &mut matrix[m - MIN_M][eps];
+
+        if cell.is_some() {
+            return Err(at(format!(
+                "duplicate entry for (m, eps) = ({}, {})",
+                m, eps
+            )));
+        }
+
+        *cell = Some((bits, redundancy, score_coef));
+    }
+
+    collect_cells(matrix, |m, eps| {
+        format!(
+            "{}: missing entry for (m, eps) = ({}, {})",
+            path.display(),
+            m + MIN_M,
+            eps
+        )
+    })
+}
+
+/// [eps][graph] = 正解数 の精度表を読み込む
+fn read_accuracy(path: &Path, graph_count: usize) -> Result<Vec<Vec<u16>>, String> {
+    let rows = read_csv(path, &["eps", "graph", "correct"])?;
+    let mut matrix = vec![vec![None; graph_count]; MAX_EPS + 1];
+
+    for (line, row) in rows.iter() {
+        let at = |message: String| format!("{}:{}: {}", path.display(), line, message);
+        let eps: usize = parse(&row[0]).map_err(at)?;
+        let graph: usize = parse(&row[1]).map_err(at)?;
+        let correct: u16 = parse(&row[2]).map_err(at)?;
+
+        if eps > MAX_EPS || graph >= graph_count {
+            return Err(at(format!(
+                "(eps, graph) = ({}, {}) is out of range",
+                eps, graph
+            )));
+        }
+
+        if correct as u32 > SAMPLE_COUNT {
+            return Err(at(format!("correct must be at most {}", SAMPLE_COUNT)));
+        }
+
+        let cell = &mut matrix[eps][graph];
+
+        if cell.is_some() {
+            return Err(at(format!(
+                "duplicate entry for (eps, graph) = ({}, {})",
+                eps, graph
+            )));
+        }
+
+        *cell = Some(correct);
+    }
+
+    collect_cells(matrix, |eps, graph| {
+        format!(
+            "{}: missing entry for (eps, graph) = ({}, {})",
+            path.display(),
+            eps,
+            graph
+        )
+    })
+}
+
+/// (eps, 送ったグラフ, 復元したグラフ, 回数) を読み込む。回数が0の組は省略してよい
+///
+/// 送ったグラフごとの回数の合計がSAMPLE_COUNTで、正解した回数が精度表と一致することを確かめる
+fn read_confusion(
+    path: &Path,
+    graph_count: usize,
+    accuracy: &[Vec<u16>],
+) -> Result<Vec<(u8, u8, u8, u16)>, String> {
+    let rows = read_csv(path, &["eps", "truth", "answered", "count"])?;
+    let mut entries = Vec::with_capacity(rows.len());
+    let mut counts = HashMap::new();
+    let mut sums = vec![vec![0; graph_count]; MAX_EPS + 1];
+
+    for (line, row) in rows.iter() {
+        let at = |message: String| format!("{}:{}: {}", path.display(), line, message);
+        let eps: u8 = parse(&row[0]).map_err(at)?;
+        let truth: u8 = parse(&row[1]).map_err(at)?;
+        let answered: u8 = parse(&row[2]).map_err(at)?;
+        let count: u16 = parse(&row[3]).map_err(at)?;
+
+        if eps as usize > MAX_EPS
+            || truth as usize >= graph_count
+            || answered as usize >= graph_count
+        {
+            return Err(at(format!(
+                "(eps, truth, answered) = ({}, {}, {}) is out of range",
+                eps, truth, answered
+            )));
+        }
+
+        if counts.insert((eps, truth, answered), count).is_some() {
+            return Err(at(format!(
+                "duplicate entry for (eps, truth, answered) = ({}, {}, {})",
+                eps, truth, answered
+            )));
+        }
+
+        sums[eps as usize][truth as usize] += count as u32;
+        entries.push((eps, truth, answered, count));
+    }
+
+    for (eps, line) in sums.iter().enumerate() {
+        for (truth, &sum) in line.iter().enumerate() {
+            if sum != SAMPLE_COUNT {
+                return Err(format!(
+                    "{}: counts for (eps, truth) = ({}, {}) sum to {}, expected {}",
+                    path.display(),
+                    eps,
+                    truth,
+                    sum,
+                    SAMPLE_COUNT
+                ));
+            }
+
+            let correct = counts
+                .get(&(eps as u8, truth as u8, truth as u8))
+                .copied()
+                .unwrap_or(0);
+
+            if correct != accuracy[eps][truth] {
+                return Err(format!(
+                    "{}: correct count for (eps, truth) = ({}, {}) is {}, but the accuracy table says {}",
+                    path.display(),
+                    eps,
+                    truth,
+                    correct,
+                    accuracy[eps][truth]
+                ));
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+/// bits頂点のグラフの一覧に含まれるグラフの数
+fn count_graphs(bits: usize) -> Result<usize, String> {
+    let path = Path::new(GRAPH_DIR).join(format!("graphs{}.g6", bits));
+    let text = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(text.lines().filter(|line| !line.trim().is_empty()).count())
+}
+
+/// ヘッダを確かめ、(行番号, 各列の値) を返す
+fn read_csv(path: &Path, header: &[&str]) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+
+    let actual = lines.next().map(|(_, line)| line).unwrap_or("");
+
+    if actual != header.join(",") {
+        return Err(format!(
+            "{}: expected header \"{}\", found \"{}\"",
+            path.display(),
+            header.join(","),
+            actual
+        ));
+    }
+
+    lines
+        .map(|(i, line)| {
+            let row = line
+                .split(',')
+                .map(|s| s.trim().to_owned())
+                .collect::<Vec<_>>();
+
+            if row.len() == header.len() {
+                Ok((i, row))
+            } else {
+                Err(format!(
+                    "{}:{}: expected {} columns, found {}",
+                    path.display(),
+                    i,
+                    header.len(),
+                    row.len()
+                ))
+            }
+        })
+        .collect()
+}
+
+fn parse<T: std::str::FromStr>(s: &str) -> Result<T, String> {
+    s.parse()
+        .map_err(|_| format!("cannot parse \"{}\" as {}", s, std::any::type_name::<T>()))
+}
+
+/// 全てのセルが埋まっていることを確かめる
+fn collect_cells<T>(
+    matrix: Vec<Vec<Option<T>>>,
+    missing: impl Fn(usize, usize) -> String,
+) -> Result<Vec<Vec<T>>, String> {
+    matrix
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            line.into_iter()
+                .enumerate()
+                .map(|(j, cell)| cell.ok_or_else(|| missing(i, j)))
+                .collect()
+        })
+        .collect()
+}
@@ -1,6 +1,8 @@
-import numpy as np
-import json
+import csv
+import sys
+
 import matplotlib.pyplot as plt
+import numpy as np
 import seaborn as sns
 
 MIN_M = 10
@@ -8,20 +10,23 @@ MAX_M = 100
 MAX_EPS = 40
 
 
-def load(time_stamp: str) -> np.ndarray:
-    with open(f"./data/accuracy/{time_stamp}/statistics.json", "r") as f:
-        data = json.load(f)
-
+def load(path: str) -> np.ndarray:
+    """strategy_searchの結果ファイルから、各(M, ε)の最良の期待スコアを求める"""
     max_scores = np.zeros((MAX_M - MIN_M + 1, MAX_EPS + 1))
 
-    for stat in data:
-        m = stat["m"] - MIN_M
-        eps = int((stat["error_ratio"] * 100) + 0.01)
-        score = stat["expected_score"]
+    with open(path, "r") as f:
+        # 1行目は測定条件
+        f.readline()
+
+        for row in csv.DictReader(f):
+            m = int(row["m"]) - MIN_M
+            eps = int(row["eps"])
+            score = float(row["expected_score"])
 
-        if max_scores[m, eps] < score:
-            max_scores[m, eps] = score
+            if max_scores[m, eps] < score:
+                max_scores[m, eps] = score
 
+    # 調べていないMは、それより大きいMで最も近いものと同じにする
     for m in reversed(range(MIN_M, MAX_M)):
         for eps in range(MAX_EPS + 1):
             if max_scores[m - MIN_M, eps] == 0:
@@ -29,13 +34,15 @@ def load(time_stamp: str) -> np.ndarray:
 
     return max_scores
 
-TIMESTAMP1 = "20221120_024611"
-TIMESTAMP2 = "20221120_124623"
 
-scores1 = load(TIMESTAMP1)
-scores2 = load(TIMESTAMP2)
+if len(sys.argv) != 3:
+    print(f"usage: python {sys.argv[0]} <before.csv> <after.csv>")
+    sys.exit(1)
+
+scores1 = load(sys.argv[1])
+scores2 = load(sys.argv[2])
 
-max_scores = np.maximum(scores1, scores2)
+max_scores = np.maximum(np.maximum(scores1, scores2), 1e-9)
 scores1 /= max_scores
 scores2 /= max_scores
 
@@ -49,4 +56,4 @@ fig, axes = plt.subplots(1, 2, figsize=(20, 7))
 
 sns.heatmap(scores1, ax=axes[0])
 sns.heatmap(scores2, ax=axes[1])
-plt.show()
\ No newline at end of file
+plt.show()
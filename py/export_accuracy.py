@@ -3,23 +3,23 @@ import numpy as np
 TIMESTAMP = "20221119_023835"
 M_LIST = [0, 0, 0, 0, 11, 34, 156]
 
-values = []
-
 for bits in range(4, 7):
     m = M_LIST[bits]
+    output_path = f"./src/encoders/isomorphism/tables/accuracy{bits}.csv"
 
-    for eps in range(0, 41):
-        csv_path = f"./data/sampled/{TIMESTAMP}/{bits}_{eps:02}.csv"
-        matrix = np.zeros((m, m), dtype=np.int32)
+    with open(output_path, "w") as out:
+        out.write("eps,graph,correct\n")
 
-        with open(csv_path, "r") as f:
-            for line in f:
-                truth, answered = map(int, line.split(","))
-                matrix[truth, answered] += 1
+        for eps in range(0, 41):
+            csv_path = f"./data/sampled/{TIMESTAMP}/{bits}_{eps:02}.csv"
+            matrix = np.zeros((m, m), dtype=np.int32)
 
-        diag = np.diag(matrix)
+            with open(csv_path, "r") as f:
+                for line in f:
+                    truth, answered = map(int, line.split(","))
+                    matrix[truth, answered] += 1
 
-        for d in diag:
-            values.append(f"{d:03x}")
+            diag = np.diag(matrix)
 
-print("".join(values))
+            for graph, d in enumerate(diag):
+                out.write(f"{eps},{graph},{d}\n")
@@ -3,23 +3,24 @@ import numpy as np
 TIMESTAMP = "20221119_023835"
 
 for bits in range(4, 7):
-    for eps in range(0, 41):
-        m = [0, 0, 0, 0, 11, 34, 156][bits]
-        CSV_PATH = f"./data/sampled/{TIMESTAMP}/{bits}_{eps:02}.csv"
+    m = [0, 0, 0, 0, 11, 34, 156][bits]
+    OUTPUT_PATH = f"./src/encoders/isomorphism/tables/confusion{bits}.csv"
 
-        matrix = np.zeros((m, m), dtype=np.int32)
+    with open(OUTPUT_PATH, "w") as out:
+        out.write("eps,truth,answered,count\n")
 
-        with open(CSV_PATH, "r") as f:
-            for line in f:
-                truth, answered = map(int, line.split(","))
-                matrix[truth, answered] += 1
+        for eps in range(0, 41):
+            CSV_PATH = f"./data/sampled/{TIMESTAMP}/{bits}_{eps:02}.csv"
 
-        for col in range(0, m):
-            edges = []
-            for row in range(0, m):
-                if matrix[row, col] > 0:
-                    edges.append((row, matrix[row, col]))
+            matrix = np.zeros((m, m), dtype=np.int32)
+
+            with open(CSV_PATH, "r") as f:
+                for line in f:
+                    truth, answered = map(int, line.split(","))
+                    matrix[truth, answered] += 1
 
-            print(f"{len(edges):02x}", end="")
-            for row, count in edges:
-                print(f"{row:02x}{count:03x}", end="")
+            # 0回の組は省略する
+            for row in range(0, m):
+                for col in range(0, m):
+                    if matrix[row, col] > 0:
+                        out.write(f"{eps},{row},{col},{matrix[row, col]}\n")
@@ -0,0 +1,280 @@
+//! 各エンコーダの焼きなましで共通の枠組み
+//!
+//! エンコーダ側は状態（[`State`]）と近傍（[`Neighbor`], [`NeighborGenerator`]）だけを用意し、
+//! 時間管理・温度管理・遷移判定・最良解の記録は[`Annealer`]が行う。
+//! 予算を反復回数で与えると、実行速度によらず同じ乱数シードから同じ結果が得られる
+
+pub mod schedule;
+
+use self::schedule::Schedule;
+use crate::{graph::Graph, utils::ChangeMinMax};
+use rand::prelude::*;
+use rand_pcg::Pcg64Mcg;
+use std::{fmt::Display, time::Instant};
+
+/// 焼きなましの状態
+pub trait State: Clone {
+    /// スコアの型。大きいほど良い
+    type Score: Copy + PartialOrd + Display + Into<f64>;
+
+    fn score(&self) -> Self::Score;
+}
+
+/// 状態を変形する近傍
+pub trait Neighbor<S> {
+    fn apply(&mut self, graph: &Graph, state: &mut S);
+    /// applyで変形した状態を元に戻す
+    fn rollback(&mut self, graph: &Graph, state: &mut S);
+}
+
+/// 現在の状態から近傍を1つ選ぶ
+pub trait NeighborGenerator<S> {
+    fn gen(&self, graph: &Graph, state: &S, rng: &mut Pcg64Mcg) -> Box<dyn Neighbor<S>>;
+}
+
+/// 焼きなましの予算
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Budget {
+    /// 実時間（秒）
+    Time(f64),
+    /// 近傍を試す回数
+    Iterations(usize),
+}
+
+impl Budget {
+    /// iterationsが指定されていれば反復回数、なければduration秒の予算
+    pub fn new(duration: f64, iterations: Option<usize>) -> Self {
+        match iterations {
+            Some(iterations) => Budget::Iterations(iterations),
+            None => Budget::Time(duration),
+        }
+    }
+}
+
+/// 焼きなましの統計情報
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnnealingStats<T> {
+    /// 初期解のスコア
+    pub initial_score: T,
+    /// 最良解のスコア
+    pub best_score: T,
+    /// 時間計測を含む全てのループ回数
+    pub all_iter: usize,
+    /// 近傍を試した回数
+    pub valid_iter: usize,
+    /// 遷移を受理した回数
+    pub accepted_count: usize,
+    /// 最良解を更新した回数
+    pub update_count: usize,
+}
+
+impl<T: Display> Display for AnnealingStats<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "===== annealing =====")?;
+        writeln!(f, "init score : {}", self.initial_score)?;
+        writeln!(f, "score      : {}", self.best_score)?;
+        writeln!(f, "all iter   : {}", self.all_iter)?;
+        writeln!(f, "valid iter : {}", self.valid_iter)?;
+        writeln!(f, "accepted   : {}", self.accepted_count)?;
+        writeln!(f, "updated    : {}", self.update_count)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Annealer<T: Schedule> {
+    schedule: T,
+    /// 時間計測の間隔から1を引いたもの（間隔は2の冪）
+    check_mask: usize,
+    verbose: bool,
+}
+
+impl<T: Schedule> Annealer<T> {
+    /// 時間計測の間隔の既定値
+    const DEFAULT_CHECK_INTERVAL: usize = 1 << 4;
+
+    pub fn new(schedule: T, verbose: bool) -> Self {
+        Self {
+            schedule,
+            check_mask: Self::DEFAULT_CHECK_INTERVAL - 1,
+            verbose,
+        }
+    }
+
+    /// 進捗の計測と温度の更新をinterval回（2の冪）に1回にする
+    ///
+    /// 1回の遷移が軽いほど大きくしないと時間計測が重くなる
+    pub fn with_check_interval(mut self, interval: usize) -> Self {
+        assert!(interval.is_power_of_two());
+        self.check_mask = interval - 1;
+        self
+    }
+
+    /// 予算を使い切るまで焼きなましを行い、最良解と統計情報を返す
+    pub fn run<S: State>(
+        &self,
+        graph: &Graph,
+        initial_solution: S,
+        generator: &impl NeighborGenerator<S>,
+        budget: Budget,
+        rng: &mut Pcg64Mcg,
+    ) -> (S, AnnealingStats<S::Score>) {
+        let mut solution = initial_solution;
+        let mut best_solution = solution.clone();
+        let mut current_score = solution.score();
+        let mut stats = AnnealingStats {
+            initial_score: current_score,
+            best_score: current_score,
+            all_iter: 0,
+            valid_iter: 0,
+            accepted_count: 0,
+            update_count: 0,
+        };
+
+        let since = Instant::now();
+        let mut inv_temp = 1.0 / self.schedule.temperature(0.0);
+
+        loop {
+            stats.all_iter += 1;
+
+            // 反復回数はちょうど使い切る
+            if let Budget::Iterations(iterations) = budget {
+                if stats.valid_iter >= iterations {
+                    break;
+                }
+            }
+
+            if (stats.all_iter & self.check_mask) == 0 {
+                let progress = match budget {
+                    Budget::Time(duration) => (Instant::now() - since).as_secs_f64() / duration,
+                    Budget::Iterations(iterations) => stats.valid_iter as f64 / iterations as f64,
+                };
+
+                if progress >= 1.0 {
+                    break;
+                }
+
+                inv_temp = 1.0 / self.schedule.temperature(progress);
+            }
+
+            // 変形
+            let mut neighbor = generator.gen(graph, &solution, rng);
+            neighbor.apply(graph, &mut solution);
+
+            // スコア計算
+            let new_score = solution.score();
+            let score_diff = new_score.into() - current_score.into();
+
+            if score_diff >= 0.0 || rng.gen_bool(f64::exp(score_diff * inv_temp)) {
+                // 解の更新
+                current_score = new_score;
+                stats.accepted_count += 1;
+
+                if stats.best_score.change_max(current_score) {
+                    best_solution = solution.clone();
+                    stats.update_count += 1;
+                }
+            } else {
+                neighbor.rollback(graph, &mut solution);
+            }
+
+            stats.valid_iter += 1;
+        }
+
+        if self.verbose {
+            eprintln!("{}", stats);
+        }
+
+        (best_solution, stats)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{schedule::Exponential, Annealer, Budget, Neighbor, NeighborGenerator, State};
+    use crate::graph::Graph;
+    use rand::prelude::*;
+    use rand_pcg::Pcg64Mcg;
+
+    /// 10に近いほど良い整数
+    #[derive(Debug, Clone)]
+    struct Number(i32);
+
+    impl State for Number {
+        type Score = i32;
+
+        fn score(&self) -> i32 {
+            -(self.0 - 10).abs()
+        }
+    }
+
+    struct Step(i32);
+
+    impl Neighbor<Number> for Step {
+        fn apply(&mut self, _graph: &Graph, state: &mut Number) {
+            state.0 += self.0;
+        }
+
+        fn rollback(&mut self, _graph: &Graph, state: &mut Number) {
+            state.0 -= self.0;
+        }
+    }
+
+    struct StepGenerator;
+
+    impl NeighborGenerator<Number> for StepGenerator {
+        fn gen(
+            &self,
+            _graph: &Graph,
+            _state: &Number,
+            rng: &mut Pcg64Mcg,
+        ) -> Box<dyn Neighbor<Number>> {
+            Box::new(Step(if rng.gen_bool(0.5) { 1 } else { -1 }))
+        }
+    }
+
+    #[test]
+    fn run_test() {
+        let annealer =
+            Annealer::new(Exponential::new(1e0, 1e-2), false).with_check_interval(1 << 8);
+        let mut rng = Pcg64Mcg::new(42);
+        let (best, stats) = annealer.run(
+            &Graph::new(0),
+            Number(-30),
+            &StepGenerator,
+            Budget::Time(0.01),
+            &mut rng,
+        );
+
+        assert_eq!(best.0, 10);
+        assert_eq!(stats.initial_score, -40);
+        assert_eq!(stats.best_score, 0);
+        assert!(stats.update_count >= 40);
+        assert!(stats.accepted_count >= stats.update_count);
+        assert!(stats.valid_iter >= stats.accepted_count);
+    }
+
+    #[test]
+    fn iterations_test() {
+        let annealer = Annealer::new(Exponential::new(1e1, 1e-1), false);
+        let run = |seed| {
+            let mut rng = Pcg64Mcg::new(seed);
+            annealer.run(
+                &Graph::new(0),
+                Number(-30),
+                &StepGenerator,
+                Budget::Iterations(1000),
+                &mut rng,
+            )
+        };
+
+        // 反復回数が同じなら、同じシードからは同じ結果になる
+        let (best0, stats0) = run(0);
+        let (best1, stats1) = run(0);
+        assert_eq!(best0.0, best1.0);
+        assert_eq!(stats0, stats1);
+        assert_eq!(stats0.valid_iter, 1000);
+
+        assert_eq!(Budget::new(1.0, Some(100)), Budget::Iterations(100));
+        assert_eq!(Budget::new(1.0, None), Budget::Time(1.0));
+    }
+}
@@ -0,0 +1,60 @@
+//! 焼きなましの温度スケジュール
+
+/// 進捗（0.0から1.0）から温度を決める
+pub trait Schedule {
+    fn temperature(&self, progress: f64) -> f64;
+}
+
+/// temp0からtemp1まで指数関数的に下げる
+#[derive(Debug, Clone, Copy)]
+pub struct Exponential {
+    temp0: f64,
+    temp1: f64,
+}
+
+impl Exponential {
+    pub fn new(temp0: f64, temp1: f64) -> Self {
+        Self { temp0, temp1 }
+    }
+}
+
+impl Schedule for Exponential {
+    fn temperature(&self, progress: f64) -> f64 {
+        f64::powf(self.temp0, 1.0 - progress) * f64::powf(self.temp1, progress)
+    }
+}
+
+/// temp0からtemp1まで線形に下げる
+#[derive(Debug, Clone, Copy)]
+pub struct Linear {
+    temp0: f64,
+    temp1: f64,
+}
+
+impl Linear {
+    pub fn new(temp0: f64, temp1: f64) -> Self {
+        Self { temp0, temp1 }
+    }
+}
+
+impl Schedule for Linear {
+    fn temperature(&self, progress: f64) -> f64 {
+        self.temp0 + (self.temp1 - self.temp0) * progress
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Exponential, Linear, Schedule};
+
+    #[test]
+    fn temperature_test() {
+        let exponential = Exponential::new(1e2, 1e0);
+        assert!((exponential.temperature(0.0) - 1e2).abs() < 1e-9);
+        assert!((exponential.temperature(0.5) - 1e1).abs() < 1e-9);
+        assert!((exponential.temperature(1.0) - 1e0).abs() < 1e-9);
+
+        let linear = Linear::new(1e2, 0.0);
+        assert!((linear.temperature(0.25) - 75.0).abs() < 1e-9);
+    }
+}
@@ -1,4 +1,7 @@
+use ahc016::{graph::Graph, noise::NoiseChannel};
+use itertools::Itertools;
 use rand::prelude::*;
+use rand_pcg::Pcg64Mcg;
 
 const M: usize = 4;
 const BITS: usize = 7;
@@ -22,13 +25,7 @@ fn main() {
 
     // ノイズ付与
     let mut rng = Pcg64Mcg::new(42);
-    for i in 0..N {
-        for j in (i + 1)..N {
-            if rng.gen_bool(NOISE_PROB) {
-                graph.filp(i, j);
-            }
-        }
-    }
+    let graph = NoiseChannel::new(NOISE_PROB).flip_edges(&graph, &mut rng);
 
     println!("--------------------------------------------");
     println!("{}", &graph);
@@ -51,7 +48,7 @@ fn main() {
 
         for i in 0..N {
             for j in (i + 1)..N {
-                if graph[state[i]][state[j]] {
+                if graph.has_edge(state[i], state[j]) {
                     new_graph.connect(i, j);
                 }
             }
@@ -67,7 +64,7 @@ fn main() {
                 let i = k * M + i;
 
                 for j in (i + 1)..N {
-                    if graph[state[i]][state[j]] {
+                    if graph.has_edge(state[i], state[j]) {
                         s += 1;
                     } else {
                         s -= 1;
@@ -176,7 +173,7 @@ fn calc_score(graph: &Graph, state: &[usize]) -> i64 {
         for col in (row + 1)..N {
             let j = state[col];
 
-            if graph[i][j] {
+            if graph.has_edge(i, j) {
                 s += 1;
             } else {
                 s -= 1;
@@ -188,86 +185,3 @@ fn calc_score(graph: &Graph, state: &[usize]) -> i64 {
 
     score
 }
-
-use std::ops::Index;
-
-use itertools::Itertools;
-use rand::seq::SliceRandom;
-use rand_pcg::Pcg64Mcg;
-
-#[derive(Debug, Clone)]
-pub struct Graph {
-    pub n: usize,
-    edges: Vec<Vec<bool>>,
-}
-
-impl Graph {
-    pub fn new(n: usize) -> Self {
-        Self {
-            n,
-            edges: vec![vec![false; n]; n],
-        }
-    }
-
-    pub fn connect(&mut self, u: usize, v: usize) {
-        self.edges[u][v] = true;
-        self.edges[v][u] = true;
-    }
-
-    pub fn filp(&mut self, u: usize, v: usize) {
-        self.edges[u][v] ^= true;
-        self.edges[v][u] ^= true;
-    }
-
-    pub fn deserialize(str: &str, n: usize) -> Self {
-        let mut edges = vec![vec![false; n]; n];
-        let mut chars = str.chars();
-
-        for row in 0..n {
-            for col in (row + 1)..n {
-                if chars.next().unwrap() == '1' {
-                    edges[row][col] = true;
-                    edges[col][row] = true;
-                }
-            }
-        }
-
-        Self { n, edges }
-    }
-
-    pub fn serialize(&self) -> String {
-        let mut s = vec![];
-
-        for row in 0..self.n {
-            for col in (row + 1)..self.n {
-                let c = if self.edges[row][col] { '1' } else { '0' };
-                s.push(c);
-            }
-        }
-
-        s.iter().collect()
-    }
-}
-
-impl std::fmt::Display for Graph {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in 0..self.n {
-            for col in 0..self.n {
-                let c = if self.edges[row][col] { '#' } else { '.' };
-                write!(f, "{}", c)?;
-            }
-
-            writeln!(f)?;
-        }
-
-        Ok(())
-    }
-}
-
-impl Index<usize> for Graph {
-    type Output = [bool];
-
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.edges[index]
-    }
-}
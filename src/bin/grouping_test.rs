@@ -1,8 +1,8 @@
+use ahc016::{graph::Graph, noise::NoiseChannel};
 use itertools::Itertools;
 use rand::prelude::*;
 use rand::seq::SliceRandom;
 use rand_pcg::Pcg64Mcg;
-use std::ops::Index;
 
 const WIDTH: usize = 16;
 const BITS: usize = 6;
@@ -24,7 +24,7 @@ fn main() {
             let u = state[i / WIDTH][i % WIDTH];
             let v = state[j / WIDTH][j % WIDTH];
 
-            if graph[u][v] {
+            if graph.has_edge(u, v) {
                 new_graph.connect(i, j);
             }
         }
@@ -61,13 +61,7 @@ fn gen_graph() -> Graph {
 
     // ノイズ付与
     let mut rng = Pcg64Mcg::new(42);
-    for i in 0..N {
-        for j in (i + 1)..N {
-            if rng.gen_bool(ERROR_RATIO) {
-                graph.flip(i, j);
-            }
-        }
-    }
+    let graph = NoiseChannel::new(ERROR_RATIO).flip_edges(&graph, &mut rng);
 
     println!();
     println!("{}", graph);
@@ -107,7 +101,7 @@ fn calc_score(graph: &Graph, state: &State) -> i32 {
             for j in (i + 1)..group.len() {
                 let v = group[j];
 
-                if graph[u][v] {
+                if graph.has_edge(u, v) {
                     sum += 1;
                 }
             }
@@ -124,7 +118,7 @@ fn calc_score(graph: &Graph, state: &State) -> i32 {
 
             for &u in state[g1].iter() {
                 for &v in state[g2].iter() {
-                    if graph[u][v] {
+                    if graph.has_edge(u, v) {
                         sum += 1;
                     } else {
                         sum -= 1;
@@ -212,80 +206,3 @@ fn annealing(graph: &Graph, initial_solution: State, duration: f64) -> State {
 
     best_solution
 }
-
-#[derive(Debug, Clone)]
-pub struct Graph {
-    pub n: usize,
-    edges: Vec<Vec<bool>>,
-}
-
-impl Graph {
-    pub fn new(n: usize) -> Self {
-        Self {
-            n,
-            edges: vec![vec![false; n]; n],
-        }
-    }
-
-    pub fn connect(&mut self, u: usize, v: usize) {
-        self.edges[u][v] = true;
-        self.edges[v][u] = true;
-    }
-
-    pub fn flip(&mut self, u: usize, v: usize) {
-        self.edges[u][v] ^= true;
-        self.edges[v][u] ^= true;
-    }
-
-    pub fn deserialize(str: &str, n: usize) -> Self {
-        let mut edges = vec![vec![false; n]; n];
-        let mut chars = str.chars();
-
-        for row in 0..n {
-            for col in (row + 1)..n {
-                if chars.next().unwrap() == '1' {
-                    edges[row][col] = true;
-                    edges[col][row] = true;
-                }
-            }
-        }
-
-        Self { n, edges }
-    }
-
-    pub fn serialize(&self) -> String {
-        let mut s = vec![];
-
-        for row in 0..self.n {
-            for col in (row + 1)..self.n {
-                let c = if self.edges[row][col] { '1' } else { '0' };
-                s.push(c);
-            }
-        }
-
-        s.iter().collect()
-    }
-}
-
-impl std::fmt::Display for Graph {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in 0..self.n {
-            for col in 0..self.n {
-                let c = if self.edges[row][col] { '#' } else { '.' };
-                write!(f, "{}", c)?;
-            }
-
-            writeln!(f)?;
-        }
-
-        Ok(())
-    }
-}
-
-impl Index<usize> for Graph {
-    type Output = [bool];
-
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.edges[index]
-    }
-}
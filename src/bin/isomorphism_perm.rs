@@ -1,4 +1,5 @@
 use std::{
+    collections::HashSet,
     ops::Index,
     time::{Duration, Instant},
 };
@@ -11,8 +12,10 @@ const EDGE_COUNTS: usize = N * (N - 1) / 2;
 fn main() {
     let graphs_perm = gen_isomorphisms_perm();
     let graphs_vf2 = gen_isomorphisms_vf2();
+    let graphs_canonical = gen_isomorphisms_canonical();
 
     assert_eq!(graphs_perm, graphs_vf2);
+    assert_eq!(graphs_perm, graphs_canonical);
 
     println!("counts: {}", graphs_perm.len());
 
@@ -66,6 +69,32 @@ fn gen_isomorphisms_vf2() -> Vec<(usize, Graph)> {
     graphs
 }
 
+/// 標準形のハッシュで重複を判定する
+fn gen_isomorphisms_canonical() -> Vec<(usize, Graph)> {
+    let mut duration = Duration::ZERO;
+    let mut graphs = vec![];
+    let mut forms = HashSet::new();
+
+    for bits in 0..(1 << EDGE_COUNTS) {
+        let graph = gen_graph(bits);
+        let since = Instant::now();
+        let form = ahc016::graph::Graph::deserialize(&graph.serialize(), N)
+            .unwrap()
+            .canonical_form();
+        let found = !forms.insert(form);
+        let until = Instant::now();
+        duration += until - since;
+
+        if !found {
+            graphs.push((bits, graph));
+        }
+    }
+
+    println!("{}s", duration.as_secs_f64());
+
+    graphs
+}
+
 fn gen_graph(bits: usize) -> Graph {
     let mut graph = Graph::new(N);
     let mut index = 0;
@@ -0,0 +1,464 @@
+//! 同型エンコーダの戦略 (bits, redundancy, score_coef) を(M, ε)ごとに探索する
+//!
+//! 各設定でクエリをプロセス内でシミュレートして正解率を測り、期待スコアを結果ファイルに1行ずつ追記する。
+//! 途中で中断しても、同じ結果ファイルを指定して再実行すれば測定済みの設定は飛ばして再開できる。
+//! 結果ファイルの1行目には測定条件を書いておき、条件が異なるときは再開せずに終了する。
+//! 最後に結果ファイル全体から各(M, ε)の最良の戦略を選び、strategy.csvと同じ形式で書き出す
+//!
+//! (bits, M, score_coef)ごとにεを小さい方から順に調べ、redundancyは前のεの最良値から
+//! 期待スコアが下がるまで増やしていく
+
+use ahc016::{
+    encoders::{
+        isomorphism::checker::try_generate_isompic_graphs, EncoderKind, EncoderOptions, Prediction,
+        MAX_N,
+    },
+    logger,
+    noise::parse_noise_model,
+    simulator::{simulate_with_noise, InputSpec},
+};
+use clap::Parser;
+use itertools::{iproduct, Itertools};
+use std::{
+    collections::HashMap,
+    fs::{self, File, OpenOptions},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+};
+
+const MIN_M: usize = 10;
+const MAX_M: usize = 100;
+const MAX_EPS: usize = 40;
+/// score_coefがこれ以上の戦略は戦略表に採用しない
+const MAX_SCORE_COEF: f64 = 2.6;
+const HEADER: &str =
+    "m,eps,bits,redundancy,score_coef,graph_size,trial_count,accepted,accuracy,expected_score";
+
+#[derive(Parser, Debug)]
+struct AppArg {
+    /// 測定結果を追記するCSV。既にあれば測定済みの設定を飛ばして再開する
+    #[clap(short = 'o', long = "output", default_value = "strategy_search.csv")]
+    output: PathBuf,
+    /// 各(M, ε)の最良の戦略の書き出し先（strategy.csvと同じ形式）
+    #[clap(long = "best", default_value = "best_strategy.csv")]
+    best: PathBuf,
+    /// 調べるbits
+    #[clap(
+        short = 'b',
+        long = "bits",
+        value_delimiter = ',',
+        default_value = "4,5,6"
+    )]
+    bits: Vec<usize>,
+    /// 調べるM。間のMの戦略は、それより大きいMで最も近いものと同じにする
+    #[clap(
+        short = 'm',
+        long = "graph-counts",
+        value_delimiter = ',',
+        default_value = "10,11,20,25,28,30,31,32,33,34,40,50,60,70,80,90,100"
+    )]
+    graph_counts: Vec<usize>,
+    /// 調べるscore_coef
+    #[clap(
+        short = 's',
+        long = "score-coefs",
+        value_delimiter = ',',
+        default_value = "1.25,1.5,2.0"
+    )]
+    score_coefs: Vec<f64>,
+    /// 1設定あたりにシミュレートするクエリの数
+    #[clap(short = 't', long = "trial-count", default_value_t = 1500)]
+    trial_count: usize,
+    /// クエリ100回分の制限時間（秒）
+    #[clap(long = "time-limit", default_value_t = 5.0)]
+    time_limit: f64,
+    /// 焼きなまし1回あたりの反復回数。指定すると持ち時間によらず結果が再現できる
+    #[clap(long = "iterations")]
+    iterations: Option<usize>,
+    /// ノイズモデル（iid, asym:p01,p10, burst:ratio,burst_eps, delete:count, dup:count）
+    #[clap(short = 'n', long = "noise", default_value = "iid")]
+    noise: String,
+    /// 並列に測定する設定の数
+    #[clap(long = "threads", default_value_t = 1)]
+    threads: usize,
+    /// 送信するグラフと頂点の並べ替え・ノイズの乱数シード
+    #[clap(long = "seed", default_value_t = 42)]
+    seed: u64,
+}
+
+/// 1つの設定の測定結果
+#[derive(Debug, Clone, Copy)]
+struct Statistics {
+    m: usize,
+    eps: usize,
+    bits: usize,
+    redundancy: usize,
+    score_coef: f64,
+    /// グラフの大きさN
+    graph_size: usize,
+    trial_count: usize,
+    accepted: usize,
+}
+
+impl Statistics {
+    fn key(&self) -> Key {
+        (
+            self.m,
+            self.eps,
+            self.bits,
+            self.redundancy,
+            self.score_coef.to_bits(),
+        )
+    }
+
+    fn accuracy(&self) -> f64 {
+        self.accepted as f64 / self.trial_count.max(1) as f64
+    }
+
+    fn expected_score(&self) -> f64 {
+        Prediction::new(self.graph_size, self.accuracy()).expected_score()
+    }
+
+    /// 結果ファイルの1行を読む。正解率と期待スコアは他の列から求め直す
+    fn parse(line: &str) -> Option<Self> {
+        let cols = line.split(',').map(|c| c.trim()).collect::<Vec<_>>();
+
+        if cols.len() != HEADER.split(',').count() {
+            return None;
+        }
+
+        Some(Self {
+            m: cols[0].parse().ok()?,
+            eps: cols[1].parse().ok()?,
+            bits: cols[2].parse().ok()?,
+            redundancy: cols[3].parse().ok()?,
+            score_coef: cols[4].parse().ok()?,
+            graph_size: cols[5].parse().ok()?,
+            trial_count: cols[6].parse().ok()?,
+            accepted: cols[7].parse().ok()?,
+        })
+    }
+
+    /// 結果ファイルの1行
+    fn row(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{:.6},{:.0}",
+            self.m,
+            self.eps,
+            self.bits,
+            self.redundancy,
+            self.score_coef,
+            self.graph_size,
+            self.trial_count,
+            self.accepted,
+            self.accuracy(),
+            self.expected_score()
+        )
+    }
+}
+
+/// (M, ε, bits, redundancy, score_coefのビット列)
+type Key = (usize, usize, usize, usize, u64);
+
+impl AppArg {
+    /// 結果ファイルの1行目に書く測定条件。これが同じ結果ファイルからしか再開しない
+    fn conditions(&self) -> String {
+        let iterations = self
+            .iterations
+            .map_or("none".to_string(), |it| it.to_string());
+        format!(
+            "# trial_count={} noise={} seed={} iterations={} time_limit={}",
+            self.trial_count, self.noise, self.seed, iterations, self.time_limit
+        )
+    }
+}
+
+fn main() {
+    let args = AppArg::parse();
+    let conditions = args.conditions();
+    logger::init(logger::ERROR, None).unwrap();
+
+    if let Err(err) = parse_noise_model(&args.noise, 0.0) {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+
+    let done = load_results(&args.output, &conditions).unwrap_or_else(|e| {
+        eprintln!("failed to read {}: {}", args.output.display(), e);
+        std::process::exit(1)
+    });
+    eprintln!("{} settings already measured", done.len());
+
+    let mut writer = open_output(&args.output, &conditions).unwrap_or_else(|e| {
+        eprintln!("failed to open {}: {}", args.output.display(), e);
+        std::process::exit(1)
+    });
+
+    // (bits, M, score_coef) ごとに、εについて順に調べる
+    let chains = iproduct!(
+        args.bits.iter().copied(),
+        args.graph_counts.iter().copied(),
+        args.score_coefs.iter().copied()
+    )
+    .filter(|&(bits, m, _)| {
+        (MIN_M..=MAX_M).contains(&m) && try_generate_isompic_graphs(m, 0.0, bits).is_some()
+    })
+    .collect::<Vec<_>>();
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    let mut results = done.values().copied().collect::<Vec<_>>();
+
+    std::thread::scope(|scope| {
+        for _ in 0..args.threads.max(1) {
+            let sender = sender.clone();
+            let (args, chains, next, done) = (&args, &chains, &next, &done);
+
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+
+                let (bits, m, score_coef) = match chains.get(index) {
+                    Some(&chain) => chain,
+                    None => break,
+                };
+
+                search_chain(args, bits, m, score_coef, done, |stats| {
+                    sender.send(stats).unwrap();
+                });
+            });
+        }
+
+        // 全てのスレッドが終わるとチャネルが閉じる
+        drop(sender);
+
+        for stats in receiver.iter() {
+            println!("{}", stats.row());
+            writeln!(writer, "{}", stats.row()).unwrap();
+            writer.flush().unwrap();
+            results.push(stats);
+        }
+    });
+
+    let best = select_best(&results);
+    let missing = best.iter().flatten().filter(|s| s.is_none()).count();
+
+    if missing > 0 {
+        eprintln!("warning: no strategy for {} (M, eps) cells", missing);
+    }
+
+    if let Err(e) = write_best(&args.best, &best) {
+        eprintln!("failed to write {}: {}", args.best.display(), e);
+        std::process::exit(1);
+    }
+}
+
+/// (bits, M, score_coef) を固定し、εごとに最良のredundancyを探す
+///
+/// 測定済みの設定は測り直さずに結果を使う。新しく測った結果はon_measuredに渡す
+fn search_chain(
+    args: &AppArg,
+    bits: usize,
+    m: usize,
+    score_coef: f64,
+    done: &HashMap<Key, Statistics>,
+    mut on_measured: impl FnMut(Statistics),
+) {
+    let mut min_redundancy = 1;
+
+    for eps in 0..=MAX_EPS {
+        let mut best_score = 0.0;
+        let mut redundancy = min_redundancy;
+
+        while redundancy * bits <= MAX_N {
+            let key = (m, eps, bits, redundancy, score_coef.to_bits());
+            let stats = match done.get(&key) {
+                Some(&stats) => stats,
+                None => {
+                    let stats = measure(args, m, eps, bits, redundancy, score_coef);
+                    on_measured(stats);
+                    stats
+                }
+            };
+
+            let score = stats.expected_score();
+
+            if score <= best_score {
+                break;
+            }
+
+            best_score = score;
+            min_redundancy = redundancy;
+
+            // 次で全問正解しても超えられなければ打ち切る
+            let upper = Prediction::new(bits * (redundancy + 1), 1.0).expected_score();
+
+            if score > upper {
+                break;
+            }
+
+            redundancy += 1;
+        }
+    }
+}
+
+/// 1つの設定でクエリをシミュレートし、正解数を数える
+fn measure(
+    args: &AppArg,
+    m: usize,
+    eps: usize,
+    bits: usize,
+    redundancy: usize,
+    score_coef: f64,
+) -> Statistics {
+    let error_ratio = eps as f64 / 100.0;
+    let options = EncoderOptions {
+        bits: Some(bits),
+        redundancy: Some(redundancy),
+        score_coef: Some(score_coef),
+        iterations: args.iterations,
+        ..Default::default()
+    };
+    let encoder = EncoderKind::Isomorphism.create(m, error_ratio, &options);
+    let noise = parse_noise_model(&args.noise, error_ratio).unwrap();
+    let spec = InputSpec::random(m, error_ratio, args.trial_count, args.seed);
+
+    // Nが範囲外なら1問も正解できなかったものとする
+    let accepted =
+        match simulate_with_noise(encoder.as_ref(), &spec, noise.as_ref(), args.time_limit) {
+            Ok(result) => args.trial_count - result.errors,
+            Err(_) => 0,
+        };
+
+    Statistics {
+        m,
+        eps,
+        bits,
+        redundancy,
+        score_coef,
+        graph_size: encoder.graph_size(),
+        trial_count: args.trial_count,
+        accepted,
+    }
+}
+
+/// 結果ファイルを読み込む。中断で途中までしか書かれなかった行は捨てる
+///
+/// 測定条件がconditionsと異なるファイルは、混ぜると期待スコアを比べられなくなるのでエラーにする
+fn load_results(path: &Path, conditions: &str) -> std::io::Result<HashMap<Key, Statistics>> {
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+
+    let text = fs::read_to_string(path)?;
+    let mut lines = text.split_inclusive('\n').collect::<Vec<_>>();
+
+    if lines.last().is_some_and(|line| !line.ends_with('\n')) {
+        lines.pop();
+    }
+
+    let written = lines.first().map_or("", |line| line.trim_end());
+
+    if written != conditions {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "measured under different conditions (file: \"{}\", args: \"{}\"). use another --output",
+                written, conditions
+            ),
+        ));
+    }
+
+    let results = lines
+        .iter()
+        .skip(2)
+        .filter_map(|line| Statistics::parse(line))
+        .map(|stats| (stats.key(), stats))
+        .collect::<HashMap<_, _>>();
+
+    // 壊れた行を除いて書き直しておく
+    let mut writer = BufWriter::new(File::create(path)?);
+    writeln!(writer, "{}", conditions)?;
+    writeln!(writer, "{}", HEADER)?;
+
+    for stats in results.values().sorted_by_key(|stats| stats.key()) {
+        writeln!(writer, "{}", stats.row())?;
+    }
+
+    writer.flush()?;
+    Ok(results)
+}
+
+fn open_output(path: &Path, conditions: &str) -> std::io::Result<BufWriter<File>> {
+    let exists = path.exists();
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut writer = BufWriter::new(file);
+
+    if !exists {
+        writeln!(writer, "{}", conditions)?;
+        writeln!(writer, "{}", HEADER)?;
+        writer.flush()?;
+    }
+
+    Ok(writer)
+}
+
+/// [M - MIN_M][ε] = 期待スコアが最大の測定結果
+///
+/// 測っていないMは、それより大きいMで最も近いものの戦略を使う
+fn select_best(results: &[Statistics]) -> Vec<Vec<Option<Statistics>>> {
+    let mut best: Vec<Vec<Option<Statistics>>> = vec![vec![None; MAX_EPS + 1]; MAX_M - MIN_M + 1];
+
+    for stats in results.iter() {
+        if stats.score_coef >= MAX_SCORE_COEF
+            || !(MIN_M..=MAX_M).contains(&stats.m)
+            || stats.eps > MAX_EPS
+        {
+            continue;
+        }
+
+        let cell = &mut best[stats.m - MIN_M][stats.eps];
+
+        if cell.is_none_or(|b| b.expected_score() < stats.expected_score()) {
+            *cell = Some(*stats);
+        }
+    }
+
+    for m in (0..(MAX_M - MIN_M)).rev() {
+        let (smaller, larger) = best.split_at_mut(m + 1);
+
+        for (cell, &next) in smaller[m].iter_mut().zip(larger[0].iter()) {
+            if cell.is_none() {
+                *cell = next;
+            }
+        }
+    }
+
+    best
+}
+
+fn write_best(path: &Path, best: &[Vec<Option<Statistics>>]) -> std::io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    writeln!(writer, "m,eps,bits,redundancy,score_coef")?;
+
+    for (m, line) in best.iter().enumerate() {
+        for (eps, stats) in line.iter().enumerate() {
+            if let Some(stats) = stats {
+                writeln!(
+                    writer,
+                    "{},{},{},{},{}",
+                    m + MIN_M,
+                    eps,
+                    stats.bits,
+                    stats.redundancy,
+                    stats.score_coef
+                )?;
+            }
+        }
+    }
+
+    writer.flush()
+}
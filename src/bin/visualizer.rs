@@ -0,0 +1,64 @@
+use ahc016::{
+    encoders::{isomorphism::IsomorphismEncoder, Encoder, EncoderOptions},
+    noise::parse_noise_model,
+    time_manager::QueryBudget,
+    visualizer::{draw_matrix, draw_matrix_with_groups, draw_node_link},
+};
+use clap::Parser;
+use rand::prelude::*;
+use std::path::PathBuf;
+
+/// 1クエリ分の送信グラフ・ノイズ付きグラフ・焼きなまし後の並べ替えをSVGで出力する
+#[derive(Parser, Debug)]
+struct AppArg {
+    #[clap(short = 'm', long = "graph-count")]
+    m: usize,
+    #[clap(short = 'e', long = "eps")]
+    eps: f64,
+    /// 送信するグラフの番号
+    #[clap(short = 'i', long = "index", default_value = "0")]
+    index: usize,
+    #[clap(short = 's', long = "seed", default_value = "42")]
+    seed: u64,
+    /// 焼きなましの実行時間（秒）
+    #[clap(short = 't', long = "duration", default_value = "0.05")]
+    duration: f64,
+    #[clap(short = 'd', long = "dir", default_value = "vis")]
+    dir: PathBuf,
+    /// ノイズモデル（iid, asym:p01,p10, burst:ratio,burst_eps, delete:count, dup:count）
+    #[clap(short = 'n', long = "noise", default_value = "iid")]
+    noise: String,
+}
+
+fn main() {
+    let args = AppArg::parse();
+    let encoder = IsomorphismEncoder::new(args.m, args.eps, &EncoderOptions::default());
+    let original = encoder.encode(args.index);
+
+    let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(args.seed);
+    let noise = parse_noise_model(&args.noise, args.eps).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1)
+    });
+    let (noisy, _) = noise.transmit(&original, &mut rng);
+
+    let groups = encoder.group_vertices(&noisy, args.duration, 0);
+    let decoded = encoder.decode(&noisy, &mut QueryBudget::fixed(args.duration));
+    eprintln!("expected: {}", args.index);
+    eprintln!("decoded : {}", decoded);
+
+    if !args.dir.exists() {
+        std::fs::create_dir(&args.dir).unwrap();
+    }
+
+    let save = |name: &str, doc: &svg::Document| {
+        let path = args.dir.join(name);
+        svg::save(&path, doc).unwrap();
+        eprintln!("saved   : {}", path.display());
+    };
+
+    save("original.svg", &draw_matrix(&original));
+    save("noisy.svg", &draw_matrix(&noisy));
+    save("restored.svg", &draw_matrix_with_groups(&noisy, &groups));
+    save("restored_graph.svg", &draw_node_link(&noisy, Some(&groups)));
+}
@@ -1,15 +1,291 @@
-#[allow(dead_code)]
 pub mod barcode;
-#[allow(dead_code)]
 pub mod binomial;
-#[allow(dead_code)]
 pub mod clique;
 pub mod isomorphism;
 
-use crate::graph::Graph;
+use self::{
+    barcode::BarCodeEncoder, binomial::BinomialEncoder, clique::CliqueEncoder,
+    isomorphism::IsomorphismEncoder,
+};
+use crate::{graph::Graph, time_manager::QueryBudget, utils::ChangeMinMax};
+use std::ops::Range;
 
 pub trait Encoder {
     fn graph_size(&self) -> usize;
     fn encode(&self, index: usize) -> Graph;
-    fn decode(&self, graph: &Graph, duration: f64) -> usize;
+
+    /// 受信したグラフが各グラフである確率を返す（和は1）
+    ///
+    /// 復号に自信が持てない場合はbudgetに延長を求めてよい
+    fn decode_ranked(&self, graph: &Graph, budget: &mut QueryBudget) -> Vec<f64>;
+
+    /// 最も確率の高いグラフの番号を返す（同率なら番号の小さい方）
+    fn decode(&self, graph: &Graph, budget: &mut QueryBudget) -> usize {
+        argmax(&self.decode_ranked(graph, budget))
+    }
+}
+
+/// 問題のクエリ数
+pub const QUERY_COUNT: usize = 100;
+/// 問題で許されるグラフの頂点数の下限
+pub const MIN_N: usize = 4;
+/// 問題で許されるグラフの頂点数の上限
+pub const MAX_N: usize = 100;
+
+/// エンコーダの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncoderKind {
+    Isomorphism,
+    BarCode,
+    Binomial,
+    Clique,
+}
+
+impl EncoderKind {
+    pub const ALL: [EncoderKind; 4] = [
+        EncoderKind::Isomorphism,
+        EncoderKind::BarCode,
+        EncoderKind::Binomial,
+        EncoderKind::Clique,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EncoderKind::Isomorphism => "isomorphism",
+            EncoderKind::BarCode => "barcode",
+            EncoderKind::Binomial => "binomial",
+            EncoderKind::Clique => "clique",
+        }
+    }
+
+    /// (M, ε)に対する正解率とグラフの大きさを予測する
+    pub fn predict(&self, graph_count: usize, error_ratio: f64) -> Prediction {
+        match self {
+            EncoderKind::Isomorphism => IsomorphismEncoder::predict(graph_count, error_ratio),
+            EncoderKind::BarCode => BarCodeEncoder::predict(graph_count, error_ratio),
+            EncoderKind::Binomial => BinomialEncoder::predict(graph_count, error_ratio),
+            EncoderKind::Clique => CliqueEncoder::predict(graph_count, error_ratio),
+        }
+    }
+
+    /// エンコーダを生成する
+    pub fn create(
+        &self,
+        graph_count: usize,
+        error_ratio: f64,
+        options: &EncoderOptions,
+    ) -> Box<dyn Encoder> {
+        match self {
+            EncoderKind::Isomorphism => {
+                Box::new(IsomorphismEncoder::new(graph_count, error_ratio, options))
+            }
+            EncoderKind::BarCode => {
+                Box::new(BarCodeEncoder::new(graph_count, error_ratio, options))
+            }
+            EncoderKind::Binomial => Box::new(BinomialEncoder::new(graph_count, error_ratio)),
+            EncoderKind::Clique => Box::new(CliqueEncoder::new(graph_count, error_ratio, options)),
+        }
+    }
+}
+
+impl std::fmt::Display for EncoderKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl std::str::FromStr for EncoderKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EncoderKind::ALL
+            .iter()
+            .find(|kind| kind.name() == s)
+            .copied()
+            .ok_or_else(|| {
+                let names = EncoderKind::ALL.iter().map(|kind| kind.name());
+                format!(
+                    "unknown encoder {:?} (expected one of: {})",
+                    s,
+                    names.collect::<Vec<_>>().join(", ")
+                )
+            })
+    }
+}
+
+/// エンコーダの設定（Noneなら各エンコーダの既定値を使う）
+///
+/// 使わない設定は無視される
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EncoderOptions {
+    /// 冗長性考慮前のグラフの大きさ（IsomorphismEncoderのみ）
+    pub bits: Option<usize>,
+    /// 冗長性（IsomorphismEncoderのみ）
+    pub redundancy: Option<usize>,
+    /// 焼きなましスコアのグループ内:グループ外の重みの比（IsomorphismEncoderのみ）
+    pub score_coef: Option<f64>,
+    /// 1クエリあたりの焼きなましの回数
+    pub trial_count: Option<usize>,
+    /// 焼きなまし1回あたりの反復回数。指定すると実行速度によらず結果が再現できる
+    pub iterations: Option<usize>,
+    /// 焼きなましを並列に行うスレッド数（IsomorphismEncoder, BarCodeEncoderのみ）
+    ///
+    /// 各試行の乱数シードは試行番号から決まるので、反復回数を指定していれば結果はスレッド数によらない
+    pub threads: Option<usize>,
+    /// 焼きなましの乱数シード
+    pub seed: Option<u64>,
+}
+
+/// エンコーダの性能の予測値
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Prediction {
+    /// グラフの大きさN
+    pub graph_size: usize,
+    /// 1クエリあたりの正解率
+    pub accuracy: f64,
+}
+
+impl Prediction {
+    pub fn new(graph_size: usize, accuracy: f64) -> Self {
+        Self {
+            graph_size,
+            accuracy: accuracy.clamp(0.0, 1.0),
+        }
+    }
+
+    /// 1e9 * 0.9^E / N の期待値
+    ///
+    /// 各クエリが独立に確率accuracyで正解するとき、E[0.9^E] = (1 - 0.1 * (1 - accuracy))^Q となる。
+    /// Nが問題の制約を満たさない場合は0
+    pub fn expected_score(&self) -> f64 {
+        if !(MIN_N..=MAX_N).contains(&self.graph_size) {
+            return 0.0;
+        }
+
+        let per_query = 1.0 - 0.1 * (1.0 - self.accuracy);
+        1e9 * per_query.powi(QUERY_COUNT as i32) / self.graph_size as f64
+    }
+}
+
+/// 予測スコアが最大となるエンコーダを選ぶ（同点なら`EncoderKind::ALL`で先にあるもの）
+pub fn select_encoder(graph_count: usize, error_ratio: f64) -> EncoderKind {
+    let mut best_kind = EncoderKind::Isomorphism;
+    let mut best_score = f64::NEG_INFINITY;
+
+    for &kind in EncoderKind::ALL.iter() {
+        let score = kind.predict(graph_count, error_ratio).expected_score();
+
+        if best_score.change_max(score) {
+            best_kind = kind;
+        }
+    }
+
+    best_kind
+}
+
+/// 最大値を取る最初の添字を返す
+pub fn argmax(values: &[f64]) -> usize {
+    let mut max_value = f64::NEG_INFINITY;
+    let mut max_index = 0;
+
+    for (i, &v) in values.iter().enumerate() {
+        if max_value.change_max(v) {
+            max_index = i;
+        }
+    }
+
+    max_index
+}
+
+/// trialsの各試行をthreads個のスレッドで分担して実行し、試行番号順に結果を返す
+fn run_trials<T: Send>(
+    trials: Range<usize>,
+    threads: usize,
+    trial: impl Fn(usize) -> T + Sync,
+) -> Vec<T> {
+    if threads <= 1 || trials.len() <= 1 {
+        return trials.map(trial).collect();
+    }
+
+    let trials = trials.collect::<Vec<_>>();
+    let chunk_size = trials.len().div_ceil(threads);
+    let trial = &trial;
+
+    std::thread::scope(|scope| {
+        let handles = trials
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(|&t| trial(t)).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    })
+}
+
+/// 非負のスコアを和が1になるよう正規化する（全て0なら一様分布にする）
+fn normalize(scores: &[f64]) -> Vec<f64> {
+    let sum: f64 = scores.iter().sum();
+
+    if sum > 0.0 {
+        scores.iter().map(|s| s / sum).collect()
+    } else {
+        vec![1.0 / scores.len() as f64; scores.len()]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{argmax, binomial::BinomialEncoder, run_trials, Encoder, EncoderKind, Prediction};
+    use crate::time_manager::QueryBudget;
+
+    #[test]
+    fn argmax_test() {
+        assert_eq!(argmax(&[0.1, 0.4, 0.4, 0.1]), 1);
+        assert_eq!(argmax(&[0.25; 4]), 0);
+    }
+
+    #[test]
+    fn run_trials_test() {
+        let expected = (3..20).map(|t| t * t).collect::<Vec<_>>();
+
+        for threads in 1..=5 {
+            assert_eq!(run_trials(3..20, threads, |t| t * t), expected);
+        }
+
+        assert!(run_trials(0..0, 4, |t| t).is_empty());
+    }
+
+    #[test]
+    fn decode_ranked_test() {
+        let encoder = BinomialEncoder::new(10, 0.0);
+
+        for index in 0..10 {
+            let graph = encoder.encode(index);
+            let probs = encoder.decode_ranked(&graph, &mut QueryBudget::fixed(0.0));
+            let sum: f64 = probs.iter().sum();
+
+            assert!((sum - 1.0).abs() < 1e-9);
+            assert_eq!(encoder.decode(&graph, &mut QueryBudget::fixed(0.0)), index);
+        }
+    }
+
+    #[test]
+    fn expected_score_test() {
+        assert_eq!(Prediction::new(4, 1.0).expected_score(), 2.5e8);
+        assert_eq!(Prediction::new(101, 1.0).expected_score(), 0.0);
+
+        let expected = 1e9 * (1.0f64 - 0.1 * 0.5).powi(100) / 50.0;
+        assert!((Prediction::new(50, 0.5).expected_score() - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn encoder_kind_test() {
+        for &kind in EncoderKind::ALL.iter() {
+            assert_eq!(kind.name().parse::<EncoderKind>(), Ok(kind));
+        }
+
+        assert!("vf2".parse::<EncoderKind>().is_err());
+    }
 }
@@ -1,19 +1,34 @@
 mod annealing;
 mod restorer;
-use super::Encoder;
-use crate::{encoders::barcode::restorer::Restorer, graph::Graph};
+use super::{normalize, run_trials, Encoder, EncoderOptions, Prediction};
+use crate::{
+    annealing::Budget, encoders::barcode::restorer::Restorer, graph::Graph,
+    time_manager::QueryBudget, utils::normal_cdf,
+};
 use itertools::Itertools;
 
+/// 1クエリあたりの焼きなましの回数の既定値
+const TRIAL_COUNT: usize = 3;
+/// 焼きなましの乱数シードの既定値
+const SEED: u64 = 42;
+
 /// 2進数のバーコードで識別するエンコーダ
 #[derive(Debug, Clone)]
 pub struct BarCodeEncoder {
     graph_size: usize,
     graph_count: usize,
     bar_widths: Vec<usize>,
+    /// 1クエリあたりの焼きなましの回数
+    trial_count: usize,
+    /// 焼きなまし1回あたりの反復回数。指定されていなければ実時間で打ち切る
+    iterations: Option<usize>,
+    /// 焼きなましを並列に行うスレッド数
+    threads: usize,
+    seed: u64,
 }
 
 impl BarCodeEncoder {
-    pub fn new(graph_count: usize, error_ratio: f64) -> Self {
+    pub fn new(graph_count: usize, error_ratio: f64, options: &EncoderOptions) -> Self {
         let bar_widths = Self::get_bar_widths(graph_count, error_ratio);
         let graph_size = bar_widths.iter().sum();
 
@@ -21,9 +36,46 @@ impl BarCodeEncoder {
             graph_size,
             graph_count,
             bar_widths,
+            trial_count: options.trial_count.unwrap_or(TRIAL_COUNT).max(1),
+            iterations: options.iterations,
+            threads: options.threads.unwrap_or(1).max(1),
+            seed: options.seed.unwrap_or(SEED),
         }
     }
 
+    /// 頂点の並びの復元に成功する確率と、各桁の多数決を誤る確率から正解率を見積もる
+    ///
+    /// 実測値に当てはめたものではない大雑把な目安で、エンコーダの選択にしか使わない
+    pub fn predict(graph_count: usize, error_ratio: f64) -> Prediction {
+        // ε=0.25を超えたあたりから焼きなましでの並べ替えが破綻し始める
+        // 桁ごとの復元成功率をロジスティック曲線で近似している。中心と幅は手元で何ケースか動かした印象から
+        // 決めた当て推量で、測定データの裏付けはない
+        const RESTORE_CENTER: f64 = 0.44;
+        const RESTORE_SCALE: f64 = 0.048;
+        let encoder = Self::new(graph_count, error_ratio, &EncoderOptions::default());
+        let restore = 1.0 / (1.0 + ((error_ratio - RESTORE_CENTER) / RESTORE_SCALE).exp());
+        let mut accuracy = restore.powi(encoder.bar_widths.len() as i32);
+        let mut row = 0;
+
+        for &w in encoder.bar_widths.iter() {
+            // この桁の多数決に使われるマスの数
+            let cells: usize = (row..(row + w)).map(|r| encoder.graph_size - 1 - r).sum();
+            row += w;
+
+            if cells == 0 {
+                continue;
+            }
+
+            // 反転したマスが過半数となる確率（正規近似）
+            let cells = cells as f64;
+            let std_dev = (cells * error_ratio * (1.0 - error_ratio)).sqrt().max(1e-9);
+            let error = 1.0 - normal_cdf((cells / 2.0 - cells * error_ratio) / std_dev);
+            accuracy *= 1.0 - error;
+        }
+
+        Prediction::new(encoder.graph_size, accuracy)
+    }
+
     fn get_bar_widths(graph_count: usize, error_ratio: f64) -> Vec<usize> {
         let mut bar_widths = if error_ratio <= 0.05 {
             vec![8, 7, 6, 6, 6, 5, 5]
@@ -53,16 +105,19 @@ impl BarCodeEncoder {
         bar_widths
     }
 
-    fn restore_bits(&self, duration: f64, graph: &Graph) -> Vec<bool> {
+    /// 各桁が1である確率を求める
+    fn restore_bits(&self, duration: f64, graph: &Graph) -> Vec<f64> {
         // K回焼きなましを回して多数決を取る
-        const TRIAL_COUNT: usize = 3;
         let mut votes = vec![0; self.bar_widths.len()];
-        let duration = duration / TRIAL_COUNT as f64;
+        let budget = Budget::new(duration / self.trial_count as f64, self.iterations);
         let restorer = Restorer;
 
-        for trial in 0..TRIAL_COUNT {
-            let seed = trial as u128 + 42;
-            let graph = restorer.restore(graph, duration, seed);
+        let restored = run_trials(0..self.trial_count, self.threads, |trial| {
+            let seed = trial as u128 + self.seed as u128;
+            restorer.restore(graph, budget, seed)
+        });
+
+        for graph in restored.iter() {
             let mut row = 0;
 
             for (d, &w) in self.bar_widths.iter().enumerate() {
@@ -70,7 +125,7 @@ impl BarCodeEncoder {
 
                 for _ in 0..w {
                     for col in (row + 1)..self.graph_size {
-                        if graph[row][col] {
+                        if graph.has_edge(row, col) {
                             count += 1;
                         } else {
                             count -= 1;
@@ -80,12 +135,17 @@ impl BarCodeEncoder {
                     row += 1;
                 }
 
-                votes[d] += if count > 0 { 1 } else { -1 };
+                if count > 0 {
+                    votes[d] += 1;
+                }
             }
         }
 
-        let bits = votes.iter().map(|c| *c > 0).collect_vec();
-        bits
+        // 0票や満票でも確率が0・1にならないようラプラス平滑化する
+        votes
+            .iter()
+            .map(|&c| (c + 1) as f64 / (self.trial_count + 2) as f64)
+            .collect_vec()
     }
 }
 
@@ -115,24 +175,20 @@ impl Encoder for BarCodeEncoder {
         graph
     }
 
-    fn decode(&self, graph: &Graph, duration: f64) -> usize {
-        let bits = self.restore_bits(duration, graph);
-
-        // ビット列をindexに復元
-        let mut result = 0;
-
-        for (d, &b) in bits.iter().enumerate() {
-            if !b {
-                continue;
-            }
-
-            let next = result + (1 << d);
-
-            if next < self.graph_count {
-                result = next;
-            }
-        }
-
-        result
+    fn decode_ranked(&self, graph: &Graph, budget: &mut QueryBudget) -> Vec<f64> {
+        let probs = self.restore_bits(budget.allotted(), graph);
+
+        // 各桁が独立だとして、indexのビット列が得られる確率を求める
+        let likelihoods = (0..self.graph_count)
+            .map(|index| {
+                probs
+                    .iter()
+                    .enumerate()
+                    .map(|(d, &p)| if ((index >> d) & 1) > 0 { p } else { 1.0 - p })
+                    .product()
+            })
+            .collect_vec();
+
+        normalize(&likelihoods)
     }
 }
@@ -1,4 +1,19 @@
-pub mod annealer;
-pub mod binarygraph;
 mod neighbors;
 pub mod state;
+
+use self::{neighbors::NeighborGenerator, state::State};
+use crate::{
+    annealing::{schedule::Exponential, Annealer, Budget},
+    graph::Graph,
+};
+use rand_pcg::Pcg64Mcg;
+
+/// 予算を使い切るまで焼きなまし、頂点の並び順を改善する
+pub fn anneal(graph: &Graph, initial_solution: State, budget: Budget, rng: &mut Pcg64Mcg) -> State {
+    let graph_size = graph.n as f64;
+    let annealer = Annealer::new(Exponential::new(graph_size * graph_size, 1e0), false);
+
+    annealer
+        .run(graph, initial_solution, &NeighborGenerator, budget, rng)
+        .0
+}
@@ -1,5 +1,5 @@
 use super::Neighbor;
-use crate::encoders::barcode::annealing::{binarygraph::BinaryGraph, state::State};
+use crate::{encoders::barcode::annealing::state::State, graph::Graph};
 use rand::prelude::*;
 use rand_pcg::Pcg64Mcg;
 
@@ -11,7 +11,7 @@ pub struct SwapNode {
 }
 
 impl SwapNode {
-    pub fn gen(graph: &BinaryGraph, state: &State, rng: &mut Pcg64Mcg) -> Self {
+    pub fn gen(graph: &Graph, state: &State, rng: &mut Pcg64Mcg) -> Self {
         let prev_score = state.score();
         let u = rng.gen_range(0, graph.n);
         let v = (u + rng.gen_range(1, graph.n)) % graph.n;
@@ -20,13 +20,13 @@ impl SwapNode {
     }
 }
 
-impl Neighbor for SwapNode {
-    fn apply(&self, graph: &BinaryGraph, state: &mut State) {
+impl Neighbor<State> for SwapNode {
+    fn apply(&mut self, graph: &Graph, state: &mut State) {
         assert!(state.score() == self.prev_score);
         state.swap_node(graph, self.u, self.v);
     }
 
-    fn rollback(&self, graph: &BinaryGraph, state: &mut State) {
+    fn rollback(&mut self, graph: &Graph, state: &mut State) {
         state.swap_node_with(graph, self.u, self.v, self.prev_score);
     }
 }
@@ -1,4 +1,7 @@
-use super::binarygraph::BinaryGraph;
+use crate::{
+    annealing,
+    graph::{Graph, WORD_BITS},
+};
 use itertools::Itertools;
 use rand::seq::SliceRandom;
 use rand_pcg::Pcg64Mcg;
@@ -10,7 +13,7 @@ pub struct State {
 }
 
 impl State {
-    pub fn init_rand(graph: &BinaryGraph, rng: &mut Pcg64Mcg) -> Self {
+    pub fn init_rand(graph: &Graph, rng: &mut Pcg64Mcg) -> Self {
         let mut permutation = (0..graph.n).collect_vec();
         permutation.shuffle(rng);
         let mut state = Self {
@@ -30,31 +33,42 @@ impl State {
         self.score
     }
 
-    pub fn swap_node(&mut self, graph: &BinaryGraph, u: usize, v: usize) {
+    pub fn swap_node(&mut self, graph: &Graph, u: usize, v: usize) {
         self.permutation.swap(u, v);
         self.update_score_all(graph);
     }
 
-    pub fn swap_node_with(&mut self, _graph: &BinaryGraph, u: usize, v: usize, score: i32) {
+    pub fn swap_node_with(&mut self, _graph: &Graph, u: usize, v: usize, score: i32) {
         self.permutation.swap(u, v);
         self.score = score;
     }
 
-    fn update_score_all(&mut self, graph: &BinaryGraph) {
+    fn update_score_all(&mut self, graph: &Graph) {
         let mut score = 0;
 
-        for row in 0..graph.n {
-            let mut sum = 0;
-            let i = self.permutation[row];
-            let edges = &graph[i];
+        // 後ろの行から順に、自分より後ろにある頂点集合をビット列で持っておく
+        let mut suffix = vec![0u128; Graph::words_for(graph.n)];
 
-            for &j in self.permutation[(row + 1)..].iter() {
-                sum += edges[j];
-            }
+        for row in (0..graph.n).rev() {
+            let i = self.permutation[row];
 
+            // 辺があれば+1、なければ-1
+            let len = (graph.n - row - 1) as i32;
+            let plus = graph.count_neighbors_in(i, &suffix) as i32;
+            let sum = 2 * plus - len;
             score += sum * sum;
+
+            suffix[i / WORD_BITS] |= 1 << (i % WORD_BITS);
         }
 
         self.score = score;
     }
 }
+
+impl annealing::State for State {
+    type Score = i32;
+
+    fn score(&self) -> i32 {
+        self.score
+    }
+}
@@ -1,6 +1,6 @@
-use super::annealing::binarygraph::BinaryGraph;
 use crate::{
-    encoders::barcode::annealing::{annealer::Annealer, state::State},
+    annealing::Budget,
+    encoders::barcode::annealing::{anneal, state::State},
     graph::Graph,
 };
 use rand_pcg::Pcg64Mcg;
@@ -9,13 +9,11 @@ use rand_pcg::Pcg64Mcg;
 pub struct Restorer;
 
 impl Restorer {
-    pub fn restore(&self, graph: &Graph, duration: f64, seed: u128) -> Graph {
-        let binary_graph = BinaryGraph::new(graph);
-        let annealer = Annealer::new(false);
+    pub fn restore(&self, graph: &Graph, budget: Budget, seed: u128) -> Graph {
         let mut rng = Pcg64Mcg::new(seed);
 
-        let state = State::init_rand(&binary_graph, &mut rng);
-        let state = annealer.run(&binary_graph, state, duration, &mut rng);
+        let state = State::init_rand(graph, &mut rng);
+        let state = anneal(graph, state, budget, &mut rng);
 
         let mut restored_graph = Graph::new(graph.n);
 
@@ -24,7 +22,7 @@ impl Restorer {
             for col in (row + 1)..graph.n {
                 let j = state.permutation()[col];
 
-                if graph[i][j] {
+                if graph.has_edge(i, j) {
                     restored_graph.connect(row, col);
                 }
             }
@@ -1,14 +1,15 @@
-use crate::{graph::Graph, utils::ChangeMinMax};
+use crate::{graph::Graph, time_manager::QueryBudget, utils::normal_cdf};
 
-use super::Encoder;
+use super::{normalize, Encoder, Prediction};
 
 /// 二項分布を考慮し、辺の数によって識別するエンコーダ
 #[derive(Debug, Clone)]
 pub struct BinomialEncoder {
     graph_size: usize,
-    graph_count: usize,
     initial_values: Vec<f64>,
     expected_values: Vec<f64>,
+    /// ノイズによる辺の数のずれの標準偏差
+    error_std_dev: f64,
 }
 
 impl BinomialEncoder {
@@ -49,14 +50,49 @@ impl BinomialEncoder {
             }
         }
 
+        let (_, error_std_dev) = Self::calc_error_count(graph_size, error_ratio);
+
         Self {
             graph_size,
-            graph_count,
             initial_values,
             expected_values,
+            error_std_dev,
         }
     }
 
+    /// 辺の数が正規分布に従うとして、一番近い期待値を選んだときの正解率を見積もる
+    ///
+    /// 復号に使う期待値は安全側に取ったエラー数から計算しているので、真の期待値とはずれている。
+    /// 実測の正解率と突き合わせてはいない大雑把な目安
+    pub fn predict(graph_count: usize, error_ratio: f64) -> Prediction {
+        let encoder = Self::new(graph_count, error_ratio);
+        let exps = &encoder.expected_values;
+        let std_dev = encoder.error_std_dev.max(1e-9);
+        let edge_count = (encoder.graph_size * (encoder.graph_size - 1) / 2) as f64;
+        let mut accuracy = 0.0;
+
+        for i in 0..graph_count {
+            let init = encoder.initial_values[i].round();
+            let mean = init + edge_count * error_ratio * (1.0 - 2.0 * init / edge_count);
+
+            // 隣との中点の間に収まれば正解
+            let lower = if i > 0 {
+                (exps[i - 1] + exps[i]) / 2.0
+            } else {
+                f64::NEG_INFINITY
+            };
+            let upper = if i + 1 < graph_count {
+                (exps[i] + exps[i + 1]) / 2.0
+            } else {
+                f64::INFINITY
+            };
+
+            accuracy += normal_cdf((upper - mean) / std_dev) - normal_cdf((lower - mean) / std_dev);
+        }
+
+        Prediction::new(encoder.graph_size, accuracy / graph_count as f64)
+    }
+
     /// あるグラフサイズでエラーが発生する辺の数の(期待値, 標準偏差)を求める
     fn calc_error_count(graph_size: usize, error_ratio: f64) -> (f64, f64) {
         let edge_count = graph_size * (graph_size - 1) / 2;
@@ -124,7 +160,7 @@ impl Encoder for BinomialEncoder {
         self.graph_size
     }
 
-    fn encode(&self, index: usize) -> crate::graph::Graph {
+    fn encode(&self, index: usize) -> Graph {
         let mut graph = Graph::new(self.graph_size);
         let needed = self.initial_values[index].round() as usize;
 
@@ -144,29 +180,30 @@ impl Encoder for BinomialEncoder {
         graph
     }
 
-    fn decode(&self, graph: &crate::graph::Graph, _duration: f64) -> usize {
-        let mut count = 0;
-
-        for i in 0..self.graph_size {
-            for j in (i + 1)..self.graph_size {
-                if graph[i][j] {
-                    count += 1;
-                }
-            }
-        }
-
-        // 一番近いやつを探す
-        let mut best_index = !0;
-        let mut best_diff = std::f64::MAX;
-
-        for i in 0..self.graph_count {
-            let diff = (count as f64 - self.expected_values[i]).abs();
-
-            if best_diff.change_min(diff) {
-                best_index = i;
-            }
-        }
-
-        best_index
+    fn decode_ranked(&self, graph: &Graph, _budget: &mut QueryBudget) -> Vec<f64> {
+        let count = graph.edge_count() as f64;
+
+        // 期待値を中心とする正規分布で近似する
+        // 標準偏差を共通にしているので、一番近いものが最も確率が高くなる
+        let std_dev = self.error_std_dev.max(0.5);
+        let log_likelihoods = self
+            .expected_values
+            .iter()
+            .map(|&exp| {
+                let z = (count - exp) / std_dev;
+                -0.5 * z * z
+            })
+            .collect::<Vec<_>>();
+
+        // オーバーフローしないよう最大値を引いてからexpを取る
+        let max = log_likelihoods
+            .iter()
+            .fold(f64::NEG_INFINITY, |acc, &l| acc.max(l));
+        let likelihoods = log_likelihoods
+            .iter()
+            .map(|&l| (l - max).exp())
+            .collect::<Vec<_>>();
+
+        normalize(&likelihoods)
     }
 }
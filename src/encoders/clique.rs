@@ -1,8 +1,10 @@
 mod annealing;
 
-use self::annealing::annealer::Annealer;
-use super::Encoder;
-use crate::{graph::Graph, utils::ChangeMinMax};
+use self::annealing::anneal;
+use super::{Encoder, EncoderOptions, Prediction};
+use crate::{
+    annealing::Budget, graph::Graph, log_debug, time_manager::QueryBudget, utils::ChangeMinMax,
+};
 use itertools::Itertools;
 
 #[derive(Debug, Clone)]
@@ -12,10 +14,12 @@ pub struct CliqueEncoder {
     /// グラフの大きさ
     graph_size: usize,
     k_arries: Vec<KAry>,
+    /// 焼きなましの反復回数。指定されていなければ実時間で打ち切る
+    iterations: Option<usize>,
 }
 
 impl CliqueEncoder {
-    pub fn new(graph_count: usize, error_ratio: f64) -> Self {
+    pub fn new(graph_count: usize, error_ratio: f64, options: &EncoderOptions) -> Self {
         // とりあえず暫定値
         // 全bitが1になることがなければ少しケチれる
         let k_arries = if error_ratio < 0.15 {
@@ -48,6 +52,7 @@ impl CliqueEncoder {
             graph_count,
             graph_size: 0,
             k_arries,
+            iterations: options.iterations,
         };
 
         // 必要なグラフサイズを計算
@@ -65,6 +70,37 @@ impl CliqueEncoder {
         encoder
     }
 
+    /// 各クリークが独立に壊れるとして正解率を大雑把に見積もる
+    ///
+    /// クリーク内の辺の過半数が残っている頂点が許容下限以上あればクリークを検出できるとみなす。
+    /// 表せる整数がMより少ない場合、表せない分は不正解とする。
+    /// この検出条件は実際の復号手順を単純化した仮定で、実測の正解率と突き合わせてはいない
+    pub fn predict(graph_count: usize, error_ratio: f64) -> Prediction {
+        let encoder = Self::new(graph_count, error_ratio, &EncoderOptions::default());
+        let survive_probs = encoder
+            .k_arries
+            .iter()
+            .map(|k_ary| {
+                let degree = k_ary.size - 1;
+                let stay = binomial_tail(degree, 1.0 - error_ratio, degree / 2 + 1);
+                binomial_tail(k_ary.size, stay, k_ary.lower_bound)
+            })
+            .collect_vec();
+        let mul: usize = encoder.k_arries.iter().map(|a| a.count).product();
+        let mut accuracy = 0.0;
+
+        for index in 0..graph_count.min(mul) {
+            let counts = encoder.to_base_k_num(index);
+            accuracy += counts
+                .iter()
+                .zip(survive_probs.iter())
+                .map(|(&c, &p)| p.powi(c as i32))
+                .product::<f64>();
+        }
+
+        Prediction::new(encoder.graph_size, accuracy / graph_count as f64)
+    }
+
     fn to_base_k_num(&self, mut index: usize) -> Vec<usize> {
         let mut mul: usize = self.k_arries.iter().map(|a| a.count).product();
         let mut counts = vec![];
@@ -108,10 +144,9 @@ impl CliqueEncoder {
 
     fn expect(&self, graph: &Graph, duration: f64) -> usize {
         const MIN_VIS: usize = 4;
-        let annealer = Annealer::new(false);
-        let groups = annealer.run(graph, duration);
+        let groups = anneal(graph, Budget::new(duration, self.iterations));
         let groups = groups.into_iter().filter(|s| *s >= MIN_VIS).collect_vec();
-        eprintln!("{:?}", &groups);
+        log_debug!("{:?}", &groups);
 
         // 復号する
         let mut mul: usize = self.k_arries.iter().map(|a| a.count).product();
@@ -155,9 +190,28 @@ impl Encoder for CliqueEncoder {
         self.create_graph(&counts)
     }
 
-    fn decode(&self, graph: &Graph, duration: f64) -> usize {
-        self.expect(graph, duration)
+    fn decode_ranked(&self, graph: &Graph, budget: &mut QueryBudget) -> Vec<f64> {
+        // 確率を見積もる手段がないので、復号結果に確率1を割り当てる
+        let mut probs = vec![0.0; self.graph_count];
+        probs[self.expect(graph, budget.allotted())] = 1.0;
+        probs
+    }
+}
+
+/// 二項分布B(n, p)に従う確率変数がk以上となる確率
+fn binomial_tail(n: usize, p: f64, k: usize) -> f64 {
+    let mut prob = 0.0;
+    let mut comb = 1.0;
+
+    for i in 0..=n {
+        if i >= k {
+            prob += comb * p.powi(i as i32) * (1.0 - p).powi((n - i) as i32);
+        }
+
+        comb = comb * (n - i) as f64 / (i + 1) as f64;
     }
+
+    prob
 }
 
 /// K進数（？）を表す構造体
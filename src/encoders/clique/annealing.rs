@@ -1,3 +1,31 @@
-pub mod annealer;
 mod neighbors;
 mod state;
+
+use self::{neighbors::NeighborGenerator, state::State};
+use crate::{
+    annealing::{schedule::Exponential, Annealer, Budget},
+    graph::Graph,
+};
+use itertools::Itertools;
+use rand_pcg::Pcg64Mcg;
+use std::cmp::Reverse;
+
+/// 予算を使い切るまで焼きなましてクリークに分割し、大きい順にクリークの大きさを返す
+pub fn anneal(graph: &Graph, budget: Budget) -> Vec<usize> {
+    // TODO: 初期解を貪欲で作る
+    let state = State::init(graph);
+    let annealer = Annealer::new(Exponential::new(graph.n as f64, 1e-1), false);
+    let mut rng = Pcg64Mcg::new(42);
+    let (state, _) = annealer.run(graph, state, &NeighborGenerator, budget, &mut rng);
+
+    // グループの大きさを集計
+    let mut sizes = state
+        .get_group_size_list()
+        .iter()
+        .copied()
+        .filter(|s| *s > 0)
+        .collect_vec();
+
+    sizes.sort_by_key(|s| Reverse(*s));
+    sizes
+}
@@ -8,6 +8,7 @@ use rand_pcg::Pcg64Mcg;
 
 use super::state::State;
 use crate::{
+    annealing::{self, Neighbor},
     encoders::clique::annealing::neighbors::{
         change_node::ChangeNode, merge_groups::MergeGroups, separate_node::SeparateNode,
         split_group::SplitGroup,
@@ -15,19 +16,14 @@ use crate::{
     graph::Graph,
 };
 
-pub trait Neighbor {
-    fn apply(&self, graph: &Graph, state: &mut State);
-    fn rollback(&self, graph: &Graph, state: &mut State);
-}
-
 pub struct NeighborGenerator;
 
-impl NeighborGenerator {
-    pub fn gen(&self, graph: &Graph, state: &State, rng: &mut Pcg64Mcg) -> Box<dyn Neighbor> {
+impl annealing::NeighborGenerator<State> for NeighborGenerator {
+    fn gen(&self, graph: &Graph, state: &State, rng: &mut Pcg64Mcg) -> Box<dyn Neighbor<State>> {
         loop {
             let neigh_type = rng.gen_range(0, 100);
 
-            let neighbor: Option<Box<dyn Neighbor>> = if neigh_type < 40 {
+            let neighbor: Option<Box<dyn Neighbor<State>>> = if neigh_type < 40 {
                 Self::into_box(ChangeNode::gen(graph, state, rng))
             } else if neigh_type < 80 {
                 Self::into_box(SeparateNode::gen(graph, state, rng))
@@ -42,8 +38,12 @@ impl NeighborGenerator {
             }
         }
     }
+}
 
-    fn into_box(neighbor: Option<impl Neighbor + 'static>) -> Option<Box<dyn Neighbor>> {
+impl NeighborGenerator {
+    fn into_box(
+        neighbor: Option<impl Neighbor<State> + 'static>,
+    ) -> Option<Box<dyn Neighbor<State>>> {
         // なんか.map()だとダメだった
         if let Some(neighbor) = neighbor {
             Some(Box::new(neighbor))
@@ -34,12 +34,12 @@ impl ChangeNode {
     }
 }
 
-impl Neighbor for ChangeNode {
-    fn apply(&self, graph: &Graph, state: &mut State) {
+impl Neighbor<State> for ChangeNode {
+    fn apply(&mut self, graph: &Graph, state: &mut State) {
         state.change_group(graph, self.node, self.group);
     }
 
-    fn rollback(&self, graph: &Graph, state: &mut State) {
+    fn rollback(&mut self, graph: &Graph, state: &mut State) {
         state.change_group(graph, self.node, self.prev_group);
     }
 }
@@ -57,7 +57,7 @@ mod test {
     fn apply_test() {
         let graph = Graph::new(4);
         let mut state = State::init(&graph);
-        let change_node = ChangeNode {
+        let mut change_node = ChangeNode {
             node: 1,
             group: 0,
             prev_group: 1,
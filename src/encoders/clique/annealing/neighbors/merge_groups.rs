@@ -54,14 +54,14 @@ impl MergeGroups {
     }
 }
 
-impl Neighbor for MergeGroups {
-    fn apply(&self, graph: &Graph, state: &mut State) {
+impl Neighbor<State> for MergeGroups {
+    fn apply(&mut self, graph: &Graph, state: &mut State) {
         for &v in self.child_nodes.iter() {
             state.change_group(graph, v, self.parent_group);
         }
     }
 
-    fn rollback(&self, graph: &Graph, state: &mut State) {
+    fn rollback(&mut self, graph: &Graph, state: &mut State) {
         for &v in self.child_nodes.iter() {
             state.change_group(graph, v, self.child_group);
         }
@@ -84,7 +84,7 @@ mod test {
         state.change_group(&graph, 1, 0);
         state.change_group(&graph, 3, 2);
 
-        let merge_groups = MergeGroups {
+        let mut merge_groups = MergeGroups {
             parent_group: 0,
             child_group: 2,
             child_nodes: vec![2, 3],
@@ -40,12 +40,12 @@ impl SeparateNode {
     }
 }
 
-impl Neighbor for SeparateNode {
-    fn apply(&self, graph: &Graph, state: &mut State) {
+impl Neighbor<State> for SeparateNode {
+    fn apply(&mut self, graph: &Graph, state: &mut State) {
         state.change_group(graph, self.node, self.group);
     }
 
-    fn rollback(&self, graph: &Graph, state: &mut State) {
+    fn rollback(&mut self, graph: &Graph, state: &mut State) {
         state.change_group(graph, self.node, self.prev_group);
     }
 }
@@ -64,7 +64,7 @@ mod test {
         let graph = Graph::new(4);
         let mut state = State::init(&graph);
         state.change_group(&graph, 1, 0);
-        let separate_node = SeparateNode {
+        let mut separate_node = SeparateNode {
             node: 1,
             group: 1,
             prev_group: 0,
@@ -73,14 +73,14 @@ impl SplitGroup {
     }
 }
 
-impl Neighbor for SplitGroup {
-    fn apply(&self, graph: &Graph, state: &mut State) {
+impl Neighbor<State> for SplitGroup {
+    fn apply(&mut self, graph: &Graph, state: &mut State) {
         for &v in self.target_nodes.iter() {
             state.change_group(graph, v, self.child_group);
         }
     }
 
-    fn rollback(&self, graph: &Graph, state: &mut State) {
+    fn rollback(&mut self, graph: &Graph, state: &mut State) {
         for &v in self.target_nodes.iter() {
             state.change_group(graph, v, self.parent_group);
         }
@@ -97,7 +97,7 @@ fn construct_graph(graph: &Graph, nodes: &[usize]) -> Vec<Vec<i32>> {
             }
 
             // 辺が張られていないところはカットしたいので1/そうでなければ-1
-            new_graph[i][j] = if graph[u][v] { -1 } else { 1 };
+            new_graph[i][j] = if graph.has_edge(u, v) { -1 } else { 1 };
         }
     }
 
@@ -152,7 +152,7 @@ mod test {
         state.change_group(&graph, 2, 0);
         state.change_group(&graph, 3, 0);
 
-        let split_group = SplitGroup {
+        let mut split_group = SplitGroup {
             parent_group: 0,
             child_group: 1,
             target_nodes: vec![2, 3],
@@ -1,4 +1,4 @@
-use crate::graph::Graph;
+use crate::{annealing, graph::Graph};
 
 #[derive(Debug, Clone)]
 pub struct State {
@@ -17,10 +17,6 @@ impl State {
         state
     }
 
-    pub fn score(&self) -> i32 {
-        self.score
-    }
-
     pub fn groups(&self) -> &[usize] {
         &self.groups
     }
@@ -61,17 +57,16 @@ impl State {
     fn calc_score_of(&self, graph: &Graph, index: usize) -> i32 {
         let mut score = 0;
 
-        let edges = &graph[index];
         let group1 = self.groups[index];
 
-        for (j, (&edge, &group2)) in edges.iter().zip(self.groups.iter()).enumerate() {
+        for (j, &group2) in self.groups.iter().enumerate() {
             if index == j {
                 continue;
             }
 
             let same_group = group1 == group2;
 
-            if edge == same_group {
+            if graph.has_edge(index, j) == same_group {
                 score += 1;
             }
         }
@@ -83,17 +78,24 @@ impl State {
         self.score = 0;
 
         for i in 0..graph.n {
-            let edges = &graph[i];
             let group1 = self.groups[i];
 
             for j in (i + 1)..graph.n {
                 let group2 = self.groups[j];
                 let same_group = group1 == group2;
 
-                if edges[j] == same_group {
+                if graph.has_edge(i, j) == same_group {
                     self.score += 1;
                 }
             }
         }
     }
 }
+
+impl annealing::State for State {
+    type Score = i32;
+
+    fn score(&self) -> i32 {
+        self.score
+    }
+}
@@ -1,21 +1,37 @@
 use self::{
-    annealing::{binarygraph::BinaryGraph, state::State},
-    checker::{try_generate_isompic_graphs, IsomophicChecker, Vf2Checker},
+    annealing::{anneal, state::State},
+    checker::{edit_distance, predict_accuracy, try_generate_isompic_graphs},
+    likelihood::Likelihood,
+    tables::{MIN_M, STRATEGY_MATRIX},
 };
-use super::Encoder;
+use super::{normalize, run_trials, Encoder, EncoderOptions, Prediction};
 use crate::{
-    encoders::isomorphism::annealing::annealer::Annealer,
-    graph::Graph,
-    utils::{decode_hex_to_u64, ChangeMinMax},
+    annealing::Budget,
+    graph::{CanonicalForm, Graph},
+    log_debug, log_info,
+    time_manager::QueryBudget,
 };
+use itertools::Itertools;
+use rand::SeedableRng;
 use rand_pcg::Pcg64Mcg;
+use std::collections::HashMap;
 
 mod annealing;
-mod checker;
+pub mod catalog;
+pub mod checker;
+mod likelihood;
+mod tables;
+
+/// 1クエリあたりの焼きなましの回数の既定値
+const TRIAL_COUNT: usize = 5;
+/// 焼きなましの乱数シードの既定値
+const SEED: u64 = 42;
 
 #[derive(Debug, Clone)]
 pub struct IsomorphismEncoder {
     graphs: Vec<Graph>,
+    /// 標準形からgraphsの添字を引く表
+    catalog: HashMap<CanonicalForm, usize>,
     /// 送信するグラフの種類数
     graph_count: usize,
     /// グラフの大きさ
@@ -23,80 +39,132 @@ pub struct IsomorphismEncoder {
     /// 冗長性考慮前のグラフの大きさ
     original_graph_size: usize,
     /// 冗長性
+    ///
+    /// 元のグラフの全頂点で同じにする。頂点ごとに変えると焼きなましのグループ分けが崩れやすく、かえって誤りが増える
     redundancy: usize,
+    error_ratio: f64,
     /// 焼きなましスコアのグループ内:グループ外の重みの比
     score_coef: f64,
     /// 混同行列（使いやすいよう転置されている）
     confusing: Vec<Vec<u32>>,
+    /// 1クエリあたりの焼きなましの回数
+    trial_count: usize,
+    /// 焼きなまし1回あたりの反復回数。指定されていなければ実時間で打ち切る
+    iterations: Option<usize>,
+    /// 焼きなましを並列に行うスレッド数
+    threads: usize,
+    seed: u64,
 }
 
 impl IsomorphismEncoder {
-    pub fn new(
-        graph_count: usize,
-        error_ratio: f64,
-        bits: Option<usize>,
-        redundancy: Option<usize>,
-        score_coef: Option<f64>,
-    ) -> Self {
-        let (auto_bits, auto_redundancy, auto_score_coef) =
-            Self::get_storategy(graph_count, error_ratio);
-
-        let ((graphs, confusing), original_graph_size) = if let Some(bits) = bits {
-            (
-                try_generate_isompic_graphs(graph_count, error_ratio, bits).unwrap(),
-                bits,
-            )
-        } else {
-            (
-                try_generate_isompic_graphs(graph_count, error_ratio, auto_bits).unwrap(),
-                auto_bits,
-            )
-        };
+    pub fn new(graph_count: usize, error_ratio: f64, options: &EncoderOptions) -> Self {
+        // 全て指定されていれば戦略表は引かない（表の範囲外のMでも使えるように）
+        let (bits, redundancy, score_coef) =
+            match (options.bits, options.redundancy, options.score_coef) {
+                (Some(bits), Some(redundancy), Some(score_coef)) => (bits, redundancy, score_coef),
+                _ => {
+                    let (bits, redundancy, score_coef) =
+                        Self::get_storategy(graph_count, error_ratio);
+                    (
+                        options.bits.unwrap_or(bits),
+                        options.redundancy.unwrap_or(redundancy),
+                        options.score_coef.unwrap_or(score_coef),
+                    )
+                }
+            };
 
-        let redundancy = redundancy.unwrap_or(auto_redundancy);
-        let score_coef = score_coef.unwrap_or(auto_score_coef);
-        let graph_size = original_graph_size * redundancy;
-        eprintln!("bits      : {}", original_graph_size);
-        eprintln!("redundancy: {}", redundancy);
-        eprintln!("score_coef: {}", score_coef);
+        let (graphs, confusing) =
+            try_generate_isompic_graphs(graph_count, error_ratio, bits).unwrap();
+        let trial_count = options.trial_count.unwrap_or(TRIAL_COUNT).max(1);
+        let seed = options.seed.unwrap_or(SEED);
+        let graph_size = bits * redundancy;
+        log_info!("bits      : {}", bits);
+        log_info!("redundancy: {}", redundancy);
+        log_info!("score_coef: {}", score_coef);
+
+        let catalog = graphs
+            .iter()
+            .enumerate()
+            .map(|(i, g)| (g.canonical_form(), i))
+            .collect();
 
         Self {
             graphs,
+            catalog,
             graph_count,
             graph_size,
-            original_graph_size,
+            original_graph_size: bits,
             redundancy,
+            error_ratio,
             score_coef,
             confusing,
+            trial_count,
+            iterations: options.iterations,
+            threads: options.threads.unwrap_or(1).max(1),
+            seed,
         }
     }
 
+    /// 戦略表と精度表から性能を予測する
+    ///
+    /// 誤った復元結果の票はばらけるので、TRIAL_COUNT回中2回以上正しく復元できれば正解できるとみなす
+    pub fn predict(graph_count: usize, error_ratio: f64) -> Prediction {
+        let (bits, redundancy, _) = Self::get_storategy(graph_count, error_ratio);
+        let p = predict_accuracy(graph_count, error_ratio, bits).unwrap_or(0.0);
+        let q = 1.0 - p;
+        let zero = q.powi(TRIAL_COUNT as i32);
+        let one = TRIAL_COUNT as f64 * p * q.powi(TRIAL_COUNT as i32 - 1);
+        Prediction::new(bits * redundancy, 1.0 - zero - one)
+    }
+
     fn get_storategy(m: usize, error_ratio: f64) -> (usize, usize, f64) {
         let error_ratio = (error_ratio * 100.0 + 0.1) as usize;
-        let storategy_matrix = get_storategy_matrix();
-        storategy_matrix[m - 10][error_ratio]
+        let (bits, redundancy, score_coef) = STRATEGY_MATRIX[m - MIN_M][error_ratio];
+        (bits as usize, redundancy as usize, score_coef)
     }
 
-    fn restore(
-        &self,
-        graph: &BinaryGraph,
-        annealer: &Annealer,
-        duration: f64,
-        rng: &mut Pcg64Mcg,
-    ) -> Option<usize> {
-        let state = State::init_rand(&graph, self.original_graph_size, self.score_coef, rng);
-        let state = annealer.annealing(&graph, state, duration);
+    /// trial回目の焼きなましを行い、元のグラフの各頂点に対応する頂点集合を求める
+    ///
+    /// 乱数シードはrestoreと同じなので、同じtrialならdecodeの中で行うグループ分けと一致する
+    pub fn group_vertices(&self, graph: &Graph, duration: f64, trial: usize) -> Vec<Vec<usize>> {
+        let budget = Budget::new(duration, self.iterations);
+        self.anneal_groups(graph, budget, trial).groups().to_vec()
+    }
+
+    /// 試行番号から決めた乱数シードで焼きなましを1回行う
+    fn anneal_groups(&self, graph: &Graph, budget: Budget, trial: usize) -> State {
+        let mut rng = Pcg64Mcg::seed_from_u64(self.seed.wrapping_add(trial as u64));
+        let state = State::init_rand(graph, self.original_graph_size, self.score_coef, &mut rng);
+        anneal(graph, state, budget)
+    }
+
+    /// trial回目の焼きなましでグループ分けを行い、(最も尤もらしいグラフの番号, 編集距離)を返す
+    ///
+    /// 乱数シードは試行番号から決めるので、どの順・どのスレッドで実行しても結果は同じ
+    fn restore(&self, graph: &Graph, budget: Budget, trial: usize) -> (usize, usize) {
+        let state = self.anneal_groups(graph, budget, trial);
         let graph = state.restore_graph();
-        let checker = Vf2Checker::new(&graph);
 
-        for (i, g) in self.graphs.iter().enumerate() {
-            if checker.is_isomorphic(g) {
-                return Some(i);
-            }
+        if let Some(&i) = self.catalog.get(&graph.canonical_form()) {
+            return (i, 0);
         }
 
-        eprintln!("failed to decode.");
-        None
+        // 該当しない場合は尤度の最も高いものを選ぶ
+        let likelihood = Likelihood::new(&state, self.error_ratio);
+        let (index, distance) = most_likely(&self.graphs, &likelihood, &graph);
+        log_debug!("no exact match. distance to the most likely: {}", distance);
+        (index, distance)
+    }
+
+    /// 混同行列で重み付けした投票を行う
+    ///
+    /// 完全に一致しなかった復元結果は信用できないので、編集距離1につき票を半分にする
+    fn vote(&self, restored: usize, distance: usize, votes: &mut [u32]) {
+        let shift = distance.min(31) as u32;
+
+        for (vote, &count) in votes.iter_mut().zip(self.confusing[restored].iter()) {
+            *vote += count >> shift;
+        }
     }
 }
 
@@ -127,7 +195,7 @@ impl Encoder for IsomorphismEncoder {
                     for y in 0..self.redundancy {
                         let u = i * self.redundancy + x;
                         let v = j * self.redundancy + y;
-                        if original_graph[i][j] {
+                        if original_graph.has_edge(i, j) {
                             graph.connect(u, v);
                         }
                     }
@@ -138,57 +206,216 @@ impl Encoder for IsomorphismEncoder {
         graph
     }
 
-    fn decode(&self, graph: &Graph, duration: f64) -> usize {
-        let mut rng = Pcg64Mcg::new(42);
-        let graph = BinaryGraph::new(graph);
-        let annealer = Annealer::new(false);
+    fn decode_ranked(&self, graph: &Graph, budget: &mut QueryBudget) -> Vec<f64> {
+        let mut restored = vec![0; self.graph_count];
         let mut votes = vec![0; self.graph_count];
+        let each_duration = budget.allotted() / self.trial_count as f64;
+        let each_budget = Budget::new(each_duration, self.iterations);
 
-        const TRIAL_COUNT: usize = 5;
-        let each_duration = duration / TRIAL_COUNT as f64;
+        // 残りの試行で首位が入れ替わり得なくなったら打ち切る
+        // スレッド数ずつまとめて焼きなますが、投票と打ち切りの判定は1試行ずつ逐次実行と同じ順に行う
+        let mut trial = 0;
+
+        'trials: while trial < self.trial_count {
+            if is_settled(&self.confusing, &votes, self.trial_count - trial) {
+                break;
+            }
 
-        // 多数決を取る
-        for _ in 0..TRIAL_COUNT {
-            if let Some(i) = self.restore(&graph, &annealer, each_duration, &mut rng) {
-                for (j, &count) in self.confusing[i].iter().enumerate() {
-                    votes[j] += count;
+            let batch = trial..(trial + self.threads).min(self.trial_count);
+            let results = run_trials(batch, self.threads, |t| self.restore(graph, each_budget, t));
+
+            for (i, distance) in results {
+                if is_settled(&self.confusing, &votes, self.trial_count - trial) {
+                    break 'trials;
                 }
+
+                restored[i] += 1;
+                self.vote(i, distance, &mut votes);
+                trial += 1;
             }
         }
 
-        let mut max_votes = 0;
-        let mut max_index = 0;
+        if trial < self.trial_count {
+            log_debug!("settled after {} trials.", trial);
+        }
+
+        // 復元結果が過半数で一致しなければ、延長を受けて追加で焼きなます
+        // 延長は実時間に依存するので、反復回数を指定している場合は行わない
+        let max_trial_count = if self.iterations.is_none() {
+            self.trial_count * 2
+        } else {
+            self.trial_count
+        };
+
+        for trial in self.trial_count..max_trial_count {
+            let leader = restored.iter().max().copied().unwrap_or(0);
+
+            if leader * 2 > trial {
+                break;
+            }
+
+            let granted = budget.extend(each_duration);
 
-        for (i, &c) in votes.iter().enumerate() {
-            if max_votes.change_max(c) {
-                max_index = i;
+            if granted < each_duration * 0.5 {
+                break;
             }
+
+            let (i, distance) = self.restore(graph, Budget::Time(granted), trial);
+            restored[i] += 1;
+            self.vote(i, distance, &mut votes);
         }
 
-        max_index
+        let votes = votes.iter().map(|&v| v as f64).collect_vec();
+        normalize(&votes)
     }
 }
 
-fn get_storategy_matrix() -> Vec<Vec<(usize, usize, f64)>> {
-    const HEX_DATA: &[u8] = b"4011401140214022402140314032403240324034404140444044404440544054405440644064406440644064506250714094409440a140b140b440c140d140e240f140f150f14121511141724171418151414011401140244022402140214031404140414041404150345041504140545041406440615052505440744084506150724094508140a440b140b440c140c150d140e140f151015101512141515131418141915011502150245022503250345034503450345034503450425041504150415052505450545061506450625064507250815084508150a150a150a150b150c150d150e150e151015101511151215131514151415011502150245022503250345034503450345034503450425041504150415052505450545061506450625064507250815084508150a150a150a150b150c150d150e150e151015101511151215131514151415011502150245022503250345034503450345034503450425041504150415052505450545061506450625064507250815084508150a150a150a150b150c150d150e150e151015101511151215131514151415011502150245022503250345034503450345034503450425041504150415052505450545061506450625064507250815084508150a150a150a150b150c150d150e150e151015101511151215131514151415011502150245022503250345034503450345034503450425041504150415052505450545061506450625064507250815084508150a150a150a150b150c150d150e150e151015101511151215131514151415011502150245022503250345034503450345034503450425041504150415052505450545061506450625064507250815084508150a150a150a150b150c150d150e150e151015101511151215131514151415011502150245022503250345034503450345034503450425041504150415052505450545061506450625064507250815084508150a150a150a150b150c150d150e150e151015101511151215131514151415011502150245022503250345034503450345034503450425041504150415052505450545061506450625064507250815084508150a150a150a150b150c150d150e150e151015101511151215131514151415011502150245022503250345034503450345034503450425041504150415052505450545061506450625064507250815084508150a150a150a150b150c150d150e150e151015101511151215131514151415011502150215024503150345034503450345032503250415041504150425052505450545054506250645074507150816071509150a150a150b150c250c150e450d150f251125112610151425141514151425011502150215024503150345034503450345032503250415041504150425052505450545054506250645074507150816071509150a150a150b150c250c150e450d150f251125112610151425141514151425011502150215024503150345034503450345032503250415041504150425052505450545054506250645074507150816071509150a150a150b150c250c150e450d150f251125112610151425141514151425011502150215024503150345034503450345032503250415041504150425052505450545054506250645074507150816071509150a150a150b150c250c150e450d150f251125112610151425141514151425011502150215024503150345034503450345032503250415041504150425052505450545054506250645074507150816071509150a150a150b150c250c150e450d150f251125112610151425141514151425011502150225024502450315034503450345034504450415042504450545054505450545061506250745074507460745094608150a450a150b150c150d250d150e260e161026101610151425131514251425011502150225024502450315034503450345034504450415042504450545054505450545061506250745074507460745094608150a450a150b150c150d250d150e260e161026101610151425131514251425011502150225024502450315034503450345034504450415042504450545054505450545061506250745074507460745094608150a450a150b150c150d250d150e260e161026101610151425131514251425011502150245024503150315031503250345044504150415044604250415054505450615064506450715074508150815081509450a150a150b150b150b160c150d160f260e160f1610151425141514151425011502150245024503150315031503250345044504150415044604250415054505450615064506450715074508150815081509450a150a150b150b150b160c150d160f260e160f16101514251415141514250115021502450215031503150315034503450345041504450426041604150545054506150645064507450745074508150916081509150b250b150b150c160c150d160e160f160f1610151425141514251425011502150215022503150345034503450345041504150445041604150545054505450645064605450716061508150815091509150a150b250b150c250c150d160c160e161026101610161015131514251425011502150225024503150315032503450345041504450446044604160425054506150645064507150745072606150816074509150a150a150b150c260b160c160d160d160f16101610151425142514151415011502150215021503150316034603450446034504260416041604450516052605460546054605250716061508150816074608150a250b260a160b160b160c160d160e1610261026101610151425142514260116021602460246032603160346034603460346034604160426042604160416054605460546054606460646061607460816081609160a160a160b160c160c160d160e160f161016101610161016101610260116021602460246032603160346034603460346034604160426042604160416054605460546054606460646061607460816081609160a160a160b160c160c160d160e160f161016101610161016101610260116021602460246032603160346034603460346034604160426042604160416054605460546054606460646061607460816081609160a160a160b160c160c160d160e160f161016101610161016101610260116021602460246032603160346034603460346034604160426042604160416054605460546054606460646061607460816081609160a160a160b160c160c160d160e160f161016101610161016101610260116021602460246032603160346034603460346034604160426042604160416054605460546054606460646061607460816081609160a160a160b160c160c160d160e160f161016101610161016101610260116021602460246032603160346034603460346034604160426042604160416054605460546054606460646061607460816081609160a160a160b160c160c160d160e160f161016101610161016101610260116021602460246031603160346034603460346034604160446041604160526054605460546054606260626061607160816081609160a160a160b160b160d160d160e160f161016101610161016102610260116021602460246031603160346034603460346034604160446041604160526054605460546054606260626061607160816081609160a160a160b160b160d160d160e160f161016101610161016102610260116021602460246031603160346034603460346034604160446041604160526054605460546054606260626061607160816081609160a160a160b160b160d160d160e160f161016101610161016102610260116021602460246031603160346034603460346034604160446041604160526054605460546054606260626061607160816081609160a160a160b160b160d160d160e160f161016101610161016102610260116021602460246031603160346034603460346034604160446041604160526054605460546054606260626061607160816081609160a160a160b160b160d160d160e160f161016101610161016102610260116021602460246031603160346034603460346034604160446041604160526054605460546054606260626061607160816081609160a160a160b160b160d160d160e160f161016101610161016102610260116021602460246031603160346034603460346034604160446041604160526054605460546054606260626061607160816081609160a160a160b160b160d160d160e160f161016101610161016102610260116021602460246031603160346034603460346034604160446041604160526054605460546054606260626061607160816081609160a160a160b160b160d160d160e160f161016101610161016102610260116021602460246031603160346034603460346034604160446041604160526054605460546054606260626061607160816081609160a160a160b160b160d160d160e160f161016101610161016102610260116021602460246031603160346034603460346034604160446041604160526054605460546054606260626061607160816081609160a160a160b160b160d160d160e160f16101610161016101610261026011602460246024603460316034603260346034604260416044604260446054605460546054605460646062607160816081608160a160a160b160b160c160c160d160f2610260f1610161016101610161026011602460246024603460316034603260346034604260416044604260446054605460546054605460646062607160816081608160a160a160b160b160c160c160d160f2610260f1610161016101610161026011602460246024603460316034603260346034604260416044604260446054605460546054605460646062607160816081608160a160a160b160b160c160c160d160f2610260f1610161016101610161026011602460246024603460316034603260346034604260416044604260446054605460546054605460646062607160816081608160a160a160b160b160c160c160d160f2610260f1610161016101610161026011602460246024603460316034603260346034604260416044604260446054605460546054605460646062607160816081608160a160a160b160b160c160c160d160f2610260f1610161016101610161026011602460246024603460316034603260346034604260416044604260446054605460546054605460646062607160816081608160a160a160b160b160c160c160d160f2610260f1610161016101610161026011602460246024603460316034603260346034604260416044604260446054605460546054605460646062607160816081608160a160a160b160b160c160c160d160f2610260f1610161016101610161026011602460246024603460316034603260346034604260416044604260446054605460546054605460646062607160816081608160a160a160b160b160c160c160d160f2610260f1610161016101610161026011602460246024603460316034603260346034604260416044604260446054605460546054605460646062607160816081608160a160a160b160b160c160c160d160f2610260f1610161016101610161026011602460246024603460316034603260346034604260416044604260446054605460546054605460646062607160816081608160a160a160b160b160c160c160d160f2610260f16101610161016101610260116021602160246031603160346031603460346041604160416041604460416054605460546052606260646071608160816081609160a160b160b160c160d160e260f2610261026101610161016102610260116021602160246031603160346031603460346041604160416041604460416054605460546052606260646071608160816081609160a160b160b160c160d160e260f2610261026101610161016102610260116021602160246031603160346031603460346041604160416041604460416054605460546052606260646071608160816081609160a160b160b160c160d160e260f2610261026101610161016102610260116021602160246031603160346031603460346041604160416041604460416054605460546052606260646071608160816081609160a160b160b160c160d160e260f2610261026101610161016102610260116021602160246031603160346031603460346041604160416041604460416054605460546052606260646071608160816081609160a160b160b160c160d160e260f2610261026101610161016102610260116021602160246031603160346031603460346041604160416041604460416054605460546052606260646071608160816081609160a160b160b160c160d160e260f2610261026101610161016102610260116021602160246031603160346031603460346041604160416041604460416054605460546052606260646071608160816081609160a160b160b160c160d160e260f2610261026101610161016102610260116021602160246031603160346031603460346041604160416041604460416054605460546052606260646071608160816081609160a160b160b160c160d160e260f2610261026101610161016102610260116021602160246031603160346031603460346041604160416041604460416054605460546052606260646071608160816081609160a160b160b160c160d160e260f2610261026101610161016102610260116021602160246031603160346031603460346041604160416041604460416054605460546052606260646071608160816081609160a160b160b160c160d160e260f2610261026101610161016102610260116021602460316031603460346034603460326034604260446041604160516054605460546062606260746074608160846091609160a160a160b160b160d260f4610260f160f16101610161016101610260116021602460316031603460346034603460326034604260446041604160516054605460546062606260746074608160846091609160a160a160b160b160d260f4610260f160f16101610161016101610260116021602460316031603460346034603460326034604260446041604160516054605460546062606260746074608160846091609160a160a160b160b160d260f4610260f160f16101610161016101610260116021602460316031603460346034603460326034604260446041604160516054605460546062606260746074608160846091609160a160a160b160b160d260f4610260f160f16101610161016101610260116021602460316031603460346034603460326034604260446041604160516054605460546062606260746074608160846091609160a160a160b160b160d260f4610260f160f16101610161016101610260116021602460316031603460346034603460326034604260446041604160516054605460546062606260746074608160846091609160a160a160b160b160d260f4610260f160f16101610161016101610260116021602460316031603460346034603460326034604260446041604160516054605460546062606260746074608160846091609160a160a160b160b160d260f4610260f160f16101610161016101610260116021602460316031603460346034603460326034604260446041604160516054605460546062606260746074608160846091609160a160a160b160b160d260f4610260f160f16101610161016101610260116021602460316031603460346034603460326034604260446041604160516054605460546062606260746074608160846091609160a160a160b160b160d260f4610260f160f16101610161016101610260116021602460316031603460346034603460326034604260446041604160516054605460546062606260746074608160846091609160a160a160b160b160d260f4610260f160f161016101610161016102601160216022602460316031603160346034603460316042604160416041605160546054605460626062606260716084608160816091609160a160c160c160c160e26102610260f161016101610161016102601160216022602460316031603160346034603460316042604160416041605160546054605460626062606260716084608160816091609160a160c160c160c160e26102610260f161016101610161016102601160216022602460316031603160346034603460316042604160416041605160546054605460626062606260716084608160816091609160a160c160c160c160e26102610260f161016101610161016102601160216022602460316031603160346034603460316042604160416041605160546054605460626062606260716084608160816091609160a160c160c160c160e26102610260f161016101610161016102601160216022602460316031603160346034603460316042604160416041605160546054605460626062606260716084608160816091609160a160c160c160c160e26102610260f161016101610161016102601160216022602460316031603160346034603460316042604160416041605160546054605460626062606260716084608160816091609160a160c160c160c160e26102610260f161016101610161016102601160216022602460316031603160346034603460316042604160416041605160546054605460626062606260716084608160816091609160a160c160c160c160e26102610260f161016101610161016102601160216022602460316031603160346034603460316042604160416041605160546054605460626062606260716084608160816091609160a160c160c160c160e26102610260f161016101610161016102601160216022602460316031603160346034603460316042604160416041605160546054605460626062606260716084608160816091609160a160c160c160c160e26102610260f161016101610161016102601160216022602460316031603160346034603460316042604160416041605160546054605460626062606260716084608160816091609160a160c160c160c160e26102610260f16101610161016101610260116021602460316034603160346034603460326041604160416041605460526054605460526064606260716071608160846091609160a160a160b160c260d160e260f260f161016101610161016102610160116021602460316034603160346034603460326041604160416041605460526054605460526064606260716071608160846091609160a160a160b160c260d160e260f260f161016101610161016102610160116021602460316034603160346034603460326041604160416041605460526054605460526064606260716071608160846091609160a160a160b160c260d160e260f260f161016101610161016102610160116021602460316034603160346034603460326041604160416041605460526054605460526064606260716071608160846091609160a160a160b160c260d160e260f260f161016101610161016102610160116021602460316034603160346034603460326041604160416041605460526054605460526064606260716071608160846091609160a160a160b160c260d160e260f260f161016101610161016102610160116021602460316034603160346034603460326041604160416041605460526054605460526064606260716071608160846091609160a160a160b160c260d160e260f260f161016101610161016102610160116021602460316034603160346034603460326041604160416041605460526054605460526064606260716071608160846091609160a160a160b160c260d160e260f260f161016101610161016102610160116021602460316034603160346034603460326041604160416041605460526054605460526064606260716071608160846091609160a160a160b160c260d160e260f260f161016101610161016102610160116021602460316034603160346034603460326041604160416041605460526054605460526064606260716071608160846091609160a160a160b160c260d160e260f260f161016101610161016102610160116021602460316034603160346034603460326041604160416041605460526054605460526064606260716071608160846091609160a160a160b160c260d160e260f260f1610161016101610161026101";
-
-    let mut matrix = vec![];
-    let mut cursor = 0;
-
-    for _m in 10..=100 {
-        let mut line = vec![];
-        for _eps in 0..=40 {
-            let bits = decode_hex_to_u64(&HEX_DATA[cursor..(cursor + 1)]) as usize;
-            cursor += 1;
-            let redundancy = decode_hex_to_u64(&HEX_DATA[cursor..(cursor + 2)]) as usize;
-            cursor += 2;
-            // (score_coef - 1) * 4 を格納している
-            let score_coef = decode_hex_to_u64(&HEX_DATA[cursor..(cursor + 1)]) as f64 * 0.25 + 1.0;
-            cursor += 1;
-            line.push((bits, redundancy, score_coef));
+/// 残りremaining回の投票で首位が入れ替わり得ないかどうか
+fn is_settled(confusing: &[Vec<u32>], votes: &[u32], remaining: usize) -> bool {
+    let leader = (0..votes.len())
+        .rev()
+        .max_by_key(|&i| votes[i])
+        .unwrap_or(0);
+
+    (0..votes.len()).filter(|&j| j != leader).all(|j| {
+        // 1回の投票で縮まり得る差の最大値
+        let gain = confusing
+            .iter()
+            .map(|row| row[j].saturating_sub(row[leader]))
+            .max()
+            .unwrap_or(0);
+        votes[leader] - votes[j] > gain * remaining as u32
+    })
+}
+
+/// 完全に一致する候補がないとき、尤度の最も高い候補と、復元したグラフからその候補への編集距離を返す
+///
+/// 編集距離は投票の重みにだけ使う。距離が1大きくても尤度がずっと高い候補はあり得るので、選ぶのは尤度で行う
+fn most_likely(graphs: &[Graph], likelihood: &Likelihood, restored: &Graph) -> (usize, usize) {
+    let (best, log_likelihood) = likelihood.most_likely(graphs);
+    log_debug!("log likelihood of the most likely: {:.1}", log_likelihood);
+    (best, edit_distance(restored, &graphs[best]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        annealing::state::State, checker::edit_distance, is_settled, likelihood::Likelihood,
+        most_likely, IsomorphismEncoder,
+    };
+    use crate::{
+        encoders::{Encoder, EncoderOptions},
+        graph::Graph,
+        noise::{NoiseChannel, NoiseModel},
+        time_manager::QueryBudget,
+    };
+    use rand_pcg::Pcg64Mcg;
+
+    #[test]
+    fn get_storategy_test() {
+        // strategy.csvの (m, eps) = (10, 40), (55, 20), (100, 40) の行
+        assert_eq!(IsomorphismEncoder::get_storategy(10, 0.40), (5, 20, 1.25));
+        assert_eq!(IsomorphismEncoder::get_storategy(55, 0.20), (6, 6, 2.0));
+        assert_eq!(IsomorphismEncoder::get_storategy(100, 0.40), (6, 16, 1.25));
+    }
+
+    #[test]
+    fn explicit_strategy_test() {
+        // bits, redundancy, score_coefを全て指定すれば、戦略表の範囲外のMでも作れる
+        let options = EncoderOptions {
+            bits: Some(6),
+            redundancy: Some(3),
+            score_coef: Some(1.5),
+            ..Default::default()
+        };
+        let encoder = IsomorphismEncoder::new(156, 0.1, &options);
+        assert_eq!(encoder.graph_size(), 18);
+        assert_eq!(encoder.graphs.len(), 156);
+    }
+
+    #[test]
+    fn most_likely_test() {
+        // 4頂点を3頂点ずつのグループにした受信グラフ。グループ対(0, 1), (2, 3)は9組中5組だけ辺があり、
+        // (1, 2), (0, 2)は9組全てに辺がある
+        let mut received = Graph::new(12);
+
+        for u in 0..12 {
+            for v in (u + 1)..12 {
+                let (gu, gv) = (u / 3, v / 3);
+                let weak = (gu, gv) == (0, 1) || (gu, gv) == (2, 3);
+                let strong = (gu, gv) == (1, 2) || (gu, gv) == (0, 2);
+
+                if gu == gv || strong || (weak && (u % 3 + v % 3) % 2 == 0) {
+                    received.connect(u, v);
+                }
+            }
         }
-        matrix.push(line)
+
+        let groups = (0..4).map(|i| (i * 3..(i + 1) * 3).collect()).collect();
+        let state = State::new(&received, groups, 1.0);
+        let restored = state.restore_graph();
+        let likelihood = Likelihood::new(&state, 0.1);
+
+        // nearestは確かな辺(1, 2)を落としたもの、likelyは弱い辺(0, 1), (2, 3)を落としたもの
+        let mut nearest = restored.clone();
+        nearest.disconnect(1, 2);
+        let mut likely = restored.clone();
+        likely.disconnect(0, 1);
+        likely.disconnect(2, 3);
+        assert_eq!(edit_distance(&restored, &nearest), 1);
+        assert_eq!(edit_distance(&restored, &likely), 2);
+
+        // 編集距離では遠くても尤度の高い方を選び、重みにはその距離を使う
+        let graphs = vec![nearest, likely];
+        assert_eq!(most_likely(&graphs, &likelihood, &restored), (1, 2));
+    }
+
+    #[test]
+    fn is_settled_test() {
+        let confusing = vec![vec![8, 1, 1], vec![1, 8, 1], vec![1, 1, 8]];
+
+        // 3回中2回一致していても、残り3回で覆り得る
+        assert!(!is_settled(&confusing, &[17, 10, 3], 3));
+        assert!(is_settled(&confusing, &[17, 10, 3], 0));
+
+        // 差が1回あたりの最大の追い上げ7 * 残り回数を超えれば確定
+        assert!(is_settled(&confusing, &[24, 3, 3], 2));
+        assert!(!is_settled(&confusing, &[24, 3, 3], 3));
+
+        // 同点は確定しない
+        assert!(!is_settled(&confusing, &[0, 0, 0], 1));
     }
 
-    matrix
+    #[test]
+    fn iterations_test() {
+        let options = EncoderOptions {
+            iterations: Some(20000),
+            ..Default::default()
+        };
+        let encoder = IsomorphismEncoder::new(20, 0.1, &options);
+        let threaded = IsomorphismEncoder::new(
+            20,
+            0.1,
+            &EncoderOptions {
+                threads: Some(3),
+                ..options
+            },
+        );
+        let mut rng = Pcg64Mcg::new(0);
+        let (graph, _) = NoiseChannel::new(0.1).transmit(&encoder.encode(7), &mut rng);
+
+        // 持ち時間によらず同じ結果になる
+        let ranked = encoder.decode_ranked(&graph, &mut QueryBudget::fixed(0.0));
+        assert_eq!(
+            encoder.decode_ranked(&graph, &mut QueryBudget::fixed(1.0)),
+            ranked
+        );
+        assert_eq!(encoder.decode(&graph, &mut QueryBudget::fixed(0.0)), 7);
+
+        // スレッド数によらず同じ結果になる
+        assert_eq!(
+            threaded.decode_ranked(&graph, &mut QueryBudget::fixed(0.0)),
+            ranked
+        );
+    }
 }
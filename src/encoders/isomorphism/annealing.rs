@@ -1,4 +1,27 @@
-pub mod annealer;
-pub mod binarygraph;
 mod neighbors;
 pub mod state;
+
+use self::{neighbors::NeighborGenerator, state::State};
+use crate::{
+    annealing::{schedule::Exponential, Annealer, Budget},
+    graph::Graph,
+};
+use rand_pcg::Pcg64Mcg;
+
+/// 予算を使い切るまで焼きなまし、頂点のグループ分けを改善する
+pub fn anneal(graph: &Graph, initial_solution: State, budget: Budget) -> State {
+    let temp0 = 1e1 * graph.n as f64 * 0.1;
+    let temp1 = 1e-1 * graph.n as f64 * 0.1;
+    let annealer = Annealer::new(Exponential::new(temp0, temp1), false).with_check_interval(1 << 7);
+    let mut rng = Pcg64Mcg::new(42);
+
+    annealer
+        .run(
+            graph,
+            initial_solution,
+            &NeighborGenerator,
+            budget,
+            &mut rng,
+        )
+        .0
+}
@@ -1,8 +1,15 @@
 use super::Neighbor;
-use crate::encoders::isomorphism::annealing::{binarygraph::BinaryGraph, state::State};
+use crate::{
+    encoders::isomorphism::{annealing::state::State, catalog::MAX_VERTICES},
+    graph::Graph,
+};
 use rand::prelude::*;
 use rand_pcg::Pcg64Mcg;
 
+/// 扱えるグループ数の上限（生成できるカタログの頂点数に合わせる）
+const MAX_GROUPS: usize = MAX_VERTICES;
+const MAX_CROSSES: usize = MAX_GROUPS * (MAX_GROUPS - 1) / 2;
+
 #[derive(Debug, Clone)]
 pub struct SwapNode {
     group0: usize,
@@ -10,12 +17,12 @@ pub struct SwapNode {
     index0: usize,
     index1: usize,
     prev_score: f64,
-    self_counts_buffer: [i32; 6],
-    cross_counts_buffer: [i32; 15],
+    self_counts_buffer: [i32; MAX_GROUPS],
+    cross_counts_buffer: [i32; MAX_CROSSES],
 }
 
 impl SwapNode {
-    pub fn gen(_graph: &BinaryGraph, state: &State, rng: &mut Pcg64Mcg) -> Self {
+    pub fn gen(_graph: &Graph, state: &State, rng: &mut Pcg64Mcg) -> Self {
         let group_count = state.group_count();
         let group_size = state.group_size();
 
@@ -30,14 +37,14 @@ impl SwapNode {
             index0,
             index1,
             prev_score: 0.0,
-            self_counts_buffer: [0; 6],
-            cross_counts_buffer: [0; 15],
+            self_counts_buffer: [0; MAX_GROUPS],
+            cross_counts_buffer: [0; MAX_CROSSES],
         }
     }
 }
 
-impl Neighbor for SwapNode {
-    fn apply(&mut self, graph: &BinaryGraph, state: &mut State) {
+impl Neighbor<State> for SwapNode {
+    fn apply(&mut self, graph: &Graph, state: &mut State) {
         state.swap_nodes(
             graph,
             self.group0,
@@ -50,7 +57,7 @@ impl Neighbor for SwapNode {
         )
     }
 
-    fn rollback(&mut self, graph: &BinaryGraph, state: &mut State) {
+    fn rollback(&mut self, graph: &Graph, state: &mut State) {
         state.revert_swap(
             graph,
             self.group0,
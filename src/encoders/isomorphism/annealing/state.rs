@@ -1,6 +1,7 @@
-use crate::graph::Graph;
-
-use super::binarygraph::BinaryGraph;
+use crate::{
+    annealing,
+    graph::{Graph, WORD_BITS},
+};
 use itertools::Itertools;
 use rand::prelude::*;
 use rand_pcg::Pcg64Mcg;
@@ -10,8 +11,9 @@ pub struct State {
     group_count: usize,
     group_size: usize,
     groups: Vec<Vec<usize>>,
-    groups_u128: Vec<u128>,
-    graph_u128: Vec<u128>,
+    /// 各グループの頂点集合をビット列で表したもの。グループごとにwords個のワードを使う
+    group_bits: Vec<u128>,
+    words: usize,
     self_counts: Vec<i32>,
     cross_counts: Vec<i32>,
     score: f64,
@@ -20,7 +22,7 @@ pub struct State {
 
 impl State {
     pub fn init_rand(
-        graph: &BinaryGraph,
+        graph: &Graph,
         group_count: usize,
         score_coef: f64,
         rng: &mut Pcg64Mcg,
@@ -37,28 +39,17 @@ impl State {
         Self::new(graph, groups, score_coef)
     }
 
-    fn new(graph: &BinaryGraph, groups: Vec<Vec<usize>>, score_coef: f64) -> Self {
+    pub fn new(graph: &Graph, groups: Vec<Vec<usize>>, score_coef: f64) -> Self {
         let group_count = groups.len();
         let group_size = graph.n / group_count;
         assert!(graph.n == group_count * group_size);
 
-        let mut graph_u128 = vec![0; graph.n];
-
-        for i in 0..graph.n {
-            for j in 0..graph.n {
-                let edges = &mut graph_u128[i];
-
-                if graph[i][j] == 1 {
-                    *edges |= 1 << j;
-                }
-            }
-        }
+        let words = Graph::words_for(graph.n);
+        let mut group_bits = vec![0; group_count * words];
 
-        let mut groups_u128 = vec![0; group_count];
-
-        for (group, u128) in groups.iter().zip(groups_u128.iter_mut()) {
+        for (group, bits) in groups.iter().zip(group_bits.chunks_mut(words.max(1))) {
             for &v in group.iter() {
-                *u128 |= 1 << v;
+                bits[v / WORD_BITS] |= 1 << (v % WORD_BITS);
             }
         }
 
@@ -66,8 +57,8 @@ impl State {
             group_count,
             groups,
             group_size,
-            groups_u128,
-            graph_u128,
+            group_bits,
+            words,
             self_counts: vec![0; group_count],
             cross_counts: vec![0; group_count * (group_count - 1) / 2],
             score: 0.0,
@@ -86,13 +77,24 @@ impl State {
         self.group_size
     }
 
-    pub fn score(&self) -> f64 {
-        self.score
+    pub fn groups(&self) -> &[Vec<usize>] {
+        &self.groups
+    }
+
+    /// グループiの内側の (辺のある頂点対の数 - ない頂点対の数)
+    pub fn self_count(&self, i: usize) -> i32 {
+        self.self_counts[i]
+    }
+
+    /// グループi, j間の (辺のある頂点対の数 - ない頂点対の数)
+    pub fn cross_count(&self, i: usize, j: usize) -> i32 {
+        let (i, j) = if i < j { (i, j) } else { (j, i) };
+        self.cross_counts[self.cross_index(i, j)]
     }
 
     pub fn swap_nodes(
         &mut self,
-        _graph: &BinaryGraph,
+        graph: &Graph,
         mut g0: usize,
         mut g1: usize,
         mut i0: usize,
@@ -114,9 +116,9 @@ impl State {
         cross_counts_buf[..len].copy_from_slice(&self.cross_counts);
 
         unsafe {
-            self.sub_relative_counts(g0, g1, i0, i1);
+            self.sub_relative_counts(graph, g0, g1, i0, i1);
             self.swap_inner(g0, i0, g1, i1);
-            self.add_relative_counts(g0, g1, i0, i1);
+            self.add_relative_counts(graph, g0, g1, i0, i1);
         }
 
         self.update_score_from_counts();
@@ -124,7 +126,7 @@ impl State {
 
     pub fn revert_swap(
         &mut self,
-        _graph: &BinaryGraph,
+        _graph: &Graph,
         mut g0: usize,
         mut g1: usize,
         mut i0: usize,
@@ -155,107 +157,115 @@ impl State {
         let v = &mut second[g1 - g0 - 1][i1];
         std::mem::swap(u, v);
 
-        let xor = (1 << *u) | (1 << *v);
-        self.groups_u128[g0] ^= xor;
-        self.groups_u128[g1] ^= xor;
+        for &w in [*u, *v].iter() {
+            let bit = 1 << (w % WORD_BITS);
+            self.group_bits[g0 * self.words + w / WORD_BITS] ^= bit;
+            self.group_bits[g1 * self.words + w / WORD_BITS] ^= bit;
+        }
+    }
+
+    fn group_mask(&self, g: usize) -> &[u128] {
+        &self.group_bits[(g * self.words)..((g + 1) * self.words)]
     }
 
     // popcnt命令の使用可否で1.5倍くらい速度差が出る
     #[target_feature(enable = "popcnt")]
-    unsafe fn sub_relative_counts(&mut self, g0: usize, g1: usize, i0: usize, i1: usize) {
+    unsafe fn sub_relative_counts(
+        &mut self,
+        graph: &Graph,
+        g0: usize,
+        g1: usize,
+        i0: usize,
+        i1: usize,
+    ) {
         assert!(g0 < g1);
 
         // グループ内・グループ間について、辺のある・なしを数えていく
         // 途中足しすぎたり引きすぎたりするが、add_relative_counts()で打ち消されるため問題ない
         for &(g0, i0) in [(g0, i0), (g1, i1)].iter() {
             let u = self.groups[g0][i0];
-            let edges = &self.graph_u128[u];
+            let edges = graph.row(u);
+
+            for g1 in 0..self.group_count {
+                let plus = count_common(edges, self.group_mask(g1));
 
-            for (g1, group) in self.groups_u128.iter().enumerate() {
                 if g0 == g1 {
                     // self
-                    let counts = &mut self.self_counts[g0];
-
                     // plus - minus = plus - (group_size - plus) = 2 * plus - group_size
                     // group_size は後で足し直すので不要
-                    let plus = (edges & group).count_ones() as i32;
-                    *counts -= 2 * plus;
+                    self.self_counts[g0] -= 2 * plus;
                 } else {
                     // cross
                     let (g0, g1) = if g0 < g1 { (g0, g1) } else { (g1, g0) };
                     let index = self.cross_index(g0, g1);
-                    let counts = &mut self.cross_counts[index];
-
-                    let plus = (edges & group).count_ones() as i32;
-                    *counts -= 2 * plus;
+                    self.cross_counts[index] -= 2 * plus;
                 }
             }
         }
     }
 
     #[target_feature(enable = "popcnt")]
-    unsafe fn add_relative_counts(&mut self, g0: usize, g1: usize, i0: usize, i1: usize) {
+    unsafe fn add_relative_counts(
+        &mut self,
+        graph: &Graph,
+        g0: usize,
+        g1: usize,
+        i0: usize,
+        i1: usize,
+    ) {
         assert!(g0 < g1);
 
         for &(g0, i0) in [(g0, i0), (g1, i1)].iter() {
             let u = self.groups[g0][i0];
-            let edges = self.graph_u128[u];
+            let edges = graph.row(u);
+
+            for g1 in 0..self.group_count {
+                let plus = count_common(edges, self.group_mask(g1));
 
-            for (g1, group) in self.groups_u128.iter().enumerate() {
                 if g0 == g1 {
                     // self
-                    let counts = &mut self.self_counts[g0];
-                    let plus = (edges & group).count_ones() as i32;
-                    *counts += 2 * plus;
+                    self.self_counts[g0] += 2 * plus;
                 } else {
                     // cross
                     let (g0, g1) = if g0 < g1 { (g0, g1) } else { (g1, g0) };
                     let index = self.cross_index(g0, g1);
-                    let counts = &mut self.cross_counts[index];
-
-                    let plus = (edges & group).count_ones() as i32;
-                    *counts += 2 * plus;
+                    self.cross_counts[index] += 2 * plus;
                 }
             }
         }
     }
 
-    pub fn update_score_all(&mut self, graph: &BinaryGraph) {
-        for c in self.self_counts.iter_mut() {
-            *c = 0;
-        }
-
-        for c in self.cross_counts.iter_mut() {
-            *c = 0;
-        }
-
+    pub fn update_score_all(&mut self, graph: &Graph) {
         // グループ内のcountを計算
-        for (group, count) in self.groups.iter().zip(self.self_counts.iter_mut()) {
-            for i in 0..group.len() {
-                let u = group[i];
-                let edges = &graph[u];
-
-                for j in (i + 1)..group.len() {
-                    let v = group[j];
-                    *count += edges[v];
-                }
+        // 辺があれば+1、なければ-1なので、plus - minus = 2 * plus - (頂点対の数)
+        for g in 0..self.group_count {
+            let group = &self.groups[g];
+            let mask = self.group_mask(g);
+            let size = group.len() as i32;
+            let mut plus = 0;
+
+            for &u in group.iter() {
+                plus += graph.count_neighbors_in(u, mask) as i32;
             }
+
+            // 各辺を両側から数えているので、plusは既に2倍されている
+            self.self_counts[g] = plus - size * (size - 1) / 2;
         }
 
         // グループ間のcountを計算
         for g0 in 0..self.group_count {
             let group0 = &self.groups[g0];
             for g1 in (g0 + 1)..self.group_count {
-                let group1 = &self.groups[g1];
+                let group1 = self.group_mask(g1);
+                let size = (group0.len() * self.groups[g1].len()) as i32;
                 let index = self.cross_index(g0, g1);
-                let count = &mut self.cross_counts[index];
+                let mut plus = 0;
 
                 for &u in group0.iter() {
-                    let edges = &graph[u];
-                    for &v in group1.iter() {
-                        *count += edges[v];
-                    }
+                    plus += graph.count_neighbors_in(u, group1) as i32;
                 }
+
+                self.cross_counts[index] = 2 * plus - size;
             }
         }
 
@@ -303,12 +313,31 @@ impl State {
     }
 }
 
+/// 2つのビット列の共通部分の大きさ
+///
+/// popcnt命令を有効にした関数の中に展開させるためinline(always)にしている
+#[inline(always)]
+fn count_common(a: &[u128], b: &[u128]) -> i32 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(a, b)| (a & b).count_ones() as i32)
+        .sum()
+}
+
+impl annealing::State for State {
+    type Score = f64;
+
+    fn score(&self) -> f64 {
+        self.score
+    }
+}
+
 #[cfg(test)]
 mod test {
     use rand::Rng;
     use rand_pcg::Pcg64Mcg;
 
-    use crate::{encoders::isomorphism::annealing::binarygraph::BinaryGraph, graph::Graph};
+    use crate::graph::Graph;
 
     use super::State;
 
@@ -356,28 +385,32 @@ mod test {
 
     #[test]
     fn rand_swap_test() {
-        const N: usize = 50;
-        const GROUP_COUNT: usize = 5;
+        rand_swap(50, 5);
+
+        // 128頂点を超えても差分計算が一致する
+        rand_swap(300, 6);
+    }
+
+    fn rand_swap(n: usize, group_count: usize) {
         const TRIAL_COUNT: usize = 1000;
 
-        let mut graph = Graph::new(N);
+        let mut graph = Graph::new(n);
         let mut rng = Pcg64Mcg::new(42);
-        for i in 0..N {
-            for j in (i + 1)..N {
+        for i in 0..n {
+            for j in (i + 1)..n {
                 if rng.gen_bool(0.5) {
                     graph.connect(i, j);
                 }
             }
         }
 
-        let graph = BinaryGraph::new(&graph);
-        let mut state = State::init_rand(&graph, GROUP_COUNT, 2.0, &mut rng);
+        let mut state = State::init_rand(&graph, group_count, 2.0, &mut rng);
 
         for _ in 0..TRIAL_COUNT {
             let g0 = rng.gen_range(0, state.group_count);
             let g1 = (g0 + rng.gen_range(1, state.group_count)) % state.group_count;
-            let i0 = rng.gen_range(0, N / GROUP_COUNT);
-            let i1 = rng.gen_range(0, N / GROUP_COUNT);
+            let i0 = rng.gen_range(0, n / group_count);
+            let i1 = rng.gen_range(0, n / group_count);
 
             let mut prev_score = 0.0;
             let mut buffer1 = [0; 6];
@@ -405,7 +438,7 @@ mod test {
         }
     }
 
-    fn gen_graph() -> BinaryGraph {
+    fn gen_graph() -> Graph {
         // \##...
         // #\#...
         // ##\...
@@ -420,6 +453,6 @@ mod test {
         graph.connect(3, 5);
         graph.connect(4, 5);
 
-        BinaryGraph::new(&graph)
+        graph
     }
 }
@@ -0,0 +1,205 @@
+//! 互いに同型でないグラフの一覧（カタログ）を実行時に生成する
+//!
+//! 空グラフから辺を1本ずつ足していく幅優先探索で、見つけたグラフを標準形 (canonical_form) で重複除去して
+//! 各同型類を1回ずつ列挙する。正準増大 (orderly generation) のように重複を作らない生成ではないので、
+//! 各同型類の全ての辺の足し方について標準形を求める分だけ遅い。
+//! 同型類を頂点、辺1本の反転で移り合う同型類の組を辺とする「反転グラフ」も同時に作っておくと、
+//! 2つの同型類の編集距離は反転グラフ上の最短距離に等しいので、幅優先探索でまとめて求まる
+
+use crate::graph::Graph;
+use itertools::Itertools;
+use std::collections::{HashMap, VecDeque};
+
+/// 生成を許す最大の頂点数（焼きなましのバッファもこの大きさで確保する）
+///
+/// k = 8 (12346個) で約1秒かかり、k = 9 (274668個) は実用的な時間で生成できない
+pub const MAX_VERTICES: usize = 8;
+
+/// k頂点の互いに同型でないグラフの一覧と反転グラフ
+#[derive(Debug, Clone)]
+pub struct Catalog {
+    /// 辺の数の少ない順
+    graphs: Vec<Graph>,
+    /// flips[i] = graphs[i]の辺を1本反転させたグラフの添字（重複を除く）
+    flips: Vec<Vec<usize>>,
+}
+
+impl Catalog {
+    /// k頂点の互いに同型でないグラフを全て生成する
+    ///
+    /// k = 7で1044個、k = 8で12346個
+    pub fn new(k: usize) -> Self {
+        assert!(k <= MAX_VERTICES);
+        let empty = Graph::new(k);
+        let mut indices = HashMap::new();
+        indices.insert(empty.canonical_form(), 0);
+        let mut graphs = vec![empty];
+        let mut flips = vec![vec![]];
+
+        // 辺を1本足して新しい同型類を見つけ、取り除く向きの反転も同時に記録する。
+        // 辺の数が1少ない同型類は全て調べ終わっているので、取り除く向きの標準形を求め直す必要はない
+        let mut i = 0;
+
+        while i < graphs.len() {
+            let mut found = vec![];
+
+            for (u, v) in (0..k).tuple_combinations() {
+                if graphs[i].has_edge(u, v) {
+                    continue;
+                }
+
+                let mut graph = graphs[i].clone();
+                graph.connect(u, v);
+                let form = graph.canonical_form();
+                let next = indices.len();
+                let j = *indices.entry(form).or_insert(next);
+
+                if j == next {
+                    graphs.push(graph);
+                    flips.push(vec![]);
+                }
+
+                found.push(j);
+            }
+
+            found.sort_unstable();
+            found.dedup();
+
+            for &j in found.iter() {
+                flips[i].push(j);
+                flips[j].push(i);
+            }
+
+            i += 1;
+        }
+
+        Self { graphs, flips }
+    }
+
+    pub fn graphs(&self) -> &[Graph] {
+        &self.graphs
+    }
+
+    pub fn into_graphs(self) -> Vec<Graph> {
+        self.graphs
+    }
+
+    /// graphs[from]から各グラフへの編集距離
+    pub fn distances_from(&self, from: usize) -> Vec<usize> {
+        let mut distances = vec![usize::MAX; self.graphs.len()];
+        let mut queue = VecDeque::new();
+        distances[from] = 0;
+        queue.push_back(from);
+
+        while let Some(i) = queue.pop_front() {
+            for &j in self.flips[i].iter() {
+                if distances[j] == usize::MAX {
+                    distances[j] = distances[i] + 1;
+                    queue.push_back(j);
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// 互いの編集距離がなるべく大きくなるよう、count個を選んで添字を返す
+    ///
+    /// 既に選んだグラフまでの距離の最小値が最大となるものを貪欲に選んでいく（同じなら添字の小さいもの）。
+    /// 1つ選ぶたびに反転グラフ上を1回幅優先探索するだけなので、k = 8の全グラフから100個選んでも速い
+    pub fn select_far_apart(&self, count: usize) -> Vec<usize> {
+        let count = count.min(self.graphs.len());
+        let mut nearest = vec![usize::MAX; self.graphs.len()];
+        let mut selected = Vec::with_capacity(count);
+
+        while selected.len() < count {
+            let i = (0..self.graphs.len())
+                .max_by_key(|&i| (nearest[i], std::cmp::Reverse(i)))
+                .unwrap();
+            selected.push(i);
+
+            for (nearest, d) in nearest.iter_mut().zip(self.distances_from(i)) {
+                *nearest = (*nearest).min(d);
+            }
+        }
+
+        selected
+    }
+}
+
+/// k頂点の互いに同型でないグラフを全て生成する
+pub fn generate_graphs(k: usize) -> Vec<Graph> {
+    Catalog::new(k).into_graphs()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{generate_graphs, Catalog};
+    use crate::encoders::isomorphism::checker::{edit_distance, IsomophicChecker, Vf2Checker};
+    use itertools::Itertools;
+    use std::collections::HashSet;
+
+    #[test]
+    fn generate_graphs_test() {
+        let counts = (1..=6).map(|k| generate_graphs(k).len()).collect_vec();
+        assert_eq!(counts, vec![1, 2, 4, 11, 34, 156]);
+
+        // 互いに同型でない
+        let graphs = generate_graphs(5);
+
+        for (g1, g2) in graphs.iter().tuple_combinations() {
+            assert!(!Vf2Checker::new(g1).is_isomorphic(g2));
+        }
+
+        // 標準形も全て異なる
+        let graphs = generate_graphs(7);
+        let forms = graphs
+            .iter()
+            .map(|g| g.canonical_form())
+            .collect::<HashSet<_>>();
+        assert_eq!(graphs.len(), 1044);
+        assert_eq!(forms.len(), 1044);
+    }
+
+    #[test]
+    #[ignore]
+    fn generate_graphs_8_test() {
+        // デバッグビルドでは数秒かかる
+        assert_eq!(generate_graphs(8).len(), 12346);
+    }
+
+    #[test]
+    fn distances_from_test() {
+        // 反転グラフ上の距離は編集距離に一致する
+        let catalog = Catalog::new(5);
+        let graphs = catalog.graphs();
+
+        for i in [0, 7, 20] {
+            let distances = catalog.distances_from(i);
+
+            for (j, &d) in distances.iter().enumerate() {
+                assert_eq!(d, edit_distance(&graphs[i], &graphs[j]));
+            }
+        }
+    }
+
+    #[test]
+    fn select_far_apart_test() {
+        let catalog = Catalog::new(4);
+        let graphs = catalog.graphs();
+
+        // 空グラフの次は完全グラフが最も遠い
+        let selected = catalog.select_far_apart(2);
+        assert_eq!(selected, vec![0, graphs.len() - 1]);
+        assert_eq!(edit_distance(&graphs[0], &graphs[graphs.len() - 1]), 6);
+
+        let selected = catalog.select_far_apart(5);
+        let min_distance = selected
+            .iter()
+            .tuple_combinations()
+            .map(|(&i, &j)| edit_distance(&graphs[i], &graphs[j]))
+            .min()
+            .unwrap();
+        assert!(min_distance >= 2);
+    }
+}
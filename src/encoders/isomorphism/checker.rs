@@ -1,4 +1,8 @@
-use crate::{graph::Graph, utils::decode_hex_to_u64};
+use super::{
+    catalog::{Catalog, MAX_VERTICES},
+    tables::{ACCURACY_MATRIX, CONFUSION_ENTRIES, SAMPLE_COUNT},
+};
+use crate::graph::{parse_graph6_list, Graph, WORD_BITS};
 use itertools::Itertools;
 use std::cmp::Reverse;
 
@@ -10,42 +14,20 @@ pub trait IsomophicChecker {
 
 /// グラフの次数集合で同型性を判定（大嘘）する構造体
 /// 実際は同型性判定はできないのだが、計算量が軽く十分な数のグラフを識別できる
-#[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct DegreeChecker {
-    n: usize,
     degs: Vec<u32>,
-    graph: Graph,
 }
 
-#[allow(dead_code)]
 impl DegreeChecker {
     pub fn new(graph: &Graph) -> Self {
         let degs = Self::get_degs(graph);
-        let n = graph.n;
-
-        Self {
-            n,
-            degs,
-            graph: graph.clone(),
-        }
+        Self { degs }
     }
 
     fn get_degs(graph: &Graph) -> Vec<u32> {
-        let n = graph.n;
-        let mut degs = vec![0; n];
-
-        for i in 0..n {
-            for j in (i + 1)..n {
-                if graph[i][j] {
-                    degs[i] += 1;
-                    degs[j] += 1;
-                }
-            }
-        }
-
+        let mut degs = (0..graph.n).map(|u| graph.degree(u) as u32).collect_vec();
         degs.sort_unstable();
-
         degs
     }
 }
@@ -71,17 +53,68 @@ pub struct Vf2Checker {
 
 impl IsomophicChecker for Vf2Checker {
     fn is_isomorphic(&self, graph: &Graph) -> bool {
-        if self.n != graph.n {
-            return false;
-        }
+        self.find_isomorphism(graph).is_some()
+    }
+}
 
+impl Vf2Checker {
+    pub fn new(graph: &Graph) -> Self {
+        let n = graph.n;
         let degs = Self::get_degs(&graph);
+        let graph = AdjacencyListGraph::from(graph);
 
-        if self.degs != degs {
-            return false;
+        Self { n, degs, graph }
+    }
+
+    /// 同型写像を1つ求める。mapping[u]は元のグラフの頂点uに対応するgraphの頂点で、
+    /// `graph.permute(&mapping)`が元のグラフに一致する
+    pub fn find_isomorphism(&self, graph: &Graph) -> Option<Vec<usize>> {
+        if self.n != graph.n || self.degs != Self::get_degs(graph) {
+            return None;
         }
 
         let graph = AdjacencyListGraph::from(graph);
+        let mut mapping = None;
+
+        self.search(&graph, |map12| {
+            mapping = Some(map12.iter().map(|v| v.unwrap()).collect_vec());
+            true
+        });
+
+        mapping
+    }
+
+    /// 自己同型写像を全て列挙する（恒等写像を含む）
+    ///
+    /// 自己同型群の位数だけ要素を持つので、空グラフや完全グラフでは頂点数の階乗個になることに注意
+    pub fn automorphisms(&self) -> Vec<Vec<usize>> {
+        let mut automorphisms = vec![];
+
+        self.search(&self.graph, |map12| {
+            automorphisms.push(map12.iter().map(|v| v.unwrap()).collect_vec());
+            false
+        });
+
+        automorphisms
+    }
+
+    fn get_degs(graph: &Graph) -> Vec<u32> {
+        let mut degs = (0..graph.n).map(|u| graph.degree(u) as u32).collect_vec();
+        degs.sort_unstable();
+        degs
+    }
+
+    /// 同型写像が見つかるたびにon_foundを呼ぶ。on_foundがtrueを返したら探索を打ち切る
+    fn search(
+        &self,
+        graph: &AdjacencyListGraph,
+        mut on_found: impl FnMut(&[Option<usize>]) -> bool,
+    ) {
+        if self.n == 0 {
+            on_found(&[]);
+            return;
+        }
+
         let mut map12 = vec![None; self.n];
         let mut map21 = vec![None; self.n];
         let mut neighs1 = vec![false; self.n];
@@ -89,43 +122,18 @@ impl IsomophicChecker for Vf2Checker {
 
         Self::isomophism_dfs(
             &self.graph,
-            &graph,
+            graph,
             &mut map12,
             &mut map21,
             &mut neighs1,
             &mut neighs2,
             0,
-        )
-    }
-}
-
-impl Vf2Checker {
-    pub fn new(graph: &Graph) -> Self {
-        let n = graph.n;
-        let degs = Self::get_degs(&graph);
-        let graph = AdjacencyListGraph::from(graph);
-
-        Self { n, degs, graph }
-    }
-
-    fn get_degs(graph: &Graph) -> Vec<u32> {
-        let n = graph.n;
-        let mut degs = vec![0; n];
-
-        for i in 0..n {
-            for j in (i + 1)..n {
-                if graph[i][j] {
-                    degs[i] += 1;
-                    degs[j] += 1;
-                }
-            }
-        }
-
-        degs.sort_unstable();
-
-        degs
+            &mut on_found,
+        );
     }
 
+    /// 探索を打ち切る場合はtrueを返す
+    #[allow(clippy::too_many_arguments)]
     fn isomophism_dfs(
         graph1: &AdjacencyListGraph,
         graph2: &AdjacencyListGraph,
@@ -134,6 +142,7 @@ impl Vf2Checker {
         neighs1: &mut [bool],
         neighs2: &mut [bool],
         depth: usize,
+        on_found: &mut impl FnMut(&[Option<usize>]) -> bool,
     ) -> bool {
         let (vs1, v2) = Self::generate_candidates(graph1.n, map12, map21, neighs1, neighs2);
         let mut stack1 = vec![];
@@ -145,7 +154,15 @@ impl Vf2Checker {
 
             if Self::is_syntactic_feasible(edges1, edges2, map12, map21, neighs1, neighs2) {
                 if depth + 1 == graph1.n {
-                    return true;
+                    map12[v1] = Some(v2);
+                    let stop = on_found(map12);
+                    map12[v1] = None;
+
+                    if stop {
+                        return true;
+                    }
+
+                    continue;
                 }
 
                 map12[v1] = Some(v2);
@@ -153,9 +170,18 @@ impl Vf2Checker {
                 Self::update_neighs(graph1, neighs1, &mut stack1, v1);
                 Self::update_neighs(graph2, neighs2, &mut stack2, v2);
 
-                let found =
-                    Self::isomophism_dfs(graph1, graph2, map12, map21, neighs1, neighs2, depth + 1);
-                if found {
+                let stop = Self::isomophism_dfs(
+                    graph1,
+                    graph2,
+                    map12,
+                    map21,
+                    neighs1,
+                    neighs2,
+                    depth + 1,
+                    on_found,
+                );
+
+                if stop {
                     return true;
                 }
 
@@ -300,6 +326,143 @@ impl Vf2Checker {
     }
 }
 
+/// 細分化の各段階での (色, 隣接頂点の色) の一覧をソートしたもの
+type RefineHistory = Vec<Vec<(u32, Vec<u32>)>>;
+
+/// 色の細分化（1次元Weisfeiler-Lehman）でグラフの同型性を判定する構造体
+///
+/// 各頂点の色を「自分の色と隣接頂点の色の多重集合」で塗り直すことを、色数が増えなくなるまで繰り返す。
+/// 各段階の多重集合が一致しなければ同型でない。一致した場合、全頂点の色が異なれば色の対応がそのまま
+/// 同型写像になるので同型と分かり、そうでなければVF2で判定する
+#[derive(Debug, Clone)]
+pub struct WlChecker {
+    history: RefineHistory,
+    /// 細分化で全頂点の色が異なる状態になったか
+    discrete: bool,
+    vf2: Vf2Checker,
+}
+
+impl WlChecker {
+    pub fn new(graph: &Graph) -> Self {
+        let (history, discrete) = Self::refine(graph);
+        let vf2 = Vf2Checker::new(graph);
+
+        Self {
+            history,
+            discrete,
+            vf2,
+        }
+    }
+
+    /// 細分化だけで同型でないと分かるかどうか
+    pub fn distinguishes(&self, graph: &Graph) -> bool {
+        self.history != Self::refine(graph).0
+    }
+
+    /// 細分化で全頂点の色が異なる状態になったか（VF2が不要か）
+    pub fn is_discrete(&self) -> bool {
+        self.discrete
+    }
+
+    /// 同型写像を1つ求める。細分化で区別できなければVF2で探す
+    pub fn find_isomorphism(&self, graph: &Graph) -> Option<Vec<usize>> {
+        if self.distinguishes(graph) {
+            return None;
+        }
+
+        self.vf2.find_isomorphism(graph)
+    }
+
+    fn refine(graph: &Graph) -> (RefineHistory, bool) {
+        let mut colors = vec![0; graph.n];
+        let mut color_count = 1;
+        let mut history = vec![];
+
+        loop {
+            let signatures = (0..graph.n)
+                .map(|u| {
+                    let neighbors = (0..graph.n)
+                        .filter(|&v| graph.has_edge(u, v))
+                        .map(|v| colors[v])
+                        .sorted()
+                        .collect_vec();
+                    (colors[u], neighbors)
+                })
+                .collect_vec();
+
+            // 前の段階の多重集合が一致していれば、色の番号の付け方も一致する
+            let mut sorted = signatures.clone();
+            sorted.sort();
+            let mut distinct = sorted.clone();
+            distinct.dedup();
+
+            for (color, signature) in colors.iter_mut().zip(signatures.iter()) {
+                *color = distinct.binary_search(signature).unwrap() as u32;
+            }
+
+            history.push(sorted);
+
+            if distinct.len() == color_count {
+                return (history, color_count == graph.n);
+            }
+
+            color_count = distinct.len();
+        }
+    }
+}
+
+impl IsomophicChecker for WlChecker {
+    fn is_isomorphic(&self, graph: &Graph) -> bool {
+        if self.distinguishes(graph) {
+            return false;
+        }
+
+        self.discrete || self.vf2.is_isomorphic(graph)
+    }
+}
+
+/// グラフの集合を各判定器がどれだけ区別できるかの統計
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeparationStats {
+    /// グラフの数
+    pub graphs: usize,
+    /// グラフの組の数
+    pub pairs: usize,
+    /// 次数列では区別できない組の数
+    pub degree_collisions: usize,
+    /// 色の細分化では区別できない組の数
+    pub wl_collisions: usize,
+    /// 細分化で全頂点の色が異なる状態になるグラフの数
+    pub discrete: usize,
+}
+
+/// 互いに同型でないグラフの集合について、DegreeCheckerとWlCheckerの区別できる割合を調べる
+pub fn separation_stats(graphs: &[Graph]) -> SeparationStats {
+    let degree_checkers = graphs.iter().map(DegreeChecker::new).collect_vec();
+    let wl_checkers = graphs.iter().map(WlChecker::new).collect_vec();
+    let mut stats = SeparationStats {
+        graphs: graphs.len(),
+        pairs: 0,
+        degree_collisions: 0,
+        wl_collisions: 0,
+        discrete: wl_checkers.iter().filter(|c| c.is_discrete()).count(),
+    };
+
+    for (i, j) in (0..graphs.len()).tuple_combinations() {
+        stats.pairs += 1;
+
+        if degree_checkers[i].is_isomorphic(&graphs[j]) {
+            stats.degree_collisions += 1;
+        }
+
+        if !wl_checkers[i].distinguishes(&graphs[j]) {
+            stats.wl_collisions += 1;
+        }
+    }
+
+    stats
+}
+
 #[derive(Debug, Clone)]
 struct AdjacencyListGraph {
     n: usize,
@@ -326,7 +489,7 @@ impl From<&Graph> for AdjacencyListGraph {
 
         for u in 0..graph.n {
             for v in (u + 1)..graph.n {
-                if graph[u][v] {
+                if graph.has_edge(u, v) {
                     adj_graph.add_edge(u, v);
                 }
             }
@@ -336,23 +499,143 @@ impl From<&Graph> for AdjacencyListGraph {
     }
 }
 
+/// 頂点の対応を全て試し、graph1をgraph2と同型にするのに必要な辺の反転回数の最小値を求める
+///
+/// 最悪で頂点数の階乗に比例する時間がかかるので、元のグラフ程度の小さいグラフにのみ使う
+pub fn edit_distance(graph1: &Graph, graph2: &Graph) -> usize {
+    edit_distance_bounded(graph1, graph2, usize::MAX)
+}
+
+/// edit_distanceと同様だが、bound以上であることが分かった時点で探索を打ち切りboundを返す
+pub fn edit_distance_bounded(graph1: &Graph, graph2: &Graph, bound: usize) -> usize {
+    assert_eq!(graph1.n, graph2.n);
+    let mut map12 = Vec::with_capacity(graph1.n);
+    let mut used = vec![false; graph2.n];
+    // 次数順に対応させたときの距離を初期の上界とする
+    let sort_by_degree = |graph: &Graph| {
+        let order = (0..graph.n)
+            .sorted_by_key(|&v| Reverse(graph.degree(v)))
+            .collect_vec();
+        graph.permute(&order)
+    };
+    let mut best = sort_by_degree(graph1)
+        .distance(&sort_by_degree(graph2))
+        .min(bound);
+
+    // 1回の反転で次数は2つしか変わらないので、ソートした次数の差の半分が下界になる
+    let degs1 = Vf2Checker::get_degs(graph1);
+    let degs2 = Vf2Checker::get_degs(graph2);
+    let diff: u32 = degs1
+        .iter()
+        .zip(degs2.iter())
+        .map(|(&d1, &d2)| d1.abs_diff(d2))
+        .sum();
+
+    if (diff as usize).div_ceil(2) >= best {
+        return best;
+    }
+
+    edit_distance_dfs(graph1, graph2, &mut map12, &mut used, 0, &mut best);
+    best
+}
+
+fn edit_distance_dfs(
+    graph1: &Graph,
+    graph2: &Graph,
+    map12: &mut Vec<usize>,
+    used: &mut [bool],
+    cost: usize,
+    best: &mut usize,
+) {
+    let v1 = map12.len();
+
+    if v1 == graph1.n {
+        *best = cost;
+        return;
+    }
+
+    for v2 in 0..graph2.n {
+        if used[v2] {
+            continue;
+        }
+
+        // 対応の決まった頂点との間の辺の食い違いを数える
+        let diff = map12
+            .iter()
+            .enumerate()
+            .filter(|&(u1, &u2)| graph1.has_edge(u1, v1) != graph2.has_edge(u2, v2))
+            .count();
+
+        if cost + diff >= *best {
+            continue;
+        }
+
+        map12.push(v2);
+        used[v2] = true;
+
+        if cost + diff + remaining_lower_bound(graph1, graph2, map12, used) < *best {
+            edit_distance_dfs(graph1, graph2, map12, used, cost + diff, best);
+        }
+
+        used[v2] = false;
+        map12.pop();
+    }
+}
+
+/// 対応の決まっていない頂点が絡む頂点対について、必要な反転回数の下界を求める
+fn remaining_lower_bound(graph1: &Graph, graph2: &Graph, map12: &[usize], used: &[bool]) -> usize {
+    let words = Graph::words_for(graph1.n);
+    let mut rest1 = vec![0; words];
+    let mut rest2 = vec![0; words];
+
+    for v in map12.len()..graph1.n {
+        rest1[v / WORD_BITS] |= 1 << (v % WORD_BITS);
+    }
+
+    for v in (0..graph2.n).filter(|&v| !used[v]) {
+        rest2[v / WORD_BITS] |= 1 << (v % WORD_BITS);
+    }
+
+    // 対応の決まった頂点から未対応の頂点に出る辺の本数の差
+    let cross: usize = map12
+        .iter()
+        .enumerate()
+        .map(|(u1, &u2)| {
+            let count1 = graph1.count_neighbors_in(u1, &rest1);
+            let count2 = graph2.count_neighbors_in(u2, &rest2);
+            count1.abs_diff(count2)
+        })
+        .sum();
+
+    // 未対応の頂点同士の辺の本数の差
+    let inner1: usize = (map12.len()..graph1.n)
+        .map(|v| graph1.count_neighbors_in(v, &rest1))
+        .sum();
+    let inner2: usize = (0..graph2.n)
+        .filter(|&v| !used[v])
+        .map(|v| graph2.count_neighbors_in(v, &rest2))
+        .sum();
+
+    cross + (inner1 / 2).abs_diff(inner2 / 2)
+}
+
 /// 互いに同型でないグラフをn個生成する
+///
+/// 精度表のないbitsでは、実行時に生成したカタログから互いに遠いものを選ぶ
 pub fn try_generate_isompic_graphs(
     n: usize,
     error_ratio: f64,
     bits: usize,
 ) -> Option<(Vec<Graph>, Vec<Vec<u32>>)> {
+    if !TABLE_BITS.contains(&bits) {
+        return generate_far_apart_graphs(n, bits);
+    }
+
     // 0.29あたりが正確に変換できない説がある
     // roundすればおそらく大丈夫なのだが念のため
     let error_ratio = (error_ratio * 100.0 + 0.1) as usize;
     let accuracy_matrix = get_accuracy_matrix();
-    let graphs = if bits == 4 {
-        gen_graphs(&GRAPHS_4, 4)
-    } else if bits == 5 {
-        gen_graphs(&GRAPHS_5, 5)
-    } else {
-        gen_graphs(&GRAPHS_6, 6)
-    };
+    let graphs = load_graphs(bits);
 
     if graphs.len() >= n {
         let graphs = take_n_graphs(graphs, &accuracy_matrix, n, error_ratio, bits);
@@ -365,6 +648,54 @@ pub fn try_generate_isompic_graphs(
     }
 }
 
+/// 精度表から、n個のグラフを使ったときの1クエリあたりの正解率を見積もる
+pub fn predict_accuracy(n: usize, error_ratio: f64, bits: usize) -> Option<f64> {
+    if !TABLE_BITS.contains(&bits) {
+        return None;
+    }
+
+    let error_ratio = (error_ratio * 100.0 + 0.1) as usize;
+    let accuracy_matrix = get_accuracy_matrix();
+    let accuracies = &accuracy_matrix[bits - 4][error_ratio];
+
+    if accuracies.len() < n {
+        return None;
+    }
+
+    // 精度表はSAMPLE_COUNT回中の正解数
+    let mut accuracies = accuracies.clone();
+    accuracies.sort_by_key(|&acc| Reverse(acc));
+    let sum: u32 = accuracies.iter().take(n).sum();
+    Some(sum as f64 / (n as u32 * SAMPLE_COUNT) as f64)
+}
+
+fn generate_far_apart_graphs(n: usize, bits: usize) -> Option<(Vec<Graph>, Vec<Vec<u32>>)> {
+    if bits > MAX_VERTICES {
+        return None;
+    }
+
+    let catalog = Catalog::new(bits);
+
+    if catalog.graphs().len() < n {
+        return None;
+    }
+
+    let graphs = catalog
+        .select_far_apart(n)
+        .into_iter()
+        .map(|i| catalog.graphs()[i].clone())
+        .collect_vec();
+
+    // 混同行列がないので、復元結果をそのまま信じる
+    let mut confusing = vec![vec![0; n]; n];
+
+    for (i, row) in confusing.iter_mut().enumerate() {
+        row[i] = 1000;
+    }
+
+    Some((graphs, confusing))
+}
+
 fn take_n_graphs(
     graphs: Vec<Graph>,
     accuracy_matrix: &Vec<Vec<Vec<u32>>>,
@@ -382,7 +713,7 @@ fn take_n_graphs(
 }
 
 fn generate_confusing_matrix(indices: &[usize], error_ratio: usize, bits: usize) -> Vec<Vec<u32>> {
-    let raw_matrix = &get_confusing_matrix()[bits - 4][error_ratio];
+    let raw_matrix = get_confusing_matrix(bits, error_ratio);
     let mut result = vec![vec![0; indices.len()]; indices.len()];
 
     for (i, &u) in indices.iter().enumerate() {
@@ -395,296 +726,149 @@ fn generate_confusing_matrix(indices: &[usize], error_ratio: usize, bits: usize)
     result
 }
 
-fn gen_graphs(bits: &[u32], n: usize) -> Vec<Graph> {
-    let mut graphs = vec![];
+/// 互いに同型でないbits頂点のグラフを全て読み込む
+///
+/// 並び順は精度表・混同行列の添字と対応している
+fn load_graphs(bits: usize) -> Vec<Graph> {
+    let graphs = match bits {
+        4 => GRAPHS_4,
+        5 => GRAPHS_5,
+        6 => GRAPHS_6,
+        _ => unreachable!("no embedded graphs for bits = {}", bits),
+    };
 
-    for &bit in bits.iter() {
-        let mut index = 0;
-        let mut graph = Graph::new(n);
+    parse_graph6_list(graphs).unwrap()
+}
 
-        for u in 0..n {
-            for v in (u + 1)..n {
-                if ((bit >> index) & 1) > 0 {
-                    graph.connect(u, v);
-                }
+/// 精度表・混同行列の用意されているbits
+const TABLE_BITS: std::ops::RangeInclusive<usize> = 4..=6;
 
-                index += 1;
-            }
-        }
+const GRAPHS_4: &str = include_str!("graphs/graphs4.g6");
+const GRAPHS_5: &str = include_str!("graphs/graphs5.g6");
+const GRAPHS_6: &str = include_str!("graphs/graphs6.g6");
+
+fn get_accuracy_matrix() -> Vec<Vec<Vec<u32>>> {
+    ACCURACY_MATRIX
+        .iter()
+        .map(|matrix| {
+            matrix
+                .iter()
+                .map(|line| line.iter().map(|&c| c as u32).collect_vec())
+                .collect_vec()
+        })
+        .collect_vec()
+}
+
+/// [送ったグラフ][復元したグラフ] = 回数 の混同行列を求める
+fn get_confusing_matrix(bits: usize, error_ratio: usize) -> Vec<Vec<u32>> {
+    let n = ACCURACY_MATRIX[bits - 4][error_ratio].len();
+    let mut matrix = vec![vec![0; n]; n];
 
-        graphs.push(graph);
+    for &(eps, truth, answered, count) in CONFUSION_ENTRIES[bits - 4].iter() {
+        if eps as usize == error_ratio {
+            matrix[truth as usize][answered as usize] = count as u32;
+        }
     }
 
-    graphs
+    matrix
 }
 
-const GRAPHS_4: [u32; 11] = [
-    0b000000, 0b000001, 0b000011, 0b000111, 0b001011, 0b001100, 0b001101, 0b001111, 0b011110,
-    0b011111, 0b111111,
-];
-
-const GRAPHS_5: [u32; 34] = [
-    0b0000000000,
-    0b0000000001,
-    0b0000000011,
-    0b0000000111,
-    0b0000001111,
-    0b0000010011,
-    0b0000010100,
-    0b0000010101,
-    0b0000010111,
-    0b0000011100,
-    0b0000011101,
-    0b0000011111,
-    0b0000110110,
-    0b0000110111,
-    0b0000111010,
-    0b0000111011,
-    0b0000111110,
-    0b0000111111,
-    0b0001111110,
-    0b0001111111,
-    0b0010110111,
-    0b0010111000,
-    0b0010111001,
-    0b0010111011,
-    0b0010111111,
-    0b0011001111,
-    0b0011011100,
-    0b0011011101,
-    0b0011011111,
-    0b0011111110,
-    0b0011111111,
-    0b0111101111,
-    0b0111111111,
-    0b1111111111,
-];
-
-const GRAPHS_6: [u32; 156] = [
-    0b000000000000000,
-    0b000000000000001,
-    0b000000000000011,
-    0b000000000000111,
-    0b000000000001111,
-    0b000000000011111,
-    0b000000000100011,
-    0b000000000100100,
-    0b000000000100101,
-    0b000000000100111,
-    0b000000000101100,
-    0b000000000101101,
-    0b000000000101111,
-    0b000000000111100,
-    0b000000000111101,
-    0b000000000111111,
-    0b000000001100110,
-    0b000000001100111,
-    0b000000001101010,
-    0b000000001101011,
-    0b000000001101110,
-    0b000000001101111,
-    0b000000001111000,
-    0b000000001111001,
-    0b000000001111010,
-    0b000000001111011,
-    0b000000001111110,
-    0b000000001111111,
-    0b000000011101110,
-    0b000000011101111,
-    0b000000011110110,
-    0b000000011110111,
-    0b000000011111110,
-    0b000000011111111,
-    0b000000111111110,
-    0b000000111111111,
-    0b000001001100111,
-    0b000001001101000,
-    0b000001001101001,
-    0b000001001101011,
-    0b000001001101111,
-    0b000001001111000,
-    0b000001001111001,
-    0b000001001111011,
-    0b000001001111111,
-    0b000001010001111,
-    0b000001010010000,
-    0b000001010010001,
-    0b000001010010011,
-    0b000001010010110,
-    0b000001010010111,
-    0b000001010011111,
-    0b000001010101100,
-    0b000001010101101,
-    0b000001010101111,
-    0b000001010110011,
-    0b000001010110100,
-    0b000001010110101,
-    0b000001010110110,
-    0b000001010110111,
-    0b000001010111100,
-    0b000001010111101,
-    0b000001010111111,
-    0b000001011101110,
-    0b000001011101111,
-    0b000001011110110,
-    0b000001011110111,
-    0b000001011111000,
-    0b000001011111001,
-    0b000001011111010,
-    0b000001011111011,
-    0b000001011111110,
-    0b000001011111111,
-    0b000001110011000,
-    0b000001110011001,
-    0b000001110011010,
-    0b000001110011011,
-    0b000001110011110,
-    0b000001110011111,
-    0b000001110111010,
-    0b000001110111011,
-    0b000001110111100,
-    0b000001110111101,
-    0b000001110111110,
-    0b000001110111111,
-    0b000001111111110,
-    0b000001111111111,
-    0b000011011001111,
-    0b000011011010101,
-    0b000011011010111,
-    0b000011011011111,
-    0b000011011101111,
-    0b000011011110100,
-    0b000011011110101,
-    0b000011011110111,
-    0b000011011111100,
-    0b000011011111101,
-    0b000011011111111,
-    0b000011101011000,
-    0b000011101011001,
-    0b000011101011011,
-    0b000011101011100,
-    0b000011101011101,
-    0b000011101011111,
-    0b000011101111011,
-    0b000011101111100,
-    0b000011101111101,
-    0b000011101111111,
-    0b000011111011100,
-    0b000011111011101,
-    0b000011111011110,
-    0b000011111011111,
-    0b000011111111110,
-    0b000011111111111,
-    0b000111111011100,
-    0b000111111011101,
-    0b000111111011111,
-    0b000111111111111,
-    0b001011011101111,
-    0b001011011110000,
-    0b001011011110001,
-    0b001011011110011,
-    0b001011011110111,
-    0b001011011111111,
-    0b001011100010001,
-    0b001011100010011,
-    0b001011100010111,
-    0b001011100011111,
-    0b001011100110101,
-    0b001011100110111,
-    0b001011100111100,
-    0b001011100111101,
-    0b001011100111110,
-    0b001011100111111,
-    0b001011101110111,
-    0b001011101111010,
-    0b001011101111011,
-    0b001011101111110,
-    0b001011101111111,
-    0b001011111111110,
-    0b001011111111111,
-    0b001100111111110,
-    0b001100111111111,
-    0b001101110011111,
-    0b001101110111100,
-    0b001101110111101,
-    0b001101110111111,
-    0b001101111111110,
-    0b001101111111111,
-    0b001111111011101,
-    0b001111111011111,
-    0b001111111111111,
-    0b011110111111110,
-    0b011110111111111,
-    0b011111111111111,
-    0b111111111111111,
-];
+#[cfg(test)]
+mod test {
+    use super::{
+        edit_distance, load_graphs, separation_stats, IsomophicChecker, Vf2Checker, WlChecker,
+    };
+    use crate::graph::Graph;
 
-fn get_accuracy_matrix() -> Vec<Vec<Vec<u32>>> {
-    // 16進数3桁ずつのデータ
-    const HEX_DATA: &[u8] = b"3e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e43e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e53e83e83e83e83e73e83e83e83e83e83e13e83e83e83e83e63e83e83e83e83e83d73e83e83e83e83e43e73e83e83e83e83b63e83e83e83e83dd3e63e83e73e83e739c3e83e83e83e83d43e63e73e73e83e635c3e83e83e83e83e53e83e83e83e83e83d03e83e83e83e83e33e83e73e83e83e83b43e83e83e83e83e73e83e73e83e83e83e03e83e83e83e83e63e83e73e73e83e83dd3e83e83e83e83e13e83e73e73e73e73c73e83e83e83e83d73e83e73e73e83e239f3e83e83e83e83e53e83e63e63e63e63de3e83e83e83e83da3e73e43e53e33e83c33e83e83e83e73d23e63e43e33e43e63a13e83e83e83e83e73e83e33e83e73e63e53e83e73e83e83e43e83e73e83e13e73dc3e83e53e83e83e13e43e33e83dd3e63c93e83e83e83e83e73e83dc3e83d23dd3e73e83e83e83e83e13e83d83e83c63e53e63e83e63e83e83d83e53d83e53bb3e03cc3e83e83e83e83e73e73cd3e73ae3d83e63e83e73e73e83e13e63c63e43933dc3dc3e83e53e63e83c73e33c53de3613db3cf3e83d83e63e83963d13cd3cb34b3cc38f3e83e83e83e83e83e82923e53a72233e83e83e83e83e83e83e82ad3e33992673e83e83e83e73e63e83e82c43e43572953e83e83e73e73e83e73e72d93dd3482fe3e83e83d63e13d93dc3df2f33c72ff3663e23e839c3d13d53b03ab32d39528e3ac3c43e830c39b3ac2fe2df3032fe2543903323e823634c3771de1a62bf2191ec30e1f83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e73e83e83e83e83e83e83e83e83e83e83e83e23e83e83e73e83e83e63e73e83e83e73e83e83e83e83e83e83e83e83e73e83e53e33e83e83e63e83e83e83e83e73e83e83e73cc3e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83de3e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e53e83e83e83e83e83e83e83e83e83e83e73e83e03e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e33e83e83e83e73e83e83e83e83e83e83e83e83cd3e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83dd3e73e83e83e63e83e83e83e83e83e83e73e53ab3e83e83e83e83e83e53e83e83e73e83e83e73e83e73e83e83e83e83e83e83c83e23e73e63e43e73e83e83e83e83e83e73e13813e83e73e83e83e83dc3e83e83e73e83e83e73e83e73e83e83e83e83e83e83a53e03e63e63e03e73e83e83e83e73e73e73df32a3e83e53e83e83e83d33e43e83e63e83e83e73e83e63e83e83e83e83e83e736a3d13e63e53dc3e43e83e73e83e63e73e73cf2b83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83d73e63e83e83e43e83e83e83e83e83e83e73e73b83e83e73e83e83e83e73e63e73e83e73e83e83e83e73e83e73e83e83e83e73c43e53e83e83e33e83e83e73e83e83e83e53d93883e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83de3e83e73e83e63e83e83e73e63e83e83e73e73d53e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83d63e43e43e83e73e83e83e83e53e83e73e23e53c43e83e83e83e83e83e73e83e63e83e73e83e83e73e73e83e83e83e83e83e83cd3e23e53e83e73e83e83e63e63e83e53e03e13a13e83e73e83e83e83de3e73e83e83e63e83e73e73e53e73e83e73e83e83e73af3d93e03e83dd3e73e83e83e43e83e63df3d73533e83e83e83e83e83e03e83e73e73e83e83e73e53e83e83e83e83e83e83e73dd3e73d73e83e53e73e83e43e13e83e73cd3dd3d33e83e83e83e83e83dd3e83e43e63e83e83e73e43e73e83e83e83e83e83e73d53e03da3e83de3e53e83dc3e13e83e73b83d83b43e83e73e83e83e83d63e53e33e13e63e83e73e03e73e83e83e83e73e83e53ba3d43d83e43d03e43e73e03d43e53df3a73cd3783e83e83e83e83e83e53e83de3e83e83e83e83db3de3e83e83e83e83e83e83e73e83aa3e63e73e83e73cc3ca3e73e538b37f3e53e83e83e83e83e83e63e83db3e83e73e83e83d33e03e73e83e63e83e73e73e63e53a03e63e43e83e83c73c73e73e336838f3e03e83e73e63e83e83e33e83e43e83e63e83e83c53df3e83e83e53e73e43e63d73e33a63e73e13e73e63bc3c13e43e33393a63cf3e83e83e83e83e83e83e73cf3e63e83e83e83cb3d03e83e83e73e73e53e83e63e832e3e83e43e83e73873a43e73e131f3183e73e83e83e83e83e83e53e73d93e53e83e73e63a63d63e63e83e53e63dc3e23e63e43403e63e53e83e836e3933e63e32fb34c3e53e83e73e73e83e83e63e53c43e43e83e53e63963d93e53e73db3e63d73e43dc3e03543e03df3e63e535d3993e23dc2b93693dd3e83e83e73e63e73e73e83a53e63e83e03e73903be3e53e83dd3e53d73e03e63e729a3e63e63e73dd3133413df3d226f25e3e83e83e73e73e53e83e43e83a53e73e53df3e73623c83e13e63c73e53a93dc3e13e32d33e23e13e73d62eb3563c93c924b2c83e43e83e33dd3e13e83d23e139e3dd3e13d33dd3473cb3d83e13a63de3773dd3d13d22e73df3d73e03ca2b73593b13c42443283d13e83d03d43d23e83ae3cb39d3cc3db3b73d53003cd3ce3cd3703da32c3c73ab39f3103c63b43cc3b529f35837a3a324536b3a53e83e73dd3dd3e83e83e832b3db3e73b73e534b2e83d23d237b3cc3323753e83e711a3d13d33e738b1c52533bf2c10ec0db3e83e83e43bc3c43e83e53e53303cd3e738c3e52e93323b23bf3353c72da3a43e73e41413cc3d73e337a1822653922d212611d3e83e83c738e3983e83d83d73203c13e23593d62bf36e38a3882ce3c825139a3e13db19c3b03d03d536414d28f3402be13d1bb3e43e838734c3443e83bd3ae2e838b3cb3203a32593a63603192623b81d83923ce3a91db3803b33a32f01482a22c02b91bb27e3cf3e83042f72e93e435c32629f33438b2d23342073a23152c91e539618d34f35132823a32d35a31627413e2b922f2741f03203823e82682892843dd28a23420c27f2fd23f25a18834c2a22151753461252ae2761f02302982ab22c1c814428916f20921e30d2713e819f23b2233bd18713e17d1ae2371a119413229a1d71811332be0cb20915b0ee1bb1ba18512b11c1142400fb15620a23a1173e71201f51cb3550980791110e414d1390cd0ea1b01601000ee20e0ba13106504d0ff0f30940710c00f31950a50941851070363e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e73e83e73e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e63e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e13e33e83e83e83e73e73e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e63e83e73e83e73e73d73e83e73e83e83e83e83e53e83e83e83e83e73e73e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83df3e73e83e83e73e73e73e83e83e83e63e83e73e73e83e73e83e83e83e83e83e83e83e83e83e83e73e83e73e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e73e83e83e83e73e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e63c73d53e83e83e73e13e03e73e83e53e83e83e83e83e83e53e73e83e83e83e83e53e73e83e83e83e83e83e83e73e63e53e83e53e83e73e23853e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e63e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e63e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e43e73e83e83e73e33e53e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e73dd3e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e73e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e53e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e53e83e83e83e73e83e83e83e73e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e33e43e83e83e83e53e83e83e83e63e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e73e83e23e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e43e73e73e83e63e73e83e83e63e83e63e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83de3e13e83e83e83e23e63e83e83e63e83e83e83e83e83e83e83e83e83e83e73e83e73e83e73e83e83e83e83e83e83e83e83e73e83e83e33cc3e83e83e83e83e83e83e63e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83da3e73e73e83e53e73e83e83e73e83e53e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e73e73e63e83e83e83e73e83e83e83e83e53e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73c83d23e73e63e83db3e43e83e83e23e73e83e83e83e73e83e83e83e83e83e73e73e53e83e83e83e73e83e83e83e73e73e83e43e83e83cd3a53e83e83e83e83e83e83e33e73e83e83e83e73e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83cc3e03e63e83e23e53e83e83e63e83e33e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e63e83e83e83e83e73e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e73e83e83e83e83e23e83e83e83e83e83e73e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e43993bf3e73e73e43bd3dc3e73e83e43e73e63e83e83e73e83e83e83e83e83e63e43df3e83e83e83e83e73e83e83e53e73e83e13e83e83be3473e83e83e83e83e83e83e13e83e83e83e83e73e83e73e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e73a63dc3e73e83dd3e33e83e83e13e83e13e73e73e53e83e83e83e83e73e83e83e83e83e83e83e83e83e83e53e73e83e83e83e83e83e83e73e83e73e83e83e83e63e83e83e83e83e83e83e83e73e83e83e83e73db3e73e83e83e83e83e63e73e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83df3593983e43e63e23993d23e43e53da3e53e63e83e83e53e83e73e83e83e73e43e03db3e83e73e83e83e73e63e83e63e63e73dc3e63e43ac2eb3e73e73e83e83e83e83d83e73e83e63e83e73e73e73e73e83e83e73e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e736e3cf3e63e63d53d93e63e83dd3e33de3e83e83e43e83e63e83e73e63e83e83e83e73e73e83e83e83e73e13e73e73e83e73e83e73e83e63e53e53e83e73e83e73e83e73e83e83e83e73e73e53e53e83e83e63d13e83e73e63e63e83e63e73e73e83e83e73e83e83e83e83e73e83e83e83e83e83e73e83e73e83d32fe3673da3e53da3653ba3e43e43d13e23e33e63e83e53e73e73e73e83e63e23da3d23e63e13e83e83e73e23e73e23e73e43ce3e43e03762553e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83d83e53e43e83e73e73e83e83e63e83e73e73e83e83e83e83e83e83e73e73e73e83e83e83e73e83e83e83e73e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e63e83e83e83e03e73e83e83e83e73e83e63e73e83e83e83e83e83e83e83e83e83e83e83e83e83e73e83e83e83e43d03dd3e33e83e83d93e33e43e73e73e73e63e83e83e83e83e53e83e83e83e63e63e53e83e73e73e63e83e13e73e03e73e73e53e33e43d13b33e83e83e83e83e83e83e73e73e73e83e83e83e83e83e83e83e63e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e73c33e13e73e73e73e43e83e83e23e73e63e83e83e73e83e63e83e83e63e73e73e83e83e83e73e73e83e83e73e83e73e83e43e83e83e83e83e83e83e83e73e83e83e83e83e73e83e83e83e83e73e33e83e83e83dc3e83e73e83e83e73e83e83e83e83e73e83e83e83e83e83e83e83e73e83e63e83e73e83e83e73e13ae3cb3dd3e83e43c93dd3e33e83e63df3e53e83e73e83e53e33e83e63e83e63e43e23e83e53e83e83e83dd3e73de3e63e63e03e03df3bb3763e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e73e73e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e03e73dd3e83e63e83e83e83e83e83e83e73e83e83e83e83e83e83e43e83e83e83e73e83e83e83e63e83e83e83e83e83e83e83e83e83e83e63e73e83e63e83e83e83e83e83e83e83e83e83e83e23e73e83e73e03e83e63e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e83e23e43d93e83e83e13e83c13e83e83d33e13e83e83e73e13e83e83e53e83e13e83e73e83e83e43e73e83c73e73d43e83e83e73df3d43c83d83e83e83e83e83e83e83e83e73e83e83e83e73e83e83e83e83e83e83e83e83e83e83e63e83e83e83e83e83e83e83e83e83e73e83e83e83de3e53dc3e83e63e83e83e83e83e73e83e73e83e83e83e83e83e73df3e83e83e83e83e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e63e73e53e83e83e83e73e83e83e83e83e73e83db3e73e73e63de3e83e63e73e83e83e53e73e83e83e63e73e83e83e83e83e83e83e83e83e83e83e73e83e83e83e53d93e13dd3e83e53dc3e43c33e83e83d93df3e83e83e73db3e83e83e33e83df3e73e43e83e83e13e63e83bd3e73d13e83e83e83d33c43cd3c53e83e73e83e83e83e83e63e73e63e73e83e83e83e83e83e83e53e73e83e83e83e83e63e83e83e83e83e83e83e83e83e83e83e83e83e73d73e43dc3e73e03e53e73e83e63e83e83e83e83e83e83e83e73e23df3e83e83e83e43e83e73e83e73e83e73e83e63e83e73e73e83e83e83e53e53e83e23e83e83e83e73e83e73e83e53e73e83d43e83e83e53dd3e83e53e73e83e73e73e83e73e83e83e73e73e83e83e83e73e83e83e83e83e83e73e83e83e73e43aa3cf3d43e73e43c73de3bc3e73e33c73df3e83e83e13dd3e83e83dd3e63dd3e43e43e83e83e43e73e63b13e53ca3e83e63e53be3c53c23943e83e73e83e83e83e83e03e53e63e43e73e83e83e73e83e83e23e73e83e83e73e73e73e83e83e83e83e83e73e83e83e83e83e83e83e73c43dc3d83e73d73e23e83e83e43e83e73e83e83e73e83e73e83e13dc3e83e73e83e63e73e73e83e63e83e53e73e43e83e83e83e83e83e73e43e33e83e23e83e83e83e63e73e53e83e63e73e73c63e73e83df3d73e63e43e53e83e73e43e83e83e73e33e83e63e83e73e83e73e83e73e83e53e83e63e83e83e83de3883b93cf3e63de3b23d13b83e73d93c73d83e83e83e33d63e83e63da3e73db3e03de3e73e83db3e13e73a63e13c73e73e33d838d3ae3b13363e83e73e83e73e83e83e43e83e33e83e83e83e83e83e83e83e03e33e83e83e83e73e83e83e83e83e83e83e83e83e83e83e83e83e83e83e43e13c93e83e63e43e73e83e53e83e83e83e83e73e83e83e83dc3d63e83e83e83e43e83e73e83e13e83e83e73e73e83e73e83e73e63e63e23e73e83d63e73e83e83e83e83e53e83e13e83e73c13e63e83d63c83e83e53e63e83e83e43e83e83e73e33e53e83e83e83e83e83e83e83e83e13e83e73e83e73e73e23da3e33993e63e53e33e63713e83e33b03cd3e83e83d63c83e73e73d93e73ba3e73e43e83e73bd3e13e032f3e43a43e73e43e43983703803d53e83e63e83e73e83e83e43e63e33e83e83e83e73e63e83e83db3e23e73e83e73e73e83e83e83e83e83e83e63e63e83e83e83e83e83e83d73dc3c43e53e03e23e53e73e13e83e63e53e83e63e83e73e63d53ce3e83e83e83e63e83e63e83dd3e83e73e73e73e73e73e83e73e53e73da3e73e83d33e73e73e83e73e83e53e83e33e83e83a73e33e83d43c03e73e23e63e73e63e43e53e73e83e33e53e73e83e83e83e33e83e83e83df3e83e33e83e73e63dd3c73d93a23e13e23d73e138a3e63de3923d23e83e83d83c13e73e63d23e73c43e33e13e83e73c53d93d93423dd3b13e53e03dd36a3623943ab3e83e53e73e83e83e83df3e43e23e73e73e73e63e33e83e73d13e43e83e53e73e83e83e83e73e83e83e83e73e43e83e83e83e83e83e83c73dc3c43e33da3d93e33e53dd3e83e13e53e73e53e63e63e63ca3d23e83e73e83d83e83e63e73da3e43df3e63e73e63e53e83e63e53e73d53e23e73ca3e53e63e83e73e63de3e73de3e73e83853e53e73c63cb3e33e03e73e43e33df3e73e53e73db3e43e43e63e83e63e33e73e83e53dc3e83e23e83e63e43d53a33b43a33dd3db3c13d038b3e43d43893cb3e53e73c33bc3e43e63c53e53bc3dd3d93e73e33b63cc3ce3233d63ac3e53da3d031133e38a3753e83e83e83e83e83e83e83e83d73e83e83e83e83e83e83e83c93d93e83e83e73e83e73e83e83e83e83e83e73e83e83e83e83e83e83e83e63e836d3e73e63e73e53e83e73e83e83d83e83e83e83e73e73b43a13e83e73e73c53e83e23e73d93e63e23e83e83e83e83e83e73e83e63d73de3e53953e83e83e83e83e73d43e83cd3e83e83633dd3e83c433d3e83d53e73e73e63ca3e73e63e73dc3e53e43e63e73e83e43e83e73e83c43e73e63e83e73e23e13e43e72ed3e43e53e83e821f3e63e82e93a03e73e839435c3e63e23b53e736b3e63e43e83e83043c03a62223d33173e73e03d631f27423c3e63e83e83e83e83e83e83e43e83cd3e73e83e83e73e73e83e83c53da3e43e73e63e63e73e83e83e83e73e83e33e73e83e73e83e73e83e83e73e63723e53e43e63e03e73e73e73e83d03e73e73e83e73e73a43983e83e63e83cb3e83e23e83d33e63e03e83e73e83e83e73e83e73e63b93db3e03933e83e83e83e73e73c93e63cb3e83e73403d83e73ab3533e43db3e73e43e13c13e63e63e73d13df3e43e53e53e63da3e53e53e23ba3e63e43e83e63e13e03e23e42ee3df3e43e23e32533e33e62d13983e03e638733b3e73dd3983e537b3e43e43e83e82e93af39e2383bb3163e73d43cc2ca2652923e53e83e83e83e83e83e83e33e83d23e63e83e83e63e83e83e73b73dd3e33e53e23e53e83e83e83e83e83e63e03e73e83e73e83e63e83e83dc3e436b3e33e13e23da3e63dd3e83e73d53e53e63e73e53e13893943e73e83e73c43e83de3e73d23df3db3e63e53e63e63e23e43e83e33ac3dd3db3883e73e63e83e73e33c03e83ca3e83e531b3d43e738d3663e63d53e53e43de3b63e53e73e53c43da3d83e13df3e33d33e23e43e03a23e33dc3e73e33d43de3d93d73173df3db3dd3dd2953e33e32c338a3de3e03713463e53dc3743e43773dc3d43e73e52ee38f3912283bd31d3e63cb3c12692592cb3d03e83e83e73e63e83e83e83e83b23e73e83e43e83e73e83e83b53c73e73e53de3e43e83e83e83e83e73e73e43e13e53e83e73e83e83e73e83e82d43e73e83e83dd3e83e83e83e73c53e33e73e63e83de3433783e83e63e83793e83e13e73bc3e33d83e73e83e33e83e43e53e83e53a13c13d72de3e73e73e63e73e539d3e63a83e83e82c73c13e73892933dc3b13e43d83e23863e83e53e73c33c83d83db3dd3de3da3e03de3db39a3dd3dd3e03e53d03b03e83e71f53df3e03e43e711c3e73e81f73403de3de30a2b23e73d63373e32c23e03dd3e83e61ff38333716b3ac26e3df3b937322b1591353e83e83e63dd3df3e83e83e53e83ac3e63e73dd3e73e83e83e839c3c93e33e33df3e63e83e73e63e63e83e63d03da3e33e63e83e43e83e73e83e82f73e33e43e83c93e83e83e73e63ba3e53e53e73e83df3303703e73e53e33853e33df3e63b43de3d33e73e53e13e73db3e13e73dd39f3c53c52db3e73e83e23e73e037a3e439d3e73e62933b63e53592c53d43b03e43cd3d938d3e73e23e23a33c63c93c93db3d53bc3d13d83c23783dc3da3de3dc3bd3ab3e53e52213cb3dc3e63e81623df3e81da3423dc3d62f42d43e63c72f43e12cf3d93d33e73e620932f31c15c38323c3db3983691d416118a3e73e83e43da3db3e83e83e23e43ab3df3e33dd3e43e73e83e63553cf3e03d63c53da3e83e83e23e53e43e33b63e33da3e03e53e63e83e43dd3e230b3dc3e13e23ba3e53e33e73e33b93df3e23e33e73da3013653e53e03e437d3de3d13e13b13cb3be3e23e13de3e43d53dd3e33da36b3c83b12e93e23e83db3e23db36a3d438f3e53e327f3953e03233083cb3b23db3cb3c93913e53d03de3783993b93c23c23c43b43bc3c53a63473d53d53d03cc38f3b33e03dc2533ca3cd3e03df17c3de3e41cd32a3d73c92d42d63dc3b72b03ca2ee3d93c83e73e022a2f72f615735c27f3c436e35219417f1fb3e13e83e73d33cd3e83e83e83e63823e23e83d53e83e83e83e83583913de3db3c23d93e53e43e73e53e13e63af3d93db3e63e53e53e73e33e83e72393e13e33e639d3e63e63e83e63853d03e73e73e83c92b23183e33e23e031b3de3dd3e038f3cf3a63e43e33d03e73ca3e03e03d435e39939d20b3dc3e83d63de3c83223cc3653e23e421835c3e332e22d3b235e3da3a63c83303e73d73d735936d3803b23b43ae3913ae3b33733303c73b33a43bd36b33c3e73e61723b33d53e03e30ac3db3e810b2d03c23bb25422a3cc3a926b3b81fc3c73af3e73e515f2cf26010034c19e3bd3342ad11e08e0cb3e83e83e43ad3b63e73e83e53e03693d93e53c23e53e53e63e732539e3cc3cc3a33da3e63de3dc3e13d93da37d3d53cb3e13e13df3e63e63e43e52723de3d93e136d3dd3e23e63e33883c83e43df3e63c928131b3de3e03d833d3db3c03c83833b939a3e13d93ca3e63b93cf3d63d03433a63542323d83e43b73df3b73063c23473df3d91fc3443cb2ef2683a53763d73973af3393db3d13c832b34036738239837337837338934c2ea3c03b53793a334836b3e53e31a73a33cf3de3df0e63da3e50fe2c63be38d22a2563c53862103952343bc3a33e53e41582652520ef30f1c139130e2cc0f80c21233e23e83d239037b3e43e83dc3dd3453c63e23a13dc3de3e33dd2bc3b73ab39a3723d03e43cb3cb3ce3ca3d23263c83983cf3cd3c93e63e13d93d62be3c33ca3d735f3da3d93e53d938c3a53de3d63dd3b42643153c43d33b733a3cb3ab3b237138a3743db3ca3ac3e137e3a93c63ae31d3a834925e3bf3df3863ce3882a93873293cd3cc21c3013b02a82d63923673c737239335b3da3b23942c02f633933433f32333f31e36a2ef2a139b3a131533e2e23693da3d51e73863b93d03cb1253c53dc0ea2b43a935b1f72683ae3331c134d25f3aa3983d83d017e1fb20c0fb2c11f63522c32b50e30f01b63e23e83b734d3463dd3e83c83bd32f3983c636f3c23c43ca3c82823c23923653163c23e13a239f3ac3a73bc2cb3af36538c3ab3b43d93d93bd3a62cd3b13b03bd3263b93bf3d53c536c38e3bd3bc3c43832073093933b736b33c3a237038435d3473663c33a838c3d333c37139039c2ce3ab2f42703b03cc3213a533c26a3542f339f3b420e2b637f24c33436f34f3ad34a36438d3b83963702452c22e82e42f32d32f02863132a023534c36b2a82e228535d3b63a92333693b23b738d1993b33b21102ca38f3111e42843832f117f3042653823863b73b215e1a31fe0ea27322b2dd2992ad0d21652803b43e83ab3013093de3e83d03c32d538d3cb31d3c03d93d23c623539a35d3452f23a03df38e37f3a237e3bb2793a33273913873943d83d13d73cb21739e3b33d72c63b33ce3d23d336535d3bc3b23ce3681a92db37b3af3602bb39434935235e3213303b338d3733ca2ea3453773832b739928e1aa3853da2da39f2f12162d829a38a38f1aa25137625f28d3152f43aa2f93453233b13533591fb24229f27d2b02752a822d2c021320832c35b20c27f2583043df3cd15f35938d3b73c30ce3923cf08f24b3642df14b23837e27b10c2d51fb3683663b43ac10513619208424c16826f1ff1e40a90c21613df3e83542b727e3ce3e83a138128933039d2bc36e3a43b138c1cd3a72f52b124437c3d333732931931a38c1f236f29a2fc30a34e3b63c83a637c25136c37a3b02ae37f3a439d3a935230637a3873a02ee18b2ca2df3792b42c83462e72da2fb2be2fc37935331138b2742e82f333a28039e2241de31a3b624334325519b2472682f134c1e32042e020f3012e72c636d2b02ff3383622f42e01611ee2361ef1f61f725f16e2431981a82cc33f18d1dc1d63083a939e1c232336838e37412d36239309f25d31625814d25231a2200f82561f732233e36134e0cd0d513f0af1d71b41de1d120f0b41302283b13e82cc27b2103a63e83352ef1f32be32e22b2eb35c36833318639a27522019332a3ac29a2a52a028c32f17830a1f12512813033743a733b2fe2712f130434627330d33f32033830428131130b3242351542ba2363131f629b28921e2292a02262bb2ea2cc2c43291d52542452c921c35b1c11fd29037418e2d11b01491a51f925a2c21f41872331d134329d2ad2fd23b28e3512e72672340e91951ea16015e1731ff0fa1aa12f13720f3041051321752a733c3161ef2ea31e3382d018b2ff3170b824928d1dd1212652b11aa0c71f322028d2df2d52c80b30a111209b1a01f310c1a52020ae1c22c831e3e825622c1ca34a3e52a421e1842392a919e21f2e42c62971283481e11991482cd3741d920c1fa1f22ed11c27614a1a61c12bd2e435b27822d22925326129521526428725e25f26e1d826d25225d19111224e17227513025121418b1591fb18322d21123723329516f1e317722e1bf2f216b2221e52fd10121b0e80ef1231ca17a1fa1f11221641632f323621725f19422332021d1e21920821361960d20d811d18608610d0bd10a1932750a80a412721d23f2291f225926a28c1d01922782580d32481c417f11f2211c31260c11421e81e322a1b91fa07e09d0d00b312c1e50ae1611ba0db2032a820f3e81c91dd17c2fa3e31c214c1251ab20d13318121a2021ef0d32a41561170d52632e310319a14313e2710c01ca0b311e12222b22c2b816b12f1c618e1971d61b118f18116c16c1b91391821731750e20ee1ef0bd1d209a1b115c0fb0e618110319015b1771b11c31021300c118a14920e11e20a11021f08615009a09d0b116b0c61351c00cf0e511c2361a21cf1850ec1882741741620ed05610412907108809913804608a0910da0e62070680650e313512310317d19019115e0d91691ae1660e21e610f1020f218f1030ac0a50e91860db1370e111f05b0830a20bf0d818d04711f11c0b41f21d60d23e615d1d71652813bc0e90a10e611e1560d20d717216414d0981dd0ed0d30ae1d92420961200e80cc20e0871170820ad0bf1ac16b1e80900901190cb0b50e41030c70a909709e10d0c50df0be0a308e0bb17d06613005712e0d609708010e0a60ce0900c30f40da0a80c205a0eb0cf13e0b71970871380590a905f07707b0f30570a51660840810b215610e12b0c707a10f1870c60bd0800380c50e60440660760d703404805b0a709514703902d0a60ad04d0480d30d30c008a0470d50e90790c61570930a409110606807f0890900fc0650720570650420520880b50ab1000280d906b09214d0cf01d3e51091d41381fd3680550470bd0bc0b60c50600dd0d90cd07411409609908514517d0530c409708116306108e05607208d1370c512901d02908c05c04a05407704c03902903508a06504504d0380430720fd0340ab0350b20860720550aa05a06603203608105407c06c02c06d07307f08910d03f0850350490490310530ae0260450f804b05b0740780930b60570370b20ca06506c03903307f07901d05906509402201f0570810620ae02c01707c02b00f00f03e04403d01600c05706101d0900c504704403c06c02904405404506202001b01a01102203e07907f08408600c0750260600b002b0023d90da1a812816f2d80180260c905a0680a804405c09707405606807107006f0ce0d004208306c04f10a03b03b02e04d0500c407807200400502f02400b01302c01500600900802d04601701400e02604d07902306203d04b05805404605d03502001601102d01603e04600c02102902804607901102b02802003a02204307401201208902303e03b02003205301b01505904903103902402003d03d01004604a05a02900903406502e04601900c04400a00100201300c00e00100001100a00304804501301d00f01b00602a03301d01b00400100400101101605105006602800b03200303604f008000";
+    #[test]
+    fn edit_distance_test() {
+        let mut path = Graph::new(5);
+        let mut star = Graph::new(5);
 
-    const DATA_SIZE: usize = 3;
-    const N_LIST: [usize; 3] = [11, 34, 156];
-    const MAX_EPS: usize = 40;
-    let mut result = vec![];
-    let mut cursor = 0;
+        for i in 0..4 {
+            path.connect(i, i + 1);
+            star.connect(0, i + 1);
+        }
+
+        // 同型なら0
+        let shuffled = path.permute(&[3, 0, 4, 1, 2]);
+        assert_eq!(edit_distance(&path, &shuffled), 0);
+
+        // 恒等写像のままでは遠いが、対応を変えれば近い
+        let mut almost = shuffled.clone();
+        almost.flip(0, 2);
+        assert!(path.distance(&almost) > 1);
+        assert_eq!(edit_distance(&path, &almost), 1);
 
-    for &n in N_LIST.iter() {
-        let mut v = vec![];
+        // パス 0-1-2-3-4 は中心2から1, 3に出る辺を残し、0-1, 3-4を2に付け替えればスター
+        assert_eq!(edit_distance(&path, &star), 4);
+        assert_eq!(edit_distance(&star, &path), 4);
+    }
+
+    #[test]
+    fn wl_checker_test() {
+        let graphs = load_graphs(6);
 
-        for _ in 0..=MAX_EPS {
-            let mut w = vec![];
+        // 同型判定の結果はVF2と一致する
+        for g1 in graphs.iter().take(40) {
+            let wl = WlChecker::new(g1);
+            let vf2 = Vf2Checker::new(g1);
 
-            for _ in 0..n {
-                let d = decode_hex_to_u64(&HEX_DATA[cursor..(cursor + DATA_SIZE)]) as u32;
-                w.push(d);
-                cursor += DATA_SIZE;
+            for g2 in graphs.iter() {
+                assert_eq!(wl.is_isomorphic(g2), vf2.is_isomorphic(g2));
             }
 
-            v.push(w);
+            let permuted = g1.permute(&[5, 3, 1, 0, 2, 4]);
+            assert!(wl.is_isomorphic(&permuted));
         }
 
-        result.push(v);
+        // 6頂点で区別できないのは、C6と2C3、K3,3と三角柱などの4組だけ
+        let stats = separation_stats(&graphs);
+        assert_eq!(stats.graphs, 156);
+        assert_eq!(stats.wl_collisions, 4);
+        assert!(stats.degree_collisions > stats.wl_collisions);
     }
 
-    result
-}
+    #[test]
+    fn find_isomorphism_test() {
+        let graphs = load_graphs(6);
+        let permutation = [5, 3, 1, 0, 2, 4];
 
-fn get_confusing_matrix() -> Vec<Vec<Vec<Vec<u32>>>> {
-    // 16進数3桁ずつのデータ
-    const HEX_DATA: &[u8] = b"01003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e801003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e801003e801013e801023e801033e801043e701053e801063e80204001073e801083e801093e8010a3e801003e801013e801023e801033e801043e701053e801063e80204001073e801083e802093e80a004010a3e401003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e801003e801013e801023e801033e801043e801053e801063e801073e801083e802093e80a001010a3e701003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e801003e801013e801023e801033e801043e801053e801063e801073e801083e802093e80a001010a3e701003e801013e801023e801033e801043e801053e801063e801073e801083e802093e80a003010a3e501003e801013e802023e80400101033e801043e701053e801063e801073e801083e802093e80a007010a3e101003e801013e802023e80400201033e801043e601053e801063e801073e801083e802093e80a011010a3d701003e802013e80500102023e80400301033e801043e401053e701063e80204001073e801083e802093e80a032010a3b601003e802013e80500102023e80400801033e801043dd01053e60205001063e80304003073e70900101083e80307001093e70a04c010a39c01003e802013e80500102023e80401001033e801043d402053e6060010205001063e70304004073e70900201083e80307001093e60a08c010a35c01003e801013e802023e80400201033e801043e501053e801063e80204001073e801083e802093e80a018010a3d001003e801013e802023e80400401033e801043e302053e80600101063e70204001073e801083e802093e80a034010a3b401003e801013e802023e80400101033e801043e701053e801063e70206001073e801083e802093e80a008010a3e001003e801013e802023e80400202033e80700101043e601053e801063e70206001073e701083e802093e80a00b010a3dd01003e801013e802023e80400702033e80700101043e101053e801063e70406001073e7080010900101083e702093e70a021010a3c701003e801013e802023e80401102033e80700101043d701053e801063e70306001073e70900402083e80900202093e20a049010a39f01003e801013e802023e80400302033e80700101043e502053e80600202063e60700103073e6080020900101083e602093e60a00a02090010a3de01003e801013e802023e80400e02033e80700101043da02053e7060040305001063e40700202073e50800101083e30308004093e80a025010a3c301003e802013e80500202023e80401602033e70700201043d202053e60600402063e4070020403001073e3080030900201083e4040700108001093e60a047010a3a101003e801013e802023e80400101033e801043e702053e80600501063e302073e80800101083e702093e60a00302090020a3e502003e80100101013e702023e80400401033e801043e402053e80600102063e70800102073e80800501083e10308001093e70a00c02090010a3dc02003e80100302013e50500402023e80400702033e80800101043e102053e40600502063e30800102073e80800801083dd0308001093e60a01f02090020a3c901003e801013e802023e80400101033e801043e702053e80600c02063dc0800202073e80801101083d20308003093dd0a001020900b0a3e701003e801013e802023e80400701033e801043e102053e80601002063d80800302073e80801901083c60308006093e50a00202090030a3e602003e80100202013e60500302023e80401003033e8070030800101043d802053e50601002063d80800403073e5080220900202083bb090030308006093e00a01c02090030a3cc01003e802013e80500102023e80400103033e8070010800101043e702053e70601b02063cd0800302073e70802902083ae09001030800d093d80a002020900f0a3e602003e80100103013e7020010500202023e70400703033e8070040800201043e102053e60602202063c60800702073e40803e020839309002030800e093dc0a00c020900a0a3dc02003e80100303013e5020020500502023e60402103033e8070090800d01043c702053e30602102063c5080150406002073de0804e09002020836109003040700108017093db0a01902090080a3cf02003e80101003013d8020020501703023e6040520600103033e80701c08014010439602053d10601903063cd07001080210406001073cb0805609008020834b0900e0308012093cc0a05902090060a38f01003e801013e801023e801033e802043e80700102053e806156010629202073e50800f01083a7030700208031092230308001091c50a3e801003e801013e801023e801033e802043e80700102053e80613b02062ad0800102073e308011010839903070040803d0926702091810a3e801003e802013e80200101023e701033e602043e80700102053e8061240303002062c40800602073e40803d010835703070030804e0929502091530a3e802003e80100103013e7020010500102023e70400103033e8070030800302043e70700202053e70610f02062d90800902073dd08045010834803070060804f092fe02090ea0a3e802003e80101204013d602007050090600204023e1030040400c0600204033d9060010700d0802102043dc0700702053df060ed030300b062f3080190306003073c70806b02082ff09005040700d08044093660a006020907d0a3e203003e80104c05001040139c020170503c0600f04023d103004040380600304033d5060040703a0805d02043b0070050303001053ab060a4030300e0632d080230306001073950809d03070010828e0901704070130803d093ac0a02402090250a3c405003e8010dc020070501406001040130c02046050f50601e050239b0301a040ea0601d0700105033ac0601d070b4080a60900102042fe0700802052df0608b04030210630307008080470406001072fe0808b0900704030010700c082540904904070190801c093900a0b602090070a33206003e8011b10201f030020506b0600204012360207c051d30603507010010234c030360420a0500206068070060602001033770604b0717f080fc0900e02041de0700602051a60603c050303805002062bf070170807d0406003072190807009017050300107017081ec090b40a0160407016080130930e0a1da02090010a1f801003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8010d3e8010e3e8010f3e801103e801113e801123e801133e801143e801153e801163e801173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e801203e801213e801003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8010d3e8010e3e8010f3e801103e801113e801123e801133e801143e801153e801163e801173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e802203e82100101213e701003e801013e801023e70202001033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8020d3e814001010e3e8010f3e801103e801113e801123e801133e801143e701153e70215001163e801173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e802203e82100601213e201003e801013e802023e7050020202001033e801043e801053e601063e70206001073e801083e802093e715003010a3e8010b3e8010c3e8020d3e81400302090010e3e8010f3e801103e802113e81800201123e70212001133e801143e501153e30215002163e801173e801183e601193e8011a3e8011b3e8011c3e8011d3e7011e3e8031d0011f3e82000102203e72101c01213cc01003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8010d3e8010e3e8010f3e801103e801113e801123e801133e801143e801153e801163e801173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e801203e801213e801003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8010d3e8010e3e8010f3e801103e801113e801123e801133e801143e801153e801163e801173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e802203e82100a01213de01003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8010d3e8010e3e8010f3e801103e801113e801123e801133e801143e801153e801163e801173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e801203e801213e801003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8020d3e814001010e3e8010f3e801103e801113e801123e801133e801143e701153e801163e801173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e802203e82100101213e701003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8020d3e814003010e3e8010f3e801103e801113e801123e801133e801143e501153e801163e801173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e7031f001203e82100801213e001003e801013e801023e801033e801043e801053e801063e70206001073e801083e801093e8010a3e8010b3e8010c3e8020d3e814005010e3e8010f3e801103e802113e81800101123e801133e801143e301153e801163e801173e801183e701193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e802203e82101b01213cd01003e801013e802023e80500101033e801043e801053e701063e801073e801083e801093e8010a3e8010b3e8010c3e8020d3e81400b010e3e8010f3e801103e802113e81800201123e801133e801143dd01153e70215001163e801173e801183e601193e8011a3e8011b3e8011c3e8011d3e8021e3e820002021f3e720001031f001203e52103d01213ab01003e801013e802023e80500301033e802043e80b00101053e501063e801073e802083e70d00102093e815001010a3e8010b3e7010c3e803080010d3e71401f010e3e8010f3e802103e81700102113e81800201123e801133e801143c801153e20315005163e7170010216001173e60214001183e401193e7011a3e8011b3e802190011c3e8011d3e803180021e3e820005021f3e720002031f001203e121067012138102003e80100101013e702023e80500a01033e802043e80b00101053dc01063e801073e80305002083e70d00102093e815003010a3e8010b3e7010c3e803080010d3e714041010e3e8010f3e802103e81700102113e81800301123e801133e801143a501153e00315005163e6170010316002173e6180010214002183e001193e7011a3e8021b3e81d00103190011c3e81e001011d3e703180041e3e720005031f3e72000421001031f001203df210bd012132a02003e80100202013e5060020301001023e80501202033e80800102043e80b00101053d301063e40206002073e80305003083e60d00102093e81500d010a3e8020b3e719002010c3e803080010d3e61407c010e3e8020f3e81700102103e817001040d001113e8130011800601123e801133e7011436a01153d1041500a163e6170011b0010316002173e5180010214002183dc01193e4011a3e8021b3e71d00203190021c3e81e001011d3e603180051e3e720007021f3e720012031f001203cf2113001212b801003e802013e80600101023e801033e801043e802053e81500101063e701073e801083e802093e815001010a3e8010b3e8010c3e8020d3e814011010e3e8010f3e801103e802113e81800401123e801133e801143d701153e601163e801173e801183e401193e8011a3e8011b3e8011c3e8011d3e8021e3e820001011f3e7031f001203e72103001213b802003e80100102013e70600103023e8050010900101033e801043e802053e71500102063e6070010206001073e702083e80f00102093e715002010a3e8010b3e8010c3e8020d3e714024010e3e8010f3e701103e802113e81800502123e81300101133e7020d001143c401153e501163e801173e801183e302193e81b001011a3e8011b3e7011c3e8021d3e81f001031e3e81f00120007021f3e520007031f001203d92106002200012138801003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8020d3e81400a010e3e8010f3e801103e802113e81800101123e801133e801143de02153e81600102163e71b00101173e801183e601193e8011a3e8011b3e7011c3e6011d3e803180011c0021e3e8011f3e7031f001203e7210130220001213d501003e801013e801023e801033e801043e801053e801063e801073e801083e802093e815002010a3e8010b3e8010c3e7030c0010d3e814012010e3e8010f3e801103e801113e801123e802133e81e00101143d602153e4160040215002163e401173e801183e701193e8011a3e8011b3e8011c3e5011d3e804180011c0031e3e71f002021f3e220001031f004203e5210240220002213c401003e801013e802023e80500101033e801043e801053e703063e8070020900101073e601083e803093e71500616001010a3e8010b3e8020c3e70d001030c0010d3e71401b010e3e8010f3e801103e802113e81800101123e802133e81e00101143cd02153e21600202163e51b00101173e801183e702193e81b001011a3e8011b3e6021c3e61e001021d3e81e001041c0021e3e51f00220002021f3e020004031f006203e1210470220001213a101003e802013e7060010401001023e80500a0900101033e801043e801053de02063e70900101073e801083e803093e60e0011500e030a3e80b00116001020b3e719001020c3e70d002030c0010d3e514039010e3e7010f3e801103e703113e8130011800801123e802133e71e001020d001143af02153d916007031000115001163e002173e81800101183dd02193e71c001011a3e8011b3e8011c3e4021d3e81e00105180021c0031e3e61f00420004021f3df2000b031f005203d72109502200022135301003e801013e802023e80500802033e80800102043e80b00101053e002063e80700101073e702083e70c00203093e81500116001010a3e8020b3e719001010c3e5030c0010d3e81400b010e3e8010f3e801103e802113e81800302123e81300103133e71e0011f00101143dd02153e71601001163d701173e801183e503193e71b0041c002011a3e8011b3e4021c3e11f002011d3e8041c0051e3e71f00820001021f3cd20002031f010203dd210150220008213d301003e801013e802023e80500b02033e80800102043e80b00101053dd02063e80700401073e402083e60c00202093e815008010a3e8020b3e719003020c3e40d00104080010c0020d3e714013010e3e8010f3e801103e802113e81800a02123e81300102133e71f00101143d502153e01600e02163da1b00601173e801183de03193e51b0061c002011a3e8011b3dc011c3e1011d3e8041c0051e3e71f01420001021f3b820007041e0011f01b203d8210340220008213b402003e80100102013e70600203023e8050120900204033e807001080040c00102043e80b00101053d602063e5070040306001073e30c00203083e10c0030d00102093e615014010a3e8020b3e719004010c3e004080030c0020d3e71402e020e3e81a001010f3e802103e81700302113e71801703123e8130031d00104133e51c0011e0021f00101143ba02153d41600f03163d8170011b00401173e40211001183d003193e41b0031c007011a3e703160011b3e01c001041b0011c3d41e0011f007031d3e51e0021f00305180011c00b1e3df1f01320005041d0021e0011f3a720010041e0031f023203cd2107002200062137801003e801013e802023e80500302033e80c00101043e801053e502063e80700a02073de0c00202083e80c00a01093e8010a3e8010b3e8020c3db0d001020d3de14001010e3e8010f3e801103e802113e81800101123e801133e8020d009143e702153e81603e03163aa170011b00302173e61b0010417001183e71d0011e00104193e81a0011b0181c00f011a3e7011b3cc021c3ca1f001011d3e7031c00f1e3e51f00c011f38b041e0021f0502037f210030220069213e501003e801013e804023e805002070010900101033e801043e801053e602063e80700c02073db0c00102083e80c00c03093e71500316003020a3e810001010b3e8020c3d30d001030c0080d3e014002010e3e7010f3e801103e602113e81800402123e71300101133e7020d007143e6030e001153e51604403163a0170011b0090216001173e60417001183e41d0011e0010410001193e81b0161c00f011a3e8011b3c705120011b0021c3c71e0011f002011d3e7041c0121e3e31f01f20001021f36820003041e0031f05f2038f210080220055213e002003e80100102013e70200203023e6050050900201033e801043e801053e302063e80700402073e40c00803083e80c0130d00104093e6100011500316001020a3e816001020b3e819001010c3c5030c0080d3df14011020e3e81a002010f3e801103e503113e7180061d00103123e4130011d00103133e61c0011f001020d008143d702153e316040051000215002163a6170011b00f02173e71b0030411001183e11d0011e00203193e71b0191c016011a3e602120011b3bc05120021b0011c3c11d0011f00503120011d3e41f00105180011c0101e3e31f03c20002021f3392000505130011e0031f06c203a621019022003b213cf01003e802013e80600101023e803033e8080020c00101043e801053e802063e70701902073cf0c00302083e60c01201093e8010a3e8010b3e8010c3cb030c0070d3d014002010e3e8020f3e81000101103e703113e7120011800202123e51d00101133e8030d018143e61800102153e8160ba021632e1b01902173e81b0030211001183e404193e81a0011b0431c019011a3e702120011b38704120011b0021c3a41f003021d3e71f00104180011c02b1e3e11f021011f31f041e0071f0a22031821001031f002200d0213e701003e802013e80600102023e80500302033e80800202043e80b00202053e50800102063e70700f02073d90c00a02083e50c02c04093e80a0010e00215004010a3e7030b3e61000111001010c3a6030c00c0d3d614002020e3e610001010f3e802103e51700103113e6120031800203123dc130011d00101133e2030d012143e61800103153e4160a81b0010310001163401b02302173e61b005031100117001183e503193e81b04b1c024011a3e802120021b36e04120071b0061c3931f002021d3e61f00305130011c0311d0011e3e31f028021f2fb2000105130041e0051f0bc2034c21003031f0042009b213e502003e80100103013e7020010600303023e7050020700203033e8070010800402043e80b00202053e61500102063e50702102073c40c00b03083e40c0370f00105093e80a0030e0031500716002030a3e51000216002030b3e61100119002020c3960d002030c0100d3d91400c020e3e510004020f3e71000103103db170041b00104113e612001180061d00103123d7130011d00204133e41c0011e0021f005030d00d143dc1800202153e016090041000316354170031b03302173e01b0070411001183df1d0011e001061000217001193e61a0031b04b1c01a02120021a3e50310001120031b35d051200b1b0051c3991d0021f00b031d3e21e0011f0080613001180011c0341e3dc1f04a20001031e0011f2b92000305130021e0071f0cd203692100b022007b213dd01003e802013e80200104023e703002050010700103033e6080010c00302043e70b00102053e70800102063e80704202073a50c00402083e60c03a05093e80a0080e0031500116001020a3e010001030b3e71200219001020c3900d001030c0170d3be14002030e3e5100011a00203040010f3e81000101103dd04113e512001180011d00101123d703133e01c0011e001020d029143e602153e71614c04100061629a170011b042051100116001173e61b0061d001051100217001183e61d0061e0010510002193e71a0091b08a1c034011a3dd02120011b313041200c1b0031c3411f00204120011d3df1e0011f00205130011c0711e3d21f01f2000204130011c0011d0011f26f04130061e0131f14e2025e04180011f00820188213e802003e80100102013e70200104023e703002050040900303033e5080010c00603043e80b0011200102053e41600102063e80704302073a50c00c03083e70c0590d00105093e50a0090e0071500516003040a3df10002160011a001060b3e7100031100112003190011a001020c3620d00105030010c01b0d3c80f00114007030e3e1100021a001020f3e61000604103c712001170011a00205113e51200b13001180041d00202123a9130010512001133dc1c0041e0011f002030d01e143e11800102153e316110060f00110010162d3170041a0011b05d03173e21b00e1d002051100217001183e11d00c1e0020510004193e71a00b1b0901c03302120011a3d604120101a0011b2eb1d001051201b1b0021c3561d0091f00303120021d3c91f0020713001180011c05b1d0031e3c91f02f2000204130011d0021f24b2000105130081e01c1f162202c82100404180011f0052011d213e402003e80100503013e30200b0600705023dd0300505016070050900403033e1080050c00e02043e80b00a03053d2080051500103063e1070450900304030010739e0c0191a00104083dd0c05f0d0030f00105093e10a0120e00f1501516007040a3d30f0011000d160020a0a0010b3dd0f003100061100312006190081a0021b0031c00103030010c3470d00405080010c01b0d3cb0f00214017040a0020e3d8100071a009040f3e11000d120031b00205103a612007170021a0031b002080b00110001113de1201213001180111c0021d0070412377130031d0051f0010511001133dd1c0071e0051f004020d016143d102153d2160f7050e00110011162e7170041b0850710001110031200116001173df1b0171d005061100217003183d71c0021d0081e002061000512002193e01a00e1b07e1c0300310002120021a3ca05100011201c1a0011b2b71d002061202c130021b0101c3591d0131f00804120021d3b11e0021f00b06110011c0531d0041e3c41f05220002031d0051f2442000405130051e01b1f1392032821017031f001200ba213d102003e80101804013d0020140601d0700208023d40300e0503a070050900a0a0010e0011500206033d2070010800f0a0010c0261000106043e80a0010b01110002120051900104053ae080091500c1600303063cb070420900306030080739d080020c02d0e0010f0010607001083cc0c07b0d0050f0061000407093db0a0270e014100011503b160071a0010a0a3b70b0010e0020f003100161200216013170011a0011b0020a0a0010b3d50f008100051100812009190191a0021b0031c001020c3000d00906080020c01a0d3cd0f002110011403d050a0060e3ce100121a0111b001050f3cd1001912003170021b001060f0011037012014170091a0071b0040d0b0010f00410005113da120271300a1700518030190011b0011c0021d0071e001051232c130081a0021d0101f002071100112002133c71c00c1d0011e0141f00c030d00d143ab18002021539f160ba060e0021001e163101700b1a0041b08f0b0f00210001110031200116001173c6180011a0011b01c1c0031d00b061100117006183b41c0011d0141e005061000412001193cc1a0091b07f1c03803120051a3b51b0010610002120361a0071b29f1c0011d009091202c13008190011b00c1c3581d01f1e0031f00e2000106120011b0061c0041d37a1e00d1f01f0713006180011c0401d0081e3a31f0792000605120021d0071e0011f2452001a05130011e01a1f0ef2036b21043022005c213a502003e80100102013e70200b03023dd030060700703033dd0a0010c00202043e80f0010403001053e8080071500102063e8070b603030040732b0c00c03083db0c03f0f00404093e70a02f0e0161a001040a3b70f0011000b1a001070b3e50f0061000f12005190011a0031b001010c34b05080060c0500d2e80f00911003030e3d2100031a003030f3d21000112001021037b12002040b00210001113cc12027021233213002021100113375040d10011005143e81801005090010a001153e7162ce1b007060f0011003c1611a170021a0081b0d9040b00111001173d11b023051101217015183d31c0021d010061001212002193e71a04c1b1101c02002120011a38b03120101a0011b1c504120661b00f1c2531d00202120011d3bf071200113007180051c16b1d0061e2c11f001031200c1d00e1f0ec061306a1c0081d0031e1261f297200db041e0011f0642030d213e802003e80100403013e40202c0600305023bc0301c050030700e0900105033c407006080020a0020c00d05043e80a0010f00310001120040503005053e5070010800e1500103063e5070a10e0020403002073300c019100010607002083cd0c07b0d0010f0071000106093e70a0560e03115003160021a003060a38c0e0020f004100111a0031b001060b3e50f007100181200b190051a007020c2e90d00208030010800b0c05e0d3320f00d110041400117001050a0020e3b20f002100101a00e040f3bf1000f120031b001050b00110335120051a0031b001080b0010f00310001113c71203117002180011d00303122da130011d002061100212002133a41c0051d0011e002040d0b311006143e71800b040a001153e4162a51b002070e0010f0011005816141170051a01a1b105060b0010f00112001173cc1b0311d001061101517014183d71c0041d01c1e001061000d12005193e31a0331b1041c020011a37a0410002120251a0031b1820512084130021b0261c2651d00c03120031b0011d3920613003180051c1531d0071e2d21f0060612012130011d01b1e0011f12620002061303d1c0071d0051e1111f2902011d041e0011f02c202c9213e803003e8010210300103013c70205a06011080238e030410501007022090020a0030c0020e001060339807007080070a0020c0180f00106043e80a0070b0090f00110004120040503001053d808013150041600203063d70709e09004050300b073200a0030c0240e002080300207001083c10a0010c0950d0050f01f1200107093e20a0760e0591000115009160031a002080a3590b0010e0010f00910036160011a0081b003080b3d60f01110020110011200b190101a00a1b004020c2bf0d002080800d0c0560d36e0f01410001110061400717001050a0080e38a0f003100321a021060b0010f3881001a11001120031a00105102ce12016170061a00b1b0040b0b0040f00410001113c81205f1300317001180041b0021c0011d0070312251130051d0050711002120031339a180011c0111d0011e009040d07311003143e11800e040a001153db162461b002090b0010e0010f0011005e120011619c170101a0151b11f080b0020f009100011100112002173b01b04d1d00907110121701f183d01b0011c0041d02d1e00107100111200317001193d51a0281b0eb1c025011a3640510001120421a0061b14d1d00206120b01300d190031b02d1c28f1d01d06120071b0071c0031d3401e0021f0040613007180051c1141d0131e2be1f017071200d130011c0011d0301e0021f13d2000207130311c0061d0031e11b1f27e201bb21004041e0011f0122022b213e404003e8010610200506002060138702097060380700e090010a0010b0234c030910502b0705c08003090150a0050c0040e0041500216001060334407016080210a0090c05a0f00c07043e80a00e0b02e0f0071000311001120050703003053bd080200c0010f0011501a1600505063ae0707b090050a0030e0010703010072e8080010a0050c03e100041a00209070050838b0a0010b0010c0ac0d0030f030100031700107093cb0a08a0e07b1000115023160141a0060a0a3200b0020e0050f0191005d1200116011170011a0181b0090a0b3a30f0231001f110071201f17001190371a0121b0161c00105080020c2590d0100f0021000309080160b0010c0460d3a60f0221100a1401a1700918001060a0170e3600f00c10058160011a065050b0010f3191003b11002120040a0b0020f00210262110011203717016190011a01e1b0081d0020c0b00c0f00910004113b8120821300d1700b1801a190011b0051c0091d00e04121d8130091d0151f002081100a12001133921b0021c0191d0071e01c1f007040d02f11002143ce1801204090020e001153a9161e00b0a0010b0010e0020f0061004f161db17016190021a0201b11d1d0010b0b0030f00d1000311003120051600117380190031b0591c0051d00f081100b120011701d183b31b0011c0061d0281e004080f0011000c1200517004193a31a0191b0b71c0270410002120051a2f01b0010510003120571a00a1b1481d00e0b1000211001120af1301a17004190071b0391c2a21d0541e0031f001071200b180011b00a1c00a1d2c01e0081f0080613006180071c0dd1d0161e2b91f02c071200c130051c0071d04a1e00b1f1bb2000b071301b1c0031d0021e0f91f1ea2027e21019031f0052015f213cf05003e8010e40200e0600e070010801304020e203001060b307021090050a0010e0020e022f7030dd0508c06001070a808010090520a0120c00c0e014100031500d160021a0010a032e90400107023080560a01a0c0890d0030e0020f015100060a043e40a0170b0860f0111000e110061200d190011a0061b0020702001030020535c070010802b150461600f040632607059090060e0030a0301f040010729f080080a0150c05f0e0090f005100021a0030b07001083340a0040b0050c0c10d0070f03b1000711001160011b001070938b0a08f0e0a0100021506d160341a00b0b040010a2d20b0020e00a0f02c1007d120031602d170041a0311b0100c0a0010b3340f03010033110131201b1300117001190b01a00e1b0221c0070907001080040b0010c2070d02d0f0041000c140011700209080170b0040c02c0d3a20f02411010140961700a18004080a0280e3150f00d1006d12002160011a0a41b0030a0b0030f2c91004e110011200d1600117007190011a0051b0070b0a0010b0030e0010f009101e5110061205d170351a03b1b0231d0030e040010b0170f0101000711396120951302a170261806b190061b00b1c0171d02f1e002071218d130221b0031c0091d0381e0011f008091100b120041334f18003190011c0361d0091e04f1f01a030d00f143511800f0315328161351b0010a0b0020e0040f00410050110011623a17020190051a01a1b1000c0b0030f009100041101012009160041732d18001190041b06b1c0091d01e0711003130021701e1835a1c0021d0271e00808100031200317004193161a00c1b07c1c02b1d0020310003120061a274080f0021000612085170021a0161b13e1c0021d01e0c100031100212080130321700418001190101b0481c2b91d07a1e00d1f007081200a13002180021b0091c0111d22f1e0241f0170713007180091c07d1d01a1e2741f05820001091200a130041b0011c00b1d04c1e0241f1f02003221001071300b1c0011d0011e0c51f160203202106502200952138207003e80118002029030030603707002090030701268021340300406179070350900e0a0010d02289031300515c0711008020090ba0a0290b0010c01a0e02815039160111a002110200203284040050704a080c6090010a0540b0080c10b0d00f0e0030f02f1000d16001170011a0011b0010c03001043dd0a01c0b11e0f02a1001a1100f12011190131a0021b0081c00206030040528a0804b0f001150911601c0506234070440901c0a0050e0060c03028060030720c080130a0340b0010c0940e01c0f0071000b160011a0060c05002070040827f0a0010b0080c08e0d0270f0581000c1100116007170030806001092fd0a0760e0c7100031512e160511a00c0f0400307001090010a23f0b00e0e0290f052100be11001120081606f1700a190071a0451b0270d0a0030b25a0f036100221102c1201f130021600317003191321a0091b0401c00b0c070020800a0a0020b0030c1880d0620f00b1000b110021400416001170020a0801b0b0010c0170d34c0f01910001110151416d1701c180090b04001090010a04b0c0020e2a20f01e10094120011600d1a0ff1b0050f040010a0060b0110e0010f21510056110071201e160021700d190011a00a1b0171c0021d00210040010a0080b0090e0030f0101017511011120a013001160021707f190071a0711b04e1c0061d0060e0b02d0f0281001411346120b71305a16002170451810b1900d1b0221c03a1d0441e00d0b10001110041212513060190021a0031b0061c01d1d0611e00e1f0190b0b0021101412003132ae18005190041c05a1d00c1e0961f03820003040d00414276170011800c04090010a001151f0160a0090b0010e0050f009100291623017032190171a0151b0ae0c0b0020f00c1000211017120051600517298180061900b1b04e1c00c1d0300711002140011700b182ab1d0141e0091f00108100021200216003170021922c1a0051b0431c0240410006120081a1c81b0090c0f00210014110011208e130011600317004190071a0241b1441c0071d0310b0f0011100412065130681700c1902c1b04c1c2891d0da1e0191f029091200a13003180051b00d1c0261d16f1e03f1f0422000107130051800c1c0311d0131e2091f06d200090a120061300b180011b0011c00a1d05e1e0511f21e200b12100f06130011c0011e07c1f0a02030d21168022001d2127107003e8012470204d03003060b20700c0900e090119f0215b0300f05001061e20704c090440a0060e00812010020223b03173040010525a0600e0718908051091250a05a0c0330e0600f00410003150c816035170011a00413020030322304015050010706508147090010a0800b0160c1360d02a0e0140f05d1001b11001120011600b1a0031b0010e03002043bd0a02a0b1990c0010f046100301102b1202916003190461a0071b0111c0020a020010300105187070010802f0c0010f0011509d1600d17001050613e0701d0902a0a0080e00c12020010303a0400705002060050717d0803e090070a0790b0040c0cc0e0470f01a1000f12001160011a0141b001100500307004081ae0a0010b00e0c0710d0520e0010f05710011110021400216015170061a0011b0010a06003092370a0510e0d30f001100021518e160591a00e1b00112030010400c07002090010a1a10b0170e05d0f071100d3110011200d15001160dc170151901e1a07e1b04c1d00110080010a0030b1940e0010f035100291105912026130071600417010191761a0041b0441c0101d0021003002080200a0030b0030c1320d0ce0e0040f0131001411005120021401f16001170051a0021b0010b080130b0040c00b0d29a0f00f110161426a1600217029180121d0010e07001090070a0520b0040e1d70f0261008912004150031603017002190041a1231b0081304001080010a00b0b0220c0010e0030f181100651101d1203613002160081703618001190111a00e1b0371c0051d004100a0070b0100e0040f02d101331102d120d6130011600d170bc180051901e1a0b01b08e1c00c1d022100b03c0d0040f02710014112be12092130ba140011707e181ef190281b0361c0651d0611e0101f0010e0e0011100a120cb1308917001180031900a1a00b1b0191c03d1d08b1e0261f042200010a040011100d132091800d190041c05f1d00d1e0d81f04b20013021415b1800502150ee160360d0b0010c0020e0030f00210016110011200215003161bb1703f1901f1a00f1b0670f0b0010f007100011101912005130011600d171ba18028190121a0011b0451c0151d0261e0010811002130011400117004181851c0011d0071e0110712001170021912b1a0021b0161c0111d0010a0b0010e001100081201a1600117001190011a11c1b00b1d0010c0f00210013120a713003160021700d190101a0191b1141c01c1d05b1f0040d100011100a12044130761700a18007190381b03a1c2401d0ed1e0561f04e200040b1200b1300c170031800b1b00a1c01c1d0fb1e0651f0672000d2100108130021800c1c0121d00b1e1561f0652002d210020a1200313009180011b0011c0131d0481e0821f20a2015421053041e0351f0322023a2127b02200082111709003e7012c00208b0300b06167070170902a0a0010e0030a0000101120021610301805007061db07059090880a0060e00c1101008021f50319f04006053180601d071e50808b091830a0640c05b0e0900f00310005151b8160371a0081602005031cb040540501c0706c081b0090050a0ce0b0320c1310d07d0e0240f07c100301100412001150021601717009190021a00d1b002140300204355080010a03e0b1f90d0010e0030f06a10036110621202513007160051700618001190531a00c1b0141c0091d00105050980801a0b001150751600a0703001060790700e090360a0030e00c1a00114020020305404016050090600b071110806e090190a09a0b0080c11a0d0090e0a20f0201002812001150011600e170021a028110500c07001080e40b00f0c0470d0950f04210005110021200214004150011601d17009190031a0031b00209060050914d0a02a0b0010e08e1000215146160481a00f17030020401a0700508003090040a1390b0420c0080d0010e06a0f0a110108110091202b130011500c16130170321904b1a0971b0501c0011d00311080020a0010b0cd0f03a1001c110721202a130111600917021180051913d1a0051b0271c0221d0041f001150300104001070010802b0a0070b0070c0ea0d1100e0030f0151001d110091200214060150011600a1701a180031a00f1b0031d0010b0800c0b0030c0010d1b00f00b110141200114314160011702b1802313030010400107001080010900e0a0510b0060c0010e1600f0331007211001120051500d1607d170091900c1a1051b0181404005080030a00a0b0380c0030d0020e0070f100100671104b1204b130071601a170711902e1a01a1b0631c0181d00e1e00115040010a00d0b01a0c0020d0010e00f0f040100ee11044120fe1300816022170c6180151905e1a0d71b0cd1c01e1d0331e0021f001120b02f0c0010d0040f022100171120e12072131271400a1709218261190431b03b1c0be1d0781e0381f00e200010f0b0011000511011120ba130b917003180131900a1a0061b0341c0681d0b01e0721f076200150c1100b1200213131170011800c190021b0011c0311d0111e0df1f05d2003d021406518001021504d1600f0d0b0010f0041000811002120011500a160ff170311902b1a0061b0331c0011d0010f0d0040f005100021101812004130041400116007170f318050190121b01c1c0181d0261e0030513001180941d0011e0091f002071200117002190711a0011b0081c00b1d00310040010a0010b0020c0010e0020f0011001011003120281600117001190061a0c01b01d1c0011d002100e0010f00210010110071208f1300d1600517027180041903a1a01d1b0f31c0421d09c1e0061f00b0d0f0011100912029130861700f18022190311b02a1c1951d0c51e0b11f0b5200180d110011200513007170031800f190021a0011b00d1c01c1d0a51e0781f07e200260813001180081c0061d0041e0941f0352005f21007081300f180051c0111d02e1e0811f185201f121117041e00c1f00b2010721294012103601003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8010d3e8010e3e8010f3e801103e801113e801123e801133e801143e801153e801163e801173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e801203e801213e801223e801233e801243e801253e801263e801273e801283e801293e8012a3e8012b3e8012c3e8012d3e8012e3e8012f3e801303e801313e801323e801333e801343e801353e801363e801373e801383e801393e8013a3e8013b3e8013c3e8013d3e8013e3e8013f3e801403e801413e801423e801433e801443e801453e801463e801473e801483e801493e8014a3e8014b3e8014c3e8014d3e8014e3e8014f3e801503e801513e801523e801533e801543e801553e801563e801573e801583e801593e8015a3e8015b3e8015c3e8015d3e8015e3e8015f3e801603e801613e801623e801633e801643e801653e801663e801673e801683e801693e8016a3e8016b3e8016c3e8016d3e8016e3e8016f3e801703e801713e801723e801733e801743e801753e801763e801773e801783e801793e8017a3e8017b3e8017c3e8017d3e8017e3e8017f3e801803e801813e801823e801833e801843e801853e801863e801873e801883e801893e8018a3e8018b3e8018c3e8018d3e8018e3e8018f3e801903e801913e801923e801933e801943e801953e801963e801973e801983e801993e8019a3e8019b3e801003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8010d3e8010e3e8010f3e801103e801113e801123e801133e801143e801153e801163e801173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e801203e801213e801223e801233e801243e801253e801263e801273e801283e801293e8012a3e8012b3e8012c3e8012d3e8012e3e8012f3e801303e801313e801323e801333e801343e801353e801363e801373e801383e801393e8013a3e8013b3e8013c3e8013d3e8013e3e8013f3e801403e801413e801423e801433e801443e801453e801463e801473e801483e801493e8014a3e8014b3e8014c3e8014d3e8014e3e8014f3e801503e801513e801523e801533e801543e801553e801563e801573e801583e801593e8015a3e8015b3e8015c3e8015d3e8015e3e8015f3e801603e801613e801623e801633e801643e801653e801663e801673e801683e801693e8016a3e8016b3e8016c3e8016d3e8016e3e8016f3e801703e801713e801723e801733e801743e801753e801763e801773e801783e801793e8017a3e8017b3e8017c3e8017d3e8017e3e8017f3e801803e801813e801823e801833e801843e801853e801863e801873e801883e801893e8018a3e8018b3e8018c3e8018d3e8018e3e8018f3e801903e802913e89500101923e801933e801943e801953e702963e89900101973e801983e801993e7029a3e89b001019b3e701003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e7010d3e8010e3e8010f3e801103e802113e82400101123e801133e801143e8020c001153e801163e801173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e801203e801213e801223e801233e801243e601253e70225001263e801273e80224001283e802293e87c001012a3e8012b3e8012c3e8012d3e8012e3e8012f3e801303e801313e801323e801333e801343e801353e801363e801373e801383e801393e8013a3e8013b3e8013c3e8013d3e8013e3e8013f3e801403e801413e801423e801433e801443e801453e801463e801473e801483e801493e8024a3e877003014b3e8014c3e8014d3e8024e3e87f001014f3e801503e801513e701523e801533e801543e801553e801563e801573e801583e801593e8015a3e8025b3e876007015c3e8015d3e8015e3e8015f3e801603e801613e801623e801633e801643e801653e802663e86d00101673e801683e801693e8016a3e8016b3e8016c3e8016d3e7016e3e8016f3e801703e801713e801723e801733e801743e801753e801763e101773e30277002783e801793e8017a3e8017b3e7017c3e7017d3e8017e3e8017f3e701803e801813e80251001823e801833e801843e801853e801863e801873e801883e801893e8028a3e894001018b3e8037b0018c3e897001018d3e8018e3e8018f3e801903e802913e89500201923e801933e801943e701953e602963e89900101973e701983e802993e79a001029a3e79b011019b3d701003e801013e70301001023e80600301033e801043e801053e802063e52500101073e801083e801093e8010a3e8010b3e7010c3e7010d3e8010e3e7030e0010f3e83300101103e802113e82400801123e8020b001133e801143e8020c001153e802163e82900101173e801183e802193e81f001011a3e8011b3e8011c3e8011d3e8011e3e8011f3e701203e801213e801223e801233e801243df01253e701263e802273e8280010224001283e702293e77c001012a3e7022a0012b3e8012c3e8012d3e8012e3e6022e0022f3e801303e701313e70230001323e801333e701343e801353e801363e801373e801383e801393e802310013a3e8013b3e8013c3e8023d3e83e001013e3e7023f3e84000101403e701413e801423e802433e85c00101443e801453e801463e801473e801483e801493e8024a3e87700a014b3e8014c3e8014d3e7024e3e87f002014f3e801503e801513e801523e801533e801543e801553e801563e801573e801583e801593e8015a3e8025b3e87601e015c3e7015d3e8025e3e786001015f3e802603e87a00102613e87b00401623e70262001633e801643e801653e8034d001663e86d00102673e88500101683e801693e8016a3e8016b3e8016c3e8016d3e7016e3e8016f3e802703e88b00302713e87500101723e801733e802743e87500101753e601763c701773d50277009783e801793e8027a3e77b00102760037b3e1017c3e0027c0077d3e7027d0017e3e8027f3e58500101803e801813e801823e801833e801843e8027f001853e5025e001863e701873e801883e801893e804850018a3e88c00194001018b3e5037b0028c3e797001028d3e893001018e3e8018f3e801903e802913e89500302923e89400101933e702943e69700201953e502963e89900102973e59a00101983e802993e79a005029a3e29b063019b38501003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8010d3e8010e3e8010f3e801103e801113e801123e801133e801143e801153e801163e801173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e801203e801213e801223e801233e801243e801253e801263e801273e801283e801293e8012a3e8012b3e8012c3e8012d3e8012e3e8012f3e801303e801313e801323e801333e801343e801353e801363e801373e801383e801393e8013a3e8013b3e8013c3e8013d3e8013e3e8013f3e801403e801413e801423e801433e801443e801453e801463e801473e801483e801493e8014a3e8014b3e8014c3e8014d3e8014e3e8014f3e801503e801513e801523e801533e801543e801553e801563e801573e801583e801593e8015a3e8025b3e876002015c3e8015d3e8015e3e8015f3e801603e801613e801623e801633e801643e801653e801663e801673e801683e801693e8016a3e8016b3e8016c3e8016d3e8016e3e8016f3e801703e801713e801723e801733e801743e801753e801763e601773e801783e801793e8017a3e8017b3e8017c3e8017d3e8017e3e8017f3e801803e801813e801823e801833e801843e801853e801863e801873e801883e801893e8018a3e8018b3e8018c3e8018d3e8018e3e8018f3e801903e801913e801923e801933e801943e801953e801963e801973e801983e801993e8029a3e89b001019b3e701003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8010d3e8010e3e8010f3e801103e802113e82400201123e801133e801143e801153e801163e801173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e801203e801213e802223e85500101233e801243e601253e801263e801273e801283e802293e87c001012a3e8012b3e8012c3e8012d3e8012e3e8012f3e801303e801313e801323e801333e801343e801353e802363e84000101373e801383e801393e8013a3e8013b3e8013c3e8013d3e8013e3e8013f3e801403e701413e801423e801433e801443e801453e801463e801473e801483e801493e8024a3e877001014b3e8014c3e8014d3e8014e3e8014f3e801503e801513e801523e801533e801543e801553e701563e801573e801583e801593e8015a3e8025b3e876004015c3e8015d3e8015e3e8015f3e802603e87a00102613e87b00401623e801633e801643e801653e801663e801673e801683e801693e8016a3e8016b3e8016c3e8016d3e8016e3e8016f3e801703e802713e88c00101723e801733e801743e801753e801763e401773e701783e801793e8017a3e7017b3e3017c3e5027c0027d3e8017e3e8017f3e801803e801813e801823e801833e801843e801853e801863e801873e801883e801893e8018a3e8018b3e8027b0018c3e7018d3e8018e3e8018f3e801903e801913e801923e801933e801943e801953e801963e801973e801983e802993e89a001029a3e79b00b019b3dd01003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8010d3e8010e3e8010f3e801103e801113e801123e801133e801143e801153e801163e801173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e801203e801213e801223e801233e801243e801253e801263e801273e801283e801293e8012a3e8012b3e8012c3e8012d3e8012e3e8012f3e801303e801313e801323e801333e801343e801353e801363e801373e801383e801393e8013a3e8013b3e8013c3e8013d3e8013e3e8013f3e801403e801413e801423e801433e801443e801453e801463e801473e801483e801493e8024a3e877001014b3e8014c3e8014d3e8014e3e8014f3e801503e801513e801523e801533e801543e801553e801563e801573e801583e801593e8015a3e8015b3e8015c3e8015d3e8015e3e8015f3e801603e801613e801623e801633e801643e801653e801663e801673e801683e801693e8016a3e8016b3e8016c3e8016d3e8016e3e8016f3e801703e801713e801723e801733e801743e801753e801763e801773e701783e801793e8017a3e8017b3e8017c3e8017d3e8017e3e8017f3e801803e801813e801823e801833e801843e801853e801863e801873e801883e801893e8018a3e8018b3e8018c3e8018d3e8018e3e8018f3e801903e801913e801923e801933e801943e801953e801963e801973e801983e801993e8029a3e89b001019b3e701003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8010d3e8010e3e8010f3e801103e802113e82400101123e801133e801143e802153e82800101163e801173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e801203e801213e801223e801233e801243e701253e801263e801273e801283e701293e8012a3e8012b3e8012c3e8012d3e8012e3e7022e0012f3e801303e801313e801323e801333e801343e801353e801363e801373e801383e801393e8013a3e8013b3e8013c3e8013d3e8013e3e8013f3e801403e801413e801423e801433e801443e801453e801463e801473e801483e801493e8014a3e8014b3e8014c3e8014d3e8014e3e8014f3e801503e801513e801523e801533e801543e801553e801563e801573e801583e801593e8015a3e8025b3e876001015c3e8015d3e8015e3e8015f3e801603e801613e801623e801633e801643e801653e801663e801673e801683e801693e8016a3e8016b3e8016c3e8016d3e8016e3e8016f3e801703e801713e801723e801733e801743e801753e801763e701773e801783e801793e8017a3e8017b3e8017c3e8027d3e890001017e3e8017f3e701803e801813e801823e801833e801843e8027f001853e801863e801873e801883e801893e8018a3e8018b3e8018c3e8018d3e8018e3e8018f3e801903e701913e801923e801933e801943e801953e801963e801973e801983e801993e8029a3e89b003019b3e501003e801013e801023e801033e801043e801053e801063e801073e801083e801093e8010a3e8010b3e8010c3e8010d3e8010e3e8010f3e801103e802113e82400301123e801133e801143e802153e82800101163e701173e801183e801193e8011a3e8021b3e82c001011c3e8011d3e8011e3e8011f3e801203e801213e801223e801233e801243e501253e801263e801273e801283e70216001293e8012a3e8012b3e8012c3e7012d3e8012e3e7022e0012f3e801303e801313e801323e801333e801343e801353e801363e801373e801383e801393e8013a3e8013b3e8013c3e8013d3e8013e3e8013f3e801403e801413e801423e801433e801443e801453e801463e801473e801483e801493e8024a3e877003014b3e8014c3e8014d3e8014e3e8014f3e801503e801513e801523e801533e801543e801553e801563e801573e801583e801593e8015a3e8025b3e876005015c3e8015d3e8015e3e8015f3e801603e802613e87b00301623e801633e801643e801653e801663e801673e801683e801693e8016a3e8016b3e8016c3e8016d3e8016e3e8016f3e801703e802713e88c00101723e801733e801743e801753e801763e301773e40277001783e801793e8017a3e8017b3e5017c3e8017d3e8017e3e8017f3e601803e801813e801823e801833e801843e8027f002853e801863e801873e801883e801893e8018a3e8018b3e8018c3e7018d3e8018e3e8018f3e801903e801913e801923e801933e801943e801953e801963e801973e801983e801993e703990019a3e89b006019b3e201003e801013e802023e80600101033e801043e801053e801063e701073e801083e801093e8010a3e8010b3e8010c3e8010d3e8010e3e8010f3e801103e802113e82400401123e801133e801143e802153e82800201163e801173e801183e801193e8011a3e8021b3e82c002011c3e8011d3e8011e3e8011f3e801203e801213e801223e801233e701243e401253e70225001263e701273e801283e601293e7012a3e8012b3e8012c3e6012d3e8012e3e6022e0022f3e801303e801313e801323e801333e801343e801353e801363e801373e801383e801393e802260013a3e8013b3e8013c3e8013d3e8013e3e8013f3e801403e801413e801423e80229001433e801443e801453e801463e801473e802483e85600101493e8024a3e877006014b3e8014c3e8014d3e8014e3e8014f3e801503e801513e801523e801533e801543e801553e801563e701573e801583e801593e8015a3e8025b3e87600a015c3e8015d3e8015e3e8015f3e801603e802613e87b00601623e801633e801643e801653e801663e801673e801683e801693e8016a3e8016b3e8016c3e8016d3e8016e3e8016f3e701703e802713e88c00101723e801733e801743e80323001753e89700101763de01773e10277001783e801793e8017a3e8017b3e2017c3e6027c0027d3e8017e3e8017f3e601803e801813e801823e801833e801843e8027f002853e801863e801873e801883e801893e8018a3e7018b3e8026f0018c3e7018d3e8018e3e7018f3e801903e801913e801923e801933e8028a001943e801953e801963e8038e001973e79a00101983e802993e89a004029a3e39b01c019b3cc01003e801013e802023e80600101033e801043e801053e801063e601073e801083e80206001093e8010a3e8010b3e8010c3e8010d3e8010e3e8010f3e801103e802113e82400d01123e801133e801143e802153e82800301163e801173e801183e801193e8011a3e8021b3e82c001011c3e8011d3e8011e3e8011f3e801203e801213e801223e801233e801243da01253e70225001263e701273e80224001283e502293e77c001012a3e8012b3e8012c3e7012d3e8012e3e5022e0032f3e801303e801313e801323e801333e801343e801353e801363e801373e801383e801393e802260013a3e8013b3e7013c3e8013d3e8013e3e8013f3e802403e85b00201413e8023b001423e80229001433e801443e801453e801463e801473e801483e701493e8034a3e85100177013014b3e8044c3e8500015200178001014d3e8024e3e87f003014f3e801503e701513e701523e601533e802543e85600101553e801563e702573e85b00101583e801593e8015a3e8025b3e57601f015c3e8025d3e879001015e3e8015f3e801603e802613e87b00c01623e801633e801643e801653e802663e88400101673e801683e801693e8016a3e8016b3e8016c3e8016d3e8016e3e8016f3e801703e802713e88c00201723e801733e802743e87500101753e701763c801773d20277003783e701793e6017a3e80348001760017b3db017c3e4037c0037d3e880001017e3e8017f3e201803e701813e801823e802833e89000101843e703520017f003853e801863e801873e801883e801893e8038a3e78c00194001018b3e7037b0018b0018c3e5018d3e8018e3e8018f3e801903e701913e801923e801933e8038a001943e79700201953e702963e897002047900195001973e49a00201983e802993e89a019029a3cd9b043019b3a501003e801013e802023e80600402033e80d00101043e801053e801063e301073e70307001083e80b0010206001093e8020a3e825001010b3e7010c3e8020d3e731001010e3e8010f3e801103e802113e82401b01123e801133e801143e802153e82800602163e82900201173e801183e801193e8011a3e8021b3e82c002011c3e8011d3e8011e3e8011f3e801203e801213e801223e802233e85600101243cc02253e0260010225007263e601273e80224001283e202293e57c001012a3e8012b3e8012c3e6012d3e8012e3e3022e0052f3e801303e801313e701323e802333e84e00101343e801353e802363e84000101373e802383e86200101393e803260013a3e841001013b3e8013c3e8013d3e8013e3e8013f3e802403e75b00201413e601423e80229001433e801443e801453e801463e701473e801483e801493e8024a3e877024014b3e8024c3e878001014d3e8024e3e77f004014f3e801503e801513e801523e801533e801543e801553e801563e702573e85b00401583e801593e8015a3e8025b3e27604e015c3e80441001460015d3e879001015e3e8015f3e802603e87a00302613e77b02901623e701633e801643e801653e801663e801673e801683e801693e8016a3e8016b3e8016c3e8016d3e8016e3e8026f3e88a00102703e88b00402713e88c00801723e801733e802743e87500401753e4017639901773bf0277005783e702793e77a001027a3e47b0010361001760017b3bd027c3dc7d001037c00b7d3e780001027e3e881002017f3e401803e701813e601823e801833e801843e701853e801863e801873e801883e80284001893e8038a3e68c00194001018b3e4027b0018c3df018d3e8018e3e8018f3e801903e802913e79500101923e801933e8038a001943e5970040291001953e702963e8970030394002973e19a00401983e802993e89a026029a3be9b0a1019b34701003e801013e802023e80600602033e80d00101043e801053e801063e102073e82e00102083e80b0010206001093e8030a3e81600125003010b3e7010c3e8020d3e731002010e3e8010f3e801103e802113e82404001123e801133e801143e802153e82800a02163e72900401173e801183e801193e8011a3e8021b3e82c006011c3e8011d3e8011e3e8011f3e801203e802213e84800101223e802233e75600101243a601253dc0225009263e701273e80224002283dd02293e37c004012a3e8012b3e8012c3e1022d3e836001012e3e1032e0062f3e73000101303e701313e501323e802333e84e00101343e801353e802363e74000301373e8022f001383e801393e80326001310013a3e8013b3e8013c3e8013d3e8013e3e8013f3e80328001403e55b00201413e701423e80529001433e85c001630017d00101443e801453e801463e801473e8042c001483e75a0016100101493e8024a3e777046014b3e8034a0014c3e878004024d3e87e002024e3e67f00a014f3e801503e801513e801523e801533e801543e801553e801563e702573e85b00b01583e801593e8015a3e7025b3db7608c015c3e703410015d3e879002015e3e8015f3e803603e8610017a00502613e67b04901623e70262001633e701643e801653e801663e801673e801683e801693e8016a3e8016b3e8016c3e8026d3e886001016e3e8026f3e88a00102703e88b0070423001713e8750028c00901723e801733e70373001743e87500701753df01763590177398027700a783e402793e67a001027a3e27b00302760037b399027c3d27d001037c0127d3e480002027e3e581001024e0017f3da027d002803e5027e001813e601823e80280001833e802843e589001027f004853e80281001863e701873e802883e88a0010384003893e795001047b0018a3e48c00394002028b3e08c001047b0028a0018c3db97001028d3e88e001028e3e792001018f3e801903e802913e79500101923e602933e896001048a00192001943e6970060291001953e6048b001963e7970059900102973dc9a00902983e6990010498001993e49a0339b0030498001990029a3ac9b0fa019b2eb01003e70200001013e70301001023e80600e03033e8090020d00102043e80e00101053e801063d802073e72e0020307001083e80b0010206002093e6030a3e81600125008020b3e70c001020c3e72d001020d3e731002010e3e7010f3e802103e81100103113e7240744a00101123e802133e82700102143e82700102153e82801202163e72900d02173e82a00101183e802193e844001011a3e8021b3e82c00a011c3e8011d3e8011e3e8011f3e801203e803213e8230014800202223e85500102233e756001012436e01253cf0325010263e62d00102273e6280010224006283d502293d97c00b012a3e6022a0012b3e8012c3dd022d3e336001012e3de022e0082f3e801303e80225001313e403323e83b0014c00102333e64e00101343e80326001353e73f001042d003363e6400065700101373e801383e801393e80326001310013a3e7013b3e7023c3e863001023d3e84600102330023e3e802350013f3e70336001403e15b00501413e701423e70229002433e802443e75d00101453e802463e75e00101473e8042c001483e6560015a00102493e54a0010431001490014a3e57706e02490024b3e8034a0014c3e77800c024d3e87e003024e3e77f011014f3e801503e701513e801523e801533e80250001543e701553e701563e503573e55b0127600101583e802593e85e001015a3e604570025a0015b3d1760e4033a0015c3e86600103410015d3e7790030440001420015e3e67a001015f3e6045f002603e8610017a00a02613e67b07a01623e70362001633e78200101643e801653e801663e701673e801683e801693e8016a3e8016b3e7016c3e8026d3e886001026e3e889001036f3e8710018a00102703e88b00b0556001713e7750048c00f9400101723e801733e70473001743e8750119600202753d39700301762fe0277367780020377013783da8400102793e57a001027a3da7b0040361001760057b365027c3ba7d001037c0237d3e480002027e3e481003027f3d185001027d003803e2037e00180002813e301823e60280002833e802843e589001057f00681001853e78e004920010381001863e78a0010282001873e703883e88a00193001038400287001893e6056b0017a0028a3e28c00494002028b3da8c00105540017b0058a0018c3d297001028d3e68e001028e3e192002018f3e801903e802913e79500102923e299001058d002933e7940029600198001068a0028e00292003943e29700a990020291001953e7048b003963e49700c99001048c00294001973ce9a01002983e499001059600198003993e09a0629b00e03990039a3769b185019b25501003e801013e802023e80600101033e801043e801053e801063e702073e82e00101083e802093e810001020a3e825002010b3e8010c3e8010d3e8010e3e8010f3e801103e702113e82401001123e801133e801143e802153e82800102163e82900101173e801183e801193e8011a3e8021b3e82c002011c3e8011d3e8011e3e8011f3e801203e801213e801223e801233e802243d87700102253e5260040225001263e401273e801283e702293e77c003022a3e87d001012b3e8012c3e6012d3e8012e3e7022f3e738001022f001303e801313e801323e801333e801343e801353e802363e74000101373e702383e76200101393e8013a3e802370013b3e8013c3e7013d3e8013e3e8013f3e80436001403e7570025b00101413e801423e702433e87d00101443e801453e801463e801473e801483e801493e8024a3e87700a014b3e8024c3e878002014d3e8014e3e8014f3e801503e801513e801523e801533e801543e801553e801563e802573e65b00401583e801593e8015a3e8025b3e076018015c3e7015d3e802420015e3e8015f3e801603e702613e87b00e023c001623e60262001633e701643e801653e801663e801673e801683e801693e8016a3e8016b3e8016c3e8026d3e886002016e3e8016f3e802703e88b00202713e78c00201723e802733e89500102743e87500401753e4025b003763d0035c001773dd7800301783e301793e802600017a3e8017b3d9027c3e37d002037c0027d3e480001027e3e781002017f3e702803e790001037e001813e6860010263001823e802833e89000101843e8037f001853e89200101863e501873e801883e801893e8038a3e68c00194001018b3e605710017b0018a0028c3e597001038d3e88e00193001028e3e792006018f3e701903e601913e8028f001923e101933e701943e001953e703963e79700298001059400796001973e5990019a00101983e30398004993e49a01303990039a3d19b035029a0039b3b301003e802013e80700102023e80600101033e801043e801053e801063e703073e7080012e00101083e702093e810001020a3e825005010b3e8020c3e82d001010d3e8010e3e8010f3e801103e60310001113e72402501123e801133e802143e82700101153e802163e82900401173e802183e83800102193e844003011a3e8031b3e82c00633001011c3e8011d3e8011e3e8011f3e801203e801213e802223e82300101233e70311001243c37700102253e1260010225002263e701273e701283e702293e47c007022a3e87d001012b3e8012c3e2022d3e736001012e3e6022e0012f3e801303e801313e703323e8330013d00101333e601343e801353e802363e64000101373e701383e701393e8013a3e802370013b3e8023c3e751001013d3e7013e3e8013f3e8052800136001403e7570035b00201413e801423e701433e801443e401453e801463e801473e801483e801493e804310014a3e84c00177018014b3e8024c3e778006014d3e8014e3e8014f3e801503e801513e701523e802533e88300101543e802553e85600102563e78e00102573e35b00701583e801593e8015a3e803570025b3dc76039015c3e802440015d3e703420015e3e87a001015f3e802603e77a003035b001613e87b01f02623e865001023c001633e801643e801653e701663e801673e801683e801693e8016a3e8036b3e8710018a001026c3e86d001026d3e786003016e3e8016f3e602703e88b00403713e7750018c00401723e802733e89500103743e7750069600101753e1025b002763ae02773cb780040277004783dd035d00178001793e802600017a3e402760017b3c9027c3dd7d004037c0047d3e380008037e3e88000181002027f3e68500201803df02813e58600201823e801833e701843e8037f002853e58e0010381001863e38800201873e801883e601893e8038a3e68c00194001018b3e4038a0018c3e297001038d3e88e00193001056f002850018e3e59200694002018f3e801903e801913e801923dd02933e7980010392003943de9700101953e6059200295001963e69700598003068c0019400796001973e0990049a00801983e00498004993df9a0239b001057400197001990059a3bb9b071029a0029b37601003e802013e80700101023e801033e801043e801053e801063e801073e701083e802093e810001020a3e825001010b3e8010c3e8010d3e8010e3e8010f3e801103e702113e72400801123e801133e801143e802153e82800201163e701173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e801203e801213e801223e801233e80211001243e002253e72600b01263dd01273e801283e60216001293e8012a3e8012b3e8012c3e8022d3e836001022e3e82f001012f3e701303e803313e83a0014900201323e802333e83e00201343e801353e802363e45700101373e801383e801393e8013a3e7013b3e8013c3e8013d3e8013e3e6013f3e80436003403e8570035e00101413e801423e801433e802443e85800101453e801463e801473e802483e85a00101493e6034a3e74c00277004014b3e8014c3e6014d3e8014e3e8014f3e801503e801513e801523e801533e801543e801553e801563e802573e25b00101583e701593e8015a3e703570025b3e076005015c3e8015d3e6015e3e7015f3e801603e802613e87b00701623e801633e801643e801653e801663e801673e801683e801693e8016a3e8016b3e8016c3e8016d3e8016e3e8016f3e801703e802713e88c00101723e801733e801743e801753e8025b007763e2034a001773e47800f01783d9025d002793e8017a3e802760017b3e1037c3e87d02780001027d3c180014027e3e881003037f3e8840018500701803d301813e101823e802833e89000101843e703853e18f002920030381004863e88800301873e801883e501893e8018a3e1018b3e8028a0078c3e7028d3e893001048e3e88f0029201b94001018f3e401903e701913e801923c701933e70292003943d401953e802963e8980050494013973e7990019a00101983df0398004993d49a0030497001990139a3c89b010029a01c9b3d801003e802013e80700101023e801033e801043e801053e801063e801073e701083e801093e8030a3e81600125003010b3e7020b0010c3e8010d3e8010e3e8010f3e801103e802113e82400a01123e801133e801143e802153e82800201163e601173e801183e801193e8011a3e8011b3e8011c3e8011d3e8011e3e8011f3e801203e701213e802223e85500101233e801243de02253e52600c02263dc3500101273e801283e60316001293e87c004012a3e8012b3e8012c3e8022d3e736001022e3e82f001012f3e701303e801313e801323e802333e83e00101343e801353e7022d001363df01373e802383e86200101393e8013a3e8013b3e8013c3e8013d3e8013e3e7013f3e80536008403e8570075b0025e00101413e801423e801433e801443e801453e801463e80220001473e801483e802493e84b001044a3e64c0037700778001014b3e7014c3e5014d3e8014e3e8014f3e801503e701513e801523e801533e80250001543e801553e701563e803573db590015b00501583e701593e7015a3e603570065b3de7600e015c3e802580015d3e6015e3e7015f3e802603e87a002035a002613e57b00c02623e76500201633e801643e801653e601663e702673e88f00101683e801693e8016a3e8016b3e8016c3e8016d3e8016e3e8016f3e802703e88b00102713e78c00301723e801733e802743e8750030271001753e5025b003763d9034a002773e17800a02783dd84001035d002793e87a001017a3e50461003760017b3dc8c001037c3e47d02580001027d3c38000e027e3e88100503660017f3e88500a02803d99000101813df01823e801833e801843e703853db880018f0020381004863e88800201873e801883e301893e803880028a3df94001018b3e7028a0098c3e4028d3e89300105850038e3e88f0049202894002018f3e101903e60290001913e801923bd02933e7940010292002943d101953e80392001963e8980090494013973e89900a9a00101983d3039800b993c49a00504980019901a9a3cd9b023029a0159b3c502003e80100102013e70700102023e80600202033e81000101043e801053e801063e602073e70800201083e603093e71000211001030a3e81600125003010b3e8010c3e8010d3e8010e3e8010f3e801103e50309001113e72401101123e801133e801143e802153e82800802163e62900302173e82a00101183e801193e8011a3e8021b3e82c002011c3e8011d3e8011e3e8021f3e84200101203e801213e802223e85500101233e702243d77700102253e42600c0425001263dc270013500201273e701283e00416001293e57c0067d001012a3e7012b3e8012c3e6032d3e83500436001012e3e8022f3e84900101303e803313e83a0044900101323e802333e83e00101343e70234001353e203363df400015700101373e801383e801393e8013a3e4013b3e8013c3e7013d3e8013e3e7023f3e8570010536008403e7570085b0045e00101413e801423e601433e801443e701453e701463e801473e803483e85a0036100101493e505490014a3e54c0067701778001014b3e8024c3e278003024d3e87e001024e3e87f004024f3e85000102503e77900101513e801523e701533e801543e502553e78f001032300154003563e802573d45b00501583e801593e8015a3e504440015700a5b3dd7603b015c3e8015d3e503420015e3e76b00102450015f3e802603e77a00302613e77b01f02623e863001023c001633e701643e801653e802663e78100101673e701683e801693e8016a3e802670016b3e7016c3e8016d3e8026e3e889001016f3e802703e88b00403713e7750018c00401723e801733e802743e7750030271001753e4025b002763aa034a003773cf780100377001783d484001035d003793e77a001017a3e40360001760037b3c7027c3de7d02b037c0047d3bc80020037e3e7800018100404660017f3e3840068500902803c79000101813df01823e801833e80352001843e189001057f001853dd8800391001920040381004863e88800401873e801883dd01893e60585001880038a3dd9200194001018b3e4047b002880018a00b8c3e4028d3e89300305850018e3e88f0039202a94002018f3e401903e701913e601923b103933e594001980010492008943ca96001990010291001953e804963e6970029801099002049401a973e5990069a00701983be06740019600198018993c59a00f9b0010597001980019901a9a3c29b053029a0109b39402003e80100102013e70700103023e8060080a00103033e8090021000201043e801053e802063e00900103073e5080022e0010207001083e604093e410003110013100105070010a3e70d001160012500b010b3e8010c3e8010d3e7010e3e8020f3e83300101103e2050900110001113e7240244a00101123e801133e801143e70314001153e72801003163e7290053800101173e801183e802193e83b001011a3e8021b3e82c004011c3e7011d3e8011e3e8021f3e84200201203e802213e84800103223e8230015500101233e702243c47700102253dc260100425001263d8270013500302273e7280010215001283d703293e27c0137d001022a3e87d001012b3e8012c3e4032d3e83500436003012e3e7012f3e801303e804313e73a002490024a00101323e802333e73e00201343e801353e103363dc400015700501373e802383e73c00101393e8023a3e641001013b3e7023c3e751001013d3e8013e3e6033f3e840001570020536009403e55700a5b0045e00101413e702423e45e0010229001433e802443e85800101453e801463e801473e803483e75a0046100201493e404490014a3e34c0067702c03490014b3e85c002024c3e278006024d3e87e001044e3e852001670017f00c024f3e85000102503e67900101513e701523e501533e8045000152002543e68500203553e7560018d0010254002563e7031c001573c65b00b01583e701593e8015a3df0440001570115b3d77605d015c3e6035d3e45e0017900103420025e3e56b001015f3e802603e77a009035a005613e47b03602623e86500203633e8650026900101643e701653e303663e8810018400102673e68f00201683e801693e702640016a3e803670016b3e78a001026c3e86d001016d3e7016e3e8016f3e503703e8710018b007046f001713e6750018c00801723e803733e8950019800103743e875009980010471001753de9400197002025b00276388034a003773b9780120377002783cf84003045d00478001793e67a00102600017a3de0461001760037b3b28c002037c3d17d02e80001037c0047d3b88001e047e3e77f001800028100a037f3d984001850090365001803c79000403813d8850018800202823e88700202833e89000202843e389001057f002853d6880028f0019200e0381005863e88800701873e601883da02893e78b0010685002880038a3db920019300194001018b3e0048a00b8c3de9600297001028d3e793005066f002850048e3e88f0099202794005018f3db01903e10290001913e7038f001923a69300103933e19400298006058a0019200c943c797002990040291001953e70594002963e397007980209900406610019401696002973d89900c9a00b029838d99001059600198033993ae9a01d9b0010497004990259a3b19b0b1029a00f9b33602003e80100102013e70800103023e8030010600401033e701043e801053e801063e402073e80800402083e31000103093e81000531001020a3e825007010b3e8010c3e8020d3e849001010e3e8010f3e801103e00310002113e32400401123e801133e801143e802153e72800202163e82900402173e82a00101183e802193e844001021a3e84d001021b3e82c002011c3e8011d3e8021e3e841001011f3e801203e802213e84800101223e801233e80211005243e402253e12601f02263c93500201273e801283e603293e47c0027d001012a3e7012b3e8012c3e5032d3e83500936008012e3e8012f3e801303e803313e73a0044900401323e802333e83e00701343e801353dc02363d65700101373e801383e801393e8013a3e4013b3e8013c3e7013d3e8023e3e15a00102350013f3e804150013600a403e85700902413e75800202423e75e00201433e802443e76400101453e801463e701473e602483e65a00701493e204490014a3e74c01077005024b3e865001034c3d67800379001014d3e7034e3e8520037f005014f3e80342001503e87900101513e801523e501533e801543e101553e80454007563e76f0028e00102573c15b00102583e66500101593e8025a3d661002035701d5b3c87600e035c3e8650016600202470015d3e502460015e3e6015f3e802603e87a002035a00a613e47b00501623e8023c001633e801643e701653e30265001663e502673e88f00101683e801693e8016a3e8016b3e8016c3e8026d3e886001016e3e8026f3e19200102703e88b001036f001713e78c00402723e87300101733e702743e7750040371001753e29900103480015b01f763da054a0014c001773e37804c84001042c0014c0017839984003035d003793e684001017a3e50347001610027b3e3037c3e67d07680001027d3718003204660017e3e88000381012037f3e38400c850180365001803b0900020380001813cd8800101823e80580001833e888001900059100101843d605853c8880018f004910019200b0381009863e78800801873e701883d9038400187001893e70485001880048a3ba92004028b3e79500105560017a0018a02c8c3e496001038d3e88f00193002086f004850078a0018e3e78f023910029209a9400c018f3bd01903e101913e0028f0019232f02933e498004068a0018f0019200d943a49600199002038900191004953e7069200293002963e4970029801c990020494038973e4990119a00201983980574001960029802f993709a00806750029700298001990629a3809b013029a05e9b3d502003e80100202013e60700104023e803001060040800102033e70d00202043e80c00101053e801063e403073e6080042e0010307001083e31000304093e8100091100131001030a3e8120012500c010b3e8020c3e71c001010d3e6010e3e8010f3e803103db11001140010310001113e22401102123e73400101133e801143e702153e72800802163e82900602173e82a00102183e84300102193e844001031a3e82b0014d001031b3e82c0074e001031c3e61d00257001031d3e64000157001021e3e841001021f3e84200102203e84700202213e84800101223e801233e80211004243d702253dc2602303263c427003350060215001273e501283e004293e22a0017c0077d001012a3e5012b3e7022c3e185001032d3e83500b36009022e3e62f003032e0012f3e54900101303e803313e63a0024900b01323e802333e73e00b021c001343e6032600134001353d50335002363ce5700701373e803383e83c0016200201393e8023a3e658001013b3e8013c3e6013d3e8023e3dd5a002023f3e8570010436011403e7570105b00302413e75800202423e75e00103433e7630016500102443e765001022a001453e801463e701473e503483e75a00b6b00101493da0631001490024a3e74c0157700f78003024b3e85c001044c3d3660017800a79001024d3e77e00105330014e3e752002670017f009024f3e85000102503e77900201513e801523e501533e80252001543e301553e80454005563e88e0018f00202573a75b00802583e36500102593e85e001025a3d46100203570275b3c076020015c3e702580025d3e202460015e3e6025f3e77900102603e67a004045a007613e4710027b01002623e565001033c00162001633e701643e801653e301663e502673e78f00101683e801693e8016a3e8046b3e3710018a0018f001016c3e8026d3e886001016e3e8036f3df8a0019200102703e88b005066b0026f002713e3740018a0038c00502723e87300102733e79500102743e6750090271001753dd025b01d763c7034a001773d978039045f001783a27900384005055d00660001793e17a0027f00103600017a3e27b001054700161002760017b3d78c002037c3e17d05d80003037d38a7e0018004d04660017e3e6800058100e037f3de840098501d036500180392900040380001813d28800302823e88700203833e89000b9100101843d8086f00284002853c188002890018a0018f003920100481008863e788007910010266001873e601883d201893e7066b002850018800a8a3c49300194001018b3e305710018a01d8c3e19500197001048d3e8910029200193007076f004850088e3e78f017910039207f9400a018f3c501903d902913d99500103740018f0049234202933dd98008076f0018a0018f00192013943b196002990010291008953e5059200293003963e0970059802806750019402c96003973dd9901a9a005029836a9900104960039804c993629a00e067500197005980029906a9a3949b03d029a0419b3ab02003e80100303013e5020010700202023e70600903033e8090010d00502043e80c00202053e80f00101063df04073e4080060a0012e0050407001083e2100071400105093e71000f11001130013100104070010a3e70b0012500c030b3e71300126001020c3e632001030d3e32f00131001010e3e8020f3e73300202103d1110010410001113e4130012401f03123e8180013400101133e502143e72700204153e81d0012800d4000102163e82900f02173e82a00304183e73a0013c0014300104193e8280012b00144003031a3e82b0014d002041b3e82c00b3e0014e001031c3e71d0033f001031d3e44000457002021e3e841001021f3e84200102203e84700305213e83e00148001540015a00102223e85500101233e80211002243c702253dc2602103263c4270033500a0426001273e3350033f0010224002283da03293d97c0177d003032a3e32b0017d002032a0012b3e57e001022c3dd7f002032d3e83500e36007022e3e12f002052e0022f3e530001380014900101303e706313e5320013a00f4900d4a0034b00101323e602333e63e00c01343e6032600134001353ca051c00135003363d2400015700701373e802383e76300101393e8023a3d865001023b3e850001013c3e6023d3e765001023e3da5a004023f3e440002053600f3f001403df570285b00202413e65800202423e75e00104433e64d0015c0016300103443e54600158001032a001453e88000102463e65900102473e56000104483e75a011610026b00102493d54a0010631001490054a3e24c01b7703378003024b3e75c003054c3ca52001650017800e79001044d3e564001650027e001074e3e6520075400166001670017f01185002014f3e8044c001503e7520017900201513e601523de02533e783001054e00152001543de670018500202553e78d0010454007563e86f0038e002033f001573855b00a01583e501593e7025a3c6610010440001570325b3cb760450541001430015c3e35d00266001025d3e06c001033d001460015e3e7035f3e46e00179002035f001603e37a00b045a00c5b001613df7b02502623e765002073c0015100162001633e565005660018200301643e701653db01663e403673e48f0029100101683e60251001693e802680016a3e6016b3e3016c3e7036a0016d3e886003026e3e589002036f3dc8a0038f00103703e8710018b00b08610016b0036f002713e2740018a0028c00d9400102723e87300202733e695002066f001743e475012930019600298004047100374001753d599001025b010763a3054a0024c0025c001773b478033055f00277001783a37900684010055d0066000178001793dd7a00203600037a3db7b00103610057b3c18c001037c3d07d05780001047c0017d38b7e00180055067d0017e3e47f001800068101490001037f3d4840128501d046500180389880019000f057e00180002813cb86001880050363001823e58700106530015f001833e788001900099100203843c387001890010c54001670016e0026f00284002853bc880038a0018e0028f0039100392011066b00181008863e48800a91001930010266001873e602883c58f0010284001893e5086f00171001850028800f8a3bc920069300194001028a0018b3dd07710017b0018a0228c3d9940019500197002048d3e78f001910029300709670016f002850098a0028e3e38f01f91004920859400a04680016a001810018f3b601903cc038f00190003913ce058f007910029232393002960010491001933d6970019800c0c6f001740018a0018e0018f0039202593002943ac96002970029800299003039100a953e59800208920049300494002963da9700b98050990079a001078c0019402d96001973d0980029902c9a006029831199002057400196008980719933e9a021057500197008990719a38a9b073029a0369b37501003e801013e801023e801033e801043e801053e801063e802073e80801102083d71000203093e81001411001020a3e826001020b3e826001020c3e814001010d3e8010e3e8020f3e83300101103c90310009113d92400202123e83400101133e801143e702153e82800202163e72900101173e801183e801193e8011a3e8021b3e82c001011c3e7011d3e8011e3e8011f3e801203e802213e85a00101223e801233e8021100e243e602253e826079032636d270013500d01273e703283e63f002400010316001293e72a003012a3e5012b3e8012c3e7042d3e8350253601b39001032e3e82f01038001022f3d84900201303e804313e83a0234900b4b00201323e803333e73d0013e00f01343e701353b4041c00135002363a15700401373e803383e73c0016200101393e7023a3c558002013b3e8023c3e263001013d3e7023e3d95a001023f3e640001033602c403e25701d02413e85800201423e801433e804443e846001580056500101453e801463e701473e804483e65a013610016b00102493d74b00104490044a3de4c04e77001024b3e565001024c39552004024d3e865001054e3e85200e660016700282001014f3e802503e85200201513e701523d401533e802543cd6f00101553e8055401a563e8680016f0038f002015736301583dd01593e8015a3c40440004570625b33d76004015c3e802580025d3d5015e3e7015f3e702603e67a00207480025a00f60001613ca70001710018b0010351001623e765005033c005633e66500301643e701653dc0265001663e503673e46f0028f00101683e601693e7016a3e8016b3e4016c3e8016d3e7016e3e8026f3c48f00101703e7036f005713e68c00102723e87300102733e79500101743e202753e19400103570025b0ab763e4054a00a4c005773e7780fb84004045f001782ed790038401c035d013793e4840020360001790017a3e5056101d7a0017b3e88b0018c003037c3e87d1c980015037d21f7e002800e00566001670017e3e68000a810360554001660017f3e8840318507e02802e99000f02813a08800406630016400169001823e7870068f00105833e8880018f0019001891003018439409670016d0016f0038535c8800a890018f00b910049200f055e0016800181012863e68801401873e201883b50284001893e7056b001860018800e8a36b92003028b3e696001066b001710018a07c8c3e49600197001048d3e88f00b9100b9300f0a6f0158500e86001880028a0018e3e88f0c29101a9219a9400d018f30401903c0038f00190001913a603740018f0029222203933d39400198004076b0016f001740038f0039201893001943170291016953e7059200193005963e098013990020792001940c296002973d6980019900e9a003019831f0474002960039809e9927407750079600197011980129913e9a23c9b0020498001990269a1a99b3e601003e801013e804023e806004080014900104033e808001090011000102043e80c00101053e801063e402073e80801903083cd100041200104093e710015130012d001040a3e8120022500226001020b3e826001020c3e714001010d3e7010e3e8020f3e83300101103c50310009113da2400101123e401133e703143e6270023400103153e61c0012800302163e72900201173e801183e801193e8011a3e7021b3e82c001011c3e3011d3e7011e3e8011f3e701203e802213e74800101223e801233e8031100e243e72800102253e6260740312001263723501001273e5051500227001283e43f002400040516001293e62a0087c0057d004032a3e02b00145001022b3e77e001012c3e7032d3e7350303601c042e3e82f0183000138001040d0012f3d0380014900801303e704313e73a01d4901e4b00202323e84b00103333e73e0155300101343e7021c001353a405140011c00135004363985700401373e802383e66200201393e8043a3cb4b0015800265001013b3e8021a0013c3e2013d3e8033e3d35a00468001013f3e6041c00136034403e05702802413e858002031f001423e75900104433e84b001630016500203443e85800c6500201453e702463e86800102473e76c00203483e65a0226b00102493b94b0030631001490084a3db4c0507700478001024b3e065004044c393520086600178001034d3e86500166001084e3e851001520105400166002670027f00282001014f3e802503e75200201513e702523c96e00101533e60552004543cb6b0016d0016f0010353001553e88f001065401b563e76b0016f00c740018f00b041c001573405b0056100101583d801593e7015a3ab061d00121001400045707b5b35376006015c3e4015d3db03420015e3e760001035f3e46e0018700102603e17a00207480015a01760002613c168001700017b004043c002623e66300165006053c004633e665006690018200102643e76900101653d102663df8700205673e46e0026f001820018f00302683e58f00101693e5026a3e66c001016b3da016c3e5026d3e586001016e3e2016f3ba02703e68b00305560016b0076f004713e48c00302723e87300101733e603743e175003980030374001753e09400204570015b090763e27b002074a00d4c0045c003773e4780f88200184003074c0015c0015f00466001782ee790088402a04500015d00d793df840020360004790017a3e404610267a0027b3e28b001037c3e37d1918000e037d2537e004800f40764001650017e3e3800148103c83001900010854001660026e0017f3e68200483001840318509702802d19001a05690018000181398880079100102823e08700804833e68f00290017910010547001520016600284387890030c670016a0016d0026e0016f005840018533b880138f00d910069201993001055e0016b00181014863e78801701873dd046f001883988f0019100202893e595001086b0026f0028801b8a37b8f001920079300494003028b3e49600105710048a06a8c3e49600197001058d3e88f0109100d92002930140a670016f01385016880048e3e88f0c091017921659300194016018f2e901903af038f003900079139e046a0018f006922389300305740018f00191001933bb98006096b0016f001740028a0028f00492025930029431696001049101b92001953e798001087300174001920039300d963d497002980369900209750018a0018c00193001940b696007973cc980059900a01982ca057400196008980bc992659a0020970001750049400196002970199801c9915e9a2929b0030498001990199a1549b3e501003e801013e802023e80600501033e802043e80c00202053e80f00103063e3090022500103073e8080162e00103083d2100061200204093e61001e1100113002040a3e8120022500326002020b3e826003010c3e6040d3e8310023a00149002010e3e8030f3e71b0023300302103b711001041000d113dd150012400c03123e3140023400104133e514001370013900104143e21c001270023400203153e51c0012800503163e8290063c00102173e82a00103183e83c0024300202193e844002021a3e84d001051b3e62c00b4e002670016a001021c3e03f002021d3e736001011e3e8021f3e74200202203e85100106213e63e00146001480015a0026f00101223e80421001233e8560016f0010411009243dc280017700102253e4260780512001140022636b270023501d0413001273e33500536001061500227001283e12b0013f0034000704293e22a00d7c00b7d008042a3da2b001450017d001022b3e67e001022c3dd7f00205140012d3e834003350343601c032e3e72f01330001042f3d5300024900a4b00101303e505313e6320013a023490224b00102323e74c00103333e53d0013e01501343e1031c0013400135389061c0041d00135009363943f0025700601373e704383e83c001620036500101393e7053a3c4410014b0025800265001041f0013b3e84100164001023c3de65001013d3e7023e3d25a004023f3df400010628001360363f001403db570405b00203413e6580026900102423e55900106433e6450024b0015c001650027d00105443e645001460025800c6500202453e26c00101463e401473e803483e35a0336100304493ac4a0014b00651001074900e4a3dd4b0014c05b5c0017701078003024b3db65005074c38851001520095f001660017800484001044d3e76500566001800010a4e3e65100252016540036500166004670077f0038400185001024f3e85000103503e7520057900101513e302523c06e00102533e8690010752003543ca67001680036d0026f0028f00402553e88d00109480015401b563e5680016b0046f00a8e0038f00d92001041c0013f0015731b5b00602583d46500101593e7015a38d0540005570875b366610017600f044b0014c0015c3e65d0020545001580045d3d560001660010442001460015d0015e3e5035f3e4690017900102603de7a0080a21001480035a02160004613b66b00270004710047a0017b00b033c001623e565003043c005633e7650098200202643e56c00102653c469001056400165001663da6c0016e0010564001673d86a0016f0038f00601683e101693df026a3e36e001026b3d36f003016c3e2036d3e48600287001026e3e091001046f3a273001740018f001036f001703e38b00709560015a0016b0046f00c713dc740028c00a8f0039400102723e77300201733e303743d475003980010371003753de94004025b07a763d9054a00a4c002773d7780ca84001074c001520015f00378317790068200184036045d00f793df7a00184001055e0026000561001790017a3db056102d7a0037b3dd8b0048c006037c3dd7d1498000c037d2957e004801010665002660017e3e3800168104b8300306660047f3e382005830048403c85083064500165001690016c001802c39003504800018138a8800c9100207630016500166001690036c001823de870080682002833e0880058f0019001b91001065d001660016a0016e00284371890021167005680016a0026d0026e0026f00881001830018400185346870028801f890028a0058f00c9100992025065e001680016b00381012863e58801903690016e001873dc0188374047300187001893e495001096b0056f00170001710018802a8a3778f0019200993003038b3dc8c0029600106710038a0668c3d4940029600197005076c0016f0018d3e78f0139101692002930120f67002680016b0026f018740018501e86001880018a0018e3e58f0aa91016921499300194014018f2ee019038f04720018f0039000991391066f001740028b0018f00a922289300305740018f00191002933bd980060d560016b0016f002740088a0048f00692040930039431d9600597001980019900105730019101c9200193001953e60974001920049300d9400195001963cb9700198056990050a710018a0018c00292001940af96008973c1980099901b9a00301982690774004930019600d9700198102992599a0040775007960019701f98016991679a2cb9b01803990079a1169b3d001003e802013e80200103023e7030020800201033e601043e801053e802063e80900103073e8080342e00102083b21000504093e7100241300214001030a3e80b00312001030b3e41400126001010c3e8030d3e73100149001010e3e8010f3e801103b5031000a113c71300101123e702133e51400202143de2700102153e41c00201163e802173e82a00102183e83c00101193e8011a3e7011b3e7011c3e4021d3e136001011e3e5011f3e801203e701213e802223e82300101233e7031102115001243e8040b001253e826113350010414005262d4340013502f02273e7350040415003283e83f0024000204293e82a00a430057c001021a0012a3dd022b3e853001041b0012c3e8530018400105140012d3e8340093506e3601e042e3e72f0233000238001042f3c530003380014900601303e306313e7320023a06f490294b0055100101323e604333e83d0013e02b4e00101343de0135343051c00235003363783f0015700201373e803383e63c0016500101393e8061e0013a379410014b0014f00158008013b3e8013c3e1013d3e7023e3bc5a008023f3e35700104360513f001403d85701902413e75800803423e8590015e00106433e3450044b0025c0016300265008051e002443e846002580126500401453e402463e56900102473e8640010446001483e55a0286b00203493a14a0014b006080d001490174a3c14b0034c0f05c0046600177001014b3d7034c2de5201565001024d3e765006084e3e7510025202c540076500166006670056c001014f3e602503e75200401513e5015239d01533e60552003543a867001680016f00104553e86a0016c001720020754039563e8680016b0036f00c8e0018f004023f001572c701583c101593e7025a38970001041d0074000e571005b293025c3dc66003044f001580055d3b15f001065d0015e3e460001680026a0016b001015f3d803603e26c0017a00109480035a02f60002613866b0016f00170005710017b001043c002623e86300165007043c003633e565005690010320001643e76c00101653c303663c86c0016d0010666001673d8680016a0016c0018f00201683db01693dd026a3de7000103680016b3da6f001016c3e0016d3de026e3db72001026f39a9100103703dd720028b001076b0026f009713dd740028a0018c0018f00401723e002733e59500101743d00271002753b005570055b15561002763e87b003084a0264c019520015c007773e7781f38200284010074c001520015f00f66004781f5790088405a045d036793df840018900106500015e00360003790017a3e08a001083e00161060710017a0077b3e48a0018b0078c004047c3e77d2cc8003a90001054d0017d11c7e001801a9900030665003660047e3e78000d8108c830030b6600d670016e0027f3e882007830078406f85117870018800192001046500169001801f790033046800180001813408800b0369004823de8700a07690026c001833de880018f0019002a9100206520016d0056e0018430a87002890011167004680026a0076d0046e0096f0038200184003852b2860018700388029890028a0018f019910089201804680038101c863e7880390269002873d60288337910010387002893e392001086b0018803a8a2c28f00191001920079300294001077000473002890018a0018b3e0950069600205710048a11b8c3dd9400196001058d3e88e0018f0209102e9301c0d670056b0036e0016f02f8501f880088a0058e3e68f19a9105a92223930019400d018f1ff0190383036c0019000491337066f0018f009910019216b9300595001037200274002933ac0a680016b0016f00273001740068f0019100192031930049426e029101a953df049200693010963b9980190d6f0017100374001750018a0018c0069200293003941689600f9737398008990050372001740019822b067400c9300195001960119813e991590775037940039600c9707398059991fc9a1350597002980059908e9a2b39b3e802003e80100202013e60200b05023dd0300706003080030a00103033df080021000702043e80c00101053e802063e50900104073e808037120012e0020403001083ac0b002100070503001093e6100301100113003040a3e70b008120042f001030b3dd1400126001070b0010c3e714002190011c0012d00139001040d3e8180013100249001010e3e8010f3e8021039c1100107090011000e113c91300115001190014a00103123e3140011800102133e31400102143df2700105153e61c00727001280013f00102163e81700102173e72a00102183e66500104193e61f0013700144001021a3e839001011b3e6031c3d01d0013f001011d3da011e3e3021f3e64200201203e803213e4480015a00302223e82300102233e754001031101d243e82800202253e8260ef0414003262f72700235042051300126001273e3350073b002061500127001283e43f005400044200105293e82a01e38001430067d003012a3c9022b3e845001031b0022c3e84800306140012d3e7340093506e360273f001032e3e62f02d38001052f3ba3000338001390014900701303e506313e5320013a062490264a0014b00904323e73b0024f0015100104333e83d0013e0345400101343df021c00535330041c00b35001363705700202373e739001041e002383e53c0036500302393e36c001043a3854b0054f00158007023b3e34f001031e0013c3df65001013d3e6051f001210013e3b45a0116800103360013f3de57001061d00128001360503f001403d35702b0339001413e75800c043b001423e5590025e00105433e1450054b0026300465007071e001443e74500346002580195d0026500301453db03463e1590016a00102473e76c0010646003483dd5a039680026b0026e001034939f4a0014b00e08310013a0014901b4a3c54b0054c0fd5c00977003041e0014b3c5580016500e074c2db4f0025201c5c0015f0016600378001064d3e751001530016500a660016c0010a3d001470014e3e85100252045540096600a67007680016e002014f3e202503e75200301513e0025237a6600102533e46c00109520065439d67001680046a0016d0016e0016f0028f00305553e76e001720028d0018f0020854040563e667001680016b00b6f0128e0018f00d033f001572935b00203583b6650026c00201593e5015a359061d00c40011571225b2c561001760030541001450045c3d4660038200105460014f001580055d3b06c00308460015d0025e3e46000167001680016a0016b001015f3cd035f001603d97a0010a21003480075a042600046138d680016b0046f0037000671002043c001623e76500c6c001093c0055100462001633e2640016500b690016c0028200102643e26900301653a3055300265001663c66c001870010b53001673c968004690016a0046c0036d0016e0026f003870028f00403683c96c0017200101693db026a3d57200105680016b3bc6f004700018f002016c3d1026a0016d3d8026e3c289001046e0016f378700028f001056e001703dc72001730018b00108560016b00c6f011713da740098a0028c0019200101723de02733dc9500204743bd750029300198001047100374003753ab9400104570055b121763e57b002074a0204c00d520015c007773e5781c684008084c003520035c0035f0196600378221790188407a055d0346d001793cb840048900107500015e0036000a790057a3dc890018a001076105a700017a00a7b3e68a0018b00e8c00905430017c3e87d2837e00180031054d0017d1627e007801c4900050965003660067e3df8001781084820018300788001900020a66006670016e0037f3e8820098300b8406c850eb87001920010664004690016c0017e001801da9006a0865001670016a0026c00280002813428800a91001046300269005823dc8700f0964001690016c0026e001833d68800b8f0049003d9100306660016d0036e008842f487004890021167005680036a0066b0026d0096e0106f0098100484002852d4870058803f890028a0048f016910169202906680066a0026b0038101d863e68804003690016c001873c705550017200173001882f491003066a001700017300187004893e1920010c56001680036b0066f0017a00186001880558a2cf920139300594001950020570001730048b3d99500396003096f001710098a10a8c3d39200193001940019600597001086f001880018d3e78e0018f0259103e920019301f0e6700e6d0016f02e810018502986001880098a0018e3e68f17691043921e1930019401602680028f209019032f0572001730019000b9131c930010a680016e00172001740018f00a910049215c930069400195001097200273002740048700191002933839400195002980040d6b0036f0077300274007750018a0058f006910029205c930079423c96003990010291026953db0874004920069302d940029500396398970029801f0b74003750038a0018c00b92008930029418c9601497369980079900901981d4097400c750018f00193001940019602698197991619a0020875036940029600b9707c9804c9922a9a18a9b0010498006990539a25c9b3e702003e80100403013e40200e0700405023da0300b06006080090b00106033db08002090010b002100071300104043e80c0031c0022000102053e80f00204063e209007130012600104073e4080320a0052e0050403002083ab100141200305093df1005a110051300631002080a3e30b008120041800125006260013400138001030b3dd1400426001070c3e414004150031c0032d0013600139001050d3e71800131002490064b001030e3e81a0013c001060f3e6190021b0031f0012b0013300102103551100109090011001e113cf13007150032400a27001280014a00105123e01400118001340013800106133d614006190011c0021f0013700106143c5150011c002270033400135001070c001153da1c009280053b0013f0014100103163e8180012900602173e82a00206183e21e0053c005430014d0016500407193e51a0011f001370023b001440034f001071a3e4200022b0013d0014d0014f00153001041b3e31f0012c00553001031c3b61d0013f001051d3e3360013f0014000257002031e3da3c00141001051e0011f3e03b002420016a00101203e5041f001213e6540015a00504223e82300355001720010421001233e454003560010411013243dd280017700103253e2260da3500106120011400d2630b27006340023505c0513003273dc3500c3b0034100107150062400127002283e13f00a400064200207293e22a02c43008630017c0087d007800010b1a0012a3ba2b001390013c001440014500253001620016300165001062b3e53b0014600153001640017e002071b0022c3e3420014800350001550028400105140062d3e734008350783602e042e3e32f02f3000238001072f3b930006380053a001490104b0045100101303df090d00118001313e2320053a067490484a0014b00d4c00106323e33b0013d0014b0014c0014f00203333e73d0013e0350214001343da041c00b34001353013f00209150011c0131f00135005363653f00640001470015700201373e50a180011e00330001383e0390013a0013c0064b002620016500301393e40a1e003390013a37d3d001410034b006510015800e6500669001051f0013b3de3d0024f00168001033c3d15100169001033d3e1640016c001053e3b148001540015a01168001041c001360013f3cb57002061d001360523f007403be420015703d02413e258014051f0013b001423e1590035e00206433de450094b0055c0026300e6500a091e0023d001443e445007460044d001580215d0026500c051a001453d563001650016c0030545001463dd4f001590026800402473e3640010c210013e00246002483da54001590015a05960001680016b0066f00170002064936b4a0024b0134c00151001650010a310023a0024901f4a3c84b0044c0f15c012660017700b78002054b3b14f00151001580026500b094c2e94f003500025202f5d0015f001650026600478003064d3e2530016300265010660016c0050d4d0014e3e8500015100452047530045400b6600c670146d0016e0037f00485002034f3db500016c002044f001503e2520026000102513db69001035236a660016a00104533d464001690026c0020c52005530025438f6700368008690016a0016b0026d0036f004850028f00307553e556002690016e001720038d0018f0020c4800254047563e367001680036a0016b00e6f0228e0048f0169100192001041c0013f0015727f5b005035839559001650010347001593e05e003015a323071d00340021571255a0015b308600017600808430014c0015c3cb5d0025f0016300165002660150b46002470014f0025800d590015d3b25f00365001660036c0046d0010742002460025e3db60003680016a0026b0040547002580015f3cb600026e00103603c96e0017a0090c480085a05360006613916b0026f00170009710047a0017b0038a0018b001053c00251002623e5630016500f093c0075100162001633d065011690016c003820028700105643de6900f6a0016c0016e00102653786c00209510015300165004663996c0076d0016e00182002870040a530025d001673b9680076a00a6c0016e0026f003870028f00501683c202693c26c00103640016a3c48700107680036a0016b3b46f009700018a0018f002026c3bc6d00104680016a0046d3c5860030653001690016c0026e3a6720017300107660016f347700017300174001880018f001066e001703d57100172005740038b0030d560016b00f6f025713d5730017400c750028a0038c0038f00294001960029700102723d073001046e002733cc9100195003047438f750079300198002082300154001560016f0017100374008753b39400305570015b0db61003763e07b003074a01c4c009520015c002773dc7819084006094c0015c0075d0015f01666005782537901b820028409c075d02f5f0026e001793ca7a002840048900308500015a0015e0086001161001790027a3cd89002065a001610527a00f7b3e08b0098c00f037c3df7d26580018064d0017c0017d17c7e008801db900090c4d0015300165003660116d0017e3de8002681097820018300a88002900040c66009670026d0016e002790017f3e48200a8301084069850e7880028900105640026500269001801cd9008a0d53002680016a0016c0026d0056e001800018132a83003860028801a90001910030663003690066c0026e003823d78700e0b5300264003690046c0026e002833c987002880038f001900479101008660026a0016c0016d0026e011842d48700689004146600267008680026a0056b0016c0016d00f6e0196f0048100384003852d6860038700a88058890118a0038f01f910149203b0767001680036a0036b00281023863dc8804906690036a0016c00172001873b78f0020a6c0016d001720027300183001882b08f002900019100995001096a0026d0016e002730028400187008893ca8f001950040c680016a0016b0036f0077000473001880668a2ee9201b9300294001950010673004890028b3d98c00395009960050c6100170001710087b001880028a0e68b0018c3c892001950019600597004068d3e78e0048f031910469200393028116700c6b0026d0016f03173001810018502786004870018800d8a0038e3e08f11f91047921ac930039401d02680018f22a01902f70672004730018f00290011912f6930020b6d001720017300474004890018f01291001921579301095004960010b7200673006740078f00591008920029335c940039500296001980040e6b0016f00b7300174018750018a0078f00a91003920799300f9427f950029600a9800304700017300191027953c40a73002740039200b9303594003950089636e970049804c99003117100374002750047b001830018a0028b0018c00b92004930059413e9500196033973529800f990169a0010374001930019819408740188f001930029400196024981ba9917f9a0060875027940029600b9708d98035992249a1fb9b00704980019902c9a1e69b3e102003e80100103013e7020150700203023d3030190800905033cd08002090010b0021000502043e80b00101053e80303001063e80900503073e60805b2e0020503001083820b001100121200105093e21004e130071400331001060a3e80b00f12008250012600134001040b3d5120013000137002060c3e814003150011c0033400137001030d3e8180012f001020e3e81a001040f3e8170021b00139001021035849001041002b11391130041500204123de14006190013400307133db140041e00137002390023b0014100102143c23400106153d9190011c0051f001280013600103163e5170022900201173e405183e71e0033c0024b0016500103193e51e0016c001031a3e13d00151001071b3e61f001200012c0023e0015300155001021c3af1d001041c0011d3d93600157001021e3db41001011f3e601203e503213e53e0015a00202223e7720030621002233e354002680016f00174001041105715001243e82800303253e7261ae3500106130011400f2623927002340033506d0813001140011c001273e13500e360013b00341001071500b27005283e3360013f01040002420030816003293e62a04a430184d001630017c0057d002081a0052a39d3c001440014500263001660017d001072b3e646001470015300164001690017e001051b0012b0022c3e6470015500106140062d3e834015350b6360383d001042e3e62f0623000e38004062f385300083800139001490104b00202303d03900106313e7320013a0c94904b4b00f5100706323e7390013d0044b0014f00165001051a001333e83d0013e0546c00101343c9031c00834001352b2051c02135004363183f0015700102373e339001071e00130001383e23c0044b001620016500301393e0081e0013a31b3b0014b00e4f001510015801e65004023b3de4f002013c3dd013d3e003210013e38f5a007021c0043f3cf071d00328001360943f006403a6480025701d05190013b001413e45801669004043b001423e359001680010a1e001380013c001433d0450114b003510016300a640016500e0a1e00539001443e745009460024d001530035804b640026500e0520001453ca64001690026c00106463e0500015300159001680016c00202473e06c001073e003483d45a03661001680016b00170001054935e4a0014b0204f002510010b3a0034902e4a3994b0054c18e520025c01666001770027800182002044b39d4f002580016500d0b4b0014c20b4f0085000451002520425c0015f00265001660056e001084d3dc5100153003580016300165027660026c0030d3d0014e3e85100e520645300554017650046601267021680016c0046e0088700103200014f3d66c002043b002503de520085d00202513c86900103523226d0016e0020451001533cc69001720030a52010543656800a6a0016c0026d0076e0036f001880018f00504553e256001720068d0010a230014800154069563e4680096b0146d0016f01e740018f01d041c0011d0013f00257218035835c650056c00601593e3025a32e700010b1c0011d00a40040480015719e5a0085b22d5e0016000261005760010a3a0013b0014100145001470015c3b25d0095f00365007660160846002470014f0025800b590015d35e690016c005074200246002590025d0025e3da680016b0030645001470015f3a6690026e0037900106470015f002603c8690016e001880010a480105a0725e00160006613306b00a6f00370018710077b001053c00251002623e76500869001043c001633d765009690030553002643d76900a6c0026e00102653596c0020b520015300164001650036636d690026c0086d0026e00182001870030f220014600147001530055400155001640016600167380680056a0136c0056e0046f0018f00301683b20251001693b404640016a3ae720048f00105680016a0016b3916f00573001026c3ae72002036a0046d3b387002026e37372002066b0016d0016f33070001720018f0020455002703c7720057300109230015a001680026b01e6f033713b3740058a0018f00101723a403733bd910029500104720017436b75002980010823003560016f00171017740117533c920019600106570135b1bb5e00161005763e77b006094a04e4c04a520035c01a66001773e67827582001840190b470014c005520025c0055d0045f03a6600c781727903182005840f7085d0795f001600016d002793b384003870018900109500055e00b600126d001790037a3d5890018a002950010960005610ad700017a0137b3e0890028a0028b0208c00f052a0017c3e37d3397e00180072094d0075300163001650057d0ac7e00b8025881002900110c4d0036400165009660127e3db80013810e982001830068700188002900060f5300166026670046c0016d0036e0127f3e8810058201b830268407e851758700988004890020464001650038010b9009f0c530026400267001690026a0016c0026d003812d08600288016900019100205630036900d6c001823c28700e0d530016400568001690056c0046e00282001833bb87001880068f001900519101007660026a0026d0066e01b8425487004890081753001660026701c680056a0106b0016c0036d0146e02a6f0057200181001840038522a86004870148806d890158a0028f0209101f9201c9300107670016800a6a0076b00381025863cc8803b07690036a0026c0016e00172002873a9910010a680016c0016e0017200573001830018826b8f0029000191004076a0016e0027300387004893b891001920010e55001560026a0026b00b6f00673001850038809a8a1fc8f0069100392028930059500306700057300c8900c8b3c795011960020b6f003710107b001880038a1d18b0018c3af93001950019600897001066a001880018d3e78f031910849301e1267025680026a0016b0036d0066f044810028504686016880128a0058e3e58f1e99109d921d9930059400695001046800272005870018f15f0272001902cf0572008730018f00190010912600c6b0016f001720067300b8700288001890018f00b91001921009300395001087200c73006740028f003910059334c95001980010d6b0036f00873001740188a0068e0038f00e91004920a49300c9419e9500996003037300291020953bd09730027400291001920159304e940019500696334980130c71007740047500a8a0098c02a9201093009942249603a972ad980069900205720017400393001950019811e0773001740349300b9500196041981e09908e087400e750a09401e9602b97135980be9925c9a0cb06940019700598011990fc9a31d9b3e802003e80100404013e40203b070080800307023ad0302906003080240a0010b0011000107033b608006090010b0020d001100041300104043e70e001140011c0020404001053e80f001190010403002063e50900d1000106073e0080520a0010b002120022e005080300508369090010b0091002412006140032f0030903002093d90b0010c0011007011001130131400131001060a3e50b01712014160012500326003050b3c21400f1700134002390010a0c3e5130021400415001190021c0062d001340033500339001070d3e50e001170011800b31001490024b001050e3e61a00332001390024f001070f3e7190011a0031b00e330023e00151001051032511001140011c0014900205100291139e13005150022400406123cc140051700134007380015800105133cc14005190011c0023700503143a31c001340040b0c001153da1c0121d0011f002280052d001370013b0013f0034200106163e61700418001290072a0013c00103173de190011a00108183dc1e00638002390013c00b430034b0026500107193e11a0011e00137002390024400158002071a3d9200032b0013c0013d0024d0016c002091a0011b3da1f001210022c0063e0034e002530026a001041c37d1d0013f0014f00105150021d3d5360014000157002031e3cb3900141002021f3e14200103203e1470016c001061f001213df48002540015a0046800104223e62300155001720020721005233e63e0015400a56003680026b0010511048243e4280095200177003060a001253e526173310013200135001081401b1c0012627227005340013509a390013a0010c0c001130011400115002190011e001273de350163b0033f0034100147001071500727005283d93f017400014200647001091600117001293e12a07a4301a450014d0017c0097d006091a0042a36d2b0053c003450064d002630017d00180001072b3dd460024d0014f00153001660017e002092b0042c3e242002470034800153001550016e0017f00106140042d3e63400c350a23603d3d002042e3e32f05c3000c38003070d0022f3883001138002490134b0044f00107170021e001303c837001390014b001510010c2f00130001313e4320043a0a6430014905c4a0014b01e4f00151002650010830001323df370013b0023d00c4b0024f00151002061a001333e63b0013d0023e05a5400101343c905140021c013340023528145001091c0341d00320001350103631b3f00540001570025900102373de39001071e00c30001383e03c00d4b004620066500a01393d80e1e001390023a33d3b0013c001410014b0184c0014f0065100552001580276500869001071f002320013b3db3d006470014f00359001071e001390013c3c05100562004640016500204200013d3c8650016a00104210023e3835a00f6800203350013f3b9570050b1d002280013608e3f0054039a420024800157026590015a0015b00104413e158016650016c00102423d959007091e0013c001433ca450194b00d510015c0026300c650160f1e005390023c0013d003443e64500a46007530065804c5d002640016500d66001670016c002083c001453b94b0014f001510015f002690016c0010a463cf470024f0025300158001590086400168003690016c0020745001473d6530015500159002690016c001093e00646002483d0590025a04d680026b0036f00170002063a001493434a0044b0334c001510041031001320013a002490324a3a64b00e4c1914f00251003520025c02b5f00166001770027800382003044b3544f004510036501d0c320013b0014c2324f01150002520515d0035f00565002660066e001870010c1a0014d3d851001530045800163001640016502a66004690016c0068100117200013b0013d0024e3e4510075207753006540216400265002660226703f68002690016a0016c0096d0016e00a7f00282001830018400185003034f3b7500015f001053b003503df520026c0016d00203513b7690026c00206200013d00152306660016a002840020651001533c2690016c0046e001720060c5200e5300154347670016801a6a0016b0016c0036d0086f006880018f0030a2200153002553df560026c004700017200a730018d0028f0020b54071563d967005680046b0126f02c7100174001850028f02392002081c0051d0013f00540002571fc590015b0026000105510015834465004690026c00301593cb055a2ef610016e0016f001700010b1d00b360013f00240049571b75a0045b2685e0016000161005760030c3a0014100245002470014b00151001580015c3a55d00a63002650076601f10410014400146008470034f00450001580145d3765f0016500266004690036a0046c0046d0046e0010c1f001420034600548001590055d0055e3d760004680026a00a6b0086d0010845001470034f0015f39760003690016e00379001074f003590015a001603af6c0026e0017a0030c480135a0916001061339680036a0016b00a6f001700127100a7a0037b003053c00251002623db630016500b0a3c005470015100562003633d1640056500e690096c00282001073d00151001643c868001690106a0016c002036532b690016c005103d00151001520015300263001640026500466340690026a0026c0156d00b6e0017200182001870011022001460015300554003673676800a690036a01d6c00e6d0016e00a6f00372001870028f005910010364001683828f00102693986c00106690016a3736c00372007730028f0010967001680086b3786c0016f0097000172001730018f007036c3736d0017200207680016a0096c0016d3896e001860048700105690026e34c7200889002910010b5500156001680026b0016f2ea700047200273002880028f006910010855001703c07200a73002740058b00393001950010c560045a0016b0236f04e713b573001740108a0048c0048f0039200395001026e001723790455001733a37400195002037000374348750050b23001560026f002710167401a7536b920019400396001970029900106570065a0015b17d61005763e57b006094a03d4c020520025c00a66001773e37823e82002840100b4c003520025c00c5d0055f0436600d6e001781a77903a8200c841220b500025d0595f00160003660016d0046e002793a38400187001890070a500035e0106001a6d0027900a7a3cf880018900b8a0028b0010a60003610a470001710017a0137b3de890018a0038b0228c01904630017c3df7d2fb8005b064d00b650047d0e67e00b80272900120e51001580016400865005660196d0027e3da8001c810fb8200283018870028800290006124e002530015f0036602a670026a0026d0046e00b7e0017f3e581004820138303c84086851458700c8800489002076400165002690036c001800fe83001900e7122b0015300364005650026700168003690016a0086c0056d0056e001812c683001860068700288024900029100b05630046400269011823be870180d5300164001690036a0016c0056e009820018338d87008880088f0049006d91013095200166003690016a0016d0066e0218422a870048900c1653001550016701d6800e6a01b6b0026c0026d02a6e03b6f00c810048300384002852568600b870158808c8901c8a0048f0279103092039086800b6a0066b0078101e863c5880328a001930010747001690056a0016e00172001873868f0010c550016a0016c0036e00172009730028300187001882108f0059000191009086a0036d0016e004730058700f8939591001950041556003680026b0116f0087000673007740018500987001880c0890058a2348b0028e0018f00791002920319300b94001950069600108700027300b8900b8a0028b3bc8c0019501c960050b6f0017100e7b001880028a1908c3a392003930019400395002960050b550016a0016c0016e0016f001880068d3e58e0018f0479108f930290f6701a680026a0016b0066f0478503f8600e880188a0078e3e48f1a79107792196930029400d06640016800172004870018f15891002019026508690016c00172016730058f0019001491252950020e6a0036e0016f002720057300c740038700188001890028f01591005920ef93010950030c6c00170001720147300674005880018d0018f002910079330f9500596002106b0036f00e730047402a75001880028a00a8e0018f00f91001920d39301c941c19500c96006980060573004890019102393001953910e710017300474004890018b0048f0049201293060940029500d9630e9700198017990010e71002740057500d8a0038c0278e0019200b93007942029500496054972cc980119900a04740019300295002980f8087402b9300a94001950029605498218990c29a0010a740077506a910019400e9601e97111980a6992999a1239b006059700898004990819a2c49b3e202003e80101604013d2020580700b0800709023900305f0600c0803d0a0040b0031000612004170010a0337b040020800b090040b00b0d0041000a13003140021800109043e40b0050c0030e0021300114002190011c0023400106053e80e0010f0091700119002210010703001063dc0901d1000313001250052600208073dd080520a0010b00312004160012e00f2f001090300b083450b008100381200813003140042f003340020e0300108001093c60b0010c005100a011003130281400327001310023500137001390010a0a3e20b0241202a16002170012500d260062f00130001340020d040010b3a112001130021401a170021c001260023000134003350013700239001090c3dc130031400b15001190061c0082d00235002390020a0d3de0e0021800c2f002310053a0024900e4b0064f00151002080e3e317001190011a00e3000132003370024f004090f3dd190041a0021b0111f003200012b0013300a3d001060300104001102bc1100414004490030b0900110041113b71300f1400115009190012400f490014a0024c0020a0b004123ab130011400d17002180041e001300013400c380020c080011339a14013170011c0041e0022000127002300013700d3b00141001080c001143721c00d1e00227004340043500165001110c0031300314001153d0190021c01d1d0051f005270012800e3500236004370013b0033f00342002500010a12001163e41700c18007290112a001380023c001430016200106173cb1a0011e0012a00439002450010e17005183cb190011a0011e0133700138001390023c00c430054b0055100163001650050c193ce1a0011e0041f00320001370053900244003530015800264001650020b1a3ca1e002200062b006390013c0013d0024d003530026c0036e001110f0011a0011b3d21f00420003210082c00f3b0013e00642001470024e004550016400166001690016e00104150011c3261d0033f00809150011c0011d3c81f0012000121001360034000a5700206190021e398390023c0034100358001071e0011f3cf370024200446001590026900104203cd3d002470096c0010b1f002213c922001330013e007480095200153001540055a0106b00108223e6230065100155002690016f0017000172007081b0012100c233e15400d560066b0026f0038f001041102a243d92800e77006060a001253d62611e3100132001350010d1200113001140191700118001190021e001262be2700c34007350a8390023a0020f130051400215005190021c0021e00126002273c3280012d00135020370033b0073f0055f00109150071f00227010283ca360013f023400034200b470010b1600117001293d72a08343034450034d003630037c01d7d012800020e170011a0061e0012a35f2b0033c00244002450134d0086300365002690017d001800030c190012b3da4400145003460044d002530076400366001690016c0017e0020d1b0042b0032c3d93b005420044600147002480065300255001660017f0058400107140031e0012d3e5340113509c360313d002062e3d92f0543000b3800439001620010b0d006180021a0012f38c3002c38009390023c002490244b0075100109190011e002303a537003380013900b4b00151001650010d2f00130001313de3200a380013a0a543001490704a0034b0184c0024f002510030b1a00120001323d6390033b0023d0144b0044f005510015200165002091a001333dd3d0063e05c460014e00351001540016400104140011c006343b43c001051c02534004352643f005450010a140011c0511d0072700135015363153f013460014f0015700202373c4390030b180021e00930006383d3390033a0013c0144b00351003620066500e051e001393b73c0014f0036c002131a0011e0133200137001390083a33a3b0013d0014100545001470014b0154f0065100b58034640016500a690036c0010d1e0011f004370023b3cb3d00441001460034f00358001590036400168001690010b1e001390013c3ab4f0015100458001620026300365006690016c001093b0013d3b25100158001590035a001690016c00572001092000121009220013d0013e37146005530015a01c68009061c003350033f38a4000457005590010b1d00628001360983f00840374420015703a5a0015b0025e00161001091e001390013b001413db4f0015802b5d001690026c001091f0013b00341001423ca460015900a5e002680026a0010c1e0022a0012b00138001433ac440014502d4b00f4d0015c002630236501c121e005390013d009443e14500f460104d0054f0015300a580555d004640096501d67001690016c0027e00183001111e001200023c0023d0014537e46001470024b0014f0045100253001580016400265002690096c00b90001121e001200023d001463a94f001500015300658001590135e001640026800c690016a0046c0066d0016f001720010545001473c659001690026e001130f0013e00c46006483ae530015400255002590035a07e5d0016000361002680096a0026b0096e0046f001700018f001074931d4a0054b0334c0014f001510045c0010e31002320023a003490254a3a84b00c4c1624f001520025c03e660017700d780058200309200014b3494f003510075800a5c0015f001650396c00218320013b00145001470014b0064c25e4f027500105100752073580015c0015d0065f01065004660196a0016c0016d00178002790018200384002870010b4d3bf4f002510055300963001640046502e6a0016c0097e008810031a3d003460014e3df4f0045100c5208c53010540375d0026400d65003660276704468002690016a0036c0066d0016e00e7f00681001820048300784001850028700206470014f38650002650016c0046d0010a200013b0034f001503ce52015540015d0036a0016d003790030451388690066c0018200408522a9660016a0066c0046d0016e0027200284003085338764001690046a0016c0086e0027200883002154f0015201c530015432955002640016700c680236a0056b0076c0036d00a6e0056f00a7000172002730018500386001880018f0080c53003553cd560036a0026e0036f003700047200c73003740028d0098f0050f3e002480025406a563cc67006680096b0226f0377100274002850018e0088f0359100292001091c0071d002360013f00a5721c590015a0015b00760001054f004583016500c690016c0070446002593b05e0026c0010748001590015a2a8600016e0016f002700010c1d009360013f00140063571885a0035b2d65e001600016100e7600d7a001163a00141001430014500a470024b0034c0024d0024f00152002580025c3925d0155f00763002650056603b6c00178001820058400187001161e001410014600b470014f0075000251001530015801d590015d3675e0015f00360001640016500266007690076a0046c0116d0098700110420074600747001590035d0085e3c76000668008690016a00d6b0086d0036e001700017a002880010a2000245003470055d0025f37260004690066e00879002870010e4600147001480014f00159006603936a0016e0016f00370002730017a00b87003880021148027560015a08f5e002600166135b680026b00c6f0057001e7100f7a0027b01088001890028a0018b005053c0075100b623da65011690020b3c0094d0015100a62003633b2640056501a66003690136c002820010920001530056439465001680026901772002870018f0020451001652c0690036c006113d0014d001510045200253009630016400465004662f6690066a0056c0226d0136e0068100282002870061520001470015300754003550036400366002673396801f690046a0386b0036c00d6d0076e00c6f0077200173001870038f00f9100203683346a003730010520001510016933f6a001720030e5300168003690036a3236c0046d0016e0036f0017200c7300187001880018f001910010d4600154001670016800e6a0046b33f6f01d70002720017400185001870018f006036c31e6d001720070b66001680046a0096d36a6e0016f00173004860038700888002950010a690026a0026c0016e2ef700037201a730078900290001910021354001550025900167001680056a0066c0016d0026f2a17000571001720037300874002880058a0028f00691002930020b550056e0016f0037039b71003720137300774012890028b00a93002125600b5a00161001680016b03a6f05b70001713a17300274025750078a00e8c00a8f00592003930019500296003027231573003076e0017333e74001870019100193001950080a550016f001700057200473003742e27500a9300696002980030d23001560037101b74023753698c0019200394009950019600197004980029900105570015b10961004763da7b007074a0364c01a520045c00a773d5781f9840120f470014c007520035c0095d0065f055660116c0016d0016e001781e77904a820098412b890010c500015d04b60004660026d0026e006793867a001830018400d870018900d0c500035e017600276d002790127a3b9850018600288002890148a0028b0020a60003610777000171001760017a01e7b3d08a0058b0258c01704620017c3cb7d2b08002908450024d00d65002690017d1257e017802979000f1320001450014d001530025f00263002640066500a660236c0017e3c580036810ee82004830308700188003900119100111470014e0025f00266021670016d0026e0117e0017f3dc810088201683043840998512487009880068900108530026400565003690046c006800ea830019011f174d00153006580016400665001660026700968003690036a00a6c0116d00e6e0037200180003812b48300a86010870018802d8900190009910100a47001510016300364002660016901a6c0016e005823a9870211053001550016400a68001690096c0036e00972001820018335b8700b880088d0038f002900859101c0c47001550016600568001690016a0026d0076e03082002841f78700f8901c1e5300154001550035f001660066702d680086a01b6b0026c0056d0226e0436f01a70001730017f0018100a8300284004852688601187022880a4890308a00a8e0028f0469104b9203d930010a67001680076a0096b0048102c863ae87001880388f001910020a64001690096a0016c0036e0057200773002820038733391001125500268001690026a0026c0036d0016e0056f0037200573004830018600187001881c18f0089000491018950010c680016a0076e006700057300884002870258934d8b0019100292005950051a540015500156004680036a0026b0126d0036f009700057100273009740018500886003880da8900a8a25f8b0018d0018e0038f007910069204b9300f9500c960020a700057300c87001890178a0028b3aa8c00391001950239600c0d6b0016f00171012740017b001880038a14f8c39892004940079500396007970020c670026f0017200173001880038d3d88e0028f0489000191085920019302710540016701c6a0026b0086f030810028504c8600f880158a0078e3d08f11f9105c92152930039401206680046a00172007730018f17e950010272002901fb0e59001640016a0016c0026e0017201f7300983001870028f004900199120c93003950071253001680026a0026c0016e0086f0047200a7302c740028700488003890028f02691013920fb9301a9500d960020d7000172020730147400e880028d0038f0069101092003932c1950099600498007126b0026f012730057404875002880038a00b8e0098f01191005920dd93025941f69500a9601597005980049900106890028f0019102a930039535298001117000272001730097400a880018b0058c0018f00191002920189307f9400695025962c3970059803b990010e7100274008750108a0048c02a9200993009941b09500296067972b598012990149a00107720027300374001910019300595002980e30d74034750048b0018f001920019300f94005950019606f9700798230990f09a00a09740037505894015960199711c98075992a89a1b69b0060498002990399a2279b3e204003e801030020040700306013b702097070280800b0a002120010d010010234d03098060200805b090070a0140b0071000d1200513001170012f0010f033460400908009090130b0150d00d0e0011001f110011300414008170011800137001490010a043dd0b0060c0190e00b130051400215001190021c0081e00105053e80e0030f020190031b0040803002063c8080010902a1000413001250102600507073bd080470a00c0b002120052e0232f0050e030070832f090030b0160c002100411200e1300214006170012f00e3000334001370011008002093980c002100bf110061303d140061c0023100e3200134001370023a0014b0014c001630010e0a3c60b02f0d0080e00112035160071700118002250322600e2f00330003340013a00110040010b36f0e0011200613005140281700a180052600a30003340033500537008390063c0013d00110040010c3c2130081401115008190091a0011c00a1f003200012d00d34003350063600237006390010f0d3c40e00a1801a1e0012f0093000331013380013a003490154a0014b0085100158001650010b0e3ca170031900f1a01a300013200e37006390023d0044f005510050f0c0010f3c8190051a0051b0261f004200032200133021390013d0013e0034e003510015300108030010c001102821100a140031c003340024900c0e0900910036113c2130141500e1f0012402b270083100137003490014a00f4c0045c001100b00e1239213002140291700418004190011e001260013000234029350013700238003390073c0020f0b0020c00113365140211900a1a0011c0041e0021f00127001350013701b390033b001410020b1300314316150021c01b2700b3401435005390053f0014b0024f001140c0051300b14006153c2190011c0431d00e1f00c2100127009280223600a370033b00b3f00941002460014f002520015f0010d163e117021180162902a2a00b300013800b390013c003430034b001620027c0010b173a2180051a0031e0022a00e300013200137001390053c00245001141700c1839f1a0091e0283700138007390033a0043c0204300c450024b00b4d00151005580026200163001650127d001800011517001193ac1a0091e0081f0072b0023700b390093b0043d0034400b45001460024f0025100153002580015d00163001650036c00110190021a3a71e0022000d2b011390023c0023d0024d0094f00253002580016400265003660016c0071a190021a0011b3bc1f01c2000721011220032b0012c029330013b0033d0023e00f46002470044e00d50002520055300354001550025900166002670016c0026e001051c2cb1d009350013f00e4f00209150031d3af1f0042d0013600e40015500015700a5e001091e3651f00120001390043c0024100c450014f0015100309190011f38c3b00341001420114600150001590056c00106203ab47011510046c0036d0016e001131f008213b4220013d0013e010460024800f4e001500015400d55001590015a01567001680046a0026b0026e001700010a20001223d92300b5300154001550126c0016f00272006730020921016233d95401a56012680026b0066f008740018f0040711015243bd280154c0027700a780058400106253a6260fc29001310043a001430010b1200213002140261a0011e001262cd2701434008350d8390023a004130c0011300615005190011b0011c0011f00226001273b13504036006370013a0013b00e3f0114100545002470025d001091500427005283b0360013b0023f01b4000a420136e00108293bd2a0a44304745006630047c05a7d03180002111a0051e0032a3262b0173c00543001440024501d460014d00753003620016400165004690027d0078000b112a0012b3b94500746007470084d002530055800163001640026500166001690016d0017e00a81002820010f1b001210012b0042c3bf3b0014200546001470034800a5f001660027f0098400785001870020a140061c002270012d3d53400c35090360393d0034400146001052e3c52f05b3001038004390010f0d00c0e001180012f36c300353100138014390043a0013c002490274b00f4f00151001620020e0e00117001180011a0023038e37005390153c0024b0044f0025100258001650056c0010d0d0032f001313bd320133a0943d00143002490a24a0044b0264c0014f002510020f1900120001323bc3300137002390023b00a3d015440014b0084c0014f0085100552003650030a333c4390013d00f3e04e44001460014e00753001540016400104140031c007343833500208140011c03f3400935207360023b0013f00b4f0010e150011c04b1d0121f001200012d0053501f363093f02e40003460024f0015700f5900106190011e001373933900a4f0015d0010c17002180051e02930004383b7390073c025430024b00f510016202065013071e002370023936b3c0034f002650016c0011a190031e00d200013200138001390103a33c3b0053c0043d005410054400145001470034b0244c0024f00e51010580535c0015f00163001640016501e690016c00113190021a0011f00c20002370033a0013b3a23d0094100242002470014f00f50002520015900968006690026a0016c0040e1a0011e00320002390053c3703d0014f002510146200563001640016500e690026c0010e190012000532001390013c0023d38446002470014f003510065300259002650036c009111f001200042100833001390013d0013e35d4600c470045300154005590035a02567002680096a0016e001091c00b35005360033f3474000c4500157014590045f0010f1c0011d00928001360803f00c4036642003460014700148002570755a0015b0015e00160001093a0023b002413c3420014f0015802b590026500169004081f0043b00841001423a8470015900a5e00569001141e0042a004380023c0024338c44002450434b0104d0074f00151002580015c0056302e640016501766001690027d00280003141e001390043c0043d009443d345018460204d00c4f002510015300e5806e5d00663001640106501e66001690026c006810020e200064533c470034f00a510065300258001590015f0056300264004650066900f6c0071820003220013d0043e00246371470024f003500015300a58001590135d0016000264005660016700168014690036a0016c00b6d0016e002720018700110200013d00445003473904f00155001590055f0016000464001690066a0056b0016c0026e0017200117210023e01646009470034839c530015400555005590055a09d5e00260001610036800b6a0046b0136e0026f006700057200187001880018f003093a00145001492ce4a0084b0464c0014f002510095c0011031004320043a0024902e4a3ab4b00a4c1554f0015c0485f0016300165001660037703578019820020e1e00120001390013c002410014b2f44f0165100a520015800f5c0055d0016503b6c002190e00120002320033b0013d0034500146001470014b0074c2704f03b500225100c5209c530025c0095d00d5f01565005660256c001780107900482005840060e440014d3b0510075300d6300364005650376600a690016c0117e00b8000181003830031f1f0013d00745001470014e3cc4f004500035100452097530185404855001600016400365007660266706d68006690016a0016c0036d0066e00a7e0017f02b81004820028300f8400a8500f8700107200014f3215000951001590015f0076c0060e1f0013b0043d00246001503a552014590015d00b5e001600016a0046d00a7900c87001075133c6200165001690056c0068200e870011222001510015226a53001590016400166002680016a0056c0046d0036e00b7200182001830018400687001890010e2000151003533545500464003690076a0016c0126e0067200a830038700190002910011b2200223001500015201f53003542f35500156001640046701e6804c6a0156b00a6c0066d0146e00b6f0167000173002850078700188005890018a0018f00f91001920010f530045539f560076a0016b0016c0026e0076f0027000472008730038d0188e0018f00991002123e0024800454063563b45a0016700c680096b0286f042710067400385003860018e0158f041910019200794001091c0041d0023f018400095720e590045a0065b00e600030a1e003200023c0014f00451001582b65900365012690036c00e0f1f00120001420014600247003580015937f5e00e60002690016a0046c0026d0016e001720030b210013e0015400155002590035a24c60001610026e0016f002700040c1d0054004b48002571365a0055b3345e004600026100b760327a0017b00119180011e0013a00241002440014500f470034b0024c0084d0014f00150001510015200353001580055c36f5d01c5f028640026500866053780028200f840011b3d00141003450034601b470074f010500075300158025590055d34f5e0015f00c6000264002650026600e67001690066a0066c0176d00e6e00872001790028700491001134200f4600b4700150002590065a0015d0065e3ad6001567001680056a00f6b00d6d006730017a0018600189001910010c2000145004470075d0035f34a60006690036e00b7900b82001830018700516470064f002590085a0045d0015e0025f0046036461003680016a0036c0046e0026f00470009730017a0158700188004890018a0018b0011448029560025a0b05e0026001e6138d680016b0146f00a70025710167a0077b02b8500188001890018a0058b0078c00595001073c00651008623b8630026400165013690010d3c006450014d002510125300262004633966400565027660036901d6c0048200a0c510015300258001643706500268004690276a0016c0096e0017200487002054f0015100265245690026c00c13470014f00151008520045301059001630016400465006662c267001680016900d6a00c6c0406d02d6e01482004870101b220015100152002530075400b55002590016400666001672e86801f690056a0456b0036c0116d0076e0156f00c7000172002810028400187003880038f02c9000191001085500164003682e4690016a0046c002720028f005095300164002692f36a0016c0036d0017200487001900021447001520025300255003590016700168002690056a2d36c00c6d0026e0087000372024730088700988001890018f001910011322001540035e00167002680186a0066b2f06d0016f02b700077100372001730037400487001880028a0038f01991002054f001690026c2866d0037200d105900166002680096a00b6c0016d3136e0036f0027300281001860148700788001890018f001910020e470015100155003690056a0026d0016e2a0700037202b730178900a9000391001950011a2200154004550055a00160001680026a0076b0026d0026e0016f2357000c710027200b7300974003870018800b890018a0068d0028f0159100792002930019500216220015500e59001690016c0016e0046f0057034c710097202d7300d7402387001890028a0018b0238f0019100192001930039500696002172300154002560125a00261003680026b04a6f06f700027136b730017403575012880018a0178c01e8e0018f003920079300294007950059600402722a873009066e00372002732e2740019100295016112200223001550016f0017000f71003720037300a74285750199100192002930109400195002960079800e0b23001560046b00171024740277535d930029401496002970119900105570025b0a561004763b67b004064a0214c00d5c00e773a978183840040d470014c002520015c00d5d0065f03c6600d6e001782337904f8200d84152890010f520015d0465e0015f0016000a660046d0046e00478002793697a002830018400f87005890150e48002500025d0015e01860029610026d001790107a3b28600188003890178a0068b00608600016103f710017a0157b3b78a0068b01f8c014037c38d7d214800210b4d00953001630026400165007660017d1997e01f8025c8100290012114d00453003630036400b6500a6602467001690016a0016c0027e3b37f00180042810cf820038304890009114e0045d0025f00166017670026d0046e00c790027f3b28100a8200c8304d84076850f58700988002890050c4d00153001640086500b690036c0066e001801108100183004901469100419450014d0015300c55001580016400765003660026700e68007690026a0176c0246d0156e00380006812ca83013850018602187002880388f00190010910150b4100151001550016300b6400165001660016901f6e0068238f8702a105300364008680026900f6a0026c0026e00f7200380001833118700f880158d0048f0059009b910330e55001640026600968002690016a0036d0086e02b82003841e4870148902b91001950012047002550015d0015f0016400366003670356800c6a01c6b0056c0046d0286e0496f02372001790017f0018100d8300f84009852848601887022880b88903c8a00d8d0048e0028f03b9104992040930030c4200167003680066a00a6b00981023863838803c8a0018f00291004930040e510016400168001690106a0046d0026e00870001720067300782003872f18f001910051555001670016a0036c0086d0016e0076f005700037200d7300e83003870028817f8a0018d0018f00790005910289200193008950011155001680016a0066d0036e0077000672001730198702b88001893048a0028b0048f0029100292005950111d540015600260002670016a0026b0256d0026f0137001d7100d73008740017a0018501086007880e2890158a2658b0068c0028d0018e0048f00f9100c9205b9300d940049500e960030a700037300988002890198a00b8b3828c009930019502e960110f6f0017101174001750017b001880018a1168b0058c386920049300194005950039600b970060e6a0016c0036e0016f0018300185001880028d3b78e0048f04e9000391062920079302b1167013680016b0016d0016f02e7400181004850428600c880158a0088e3b28f0ae910309213e93002940160a5300164002680026f0017200a73002870028f15e910049200103690037200b901a30d690026a0036c0016e0027202573017870078d0018f00e90029911fe930069500816550036a0036d0016e0066f00370005720067302574018860028700488003890088d0048f04991021920ea9303394003950159600498003126c0016f002710017201f7301e7401e880018a0018b0018d0088f00a9101792009932739400295019960059800e146b0046f01a700017100673006740557500d880048a00f8e0158f00b91004920d9930419422b9501596022970069800f990020873001870018b001910299200193003952dd980011270004710017300c7401175001890018b0058c0028f00191001920199307f940149503c962999700b9806e990070d7100574006750138a0058c01e92003930049415296073972ad9801b990399a00208720037300174003930079500696003980d2990010b7402f7500c9300b9400595005960679701598229991659a0149b0020974001750329401196019970fe98034992329a2809b03204980019900d9a1529b3b404003e80103d020020700207013ab020e50300206001070230800e2e0010b02301030ca0601708096090040a0050b00d100111200616001300010d03309040080800b0900c0b0170d0080e0011002f13004140071700118001370010c043de0b00b0c00a0e005130031500117004190021c0051f001340023700109053e80e0020f01f190011a0011b0012000122001330020603009063d0090421000625008260040a073c3080640a0170b00512010170012e0142f00330001340010c0300a04001082d50b020100481202713001140072f0093000134004380010d040010938d0b0060c00e100ec11006130591400c1e00131008370023a002490010d0a3cb0b05f0e0011204716008170071800325015260042f00830002340023c002100b31d0e0011200513007140241700518006190021a0012600430005340073500237007390043c001120b0010c3c00e001130101401415005170011900d1c00d1f002270022d012340053500537004390023b0023d0010d0d3d90e0081802c1e001260012f002300083100b3a005490114b0095100363001110e3d2170061900e1a0361e0011f00120002300053200a37003390033b0013d0034b0014f00951002650010b0f3c61900c1a00c1b0291f0042000321001330153d0033e004530010910235110051300114009150021c00134001490074b00111090090c008100391139a13018140021501f1f00124011270093100135001370023b001410024a0054c0020d0b0101235d130011402d17008180041e00226001300023402638001390033c0030a0b0011334514021190081c00c1e002340013701e3900441002080c003142f2150021c0211e0012700c3401639001190c0041300614004153a0190021c05a1d0141e0011f00c200022700a2800e2d00135008360093700a390013b0063f00b4100144001470014f00150001590011012001163df17026180201e0042900f2a0073800a390013c00d430034b00162006630017c0017d0010b1738e18002190031a0052a0082b0013000137001390043c00165002141700a1837f1a0071e04e2a0013000138009390073a0033c022410014300a4b0114d0034f00151005580036200663001650081217001193a21a0071e00a1f00520003370143900e3b0023c0023d00244007460014f00353001580076400265002101a37e1e002200192b0093c0033d00546001470014d0084f00453003630016400265004690016c0091d0f003190021b3bb1f01d2000d21018220022b0022c014330023b0063d0053e00e4600947003480014e0075100152004530095400355001640036500166001670046c0016e00183001041c2791d003350023f00b0c150061c0041d3a31f0043600f3f00140009420024600157007590015e0010a1a0011e32720003390053c00441009510055300158001650010c190021f39120001370013b0043d0024200b460015800159002680016c0010820387220024700a510035300264001690016c002101b0011f00921394220013e014460034800952002530015400555002590015a01367001680026b0020a2000121001223d8230065300354001550116a0016e001720070b1b00121029233d13e0015402a56017680036a0026b00a6f00b8f0020c11043150061f001243d727001280232c00136001420014c0017700478002080a001253cb261c12a0013100832003350013a001110c001120011300114038170011900226217270142a0013400e35121370023a0013d001440014d001580020e13009150011c004260022739e2d00135046360053700a390013b00e3f00e41001590010b150111c00127014283b3360033b0013f02c400084100142025590010b170011e001293d72a10d43061450084d0046300c7c0247d01a8000210170011a00b1e0022a2c62b01b3c00544005450224d00d5300563001640036500c7d0027e00280006161a001200012b3b3390013b0023e00142001440014500746016470034d0024f0015300c6400a6500366004690077e009800018100383001121b0012b00a2c3ce3b0024200c460064700e48005530015500766001670016d0016e0047f0088400285005870010c130011400b1c0012d3d234018350983602d390013b0013d0044100144002062e3d32f06c3001038008390013c003100d0070e00217001180051e0012f365300573200138019390043c0014902c4b00c5100362002650010a17003190023035d37008390173c0013d0014b0014f001650010f2f00130002313bc320243a1133d00343002450024909c4b0504c0014f0045100b630026500111170011a00130001323b237005390073b0143d02a44001450014b0034c0014d0014f00d51008530016500309333ce390013d0113e0454e0015100253004680016c001031c00434368350010a140031c0431e0012000134006351a9360013f0094f0015800114140011c0791d0071f001200012d00235024362db390013b0023f030400034100242002460044700150001570065800159004023737b390040f180081a0011e0293000634001383af390023a0013c0324b00c5100558001620116300165018051e002393604f004650016c002160e001190011a0011e0181f00138001390173a2bb3b0043c0033d0094101445003470014b0234f00951016520015806365013660028200113190021f00c37002390023b3943d0114100444001470014f00b500045100153001580025900664001680036c0016d001091e00320001390043c3494f002510116200f64002650070d1e00120006390013d352470024f00151003580025900164001650026a0016c005131f0012000621007220033b0013d0033e35e460094800152001530045400655003590045a01867001680096a0018f001081c00235008360023f321400054700157005590070f150011d00b280042c001360ba3f012403304200846001480025703c590015b0025e006600010a190021e00139002413b34f001580345900565001690066c001063b0084238d4600247001590175e003131e0052a00338002390023a0013c00543373440044506d4b0124d00f51004580045c001620016304b650356600290001191a0021e007200042b001390043c0033d00e41001443ca4501e460134d00d4f002510075301e5807c590015d00364013650266600168001690016c0036d001151e001200033c0023d00143001452ea460014700c4f00a5100b53007580035f004620016300364005650036900a6c00a6e0019000119200053b0013d0023e002440024500246345470024f0075300c54001550015901c5d002600026400965001670016800e690036a0086c00c6e00173001870010b3d00245002473774f005530025500259001690096a0026c0046e00219210062c0023d0013e01842002460154700348383540025500956001590045a0695e0026000161002670016800f6a0076b0136e0036f00570002880018f00108492b74a0044b0764c0024f002510095c001820010f31010320023a009490504a3994b01c4c20051005520035c0925f0026600377017780068200a122000232001390023c003450024b28e4c0014f01e5100f520015801a5c00562001630026504866004690016c0071a20003320013b0043d0024100145002470014b0064c1aa4d0014f0585002f51015520f85c0055d0185f013650056602f690026e00278004790018200784004870010e3d0014b0014d3854f001510065301b58007630046400a65061660076c01e7e00b800011d1a001330013d004460014e3da4f0075000251011520a053022540516400b65003660346709468005690036a0056c0156d0046e0116f0017f0118100482004830028400185005870050a20001390014f2da50002510015f009690016c0066e001720010e1f0013b002460014f0015039f52009540015d008680016a0036d00f6e003790038700107512f153001690056c0017200182006870010c50001510025221655001660036a0086c0066d0026e00d8400387002890010c2000151001532d8550016400165001690046a0046c0126e0037200e830061652015530065429a5500167018680646a0116b0096c0026d01c6e0076f00b72002730018100185004860018700588003890018f00e910010d53007540015538a560066a0026e0016f002700027200e730018d00d8f00c9100214230043e001540a8550015638f6700a680186a0026b0306d0036f04e710017300274003850028c0018e0078f0519100192005081c0091d0023f02840005571aa590015a0035b0050a200013d002470014f0075100258251590016500f690066c0130a470074f00158002593765e00360002690036a0016c005720030f210013e00147001480015400155001590025a25f6000161005680026e0026f00470002720010f1d01a360023f0034009a4800250001571ea5a00a5b28d5e003600016101176009790017a006182b0023a0034100243004440024503046001470064b0034c0044d0014f002510025800a5c3155d0255f0296300d6500b6606a6900578001820118700218200013d0014100444002450024601d470054f027500045200158036590075d2f45f00960004640026500366008690066a00b6c01d6d00e6e00687001171f0024200f4601947003480025000154001590035d0065e3aa6001167001680086a0206b00b6d0096e0017300184001860028700288001890010d2000545001470105d0025f2f96000c690086c0016e00879004820028300187003132c0014700a4f001590055a0025d0025e0015f00360345680016a0046c0016e00a70003720017a00b8700388003890011621003470024804c540015500156002590015a0e35e0056001c613236a0026b0196f00c7002d710177a0077b01088002890028a0048b006053c00b5100b623b1630016500b0c3c0093d0014f0015101a530016200663353640086501c6902d6c006820020b51002530036435965001680026903b6a0016c00b6e00172003870010451008651fb690026c012114d00351003520015301d5800163003640046500e662426900d6a00e6c0496d02a6e01272003820058700f1c20001220023e0014600152001530105400a550036400665001660036729f6802e690086a04a6b0036c0176d0046e0156f00b70001720018100187005880018f01f900019100105540016827d6a0016c002720020651002692b06a0016c003720058700110460015200153003550046400168004690046a2756c0056d0036e007700087201d73006870059100115460015400355002560015900167004680216a0086b2a86c0016d0016e0016f011700097100172002730068a0018f00e920019300104660016c22d6d0017200c0b3d00167002680076a00e6d2c06e00272002730058600a87008910010a5200155006690026e2137000372032730128700389003910011822002540025500756002680066a0086b0036c0016d0016e0016f2087000e720117300e7400587001880078a0038d0028e0028f0149100292002930011022001550096b0036e0076f0047032c7100272037730157401b890018b0128f00192001930019500616230014800154003560225a00261001680036b0726f09f700037135b730017403475008890018a00f8c00b8f00691001920069400295001027220c73009056e003720047327f910029500b1122002550036d0016e0016f0017000c710027200b7300c74258750088f001920019300c9500296003980020d2300c5600e6f0017103d7403b753048f00192003940119500196006970059800107570065a0015b15461004763df7a0057b020074a0464c02d520035c01b773cd7827c840150e4c00550001520085c01a5d0175f092660216e0027815f7906e8201e841bd8700189002102c001460015d0845e0015f0016000b660046a0056d00f6e003793597a0038400e8700888004890241148001500085e0246004c610016d00b6f00170002790187a38d85002860028700288002890278a0098b0040a60005610a770002710037a03b7b3b7890018a0118b0458c01605290027c3c37d2fd7e003800540b450014d01d530036300265010690017d0ce7e034802c98300390018192b00144001470024d00453004580035d004630066400c6500f6604167002690086c0026d0056e0027e3928002f8115b820058305a87001880019002d91001144e0065f00566039670096a0016c0016d0056e01a7e0077f3cf810128202883082840af85139870128800a890058d002910030a53007630016400465004690086c00a8008f83006870019017e1b4d0015301958001590015d0016400f65003660026700e68007690096a0196c0376d01c6e0086f001720027e002800038124b830098601587006880338f002900189101a0b410014700163013640026500366003690306c0016e00282364870401347001510015300455002640066500168002690076a0016c00c6e02172005832df870098800e8d0078f002900ac910300b470016600a680016a0086c0016d00c6e042820018414b87025890301e530015400155001560015e00166003670426801c690016a0306b0036c0066d04a6e07a6f022730028100c8300d84002852388601f87047880eb890458a0068d0038e0018f044910669203a0a4600167002680126a0106b00e810198637e880388d001910020d640016900b6a0056c0016d0016e0097200d73001820018727b890018f0019000113680036a0056c00a6d0036e00f6f002700027201e730108300186002870018810c890018f006900069102f930029500110550016a0146d0026e00f7000a7200373013840018500187044892d58b0068f00191002920019500f1f550035600360001680056a0016b0276d0036e0066f01c70019710037200173019740017500185017860068700188125890148a1fb8b0028d0028e00b8f0139100d92075930169400195012960010b60001700177300e87002890288a0058b3688c002920029503f960090e6f0037101d7b001880028a18d8b0068c3668e0019200d93001940039500496005970011164001670026b0016f00472001730018300185001880028d3b48e0068f03f90008910959200393019950011467024680016a0016b0066d0046f03774001810028504b8601c880248a0058d0018e3ac8f12091049920e79300394009950010b5500164001680036c0017200a73004870028f105900019100393001036c0017200690136115300168001690046c0016e00270001720577301883001870028d0028f006900189119292001930029500d1555003680036a0016d0026e0086f002700057200d7303e7400d850018700288001890028d0078f0349101c920849301a940019501c116e0016f0017000372040730327401075001870018d00c8f00891029920089324c9400295025960069800117560026b0096f02070001710027300e7406e7500b86003880078a0128c0018e01e8f02191008921629304f94168950309601b9700198005990010770001730058700191025930019526f98001115500170008710017301574019750018b0108c0018f0059100192024930b49400995066961ff970029803e106b0017100d7400f750118a00c8b0018c05c8e002920189300e9420e95004960a0971e4980199900e0872001730037400291001930089500796003980a90e7000173002740437500f8a0019200193021940089500e960bf9700798248990c29a0040974004750a69403e9604e971ed98094992b69a1619b009059700798002990619a2839b3df04003e801093020040700a0a013540212d030030705d080180a0070b00212003180012e0041201001022b70314c0400206047080db0900a0a00d0b0260d0041002b12006130011600317001180012500230001130327e0401508018090230b0340c0030d01d100511100112004130141400617002180041e0023000331001370023a0011003001043ce0b00a0c0320e01513008140051500117005190081a0011c00b1e0031f0012d001370010c04003053e80e0080f05617003190041a0041b0051f00322001330033d0010903007063a10907c0c003100051100119001250192600f0c073810804f0a0370b0041000112013160012e03a2f006300013400138001130301008289090030b0450d0011005e12035130071401017004180011a0012f01930008340113800139002490034b001170300308004093300b0030c01a100fd11008130931402815001190021a0011c00126001270023101b340023500437008390013a00a490054b001110a39d0b0640d00a1208614001160111700e1800b25051260162f013300063100232001340083800639001150b2bc0e0041201113012140431701b18005190051a0031c0021e0022600e270023000d320013401135004370103900b3a00251001140b0010c36e13015140241500917003190231a0011c0121e0031f005270042d02f3400a35005360013700c390043b00144001100d3a40e00d180421a0021e0022f00c3000d3102d32007380023a00d3c003490334a0024b01651002140e3b11700d1902b1a0531e0031f0042000330004320153700d3900d3b0033c0013d003440014b0014f0065100152001690010f0e0010f38c170011901a1a00d1b04c1f00a2000e2200233039390033d0043e00552001530030f080010c002101cd1100b1300514018150011c0041e00134004350013f0014900d4a0014b0061c0900c0c00a1003d113a713029140011503b190011c0011e0012404227015280062c00131001350023600137003390013a0013b00141004490024a0134c00b5c0056300166001100b013122f513008140481700c1800c1e00526002300073405235001370033800d390083c0024b0011a0b0010c0021000112001132b11403a1500217001190111c0181e0051f00220002270062d00430002340053500837043390053a0073b0023d001410044f00158002160b0010c001130021424415001190011c0321e00426001270182b0013402c350053600137002390073b0013c0013f0034f00559001650011b0c01613010140081537c1900f1c0821d02f1f02720002270122802e2d004350073601637011390033a0013b00d3d0013f0204100642003470024f00350001580025900212163d31703e1803d1e005290362a0153000138023390023c0114300e450024b0034d00262019690017c00580001120e0011733718003190061a0041e0092a0143000137001390073c0043d001450034d0034f00151002650027d0011e0d0010e002120021701718329190041a0161e0802a002300063800b390153a0053c04d3d00243016440024500a4b0104d0034f0035100e580045c001620086300965016660017d001800011a17002193191a0181e0111f01c200072b00230002370333901c3b0063d00e4401d460024b0014d0024f00a5100253003580085d001620016400865003690016c0031c0e001190021a31a1e0011f0022003f220012b01d33001390023b0013c0093d00a46001470054d01f4f00351004530095800464001650146600167001690016c00b7e00183001270f006190071a0021b38c1e0011f042200192102f2200a2b0042c03c330033b0093d00e3e038420014600b47007480014d0014e0214f003500045200553009540075500558001590015a00164004660036700a690016c0016e0027f001840018700209140011c1f21d009360013b0013f01e40001470015700210150081c0021d36f1f00b210032d0013601f3f00140021420034600150008570135b0015d0015e0060e180011e29a1f0012000637002390033c0073d001410114d0014f0025100c580045900114190041f2fc200022100122001370053b0063d002410014201146005470014f0015000152002580015900d5d0015e0016a0010e1a0012030a220063d001470185100253005550055a001690016a0016c0056f001720011c1b0051f016200012134e22004230023b0013d0013e02b46005470014801e4f0015000452004530015400e5500556001590055a02d670026800d6a0056b0056d0016e0016f0020c20006223b6230114e00153003540055503a6b0016f002700027200b730020e2104722001233c8330013e00754057560255a00167002680076a0026b0136f0068f005071102c15003243a62802d4c0017701678005092537c26160290013100632001350013a004430014d001140c00112004130021403e18002262512701c2b0022d00432002340193511537002390013a0063d00343001440014500158001190c0021300f140041500a190031c0061f0022736c280022b0012d005340013506936011370073b0193d0013f0154100c4400145001470014f00158001590010d1500b1d0012700e2837a2c001360043b0033f0234000c4203a480015e001840010b293b02a1054309944001450084d0055c0016300d7c06f7d0418000e1618001190021a0161e0072a2ae2b0283c004430044400c450454b0014d02a5300662002630086400565009690026c0017d0127e0038001c1b1a001200022a0032b37f33001370013b0083d001440084501146022470074d0015301b580035d0025f0016400e6500166002690087e0118000181007820018300390001151b006210022b00f2c3a43b00342016460064700b4800c530015500566003670026e0026f0017f01682001830038400185007870040a1400c1c001270022d39d3401035083360203d0034100144003042e3a92f0543001838003130d0160e003180051e0022e0012f352300753100637001380253900a3a0013c005490444b0104f001510036200165001100e0011700118001190031a0031e00430306370173902b3c0063d0024b00c4f006510025800365007102f0033137a3203438001390013a0d54300345001490954a0064b04a4c005510045c0016300465001161a0051e0011f0012b00130001323873300137008390103b0173c0023d03b44002460014b00b4c0014d0034f0115100b5200264001650040c1a00220001333a0390013d0143e044460014e0055100253006540026400103140021c002342ee0e140041c06820001270032d001340113518b390013c0013d0013f0154f00458002590021614001150011c0711d0221f002200012d0083502d362ca3b0013f04a4000242002440024600647001480014f003500025700859007600010619004372df3900d3b0014f0026900115170021800e1a0031e03c290013000c34001383793901e3a0023c04444001450014b01a4d0024f00151009580076203d63001650250f1e001200013300137006392b43c0043d0024f00951002580016200165002690016c002720011e1700118002190031a0021e03120001300013200137002390283a2c83b00a3c0043d01a4102b4300244001450054b0394d0014f01b5101e5807c5c001630036503066002690046c0038200118190061f0182000437009390043a0013b3463d0283e001410054200446001470054f01b500095100153003580065901464002690056a0016c006720010e1a0011e00120002390073c2e7450014f0025102458004620156300365006690076c002102000e390053c0013d2da470054f0055101352003530045800159011640036500666001690036c00c1c1f003200052101422007330033b0013d0073e2fb4600e47006480015200253004540055500556001590085a01f64002670036800c690016a0026c0016d0016e001720018f0010a1c00e1d00135009360083f2be4000e46001470015700e5900912150011c0011d00e2800a2c001360a33f014402fc4200a460044800457058590035a0025b0055e00b5f001600030f190021f00137002390023b0064137946001470014f0035803e59003650026900b6a0016c0010f1f0013b0083d0013e001410014235346001470054800150001590145d0015e004680026a0021b1e0012a0052b00538001390033a0013c0043d001433114400b4508d4b01d4d01f51004580035c0025d0015f0016200563086640026503966001690026c0017d00180002201a0031e004200022b001390043a0013b0023c0033d010410044438b4501d4601a470014d0164e0014f00651003530215807b5d0086300164028650286600767003690046a0036c00b6d001820019100119190011e001200142a001390023c00443001440014527446001470074f010510095300a58002590015f003630076400765006690226c0106e0017200180002201a0011f004200033d0073e00844001462e8470164d0014f00750002520015300f5500258002590375a0015d003600026400b65001660026700468021690066a00c6b0016c0156d0016e003720018800110200013d00145001472f34f0015300355004590055f00160004640026900d6a0056c0016e0026f0011b210052c0053b0013e027420074601a470074833a500035400855012560095900c5a08f5e0065f0016000761002670056801a6a0096b0156e0096f00270003850028f0010c0d0012f00130001492804a0154b0864c0054f0075100b5c0026500178001133000131016320073a00843002490454a39e4b0194c1ca4f00151001520045c0b85f0026600177034780238201284001151e003200032a0013000137001390013a0023c00443001450054b2244f03651027580325c00b5d002640016507766001690056c0082419002200052b001320033a0023b00b3d00641002430014400245005460014700d4b00a4c1de4f0665005c5101b5211c53003580015c0115d0325f02d650066604c690026a0016c0016d0046e0037800c79005820178401587003171a0011e001200013c00144001470014d31a4f0015100b5303058002630086400c650696600c690016c00b6d0017e011800028100583001900012a20002330013b0013d0043e0024500246004470014c0014d0054e3b64f0065000351009520b0530325404e5d0045f00460001640156500566038670b56800b690036a0076c00b6d0096e0147e0027f03b8100a8200583010840038500987008880058d0028f002910010f20004390013b001470014f243500095100452001590035d0015f00c60001690016c0126e001153b0074400146007470015034352028540015d0105e0016000366001680026a0026d01a6e002790117a0018400286001870018900209200014f001512555300165002690106c0058200e870021320001220013d00150002510035219b53003640016500166004690016a00b6c0066d0066e00f720018400487007890011220002220033d003510055324755004640026900a6a0056c01a6d0016e00270001720148300d8700190005910012122001230023e001460014e0045201b53008542685500a5600164003660016703a6807b690016a0196b0126c0076d02b6e0106f01d72007730027400179001850118600487002880078a0018f01d900019100415230015300754001552f15601067002680016a0016b0016c0016e0026f003700057201473003740028d0328f00d9100392001930021a230013e0024800254087550015634c5a00167015680166a0056b02f6f05870001710047300274001750018500686001880028a0038e0258f050910029200b940010f1c0121d00328001360043f03a400104200147003571e3590035a0085b0185e001600047a0020b1e004200033c0013d0034f00f510035820459006650106900d6c00b11200023d00346003470094f005592e05e0116000568002690056a0036c0126d0036e0017000172002870010e210013e002470014800755005560015900b5a20f5e005600036100b680016f00570009111d00c360013f0024009e420014800257181590015a0095b3015e00660006610107603e790017a0057b003201e0022b002320013a0073b0013c001410034300944005450384700d4b0054c0094d0074f005510025200753001580045c2e75d0395f04463011640016500866096690066c0016e003780018202b84002231f002200013b0043d0034100744004450034603d47017480014f0255000f520055300358035590145d2c65e0065f01b60004640046500766019680026900d6a01a6c0206d0156e00d72001790038200183001840018700c1b1f0023e001420134601447002480015900b5a0015d00b5e36d60028670016800a6a0226b0156d00b6e0026f001790027a00185001860038700588003890018b0019500111200042b00145002460014701152001530015d0045f2b06000f6900a6c0016e01d7900882001830028700c1e460044701c480014f00250002550035900f5a0045d0015e0095f00a602ff68001690016a0056b0016c0066d0036e0116f0057000c72003790037a022860018700388002890088a0028b0011c210043e0014806a5500256006590015a0db5e0086003961338680016a0046b0246d0016f00f7002c7101373001760017a0147b036850018600187002890048a0078b00f8c003063c00c51018623626300265010690010e3c009450024d0015103d530035800162009632f46400a6501e690406c0068200a870010f470014f001510065301055001642e06500268009690416a0056c0146e00172004870028f001074f002510075900165161690056c02b6e0011b20003470014d0074f00251008520095302d55002580015d00163005640096500a661ee670016901d6a0146c0746d04c6e01973002810018200d830028701d90001910011f2200246002470014e002520015301c5401a550095600159002640096500266006672366803c6900a6a0586b0046c0296d0126e01e6f01072009830018500187008880048d0028f03890002910070a460016400167001681ef690016a0056b0016c003720048f00307510045500165001691f66c0066e0017201215220014700153002550076400566001680096900b6a1f76b0016c0116d00a6e0156f0027000b7202d7300987009880018900191001184700248001540075500c560035a001670086803d6a00d6b25f6d0016f025700127100272003730127400186001880018a0028f01e9100292002930010c390015300155001650016700168001690026a0026c16e6d00a7201c730010d5900268009690016a0186b0016d2436e0046f00273006810018602987005910011320001220034f001510015300255007690086a0076c0046e1987000e72054730228700d890068d0019000591003930011f22003230014800150001530015400255008560026800d6a0126b0086c0016d0026e0096f1a870019720177301c7400a8600187002880148a0078c0018d0018e0018f01e910099200a93001950031a220015400155022560015a0016a0056b0066e00b6f00b702cc7100d720297302c740307500287001890038a0018b0268f00291001920019300a9500c96001980011823004540025500156036610016a0016b0886f09a700067133f730047404e75016880018a0318c0208e0048f00a91001920169300494006950059600203550017218d7300b0a220016e0037000172003731dc870018b001910059300195017122200155001560026f00170010710017200773018741d6750178f0029100392002930169400395005960149800810230045600d680016b0016f00270001710427403d753088c0039200694026960109700f980059900207570015a0015b0c961004763a97a0027b01c084a0194c01b520015c00c7739e781f0820018400a12470014c004500015200a5c0135d01c5f07e660186a0016d0016e001781c2790767f0018201c84199870038900815420014700250002520015d0575e0055f0036000f660046a0026d0066e005793237a0058100182001840228500287015880028902e1448001500035a0015e0226003b610056a0016d0056f00270001790267a3687b0018500186001870018800a8902d8a00a8b0080b5a001600016108770001710027a0397b38e890018a0088b0438c01a057c3747d2657e0018002990001101e0013c00243001450014d0195300263003640026500b690017d12d7e04f802998100183002900191d4100144002450044d0084e0024f00253005580015c0015d0035f0026300364010650106603d67003690076c0026d0036e0047e3628004c81129820088308887003880028d00290027144e0025d0035f0026601f670056c0016d0066e00f7e00c7f393810178201b8307d8409b850f68700b88003890078d0019200110450024d001510015300f6300164006650096900c6c00c6e0017e0018009f820018300c9016f910011b4600253015590025d0035e001640186500166008670106800d690056a0216c0396d0266e00a6f001720027e001800078125d8302986030870038803c8d00290033910320a5f00263015640056500166003690296c0026e0078231687038154700153004550046400f66001680026900d6a0036c0096d0016e0277200280001820018325887015880108d0138f009900e6910341247001520015d0015f0016600e680016a0076d0156e03d730017900182006830018414d8702389049900029500120540025500964001660026704968011690016a02b6b0056c0056d03d6e0796f02a70004730037f00281018830208400c852528602e8703e880f0890558a0078d00b8e0098f0519107b9203693002950020f460015300167002680156a0066b0096f0018101683001850018631a8802d8a0018f001910081251001640066500168001690126a0046c00d6d0036e00872017730068200587220890018f0039000291002950011a530015500a6400368003690016a0056c00b6d0046e0156f00770003720297301383004850018600287002880f8890018d0038f0109000791034920019300795005116a00d6d0096e00e7001272002730258400a8705388001892568b0098f0019000191003920039300195018245400155002560055a0015e001600026100167001680046a0076b0246d0096e0046f02d7002d710097200273017740017a001850248601487004880fe890308a1f78b0188d0038e0138f0149101f9208b930289400495023960050c7000a710027300f86001890328a0148b3228c00d9200193004950389600a11610016f0027000171020740017b003880028a1398b0088c33e8e0029200d9300194009950089600d9700611640016c0016d0026e0026f004830028500286001880028d3618e0108f0419000691073920039302395001176701c680016a0016b0096d0026e0026f02670001730017400181003850488601e880288a0078d0028e34e8f0a69102792093930079400f950030d64001680056a0016e001700017200d7300387003880018f0cd90001910059200105690026c0017200b900d59100111550026400268002690036a0036c0026e008720467302383001870098d0078f008900269113f9300a950131a5500356002680026a0056b0016e00d6f0097000c72015730507401b85001870028800c890088a0018d0078e0028f06691031920af930409400395025960079800215690016e0026f00370004720487303b7402b880038a0018b0018d0158e0018f01190001910359200e931d7940049502e960049800718680016a0016b0046f0237100573008740997501286003880098a0238b0018d0018e03d8f0229100b9215393066941b495030960349700398013990010a70002720027300887001890018f0039102493004951de98001156f0017000b7301d7401b750037b00188003890028a0068b0178c0018f0039100492025930a5940129509f961d1970139806a9900410560017100e740087501e8a0108c05a8e00292011930099419a950049608e9720f98021990279a0030a72002730057400c750018f001930079500496007980b4990030c7300174037750128b0019301d9400f95014960d19703298232991309a015097506a8c001940269602f971799804b9926a9a2289b0370597003980019901d9a1a89b3b106003e80111c0201b030020701f2e0010d012cc021510300906001070d8080390a0200b0020d00112002160012e00e2f001180227b031ac04001060b10700108139090300a04c0b03a0c0010d00e1003c1201c130031600517005180012500e260042f009300043400135001380021e032100403708020090470b0590c00c0d0400e0041006b11003120021302d14016160011700618005190011a0011c0011e0012f00130005310043200134001370083800139001490054b0011303001043a60b0140c06c0e02c13014140101500517005190151a0041c0081f0042d00330001320013400337006390030f04004053e80e01c0f0a7170031900b1a00b1b00c1f00c200082200133007390023b0013d0010d03008063350908a0b0010c0011000813001190012503f26019290012a001310020e072ef0805b0a04c0b00a0d00112021160092e09d2f0163000334002380023c001620011e0200103014040020600107001081f3090090b06d0d0020e001100801206d1301014021180021a0011e0032d0012e0012f0343001231001340163700338009390033a0013c003490074f001210300408005092be0b00c0c032101071101312001130c51402c15001190031a0011c001250012600c270042a0012b0032d00130003310363200b34004350053700e3a0113b00143001490094b0044c0025800118080010a32e0b05e0d0190e00212099140021602a1701d1801c1a0012509a26024290012f02d3001331007340123800e3a001450014b00162001650011f04003080010b22b0c0040e00910001120221301f1407017036180121900b1a0031c0071e00626027270012d00430021320013402e350163702938002390193a0053c0033d001430014b0024f0021b04001090010b0050c2eb1301a140211501417003190311a0041c0241d0011e0011f00620001260012d07c300013200b340073501d37018390083b0093d0014600158001160d35c0e01816001170051805f1a0011e0042f02030021310583200d37001380023a0203c006430014905e4a0044b0194c0014f001510021e0b0010e3681701518007190381a07a1e0071f002200072b0023000a320413300137012390153a0013b0063c0033d017460014b0024d0024f00e5100552002580015900163001650026c001190e0040f33317001190161a01e1b0901f00f2000f21002220082b0013309937002390033b0013d0103e012440014e0024f001510015200353002540016400117090020b0010c0030e00110186110221300e14028180011c00b1e0022400127004340083500237001390023b001490204a0024b0084c0014f001220901d0c0151002b1139a130321400415067190021c0021f003240ac27031280142b0012d004310033200235004360043700d3a0053b0013d0013f00241006490044a02f4c019510015c00e5f00166001770097800319080010a0020b0270c0010e0011227513008140531701a180141a0021c0021e00a260033000d340ac3500537002380163900b3c00c4b0035100158001620031f0b0020c00812003132201406315006170021901c1c0221e0151f0072000126002270082d00330001340093500f360013706c390113a00a3b0073d0023f0024100a450014f00251001580065c0011d0b0020c0061200113004141931500817002190021a0021c06b1d0011e0122000226001270272b0012d004340523501d3600137005390183a0013b0063c0023f0034b0024f00765001280c02013013140161532a190121b0011c0971d0691f04b20006210022703d280892b0022d00832003350143602637010390053b03d3d0023f02c400014100f4200b4400146003470074c0034f002500095200358001590055c0015d0015f001660016d0011312001163ac1706a180681a0011e00c2909c2a03b38043390023c01643020450034b0034d00562020630027c0157d0111d0e0011729a18007190081a00b1e00a200012a02b2b00430002320013300137008390183c0053d0014300144003450094b0024d0074f003510045300164001650056c0017d00280001230d002160011702f182a51900a1a0311e0bd200022a00c2b00230006320023400137001380193901d3a00f3c0853d003410024302f44006450104b0284d00c4f00851022580095c0016200d6301864002650216600180001260c0010e001130021700618002192a01a0211e01f1f02520010220012b00a3703b3901d3b0143c0033d021410044403845002460064c0014d0034e0014f00f500015100452001530055801a590055d003640086500c66002690016c0066d0011d190071a28c1e0051f0012005b2b02f3000137002390073b0023c00d3d01945002460084700b4d0374f00d510045300f5800559003640066501666006690046c00b8100183002870012c0f00e1900d1a0021b32f1e0011f06a20022210642200c2b0182c0933300f390023b0173d0103e05e4200946017470194800b4e0464f00350008520125300f5400a5500e580015900364003650026600467012680056c0046d0016e0077e0017f008820028300284001870028f0010f14001150011c1781d0233400135004360093b0013f02a400024f0025100157008590025e001171500b1c0071d30a1f01321007280022d00235001360383b0023f0074004342008460064700248002500095701e590015a0015b0015d0015e004161a0011e1f11f0022000b22001390063c0133d0034102e4400145003470014f002510115800e5900362002630016500a690036c0046e0011f1900a1a0011b0011e0021f251200072b001330013700a390013b01a3d00a4100242035440014600a470044f004500045200553001580015901b5d0015e0026400167001680056a0036c0026d001171a005202812200f3c0013d003470294f00251003520035300e55007590035a004600026400365001690026a0036c0076d0016e0026f00172006281b0161f023200042130322007230052c003360013b0023d0013e05a420024600d47001480415000452006530045401c55008560025900b5a0545d0015e003600036400167007680116a00b6b0046c0026d0026e0056f007700027200173002850018f001151a0012000c2100122374230323e001530085400955059560025a00167001680016c0016e0016f004700057200773002740028f002122104722001233a7330033e002480025200254076550015606a5a00267007680056b01b6f012740018e0018f0090811016150032433b2803a3600142002770297800e0a12001252fe260fb290042a0013100d32004350023a0024d0011b0c00112003130081403f17002190071a0021e00625001262712702d2a0032d009320053401f350f23600137002390033a0073b0023d0024100143001450024b00258001210c0041300b1400415016190031c00d1f0042000126001272f1280032b0052d00a320033506a3600f3700a390023a0033b01b3f0324101b4500246001470065100158003590015c0015d0015f001600018200212150081c0011d0012701a283042c00135001360063b0033f02340014410024204f46001480015f00178002820010d1e001293462a0ea430a244002450034d006620016300b7c1027d0917e002800061b180011a0161e002200012a2732b04538001390023c0094300d4401e450614b0024d02b5300358002620026300d640016501066001690036c0017c0017d0337e00c800402619004200012a0012b30d2c001370013b0063d0013e001410014400a4501246025470174d0024f0025300d58005590025d0045f003630026400c65002660086700168001690076e0027e0327f001800058101182001830028700188001900011a1b0041f0012b00e2c33f3b001410014201c4600c4700f4801c500015300154001550055d0016600367001690016e004790017f03782001830028400a85009870010c1300114003190011a001270042d320340063505c36020390023d00144005042e3382f03f3000b38004150d01c0e002170021800a1a0012e0032f3043009e3100832002380343900a3a0043c0094905e4b0274f00151003580016200265001150e0061700418004190021a0051e009302813200337020380053904c3a0013b0023c00d3d0014b0164f0065100a58002620036500b140d003250012f0033000431311320313a0bc3d0034300b45005490a34a0164b0404c0014d0014f001510025c00163002650011e190011a0031e0011f0012b002300023230b330013700a3900f3a0053b02d3d0413e001410014300144003450024b00a4c0084d0084f018500035100f5200653002580046400165009660020e1a0011f0013200233324370013d0183e03244002460014d0014e0115100253008640020a140081a0011c00d34235350033c003450014f002510016900114140081a0011c06b1d0011e00220003270012d00534015351543600237001390033c0013d0063f021470014f00658004590021c150021a0011c06c1d0301f0092000121001270042d0163503f362ba3b0033d0043e0013f0874000e420054600d470074f0065000257017590065a0015d0035e0015f002600010e170011900a1e0011f002372363900b3b0013d00241001450014f0045100258001590021a170021800f190011a0031e038200013001c3700138313390303a0013c0804100243005450014b0304d0024f0025100f580035d001620746300964001650276c00117190011a0061c0011e00520002300013200137004391f63b0043c00a3d007450014f01f5100c530015800359003620026400265005690016c00a2917002180021900c1a0021e037200072a0012b002300013200b3700538003390313a29b3b00e3c00a3d01f41041440044500b490014b0404c0024d0024f027510325200653001580b2590015c0035f0016200363012640026502f66007690056c0018200287001241900b1a0021e0021f02d2000422001320033700f390043b2893d03e410134200a440014500146007470064f027500105100152001530025500158006590245d00364004650036600168003690026a0016c0046d0036e00287001111e004200043000137001380013900a3c21e3d005450014f0065104458003620306300a6500e690086c0021d1a0011b0011f0032001322003390053c0043d2293e001460044700f4f00551012520035300755003580085901c5a00163002640066500866002690096a0036c0176d0016e00172002211f0052000b21020220102c001330053b0023d00e3e2a0410014200346018470094800b4e0025200353010540175500d5900f5a02d5e002640026700968017690046a00b6b0026c0016d0016f001810018f0030f1c00a20001270013500e360113e0013f2264002042001470095702f580015900c5b00160003131d00f1f001280063606c3e0013f01c402bb410014201646005470014800857093590025a0025b0135e0115f001600060e370073b00b3d004412ea470014f0035300158031590095d001650026900a6c00282001111f002280013b0123d00141007422cc460034700550002520015901c5e013680026a0016d0016e001840012218001190011e003200012a0102b0063200338001390023a0053c0063d00141001432c44400d450974b02d4c0014d04e4f0015100853002580045c00d62003630a4640046503666005690017d0097e0028000782003211a0031e00a200032b00633001390023b0013c0013d01441008443294502a46029470014d0304e0014f003500015100253025580635d00d630036403c6501c6600a67004690076a0026c0057e0018000283001241a0021e0062001a2b003390063b0013c0043d00241001451d5470164b0014d0044e0024f01f5100d520015301c5500158007590015c0015f00e6000162003630126401565014660016903a6c0146e004720018000182001900042d190012000a21002220012b0023b0023d0083e00e4200244006450094625447021480024e0014f010500035200253020540035500758006590405a0015d0045e00b600126400c6500566003670016803b6900a6a01c6c01b6d0096e00770001720028100583003850018700488001910011a3d005410014500147245480014f00353005540015500c58001590105a0015f002600086400368001690116a0046c0066e00570002720018200283001870029100123210042c00e3b0013e0254200d4602747005482c950002520015300154009550185600a5900d5a0a95e00d600126100a6400167005680236a0096b0236d0056e0056f008700077100273001830018500487006890028f0010f3100632002390013a0024921c4a0324b0794c00e4f0045100b5c00763001650016600182002123101d320063a00a4300445001490334a35b4b00f4c191520045c0c75f00163002660017709e780578200684001201a0011e008200072b00237002390083a0013c00a3d00243001440064500e470014b1c14c0044d0034f0545103653003580355c01a5d0045f00b6200563003640016509a6600e690036c0086e0018200527200042b00332010390013a0073b0163d00741006420014400545010470054b00f4c1fd4e0024f06b50082510155213553003580065c0265d0325f0436300264003650066605e67001690026c0036d00a6e0037803979008820288403485001870061b1a001330014300144002450044d2904f002510055304358003590015f0016301064014650556601767001690056c0187e0348000681009820018300e870019000891001301f00120001220032b00133007390013b0013d0063e0034400146003470014d0044e3744f007500095100d52097530255406055001580015d0045f00464019650046603f670af68009690016a0036c0046d0076e0136f0067e0027f0808100f820098301b84011850188700688003890028d0018f001900011920006390043b0014500246001470034f18e50019510055200258001590055d0025e0015f015600016400165001690046a0016c01f6d0037200283002870011e1f0023b0083d001420014600a470014c0014e0014f001502d1520255400158001590045d0165e00a5f001600096400166001680026a0076c0036d0246e004700017902a7a00284003890030e3c0014f001511b0530025500159001640016500866002690176a0016c00d82025870041c3b001450014f001500055100552149530055500256001590015d0015f00164005650016600768002690036a0186c00a6d0096e01e72002730017f00182002840178700b8900218200052200d3d0014d0015100552001531a55400155014580015d0016400e68002690146a0096c02e6e00b720237300280001830178d0079000d910042d2000122007230023e00346003470024e008500035201a5300d541f95501656006590025a00260002640076600167058680a4690036a0366b01f6c0096d0356e0166f036700037100172008730057f0018100382001830048501d860078701088012890028a0028d0018f03791008950011853005540095525a5601a6700368001690016a0056c0026e0086f00a70007710017200b73005740038d0478e0018f01c90002910089200293002950011d21001230043e004480064e0015406755001562c25a0026700d6800e6a0056b04b6f04d700037100e74004750017f0018500c86001880028a0068e0528f04591001920129400295001151c0041d00a360083f0444002342001470034800350002571f4590095a00a5b0395d0015e0035f0016000d610026c001760047a0031b1a0011e0031f00120005390013a0013d00741003440024500346001470024b0014f016510045300458187590155d0025f002630016400365017660016900c6c019820011e1f00220002220013d0023e001460084700f4f00350001530025500258003592335a0015d0015e0276000c6400368003690036a0046b0016c00b6d0026e0046f001720077a00185001870031d2000321001220013e002460014700548009540025500256001590095a1d15e0026000a6101f670016a0036b0026c0016e0036f0067000d7100272002730017a001890018a0018f0010f1d005360023f0014008248005570f45a0065b3435e0066000461012760a8790027a00a7b007281e001200012b0052c0013a0083b0014100543008440094503f46003470054a0014b0064c0104d0064f00150002510055200f530015800a5c29d5d0415f07e6301e64002650096609c6900c6a0026c0016e003780037e0018000282049830018400387004301e0031f001200032a0012b0013b0033d0023e0014100c4400e4500b46047470234b0014c0014d0014f02b5001851001520095300854001550035803c590185a0015c0025d2ad5e0135f02a600186400765009660216700368002690106a01c6c02a6d0226e014720017900a81002820078300287017880011c1f002210023d0014201f4601b470034800650002590105a0075d0125e2fd6003361001670026800e6a01d6b0146d00a6f001700047a003810028600b8700588001890038b00118410014500b4600147019500015200153002550015d00d5f23b6001f6900b6a0016c0036d0036e01a790177a0018200d83001840028700b890029000128200013e001460054702d480034f0035000455006580015901b5a00c5b0015d0055e0105f0106028e6100767001680016a00a6c0036d0056e0106f00c70015710017200473002790047a044840018500186001870058800d890178a0048b0028f00291002212100242002460014700248072540025500256004590015a0a65b0015e0116003961351680036a0036b0266e0016f0117003871019730017a0237b0888500288002890038a0168b0238c006910019200195002081e001390013c01251019622e7630056500e69002141a0011e001390013c005450084b0014d0044f002510445300d6200f632676401665022690576c0118000182014870039000116390013d00345001510085200153017550026200264234650056800d690356a0076c0126d0016e003720078200183002870048f0039000410390023d0014b0014f003510135300358001590016300264001650e966002690086c02d6e001720012620003390023d0024400145005470014d00c4f00450001510075201953039540015500358005590025f0066300b6401865015661956700168001690286a0226c07c6d0716e02e7200773003800018100782023840068702f880028d00290004282000321001220063e00146001470014d0014e0035200653021540275500c56001590026400c6500266008671ea68051690086a0536b0076c0136d0186e01d6f01b70001720068100a830058500a860048701188008890028d0048f049900019100b9200114460014700153002540015500159001640066700168160690026a00c6b0016c0026d0036e0017200573002870018d0018f0070f220025100953004550016300264004670016915e6a0016c0126e009720227300187004900021f220023d001470015200353006540015501559003640066600167002680186900b6a1736b0026c0166d00c6e0226f0037000e7203c7301674001820018701188002890028f008900029100893001224600248002520015400e5500c5600b5a0045e002610016700e680436a01e6b1ff6c0016d0076e0056f0547001b7100a720047301274002860078700188009890028a0078d0018e0038f01b910069200593003950030d220025300168003690046a0046c0fa6d0186e00372039730028600487001910011646001590015e0016400166002670016800a690016a0116b0016d1aa6e0066f0047300c81005840018603a87012880088900191003950021322001530075500e6900c6a00a6c0056d0026e12f700187206c730448700e890148b0018d001900089100592003950022622008480025400455013560045a002600016700368012690016a01b6b0116c0036d0056e0096f1377002c71006720227302d7401d750018300185002860048700288023890058a0108d0058e0018f03d90002910179200e9300e94003950071e5501c560025a0016100168001690016a0086b00b6c0016e00a6f00f7020f71012720387303c740497500187004880018900e8a0088b0628c0028f00791004920049300f9501396005970011f2300348002540045605e5a0096100267002680046a0016b0906f0877000a71304730027407075038850038600188003890028a0558b0018c0548e0078f009910019202a930039400c950069600c07220016c0016e00170001721057300f950020a6d0016e00170006720027313274002900029100c9300195021172200155004560036b0026f003700207100c7200b7301f741757503c8a0028d0018f00191002920059302d94007950129601b97003980209900112230015600d6f00171048730017402f752a78a0018c0078e00192008930019403c9601197025980079900b9a00106570015b055610067633c7a0027b01807280014a00f4c0035c00377316781508400310470024c00650001520075c0125d00b5f0586600a77002781ef790817f0028201a830018417789003162c00146003470035000152002550015c0015d03c5e0055f004600156a0076d00e6e00578002792ea7a00d84030850018700688001890371448002500035d0025e0246002a610096d0026e00170005710017901d7a31e7b00385005860058700188006890358a00a8b01c096103d70001710037a03a7b338890018a0078b02f8c01c047c2d07d17c7e001800140d4300244003450024d014530015800163004650077d18b7e05e8023d83005900141d4400345001470024d0074e00353007580025c0015d0045f0026300464013650126603a67003690046a0016c0036d0046e0027e2ff7f0018006d810fd8200c830ae870039002d91002144e001530015d0045f00266013670046e00778001790037e0077f31781019820108305384086850aa87005880038900a9000114450024d002510015300b54001630026400f6500a68001690166c00f6e0017d0017e002800b88301a87001880029014f91004284100146002470024d0034e0015200153014540045500158002590015d0055f001600016401f650016600e6702c6800b690076a01e6b0016c0276d03b6e01672003730017e0068000c812498304885001860488700d8804f890018d00d8f00490038910310f410015100153001550015d0015f001630146400a66004690336c0016e00d8228d870419000418470015300a550066401668001690166a0046c0076d0016e0257200373002800048100482007831dd850038701b88014890018d0298f005900dd9103f135200153001550015d0015f0046400166010680016a0066d00e6e030730028200a830028412187032890448f0029100125470015400455004560016000466006670426800a6a0186b0056c0016d0326e0526f032700067200173009790037f00b810268302a84016852658603b8703a88107890618a00e8d0218e0118f04b900069105e9204493004950029600112540025e00267003680066a0096b0086d0016f004700018101285002862b1880218a0018d0029100992001950011553001550056400567001680026901f6a00b6c00e6d0076e018700047201b730128200583001871aa890028d0018f00190003910051d5500664002670026800b6a00c6b0026c0056d0086e0186f00b7000a7201b73023740028300a850038600687010880c7890058a0038d00e8e0018f0179001091052920099301095006175200255001680016a00c6b0016d00a6e00e6f00170017720037302783001840088704288002891f38a0038b0118f0069000191007920059502824480025400255001560055e003600026100167001680026b02d6d0076e0046f02c7004d7101573014740077a003850298602987003880e2890398a2208b03a8c0098d0068e01c8f01a9100b9207c9303b9400b9504296006980010d6d0016e0017000d730047a0018902c8a0118b28d8c01a920049503796009970020f6100170001710177a0017b006880018a0b88b00b8c2df920059300194007950039600a970071164001670026a0016e0016f00672001830048500486001880088d2d58e0258f0379000d910529200b9302310670106b0026f01485038860178801a8a00a8c0018d0038e2c88f0489101592083930089400d950011168002690026a0016c0016d0026e0026f00470001720117300787002880018d0018f0b3900019100f950020a690016c0056e0017201073001830018d002900a1910019300117220015500164001690066a0046c0016e00f6f0017000472063730347400182001830058700588003890018d01d8f0149003b91112930099501c1d55005680036a0066d0036e0106f01170015710017200c730567403985001870048800a8900b8a0028b0048d0128e0098f06e90001910499209b930649400b950489600d98007990011c690016a0016b0016e0016f007700097202e7304e74036750018600187001880028a0028b0018c0018d0108e0058f01e900019103e92023931a09400b9504e9601d97001980171b56002680016a0016b0046f01b700047100e7300374082750408600588005890018a0238b0048c0028e05c8f0149100a921219306c941f39502f9606d9700d98022990060670004730048b002910189510c98003146f002700057301a74024750088a0098b0238c0058d0018e0018f003910089202b9307294034950c5961a597033980b6990150f740037502a8a0058b0018c05c8e00292009930039410695004960809720298022990499a0070a73003740129200193011940029500c9600f97001980ae9900e0f710017402b750278b0018c00292007930189401b95015960ad97050981d7991c29a04c9b0050875030940159601997121980209919e9a2c89b0c50497001990099a0cc9b31e0a003e80118f0203c0300407056080070a0050b0010d0022e00d12012560217e0301304001060050716c080620a03a0b00410001120101400216004180012e03d2f005340013800121010030222c031db040040613b070060817a090570a0820b0550d0280e004100741204613007140071600b1700a18009190011e001250302600a290032e0032f01d3000b310033400935002380023a001490012602002031ca04070080390908c0b08c0c02a0d0770e01d100b111007120091305014028150011700f18012190051a0011c0021e0042600a2d0012f00c300163101a32007340063500237006390023a0053b0013c00245001490164b0026500121030030434a050020b0160c0bd0e06a0f001100011301b1401d1500717019180031902c1a00b1c0141d0011e0061f00720003270012d006300043200834001350023700e390043d001440014f0015100259001120400d053e50e0330f13517009190191a0151b01e1e0021f0162001221002220073301d390023d0014e002650010f03003062a408001090990c0031000713004250682601f2700129005310053a00143001490010d0721e0803a0a0740b00c1202a1600c180012e12b2f01c3000834004380026200127030210400d0700108184090160a0020b0970c0040d00a0e002100ab120921301c1403e160011701118009190011a0011c0051e004260042e0052f05e30028310033200134034350053700438009390093a0023c0023d001490134b00458001620022803004040010600408008092390b00c0c05f100c01103312005130d61403115006190071c0061e0022601e270102b0022d0032f00130002310603200e340013500a37010380013a01f410024400145002490124a0084b0074c005510015c00163001820011e07001080030a2a90b0500d02e0e004120a414002160521701d1802f1a0011e0022512026051290082a0042e0032f05c3002031011320023401538015390013a0013c0054300249006620012f030010400808001090010a0020b19e0c00c0d0020e0191000112038130361408f170601801f190101a00f1c00e1e00d2000126052270052a0012b0012d00c3004c32008340533501c3703d380063902c3a0053b0023c00d3d0024100143002450014b0034d0014f00351001580025c001620016500126040010b0050c21f0e003130221402715021170011903d1a0091c0181d0051e0041f0112000326005270072b0052d0de3200c34009350223600a3701d3900b3b00b3c0013d0053f00141001440034b0014c0014f00352001580025900164001200a0020d2e40e01b16002170041806b190011a0011e00b25002260012a0012f03a3001a3109f3201938009390023a0273c0014300145001490884a00a4b0304c0054d0014f00151005620016300365002240b0010e2c6140011702418003190561a0971e0091f0052000a220012a0012b00330016320873701a390253a0033b0073c0073d02244004450014b0074c0014d0014e0024f00f500015100a5200358001630016400165008660011e050010c0020e0010f2971901e1a0261b0a11e0021f01b20018210072200c2a0012b009320013310e390013b0033d0173e0164500146002470014e0094f00150001510015200453006590012404001080010900c0b0030c00c0e001101281105a1300d140251500e17001190041c0121e0012400826001270052a0013000131004320023400b3500537003390043a0033f001490314a00b4b00f4c005510015c00465001770012b090100c00f1002111348130341400515086190021c0011d0011e0011f002241672600127051280212b0022c0022d0023100432003350033600437008390013a0053b0073f0034100b4200345001490064a0594b0034c01e4f00152001580015c0165f001770307801084001230a0030b03f0c0020e00110001121e11300f1405f160031702818022190061a0031e01b2000126013270012d0072f0013002d340de350093700338029390163a0043c014440014b0084d0014f00151002580016200b650012c040040b0050c0120e002100021200313199140561500d1700118002190231a0021c0331e0171f007200012600427021280012b0012d00b30002320023400d35022370943901d3a0103b00d3c0013d0083f00341014420014400245002470024b0034f006510025800b5d001650022d0b0010c0060e0010f0011200113008141481500c17004190061a0081c0a51d0011e0111f00320004260032704f280042b0052d00f30004320013406b3504b360093700e390223a0033b00b3c00a3d0063f0114100844001450034b0064f01251004580085900263001650026c00278001350c0321302014012152cd190171a0021b0021c09d1d0ad1e0031f06920005210022705a280fd2b0042c0012d01032006350343606137023390083a0013b0553d0063e0013f0474000a41013420364500146009470054c0094f008500135100152005530015700258005590085c0025d0025f00763001660036d00278002820038400387001190a0010e00112003163741707e180871a0011e00a291352a05c2f0033000538074390043c02743040450024b0054d004620366300765001690017c05a7d01c230e004171d9180091900d1a0201e01220004260012a0532b00c30004320023700d380033902d3a0023c00a3d00243003440084500e4b0044d0184f0035100453001580015c0016200263003640046500b7d00b7e001800082c0d0040e0051400116001170451820c1900e1a03a1e0d720006260012a01f2b0012f00230019310013200534001370043803a390303a0213c09b3d008410084307b4400b450204b0354c0014d02d4e0014f00c510235800f5c0046202c63024640016503a660017d0078000182001320c0010e0040f0031300217009191fa1a0381e0321f0442002422001260012b01d300023300437061390303a0053b0223c0023d0363e0034100f420014405a4500546009470024b0014d00a4e0014f022500035100b520045300a58020590085d00263005640146501a6600567001690036c0087e001800018100183002310e0031400117002180021900a1a1f21b0021e0081f0022008021001220052a0012b067320013300237002390143b0013c0153d037440034500e4600e4700a4b0014d0634e0014f011510105200453016550025800a590026200363007640066502166004690046a0016c0106e0037e00d80002810018300487001390f016190111a00a1b2ed1d0011f06a2004421079220292b02d2c1163200233024390013b01b3d0133e0a54200d44003450034602247023480134d0044e0854f00550011510035202653021540125501256004580015900b5a0035d0035e0015f00260002640086500166007670166800a690036a0056b0016c0046d0056e0057f024810018200183001840058500815150041c11c1d0361e0021f0022000621003280012d00134002350083600f3f0414000545001470024f00c57013590065d001640011c1500a190011b0021c0031d2761f01221004280032c0012d001350013604e3b0033e0013f017400804200f460064c0025000d52002570435a0035b00b5d0045e00c600047a0011f1300118001190021a0011e14a1f007200142b00137002390093b0043c0163d00341044440014500b46001470054b0034f00c5101c5300358019590015d0016200363001640026500a690036c0072c1900b1a0011b0071c0011d0011e0011f1a62000c21006220052b0012c001330013700b390043b0293d01a3e0064100842069440014601847005480014f00650007510015200c530065400155001580035902d5d0015e001640026600167001680086a0076c0016d0036e0017000121190021a0061e0031f001201c1210032202b23001390013d00b3e001450014704f4f007510085200153012540015501458001590095a0015f00160008630016500366001690096a0016c0096e00472002920012d190011b0201f03320005212bd22012230142c00d330023b0023d0053e09d420054601a470084808d4e00a4f0015000b520165300554046550175600d590135a0855e00b6000a61005640016700a68020690016a0116b0126c0016d0056e0016f00570007710018500187001880018f0041a2000b2100f222e423060330013e0014600152001530065400e5505f56013640016700368001690046a0016e0026f008700037200d73003740058d0028f00591002191f001210422335b3e009480024e002520015407b560ab5a00567008680066a0016b0266e0016f01b710067400375003850018a0018e0038f00792003930010a1100c1500124278280372c001360014a0015c0017704b78010082522d260922900c2a00231008320023a00243004230b0010c003100011200313007140231700118003190031a0021c0021e00126229270382a0062b0042d01d3200434013350c33600138001390073a0163b0023c0013d004410024300c44004450014b0034d00158002630012d0c0031300b14003150271b0021c0071d0071e0011f003200012600927253280132b0082c0012d0163200235085360213700d390023a0043b0263d0033f038400024102842004440014500246002470044c0014f0025000158007590025c0045d0025f00264002690016d001820018400112150041d003240012700e282612c002360073b0013f01b4000f410014203a46001470015d0015f00278001840020c292952a0bd4309444001450044d0036200263009690017c1b87d0ba8000b221700118001190011a00d1e007290012a2152b041320023c0073d001430104402545053460014b0014d0304f002580045c0015d0035f0016200363011640076500b66003690027c0017d07c7e0198005782001900042d190031a0021b001200012a0012b2642c00433001370013b0083d0043e001410024400a4501f46026470134c0014d0084e00150002520025300c5400255001580035d0015f00560001630016401265005660106700368001690056d0017e0567f0028000c8101982004830148700190003171b0032b0092c2874202246004470094801b4e001550035d0035f0026600167001690016a0016e005790017f04c82003830068400f8500a890021113001140021e00126001270032b0022d25e33001340053503e3601d3b0013d0023f00244005450014600207120012e25f2f02f3000838002390013c002160d0250e009170011800f1e0032e0092f26e300803101332005340013804b390093a0073c0074906c4b01e4f001510056200463001650011d0e0041700c18004190091a00b1e012200022f002301d8320043702d38006390523a0073b0023c01c3d0034100144002450014b01f4f00c5101258008620036300164001650136c0011825001260012a0012f003300053126d32040380013a0783c0013d0024301344001450034909b4a01d4b0304c00c4d0014f002510025c0056300166001250e0011a0091e0011f002200032b0093000231002322523300637008390113a00f3b01c3c0033d04f41001430024400745005470024b0124c0094d00c4f015500045100e5200c530025400158005620016300564005650056600169001141a0012b0022d001320043325d390013b0023d0123e02344001460024d0034e016510015200453003540035900164004670021014003170011a0011c0121e001270012d001341913500837002390013c0073d0014f00162002630011b1400b15001190011c0961e0022000226004270062d00a3401c351123600b37002390023d0083f04c410014400145005470034d0014f00d51001570025800a590045f00129130011400115003190011c03b1d0681e0011f0072000327006280012d02134001350283624e390013b00d3d0043e0023f0b140039410044200c4601747009480034f004500045703758001590135a0015d0095e0025f0056000365001660016a0026e001790011517001190091a0021e0021f0012000230001371723900d3b0063d0014400145001460014b0014f006510045800659001650016c0031f1700e18024190021a0031e045200012a0032b0013002c340013700138275390383a0133c0803d0014100443012440024500c490014b0534d0054f009510175800a620c56300a6502f690026c001210e0011700118001190031a0031c0011e00920008300013200137010391303b0063c0123d00c410024400145004470014f02b500015101152001530035800a590036200363001640026500a690066a0026c00c2e1700118001190121a0051e0411f00120006260012b003300073100232015340013700a3800b3902b3a2513b0193c0153d03541073430024400d45013470024b03c4c0064d0074f02b510325200553005580aa590015c0105d0035f0016200163017640076503a66009690116a0016c00782009350f001190091a0021e0081f04320008220012b0033000132006330013701c390083a0013b2143d0393e0023f0014103c42017440024500346014470134b0014c0014f030500345100352007530085500158016590405a0015c0015d00c5e0025f001600016200164007650046600468007690086a0046c00d6d0066e0037200282001870021b190021a0011b0011e00a20005220013700138002390113c18b3d00644001450054b0024f00f510635300258007590016204d6301e640086502166001690166c0097200129190011a0011e0021f006200252200932002390093a0013b0053c0033d1593e0013f00141002440014500146004470254f00e50001510215200f530165500b5800e5901e5d0015f0016300264012650106600568008690176a0036c02f6d0036e00372004870022e190041b0071f00f2000e2102f2201d230022c001330133b0073d0103e1fb4100142001450014602d470144801e4e0054f00250004520075300f5401f5501e560015901c5a050600026100164005660026701e6802b690026a00d6b0046c0026d0016e0046f002700017200173001870028f00519150011c00a1d0051f001280042c001350123601b3d0013f183400394100142004460014700b4800250001570475900b5a0015b0025e0055f007600036c001171d0072800f2c0033604b3b0013f0114022d420144600147001480085000157095590015a0065b0255e0145f0026000861005790057a005840011a180011f00137002390013a0013b00a3d004412114200446001470024f00152001530015802c590095d00a630016500366003690046a0016c002820028400187001141d0011f00221001280022c0033b004410064223746001470074800250007590125d0015e01b680016b0016d001730017f001251a0011e00220001290012a0282b0103100132006380033a0043c005432334402c45089490014b01c4c0014d0504f0025100853004580085c0195d0025f00162008630bf640056502266003690076c0017d0267e0018001182005900022d1a0021e005200012a0022b0032d0013200133002390073b0013c0023d0124100d43001442954502746026470024b0014d02e4e0024f00a51004530205804c590015d01462001630066403d65025660106700268004690056a0026c0096d0036e0017e00e800028100582001830019000231190011a0011e00320019220012a0032b00b33003390053a0013c00b3d0063e0014100243003440044516f4600a470264b0044d0064f029510225200153024550025800b590025c0015d0035f0266200163023640276501c660096700168001690496a0016c0236e008720027e0018000e820048300387004900073b1b0011e0011f00c2000821004220052b0082c001370023b00a3d0113e010420014400645006461e347039480074d0014e0014f0135000b510015200c530245400d5500b5800c5906c5a00c5d0145e0145f001600186401b650036600d670096804a690096a0206b0056c01c6d01a6e00e6f00370001720027e0018100c82001830028600387007880028d0018f00290001910012720001220013d0043e00141002450014600447177480054f00150001510015200153006540015501e5900f5a0025d0015e0015f0016000c64002680046900e6a0066c0076d0026e00a6f00172003800018200183005840028700188001890018f0012e21006230012c01f3b0013e022410014201246020470104822e4e00250003530015400d5501c56014570015900f5a0ad5b0015e01b5f00160012610196700a680116a0086b0296c0016d0056e0056f014700117100273001790027f001840018500c860028700488002890058a0018b0018f003160e00110001180012f001310073200738001390013a00443002491bf4a05d4b06a4c0144f00551005580015c00d5d0015f00265001820021331016320093a00a430014901c4a2f24b00d4c10f51001520045c0b15d0015f00463003660017713e7807282008840012d18001190011a0071e00b200092a0012b0033200137002390093a0093b0013c0133d0014100243001440044502046001490024b16b4c0114d0064e0014f061500035104a5200953003580445c0495d0105f01d620066300f64003650896601a6900e6c0067e0018200a830028700290001331a0021e001200082b0052c0013201b3a0063b0123c0013d00a410024400545018460034700c4a0024b0114c2224d0074e0094f0475009d5101e521205300355001580045c0455d0525e0015f0796000163007640016501566075690046a0046c0026d0016e006770027808e790247e0027f00382041830038406287009890021f2a0012b002390023d00244005450054b0034d1e54e0044f002510075303358007590015d0026300e6401a650336601867006690056c00d6d0026e0017e058800188101582002830139000391001331a0011f00120002220013300f390013a0013b0023d0063e00a44002460014c0024d00a4e2fd4f0035000751003520715302c54050550025c0015d0035f006630026401a650016602e670966800b690016a0026c00a6d0086e00c6f003780017e0077f0f4810108200a8302d84018850418700888002890028d0018f0039000326190011a0011e0011f001200032100122001390063b0033c0013d0033e0014500246003470074b0014f1015001e510115200553001580045900b5d0055f01f6000c640016500468002690076a0046c0256d0046e0057900582002830018700324190011f0012b0012c0013b00b410014500146009470034c0025021b5202a5400358001590035d02e5e0195f0056000a640026600568005690016a0076c0036d0316e0037000178002790567a0048400587006880028900792001173c0023d001470014f004510e852002530065f0036200163002640026500466001690216c0156e0017200c730018203583002870068d001900022120001220043d0023e0014c0014f0035000851004520ef5300a5400255002580015d0015f0026400365003660106700268008690056a0116c00a6d0136e01b72004730048200b8402385001870128900690001241f001200042201b46002470014d0024e0014f0015100a52001531235400355022590026400f650046700168005690186a00f6c02d6d0046e018700037202a730018000181002830218500187005880028d00a8f0089001c910043320001210022200b23006330013e00d440014600847001480014e00b50006520275300f541ca550205601e590045a0055e0036000364007660086708a680b26a0546b0356c0086d03e6e0226f05a7000871002720057300b7f003810088300a840048504786016870128801f8900c8a0028d00c8e0098f0549100e9200593001232000122001480015200153005540055517a560325a001640016600167003680046a0076b0086c0026e0086f014700127100172011730097400a830018500188002890028d06b8e0098f01a90001910079200593007960011b23003480055403755002561fa5a0046700f6800c6a0046b0466f0307101f730017400b750037f00185015860068a0098b0018e07c8f0289100292021930029400295001151c0011d0053600b3e0013f0454003e42001470074800650001571f15900a5a00b5b0a35d0015e00b60010610037600f790027a004211a0011e0031f0012000b370013c0033d00a3e001410074400645003470074b0044f017510095200153009581225901a5d0035f002640056502166005690146a0026c02e6d0016e0016f0017200282003900012f1c0011f00220005220023b0013d0093e001410014200944001460094701a480014f0055200353001540025500758002591645a00b5d0055e043600226400267001680046900a6a0116b0046c0146d00b6e0076f001700017200973002790017a0028300184001860028700388001890018a001910012121004220033e0044200146002470074802752001530015400155008560015900c5a1635e008600136104467002680076a0056b00e6e0046f00d70014710067a0047b00b8500188003890028a0018b0028f0010e1d001360013f0024006b48004570895a0015b2f35e0046000461014761967a00f7b006292a0012b006320013a00d3b0033c0013d003410064300b4400f45040470064b0074c02d4d00a4e00150002510055200e53003580085c2365d0595f07d620016301d6400265009660946900a6c0026d0016e0047700178013790087e00480002820808401087006361e0011f00220001210022b003360013b0063d0054101d4200244010450114604547024480024c0034d0014f01e50033510025200953008540025500158035590345d2175e0205f052600256300164009650066603b6700b68005690156a01c6b0016c0266d02f6e016720017300179022810088200d8300584003850048702e880038900690002282c001420274601a470084800b5000f520015300154001550015900e5a0035d0105e25f6004d6100664001670076800f6a0186b0186c0016d0116e0026f0017000673001790027a013810018400185001860238700588001890108a0048b0018f0019500119200023d001410014500b46001470125200353002590025d00a5e0015f1946001e64001660046900f6e020790307a0028201083006840088701389009900022f200022c0013f001420024600747031480074f00250006520035300155009560015901e5a02a5d0055e0225f01b60223610156700168005690026a0096b0036c0046d00c6e01d6f0067001c72003730047900d7a0a97b00281001840018500286006870098800e890298a00b8b00b8c00190001950011e2100146002470014806b540015500256007590015a0815b0015e022600326132067001680026a0026b01c6d0016f00d7002f7101f760027a05a7b12685003880088900d8a0228b02f8c00f0d1e0012000133001390033c0105101a530016221d63005640016501669002820011c20001390013c00645003470014d0104f0015103e5200253013550015d00162015631e264025650296600268001690546a0016c0136e00372003800098202e830018700390006211a001220013d0023e001450014f0045100a520015302454001550025800162001630026419265004670046800d690456a00a6c01c6d0016e0097200c8100282002830068700b880018d0038f0049000991002191e001200013c001450024b0014d0014f00251013520015300558002590016200163002640046508266003690126c0356e00272003800018100182001870012d220013d0034500246005470044d00f4f0075100e5201b530385500358006590055c0015d00b5e0015f00a63008640276501966136670056800c690396a02a6c0516d0776e0386f001700017200a7300379001800028100c82035830068400c8600287045890028d0018f00190011910063020002220043e00144001470034d0024e0064f002500025200a53023540245501156001590055e0015f0016000464019650016600c671966804c690076a0546b0156c0156d01b6e02e6f02a700027200a730047f001810128300a8500c860118701888010890078d00d8e0018f054900059101b92003930011d220013d001460015300254008550045900160001640036600167002680d2690016a00c6b0026c0046d0066e0046f002700017200d730058700688003890018f00d9100293001950011320001450015100d53008550066300164006650036600268002690d86a0036c0186e009700017203382003870069000d2c2200946002470024e0014f001520045300e5400755025590045a0015d001600026400965001660026700368018690186a11d6b0066c01b6d01c6e0376f0087001d7205a730207400481004820048300284001850018600187028880068900c8d0038f00d900089101a93002950022a2100122001230024200246001480045401055014560215a0075e00460004610036700e6804a6a0286b1866d00e6e0076f0677003d71016720037301674012750038500c8601c8700488013890028a0108b0038d0028e0088f03290001910089200c9300494001950061051002530035900268001690056c0866d0236e005700017203f7300286005870028d0018f001910021d50001530035400255001590026600267003680096a00e6b0026c0016d10d6e00a6f001700037301181007830018606c870148800f890088b0018f0039000391006920019300195001242200747001520025300755013590025f00264001660016700168001690126a0116c0126d0096e0bd6f003700207209773059740018200183003840018702688001890238a0018b0038d0048f0029000d910129200193002950013322006230014600148001530015400b5501f56013590045a0055e0026000461001640026700768017690026a01d6b02c6c0016d00b6e0166f10a700497101a7201d7304474039750017a0018100283001850048600d870028803c890098a0398b0088c0028d00d8e00f8f060900039102e920299301e9400395019960059800123540015503a5600a61003680016a0046b0136c0026e00d6f01a70193710387202c730477404c75007830018600187002880038901e8a00e8b0988c0108d0088e0028f008900019100b9200b9301d940029501e9600a98001222300648003540015605e5a003600026100667001680066b0746f06f7000a712757300574076750767b002850028600288002890028a0798b00b8c0af8e01a8f010910019203193005940299500b960119700798002076c00170001720a87301c91003930019500211550016b0016c0016d0016e0027000472005730a47400988001890018b00290002910089300495020960021e2300255005560046a0016b0036e0016f006700137101b72005730357412775075880018a0018b0038c0028d0028e0018f0089000191007920179304394018950219603f9700e980449900c1323001560046f0017000171047740147521d8a0018c00a8e00392005930039405d95002960179705298009990279a002045b01e610037623f7b009044a00377229780b7840030f4c00350001520025c0095d0055f0276600477002781f27906e7f0088200c8300284108890021b2c00546001470024800150002520015d0265e0075f0086001166001670016a0026d0046e00378003792597a01c7f001820028404785003860018700688003890368f0011648002500035a0015e0176001a6100b6b0016d0016f00170001790217a26a7b00a8400285004860068700188003890298a00f8b01b95001086101271002760027a01b7b28c8a0028b01a8c020047c1d07d0c77e001800040f2a0014300144003450014d0125d00163005650027c0057d1927e05f801b981001830049000e20440014d0095000153007580015c0025d0015f0026300464008650066602267004690076c0036d0026e0017d0017e2787f0028007e810b7820078308984003850018600187002880018d0019001b9100217470024e001540015d0025f00266002670036d0016e002790037e0097f2588100c82008830278404e85062860018700588003890048a001920011c3d0014400345001470014d0064e0015300d58001630026401a65007660016700168001690136c00d6e0037d0047e007800d38100b820018303587003880018d00190112910012b440014500146003470014e0034f00152001530175400355002590015d0095e0016401a650036601b670286801b690066a0296c0246d0436e00e6f00472006730037e00a8001181248820018305d84001850088606e8700b88063890048d01c8f0089003b9103a9300195001154700153001590015f001620016300c6400865001660086901c6c0016d0016e01072002821c48300387030890018d0019000391002244e0015100153007550035d0025f003630016400d650016600267001680016900c6a0036c0076d0056e02172007730037e0017f0018000681007820088317f84002850048701c88019890018d04b8f007900d29103d920019300217420014700152001560015d0015f00660001660096a0046c0016d0106e01e7200178002790047f0058200f830058411f8500387022890539000126460035300154006550045600260002640016600167030680046a00f6b00d6d0206e0286f0247000a7100173004790037f00f810248302a8401e852218604c8701e880cb890688a01a8d0438e02f8f03f90003910439202893006950059600115560015e00267004680096a0016b00a6d0026f001700027a0018101083001861c3870028802c8a0028d0018f0019100793003950021b47001520015300355007640066500166003680046900c6a00c6c0076d00e6e02a70002720107300f8201383003840028712688001890098d0028f0099000d9100f9300125470015400155007560015e00164001670046800c6a00a6b0046c00a6d0076e0196f01270018720237302d74002810028300d84001850078601687015880c18900e8a00b8b0078c0018d0258f0289000b9105d9200a9301894001950151b5200153001550026a00c6b0056d00f6e0117001a720027302b790017a00282001830018400d850048704688009891428a0018b0288e0018f0089101192007930029503023560065e0026000161001680026a0036b0256d0066e0046f0247002b71023730137400b750027a0067b0018502b860358700388094890458a1e88b05f8c0198d00a8e02b8f00e910109208a93037940119503f9601098001136b0016e0017000f7100473006750017a0027b0018700188001890198a00d8b1e38c0359200393003950269600d970030c7100a750027b00c8a0548b00e8c22a92005930019400795006960069700a166400167001690016a0016b0016e0016f003710017200173001830048500486002880078d1b98e0388f0179000b9102d920119302295001135400167005680016b0016f00770001850248600f870018800e8a0088d0018e1fa8f01f9100592051930039400c9800114550026400166001680026a0026c0026e0056f0077200373004740038700288003890018d0058f07e900029101393007950010d5500164001690066a0016c0046e0037200f7300283002870018d0019009d9100a1d550056400668002690036a0076c0066d0016e0126f002700097205d7304a74005820018300b860028700e88007890048b0018d0318e0018f0279003c910d092002930199501a980022452001540015500567001680026a0036b0046d0036e00c6f0217002171001720077305774055750058300185004860048700588013890168a0098b00a8d02c8e0178f0629000191056920b39309e94015950809601f98014990011b550016e0036f0077000f71002720177304074058750038500186002880058a0068b0058d0308e0068f01990008910409203a9312c9402a950569603c9700198038990021a560016b0066f00d7000271017730027406b750658500186001880068a02d8b0028c0118d0018e0718f00e91004920de9304b941e5950359608f9703898026990130e6a001700037300674002890038b0018d0028f0019100b9200193007950ae960029800319550016e00170009710067300f740247501188002890018a0068b0268c00e8d0018e0018f00391003920229306694043950aa9616197059980db9903c9a0020f7100474001750198a0088b0028c0508e00292002930029408a96038971ba980189905d9a01c1070001720047300374011750038b00191001920039301394004950109602397004980db990259a0060e74010750248b0028c0039200293009940239500b9609a9708798148992039a0d89b02a087500c94005960099709d98008990dd9a2a89b1af03990019a0429b20f0c003e801217020630300b070c20800d0a00d0b0010d001120022e0252f00111011c9021a20302006003071bd080730a07c0b00b0d00510001120111601217002260012e0952f015300022801008021dd032050400f0621707013081d20909d0a0be0b0740c0030d0560e0061008a1100112079130091400b1602c17011180121a0011c0012508c26028290042e00f2f05230010310123400d350013800c3a0013c00243003490044b002580016200131020040317c040ac0600508048090d90a0010b0c40c03b0d0ab0e02c100f211020120181308814046150041701a180171900f1a0071c0051e00a2500126013270082f01430029310413200f34008350063701038006390033a0143b0013c00543005490304a0064b0044c0014d001580015c0026300165001820012b03005042fa05002090020b0250c1020e0a40f00511001120011302d14023150251702218002190491a0141c0231d0031e0081f00c2000226002270012b0012d01630008320143400535005360013701f3900a3a0013b0053c0013d0024c0014d0014f0015100158003620011a0400d053e30c0030e03d0f1c317005190271a0271b04d1e0021f01b2001b21006220082300130002320043304f390053b0013d0083e005450014e0024f001520011103001061c2090610c0050d001100071100412001130022508a26017290052a0022b0013100543002490010e0714c080240a0840b00a0d003120201601f17003180022e18a2f02c3000634003380022c02002030320401307004081250903d0a0080b0b10c00d0d0130e0090f001100cb11005120c01302a14043160011701a1800b190011a0051c0021e0052500126010270012a0012d0022e00c2f06930044310133200234051370063801a3900d3a0093c003490244b0075c001620053103002040010600608003091ab0b00b0c04c0d001100a81107c12002130c61402c15017170011900b1c0091e0031f00120001250032603e270242a0042b0022d00a2f0023000331085320153400935011360013700e38001390033a01a3b00243002450014901e4a0174b00b4c0064f00152001580015c0046200121070050a20d0b0380d0590e0021209f140011609417029180371a0031e0012518f2604c290172a0062e00c2f0953002931019320083400d350013802b390013a0043c00a43005490084b0054d001620026300139030010400c060010700108001090020a0010b1330c0240d0080e04910005120521304f140a21500316001170ae1803b1902d1a0211c01a1e0291f00220001250032608a2700d2a0072b0022d0222f00430068320103408535032360013705c38009390453a0153b0033c01d3d0093f00141004430034400645003490014b00c4f00551006580065d00162008650023204002090010b0030c1810e0010f0041100313022140381503617003190441a0091b0041c02c1d0051e0081f0112000426006270152a0012b0042d11d300013201e330023400a350323601237025390103a0033b0123c0013d0173f003410014400645001460024b0034c0034d0024f00350001510035300358002630011b0b0020d21a0e0181700418059190011a0011e008260012a0022f0443001d310d232018380103a02e3c0074300a4909b4a0134b0234c0064d0034f002510036200363004310b0020d0010e2020f00417035180141906c1a0aa1e0231f00a20011260012a0042b00530027320ca3300737023390313a0113b0193c0083d0293e0014100143003440074500346001470014b0114c0094d00a4f014500045101452008530015400158005590016200263001640036500966002690016c00182001240c0010e0080f1ef190221a02d1b0c51e0011f0162001a2100a2200f2b00b2c0022d0013200b3316d390013b0083c0023d0133e03044004460034d0044e018510035200653006540015500159002650036700168001690016a0013003001040020900a0b0060c012100d31108d130201403b1501b17002190021a0011c0151d0011e0012401c2701a280012d0022f0023000231008320023401e3500e3700938001390033a0033c0023f005410034300145002490604a0264b0184c00a4d0014f00151001580015c0095f00163001770067800430090170b0010c0151000e112a413029140051507c190031b0011c0051d0011e0011f00124256260022706c280502b0012c0022d0053100e3200535006360053700a3a0063b0033f0064100b4200143001490024a0b44b0024c0304e00150001580015c0285d0015f0026600177082780187900182001840012e080010a0030b0340c0030d0020e00b10002121561301c14072160041704d1803d190071a00e1c0071e01a2500326035270072a0052d0082f0073003632003340ef350173700938055390213a0093b0013c01e3d0014300244001450014b0104d0014f0015100458006620186300264001650013f090020b0070c0270e0050f00110006110041200413117140621501b17008180031902f1a0071b0011c04c1d0021e01c1f00b200082600a27043280012a0012b0052d0183000531002320093401a3504736007370a838002390243a0213b01e3c0023d00d3f0094102943001440064500446002470014b0044c0024d0014f00d500015100258013590045c0025d0016200163003640016600169002820014104001050020b0020c00c0e00510002110021200713018140d51501b17007180041900b1a0131b0011c0e11d0021e01e1f0072000821001260082705c280032a0032b00d2c0012d02330003320073408b35070360183701438001390353a0063b0143c0093d0093f0294100d4300244009450034600247004490014b0104c0034d0014f01e500025100b520025800c590015c0015d0015f0016200265006690018200142090010c03f0e0010f00111005130191401215263190241a0021b0091c09e1d1101e0071f08820008210042200124003260012707f281792b0152c0062d02c320073400235034360a037023390083a0033b06a3c0013d0083e0013f0744001a410224205e4300144002450034600d47009480024a0014c0174f0095001d520065700c58007590065c0095d0085e0025f00b64001660046d0016e00178004790018200284003220a0030b0010d0010e00112002162e31706e1807d1a0021e00c291d62a0832f0073000932003340023808b390023a0033c02d430654400345009490014b00c4d004510026204463009650017c1187d03c7e00180001290e00714001160031710318016190101a0231e01b1f002200042a0732b00d3000d3200533003340033700f38001390283a0033b0023c0133d00241001430064400f450114b00b4d0144f00451005580025f0016200c63010640056500e690017d0247e00b8000d380d0050e01b1200516007170521819a190101a03c1c0011e0d62000926002290022a0422b00430028310023200f3300134005350013700d38052390443a03d3b0023c0a53d00941009430a54401445032490014b05e4c0024d03f4e0014f00d5102b530035801b5c00b5d0026204e63049640076503b66003690086c0027c0017d01d7e0078000f810018200144050010c0010e0080f0011300214001150031701118004191431a03e1b0071c0031d0011e0341f0702002522004270012a0032b0342d001300033200533009370753903f3a00a3b0353c0063d0473e0034101e4200243002440824501446016470044b0084c0014d0134e0064f033500045100d5200b5301454001580325900d5d0086300464022650186600667001680036900b6a0026c0076e0017e00780002810018200183002880013e0e00517005180011901f1a13e1b0081e0101f00a20080210012200f2a0022b08b2c001320033300c3500137004390133b0053c0153d03b3e00341001430014400a45013460114701c4b0024d0754e0064f01c50001510195200b53020540015800f5900a5d0015f001620036300964015650276600e6700368001690076a0036c0186d004720017d0017e0177f0018000981006820018300790001470e0020f02315001190191a00e1b2711d0031e0031f06a2004c210e22203623007280012b0352c1c52d001320053304c3600237002390043b0213d0223e0d24100342020440064603147038480354c0044d0034e0cb4f0065001c510015202c5301a5401b5500d56003580015900e5a0165d0085e0025f00460004630026400e6500166008670256800a690026a0096c0036d0056e0086f0017e0027f0538100282003830058400b85007870068d0028f0012e1400215001190011a0011c0c01d0521f008200082100222001270022d00134001350173601937001390023b0023c0013d0013e0023f062400174100144001450024600347005480014b0024c0014f00f500065702958001590055a0025b0015d0015e0055f0026000465001690016c0027a0011d1500b1b0011c0011d1ca1f010210062800d2c00232001350013604e3b0043e0013f00b400ac41001420154600b480054c0015001a57050590025a0015b01b5d0025e00d6000679004311400118004190041a00f1e0b31f00220021220032a0012b001300013700a38001390123a0043b0083c0223d00d4105d4200243002440054501446005470094b0034d0024e0014f0135102252003530015400158022590055c0015d0026200663003640076501066002680016900a6c0096e0028000282001870013815002190131a0021b0071c0021d0011e0041f11e200192100b220042b0062c00333003360013700e390043b0363d0163e00a4100e4208344002450014602947008480044d0014e0064f00c5001351002520175300a5400655002580065902c5a0015d0065e00b6000162001630016400866005670026800c690026a00e6c0056d0066e006840018600187001331a0091b0011d0011e0041f004201222100522062230022b00133002390013b0013c0023d00c3e0044200144001450044600447047480024d0014e0034f00f500025100a52005530215400355020590145a0045f0066000c620016400a650036600267001680046900a6a0066c00e6d0056e0096f003700017200579001870023c0f002190021b02a1d0011f035200092122b220232304c2c023330033b0043d0023e0b23f001400014200f460294700f480d44e00e4f0025001d5201853006540775502656016590155a0ad5d0035e0215f0036000c61012640016701c68029690016a01b6b01a6c0016d00a6e0066f01770008710027200173002790017a0017f00481001850098600287002890028a0018f0049200225200132100d2222c230a9330013e0074700251002520045300c5402155084560235a005660016700568002690036a0016b0086c0036d0046e0046f00e7000a7100472014730067400975002850018d0088e0048f0069000191001930011a1b0012103d22002232b8330013e00a46001480024e0035405e560e55a0086100167008680086a0016b02b6f01871016720017400575002850028e0078f00b9300107110022416b2801e420015c001770567800d072512f26042290122a0053100c3a00143004280c0020d00112005130061400f1700118001190031a0031c0021e0092000125008261c62702e2a0182b0062d03e3000131001320123400b3508b3600137005390053a0163b0043c0023d005410014300d44005450064b0014d0034f003580016300569001300c001120011300e140031501d190021b0021c0071d0081e0011f007240012600d2718e280312a0012b0082c0012d00e350533602837007390013a0073b0233d0093f038400014102b42014440044500846004470074c0014f007500055700158009590065c0105d0035f007660027800182005840018700112150022400727009281972c003360083f00c40015420374600147001570025d0015e00278007790017d0018400108291d62a0594304c4d003630047c1e47d094800022517002180021a0091e00720001290032a1b12b05232001390013a0013c0053d0024301f440214504e470024b0054d0324f0025100153003580065c00362002630176400a6500566002690047c0027d0d67e02f8007a8200183001900023119003200032a0012b18f2c007330023b0083d0033e0044100142002440094501d460214701e480014d00a4e0014f0055000251001530065500158003590055c0035d0065e0015f0056300264014650026601667005690056c0016d0026e002790017d0047e0757f00980014810278200983015840048700190005211b00121001280012b0072c1813b0013e0023f0014201e4600147008480135000156001590025a0015f003600016600167001680016e00178001790017a0017e0027f061830028400f8500987001890018d001141700126001270022a0022b0022d16c33001340053501836015370013d0023f0014400545001460034d001500015800182001060d0012e16c2f0213000438001490011c0b0010d03c0e008120011600217004180111e003290012e0112f1b93007a310333200838044390083a00d3c00e4300345001490904b0244c00251001580015c00162007630022a040010d0020e00813001140021700f18008190081a01c1e01e1f001200012a0012b0012f00130139310013200c33001340033703738012390503a0103c0213d00b410044400245004490024b02e4c0014d0094f00951015530015800c620096300264001650126c001190d00325001290012f003300013118232023380023a0493c001410014301145001490734a03a4b0194c0114d0014f001510015c009620016300565001820013117001190011a0051e0011f002200052a0052b00c2d001300023100232173330063700638001390133a01b3b01d3c0023d02d4100343001440124500a4600147001490014b00b4c01b4d01c4f01350005510095200e53006580025c0045d0025f001630096400a650096600467001690036a0016c0017e00187001141a0012b0022c0013200133175390033b0013d0103e01944003460024d0034e01e5200553005540046400367005680026a00113100011300114008190011c00f1e00520001340e23500d390073c0093d0013f001450024b0024f0065100458001620062d130011400c15003190021a0031c06b1d0061e0042000922001260012700d2b0032d01a3200134019350ee3601d37005390033a0013b0083c0043d0133e0013f06841004440024500846002470044f009510025700158004590055f0016000162001630036400165001660016900282001310c001130011400215008190011c0251d07f1f00c200032100227010280102b0032c0032d03735030361ef37005390013a0023b00d3d0023e0063f0c44007141008420134400445001460184700f480054f00350010520015707d580075901f5a0085b0055d00c5e00c5f0096000a6a0036d0017900281001820011c190051e0061f00420003270012b0043000232003370bd3900b3a0023b0103d0074100744002470014c0014f00851001530015800b590015d0016500368001690026c00372001290e001160021700b18034190011a0071e03b20003260012a0092f0073003132002340033500137001381d2390383a0213c0963d004410054302944007450184b0534c0014d0104f004510255800c5c001620d1630156400165028690016c0017d00580003900012e0e00217003190061a0031c0021e0151f0062000f220012a0022b0013000334001370113909a3b0083c0123d0153f0014100a440044500946001470034b0034d0034e0024f0435000151020520015300a5800c5900a5f0016200663006640046501a6900f6a0016c0166e0017200280001820013d0b0010e0021700218001190041a0091e0381f0062000a26002270012a0012b00730002310043202a34001350013700f38005390283a1b13b01a3c00f3d0264108b4301344027450294600247007490014a0014b0584c0174d0114e0014f04250001510405200b5300358090590055c02e5d0075f00d62004630286400a65034660166900b6a0016c0066d0017d0017e0028000182010870023e190111a0051b0021e0051f03b200132100522004270012b00f2c0033200e33002360033701a3900e3a0063b15c3d0383e004410484203d44007450094602047022480024b0014c0024d0014e0014f0215004951004520185300e54002550015801b590505a0045d01a5e0075f00364008650096600e67002680056900a6a0066c00e6d00e6e0026f001720027a0018100182005840018600187004271700218001190011a0081e01720011220012b00130003320013400238001390103a0033c0fb3d00745008470014b0064d0014f01451071520015300158009590016206e6301f6400a6502a66002690116c00c6e0027200180001820048f001900013f190021a00b1c0011e0061f0052003721001220152b0032d00133004340013700438001390063a0023b00b3c00a3d0e63e00241002440034500846014470234b0024d0044e0024f02b50003510215201853034540035500b58016590395d0045e0015f0016000162001630066401c650166600667003680096902f6a00d6c0396d0076e009700017200b80001820078300184001870038f00290001910013a1b00a1c0011e0011f0142000d2104122020230082b0022c00d33012360013b00b3d0163e181420084603647029480334e00f4f0015000951003520115301d540355502456006590235a0635d0025e00460007610026400a660036703968035690016a0196b0076c00a6d00b6e0076f0097000472002730017f0018100282001830048500287007880018d0018f0039100128150011c00b1d0091f00127001280052b0012c0022d00135010360223b0013d0013f1034004a42007460054700d480034f00350001520025706d58001590135a0045b0045c0015d0035e0055f0086000d61001660016e00178002790067a002820018700115150021d00a2800d2c001360203e0023f00b4019042012460024800d500035707c5a0035b04c5e017600086100376002790087a00622190011f004200012b0012c00137002390013a0023b00c3d0054115b4200744002450014600247006480014f00458027590075d0055e0015f00160001640016500366002670016900b6a0026c0018100182002870021a1f0022100127001280022c0083b00441009421774700b4800250003550025900e5a0035d0015e0186000164001680016a0046d002700017900184006870018900226170011a0011e001290032a03e2b00b310043200538003390023a00241002431b1440244505d4b00d4c0014d0614f003500015100553002580055c01e5d0055f001620076308f640016501e66004690017c0027d0457e00a800318200a900022f190011a0011e00220002220012a0022b00f2d0013200233001390023b0013c0023d0094100743001441c3450264601c470024d0404e00b4f004510045301f5802e590015d0215f002630056403665016660106700468002690066a0016c0056d0027d0017e0278000f8100c820028300b87001900043b190011a0021e00720015210012a0072b0163200233002390063a0013b0033c00a3d0113e001410044300244017451024600a470274b0074c0024d0104e0034f03150002510245200a53029550025801b5900a5c0035d0145f03760003620056302b640366502e6600f6700168004690526a0026c02c6d0026e00a720027d0017e00280019810028201683008870079001591001491a0011e0021f0062000c21003220042b0122c004330033600137002390013b0123d0153e0173f00140001410044200c440134500c46130470434800b4d0014e00b4f00e5001b51001520115302354013550125800a5906f5a00e5d0235e0345f00760029610016401d65007660106701c68076690116a0326b0076c0236d02a6e0126f006700027200573002790047e0027f00180004810178300984001850028600d8701388005890028b0018d0018f0059000391002342c001330013b0013d0023f00142002440014500546008470c1480044e0014f00353005550195600158001590105a0055d0025e0015f009600136400565002660036700268004690056a00a6b0046c0046d0046e00e6f001700017200673001790017a0017e0018200483009840028700388002890028b0018f0019000291005950012f1b001210012c0303e0154000141001420114600e4700a4818a5000352001530015400a550115602f590075a0875d0015e02760023610416700a680126a00b6b0366d0046e0036f0117001871003790027a0027f00181001830018400a8500b8600687005880048900c8a0048b0028d0018f002920021d0d0020e001180011e0012f003300023100e3200738001390013a0063c00143008491494a08a4b0404c01d4d0014f00351002580015c0235f0026300165002660017701078004820021531018320093a008430034400145001490144a20e4b0034c09c4f001510015c0755d0015f0026300166001771dd7807882003840033617001180021a0051e00920009260012b00230005320063500138002390153a0143b0013c0213d00a41005430084400d450284600247003490034b11e4c0194d0184e0044f0535000a510475200a5300458048590015c0615d0105f0226200963014640046508766023690096c0126d0016e002780047e0048000381001820268300184001870013d180011b0011c0011e0021f0042000321001220012b00d2c0013202333001370013a00c3b0113d00d3e00141006430014400b45017460094700d4a0074b0164c20a4d00c4e0104f02a500945100a520da530065400158006590035c0745d04f5f088600016300564003650076606b67003690036a0046c0016d0056e00477007781247903e7e0037f00e810018203b830018408685001870092820004220012b0023200133001390013c0013d001430014400a45007460044b0014d1104e00b5100553026580085c0015d0035f0026301464015650296601867001690066c0106d0016e0017d0057e0697f001800328101d820028301f880018d002900083420001220052b001330133b0013d0033e0024400645002460044c0044d00b4e21f4f0015000a510015204f5301b5403455001590015d0035e0015f0036401c6500166024670786800f690016a0016c0066d0076e0096f001790047e0117f14b800018101c8200b8302784028850498700588002890018d0028f0049000291002920013d1a0021e0011f00220004220032b001300013600137001390033b0053d0024100144001450054600a47018480014c0024d0024e0014f086500395100a5200d5300d540015500158009590135d00d5e0045f03d6001163002640036500a6600967001680026900f6a0076c0286d00b6e0076f001700027200a78001790117f001820078300184009860018700988002890058d0019000391001281f003210052c0023b0093d0023e002420044600a47001480014e0014f001501505202454004590035a0015d0215e01f5f0086001f630016600768005690026a0096c0016d0326e00478003790777a00b830018400b850028600487009880038900d8b001212000322005390013c0063d002450014f0055109a5200253009550015f0046200463006640066500f6600167001680016902b6a0016c01a6d0016e0097200a79001820548300684001850018700b9000791001361e00121001220053b0013d0063e001420014500146001470024c0024e0024f00350008510095209d5300a54004550085600158001590055a0025c0015d0055e0025f0076300164008660156700668006690096a01b6c00c6d0176e0136f001700027200b7300678002790067e0027f003810018201183003840418701288002890088f001900032d1e00120003220233d00145002470054d0044e0014f0045100b52008530b15400555036590045a0025f00160001640266500566005670066800d6902c6a0166b0036c03b6d0096e0316f00570006720387300a80002810048200383048850028700d880048d0218f00b900279100c920013e1f00120001210052201323011330013d0013e00f4600f47007480074e020500095201c530105416b5502756025590045a00c5e00760005610026400b66002670a6680a5690026a0496b0536c0066d0386e0186f0687001971007720047300e7400e790017a0017f008810178300e840098507886038870138803e890188a00b8b0018d0178e01a8f058900049101892012930069400195002960012922002230013e00147001480024e001520015300454008550c6560385a005640016700a68004690016a0076b0086c0026e0056f02270021710047200e7300b7400981002830028500586003880038a0018d06e8e01a8f016900049101392006930049400195003222100123002480095401d55001561355a00460002610016700a680056b0466d0016e0016f02c700017102f73002740087500481001850218600388001890028a0088d0028e0a68f017910019201e9300194006960031c1c0031d00721001280012c0013600a3f03d400674200146004470034800550003571c0590085a0105b1395d0015e0085f005600136100976050790057a00f7b00184001860012e18001190011a0011e00720009220013500136001390063a0033b0023c0033d00f41010420014400445008460044700a4d0074f020500015100b520025300b580cf590225d0125f00760002630026400865034660086700168002690186a0016c0256d0026e00272003810018200587001900033b190011f0052000621001220042b0012c0023b0023d0033e0073f002410014200c450014601047023480074f00350004510015200a53004540025500b5800a590e55a00f5d0055e0635f0036002c61003640046600167002680086900a6a0236b0076c0186d0136e00f6f002700027201173003790027a0058100382001830018400285001860098700788003890048f001910012d2100122003230033e0024600647006480265000153003540025500d5600b5900c5a11c5e012600226108367002680086a0086b00a6c0016d0036e0026f00c7001471007720027300174001760017a0097b01885002860028700188003890028a0068b0058c0018f0049300194001950010d3f0014003f4200148001570365a0025b2365e0036000461012762697a0117b0162d1a001200012b00b2c001390013a00e3b0013c0013d0024100543010440094502d46001470064a0034b0044c02f4d008500015100152010580055c1a25d03e5e0015f082630206500466064690086c0016d0016e0027700378037790077e0117f001800028100182070830068401287009441f0052000621001270012b0062c00335001390013b0063d0053e0014101b42009440144500b4604d47030480044c0074d0024e0044f01350041510045201b5300a54003550045802c590275a0015c0055d1cf5e0265f06a60035630036400d6500a6603d6700968007690116a0286c0186d0386e0177000372003780037904f7a0027e0047f00480003810198200f8300f8401185001860098703888006890098d0018f0019000391001271b0012c0013b0013f001400014201f4600f47003480125000c54002560015900a5a00b5d0115e1855f0026004a6100d670026800e6a00c6b0106d00a6e0016f0027000b7900f7a03381003840028601d87004880048900d8a0018b00a8f00191001274200145006460014700e480014f0015000251001520025300355003580015d00e5e0055f0ec6002461001630016400166006690066a0046d0066e01c78005790407a0037f0018000181002820218300784010850038700f880018900f90004910013620001220013b0013d0013e002420064600c47027480154f0055000b5200553002540055500b570035901e5a02c5d0065e03f5f01a6018861032660026700368007690066a0136b0076c0056d0116e0216f00d70027710017200473006790277a1037b0087f00183002840028500686009870188801c890388a00b8b0198f0029000191005950031d230014804c5500356010590015a0475b0045e02160029612746b0226f010700217103175001760047a0867b1fd8500986002880038900e8a0218b0458c01f8f001920029300195003101e00220001380043c00e3d001450014f0015101f58001621746300a6400265010690066c0017d001281e00120002220012a00132001390023b0013c0053d00343002440014500a470024c0014d0114f0065102c520025301455001580035c0015f0036201a6316264022650256600c67001690516a0026c0116e00472002780018000e820338300387002900052b20002220032a001390013c0023d0023e001410014600147001480014d0014e0045100d520045303d5500a5900363003640ed65004660046700168013690326a00c6c0246d0026e0106f0017000272011730018000481002820058300c87014880018d0038f006900229100420220013c0023d00445001470024b0014d0014f00851020520025300b5500158005590015d002620026300a64004650566600c680026901e6a0016c0386d0026e0057200580003810028200387005900073c20004220012c001390013a0013c0013d00641002440044500946008470094c0054d01a4e0054f00350006510085203a5303c540035500258009590045c0035d00c5e0015f0196000363012640306502366104670096800b6903d6a0356c0486d0746e035700027200e73003780017e0037f003800058101f820538301d8402a85002860038705b88009890068d0048f0029001a910093720001210012200b2b0013e005450014600b47002480024d0034e0124f001500015200c5301754037550195600a590025d0025e00160002640186600f671296805d690056a0496b0256c0116d0286e02e6f0347000572007730097a0017f005810238200183015840018502086013870188802a890098a0018b0018d0208e0038f0549000d9101892009242100122001460034700152001530045400a55005590025e00160002640026700268071690026a0156b0076c0046d00f6e0046f006700027200773008810028300185001860078700f8800a8d0028f014900019100792002950012120004220053d00346001470024f0035100a520015300e540015500d590025d0025f002630036400d65008690886a0036c0306d0036e0127204d7300580001820088300484001870168d0028f0029001791002342200c3d0013e0014600447009480014e00150001510015200d530115400b55047590025a0025e0025f001600066401265002660066700968028690126a0996b00d6c0186d0256e0526f01a70038720727304474008810028200383003840038500386005870398801d890188a0018b0058d00d8f0299001291026920049300b9500a3022001230083b00147002480075401c5500b56032590015a00d5e00b60003610066701068035690016a01e6b1386c0016d0136e0066f0787003d7103372004730227401d750027a0018100183001850138604087003880298900d8a02d8b00c8d0088e0088f040900019101792022930139400495012960021f4d0014f00251001520015300655007640036600267001680036900c6a00a6b0016c0466d0276e00b6f00170004720447300a81002820018600d8700988004890028d0028f0049000691005950012622001480015000152002540025500256001590025d0025e0046600267002680096a0116b0036c0016d08a6e00c6f00470009720027300f7a0018100a83001850018607b87011880118900b8a0018b0018f004900019100a9200193002950032b2200947002510015200353007540015502c56001590025e001640056600468005690126a0196b0016c0176d00e6e0916f00670022720887305f740057f0018100382007830068400385002860018702c8800b8903c8a0028b0098d0108f0119001e91022920039300995015342200623004480055300254014550295601a590035a00a5e0016100164003660016700c680216a01f6b0416c0016d00f6e0126f0da700607102c720127304c74063750077a0037b0017f00181004830028400185016860188700688046890168a05f8b0178c00c8d0138e0238f073900069103f9205c930439400d950259600c980032b2200123001480015502e5601b5a0046000461004680046a00d6b0206c0016d0016e0106f014700e671050720227304f74072750197b002810018500286006870058800b8902a8a0248b0d48c03c8d00c8e0068f0099000391012920259302194008950459601c9800b99001242300448002540025605e5a0055e0016100a680046a0016b0476f02d7000e712077300374061750b77a0027b00c81001850028600488004890028a0858b01a8c1158e0248f00b91002920379300c94041950059601d97011980040e55001690016b0016e00470003720687301c740018f001900019100693003950079600114550026d0016e0036f0027000b72003730657400886001890058b0058d0018f0029000191008920049300b9502b96006980071f2200155003560036a0016b0016f00a700257100f7200373024740e3750c48500188001890028a0038b0098c0098d0068e0058f0079100c920199305994046950449606b9702c9806f990279a0041256004610016b00170002710247400a751358a0028c0198e0059200393001940459601397072980059901c9a010035b003761237b0070277103780390e2c0014c001520015c0065d0035f01166003770107817d790627f00e820068408b890041a2c0014600447001520035d00b5e0075f0016001061001660016a0016e00378004791907a0287f0038100182002830018403e8500e86002870028902c8a0028b00114500025a0015e01060009610176b0017000276001790157a1917b02c7f00184002850048600488002890118a00f8b0208c001075b00161003760047a00b7b15e8b0088c01c037c0d97d038800010a440024d006630037c00e7d1697e051800fa8100383002900071b4300244002460024d0074e004530035d0045f00263005640066500166016670016e0037d0047e1ae7f007800708106c82004830498400485001860018700388001900130e5f00178003790087e0057f16681008820038300e8402a8503c870018800289004900011a44002450044d0095100253008580016300464015650086600167003690096a0016c00f6d0026e0057d0037e014800e28100a8200383039880038d007900c4910052f410014400145001460034d0024e0035300c5400355003580035d0065e0065f003640176600d670386800b690086a0136b0016c0146d03f6e00d6f0047000372002730017e01f7f0028001a811e6820038307b8400285017860738701788057890078a0018d02a8e0018f00b900419103d9200193003162b001450014f001530025c0025d0015f0066300d640056600e690186a0016d0036e00f70001720028210f83008840038702d9000491001273b0014600153006550075e0015f001600016400d660056700668005690066a0056b0016c0046d0066e01d6f00170002720087e003800098100c8200b831028400485005860028701b8801b890078b0018d0528f00d9008d9103992003930029500118470015000152002550025f0026000266008690016a0026d0076e00a78008790097f007810028200e83005840f2850048701d8903e8d00190001910012c4e001520015300155002560035d0015e0015f0016000567018680046a0096b00a6c0016d00e6e0066f01a70009710027300874001790047a0017f0238101b83028840248518f8604387012880848905f8a0218b0038d0398e0428f02790005910319202c93004940019500896001155e00367003680036a0016b0036d001700038100e850048610388014890038a0038d0028e0028f001900019100792001930049500328470025100152001530025400155004590025a0015d0015f001600026400f6600368006690116a0136c00a6d00b6e02b6f002700087201a7301f79002820158300a840038500386001870ac880058900e8b0028d0098f00a900189101e9200193001950062b46001520015300154003550035a0015e001600026700368006690026a00f6b00b6c0016d0136e01b6f0177002271002720177302f740077a0018100d83012840028500d8603487014880a58900e8a01c8b00b8d0298e0098f0299001a9105e9201493034940019501c960011f550025a0015e001600046a0046b0036d00e6e0086f0017001d7100273017790017a00281001830018401085004860038701988006890e98a0078b0408d0068e0018f0049100992008930059502b294800253001560035e0016000261003680016a0026b0156d0016e0026f01a7002371031730067400d75007790017a00b7b00483001840038502e860398804d8903e8a1868b0938c0458d0068e03a8f0099100a920669302b940309503b960179700198004990020c6000161001700047a0037b00587001890138a0138b0db8c03295011960070c71006750047b00b8a01f8b0028c1379300194005950019600b9700a98002126e0026f00273002810018300285003860028700388002890028d0e18e04a8f00990002910109200a93011960010e670016f0058501386003880068a0058d0048e11f8f0089201c930029400e950019600120530015400155004560016700368003690016a0036b0016c0056d0026e0076f0097000371002720117300c7400683003860018700788006890028a0018d00f8e0018f05b900049100e9200393004950051052001550016300165001690026a00172012730037e001800018300587001880018d00c90083910091f53002550036400168004690016a0046b0026c0036d0036e01c6f0077000f71001720497304a7400a8300a85001860018701188010890048d0508e0018f02990043910a29200f93033950379800528550035600267001680056a0016b0056c0026d0056e0066f0247001b7100b7200873050740637500d7f0018300185007860068700388025890128a00b8b0178c0048d0288e02e8f06d9000191044920bf930a0940379508896036970049802d990049a00122550026a0016b0036d0016e0026f00770010710017200e730407405b750148300186001870018800b890028a00c8b00e8c0038d03d8e01b8f01c90004910329204d930d8940409507196058970089806d9900d9a001186b0066f005700037101a74046750918500186002880038a0268b0028c01f8e0638f00191002920879302c9418d9501d960899706498049990469a0020e6e00170003730047400283001890028b00791006920039300c95047960039800699001176f00170006710057300774023750178a0088b02d8c02e8d0028e0018f00191003920149304e94057950679611f9709d980f6990769a0109b0020d71001750138a0018c0259200194027950019601a9711c98007990509a0409b0020f730027400b750038b001910019200293016940049500e9603497009980b4990439a00d9b0090e710017400b750218c00592004930079402f9500a96062970b1980b5991f29a1939b0af0775002960029704b980019904f9a1d69b25a029a00a9b0d20e003e60127c0208f0300f07136080140a0290b0020d00212002160042e0632f003300011b000020115d021780302a040020600c071d508077090010a09f0b00e0d00a0e001100011201a140011601b170051800225004260012e0fd2f022300013400138006490012c0100f021d7031e50401a062b407032081f0090b00a11b0b0a90c0050d0880e00c100b211002120b01300e1400f1604b170211801e1a0011e00325111260312901a2a0042e02f2f0923001c3101e3200134018350023700138012390023a0023c00243005490174b00258001620023d0200703165040e9050010601b070010805b091340a0060b0ed0c07a0d0d70e0490f002101091105612030130b51406d1500d1600617034180241900c1a0081c00c1e0092000125009260322700b290012a0042b0022d0082e0012f02c300353108b32020340193500b36001370203800a390093a01c3b0053c0053d001410024300745001490574a01b4b0114c0084d003580016500182001380300e0428105017090030b02a0c11c0d0040e0c20f01710002120021303214027150361702e180071906e1a0231b0041c0221d0071e00c1f00f2000b26004270042a0012b0012d02b300123100132025330023400a3500e360043702538001390103a0063b0073c0043d0053f00241001440074a0014b0034c0044f0085000152002580025900165001690022604011053bc0c0080e0550f2121500117008190241a02c1b0721d0011e0011f023200222101022010230022b00130001320093307337002390043b0023d0063e00842001460014c0024d0014e0094f0015000251002520025300264001650011003002060e90903d0b0010c0011000611005130042506f26013290092a0013100e320013a002430021103003070a1080140a07f0b0080d0070e00112027160241700218003250042e15a2f0183000a3400138001340200303046040220600c07006080e60905d0a00e0b0c10c0180d0260e014100ed1100f120d41304d140611500216009170221802a190031a0071c0071e00b250092602527003290032a0032d0012e0192f0973005331027320073406d3500b3700d380423900e3a00b3c00d3d001430064400145001490594a0024b00a5800162004310300506016080040911e0b0060c0600d0020f0011006d110cd120071307a140261501e17002190071a0011c0061d0011f0012400825007260512703d280022a0082b0052d00e2f00230002310863201934009350173700c380013a0203b0023d001410044300945001490244a0284b0084c00c5c0056300477001290600107003080050a1560b0260c0010d0680e005120621300114004160cd1702018033190011a0011e0012518e2604a290352a0102d0012e02e2f0a63002831023320023400f3500238042390013a0083c0044300b45001490184b0024d0015100162006630014303006040230500508008090080a0060b0d20c0320d0140e06e0f00310016110021206913079140c11500c16004170bf1805b1902f1a0381b0021c02d1e0441f0022000425007260a72701f290012a00f2b00d2d0412f00a3009b3100532025340973503e360033707538018390713a0283b0083c0283d00a410034300c440064500747001490044a0014b01d4d0084f0085100652001580125c0046200b63004650056c001800013e04001090060b0050c0d70e0060f00710002110061302c140211504b1700518002190341a00f1b0071c02a1d00d1e0061f016200042600d2701b2a0042b0082c0012d0ff300023202b3300434007350303600e3701d3900d3a0033b0183d00d3f00841005420024300244011450014600347002490014b0034c0064d0014f005520015c0015d0025f00163003640016500466002690016a0016d001230a0030d1720e015160061700a1804d190011a0051e00325002260042a0022f04c30018310d23201f340023700138018390023a0303c0084301645001490924a03a4b01c4c0094d00251001580015c00862004630086500140050040b0050c0060d0070e1640f00e1200115001160011703b180171906a1a0aa1b0021c0041e0241f011200262100122004260022a0082b0112d0043002331005320c833015340013500337034380013902b3a01b3b0213c01c3d03d4100343002440154500d4600147001490024b0164c0124d0184e0054f01650008510185200b53003580085d0016200563006640066500e66004690016c00187001900012e050040c0020e0040f14d15001170011901b1a0231b0be1c0011e0041f032200152101922012230012a0012b00e2c0082d0023200e3317037001390053a0023b00a3c0013d0193e028410014400546007470014d0054e0304f007510015200b53007540025d001640066500166003670056c0013503001040010600108003090190b0090c0150d0020e00110098110ad120051302e140301502f1700218002190021a0011c0271d0011e0032403b2600b270332800a2b0012d0062f00530005310103200a34016350103600237004390033a0093c0023d0013f0024100243004490784a0634b0144c00b4f002520015c01563003770207800329040010900b0c00e10007111dd13016140031505f190021c0021d0041f002242e5260052705d2806c2b0022c0012d0043100d320013500636009370023a0033b0063f0054100b4200543002490034a0c74b0024c02b580015c0245f001770fd7802c8200384001350400305001080040a0090b02d0c0060d00c0e00b10001120ed1301d14053160121705b18059190091a0121c00f1e02f2000225008260542700a290022a0082d0182e0012f0113004f310043200c340f1350233701638077390313a0123c0343d004410044300845005490014b0244c0014d0014f00351004580046203463005650097d0014604003090140b0070c0330e0060f0041000c1100a12006130d31405b150411700818007190411a00e1b0021c05a1d0071e02b1f018200032602427077280082a0032b00e2c0012d0452f00130011310033201a330013402135087360153709d38002390223a0323b0323c0033d00f3f01b4103a4200443003440044500946002470024a0014b00f4c00a4d0014f01150001510075200258020590035c0035d0045f00462001630026500380001820014f04001090010b0050c0180e0090f00510004110031200413021140ae1502e17014180081901e1a0151b0021c0f91d00b1e0341f00d2000e210032200126014270742801e2a0012b00b2c0012d0532f0013000f320123300134085350a5360263703538004390433a0183b0213c0173d0113e0013f03b400014102942003430054400d4500e4600747008490044b01e4c00a4d0054f0325100e5200257002580175900f5c0085d0055f00b6200963006640016500966001690016c004780017900182002840014d04001090010c0360e0010f003100011100d130161400c151d9190261a0041b00f1c0761d1711e0041f0852000d210082401d260022709f281f02a0012b0242c01b2d04e320153300235040360f33701d390083a0073b0793d0123e0083f097400504102a4208f44005450074601447011490014a0034c02b4d0014e0014f013500345200853003570155800d590125a0025c00e5d0145e0055f0106000163002640016600768002690016b0016d0016e0017700578015790077f0018200484007230a00a0d0060e0011200b162421703f180651a0041e00c2500426001292622a0892e0022f00d3000531003340033500138087390063c0154307c4400145005490024b0084d006510016203863005690017c1cf7d067800033b050020b0010d0010e00b0f001120011300114004160011709618018190131a01c1c0011e0181f002200052600127001290032a08b2b0152d001300133200e340023701a380043902c3a0063b0023c01c3d00643013440114501a46001490014b00e4c0024d01d4e0024f00c51008530035800a5c001620096300c640056500f66002690017c0077d03d7e0118001a820028300144050010b0010d00d0e0191200513001140071600e17052181201901a1a04a1b0021c0011e0ae1f0012000f2200126002290092a07a2b00a2d0032f005300413100632015330063400e3700a380613903e3a04a3b0043c0ad3d01141007430cd440324503747003490084b05d4c0084d0644e0014f02150002510335200153004580265c0135d0016206563061640076504e66006690086c0037c00d7d0487e008800208100182002900025604001050020c0060e00b0f0091300414003150051701218003190e81a04a1b0111c0071d0011e0321f06d20041210052200c270012a0022b04d2c0022d00630004320173301635002360023706938001390343a0153b0573c00b3d0633e00b3f004400014103f420054300144090450264601d4700c480014a0014b0094c0054d0264e01b4f03e5000d51016520145301054003550015803e590175c0045d01762001630076402d65016660176700368003690096a0046c00f6d0016e001720017d0037e01a7f0018000e81009820048300487001900014b0e00a0f0061700718004190141a0cc1b0091c0021d0011e0151f00f2009921003220172a00c2b07d2c005300023200b3300e34001360033700b3901a3a0063b00d3c0193d0443e0063f0014100243001440184502546019470134b0094c0024d0694e0144f025500025101f5200f530255400455003580115900e5c0035d0045f006600016200663015640146501f660156700768001690136a0026c0156d0036e002720027d0047e0397f0048000c810078200283008870019000450050010e0010f0341500317001190181a00c1b20e1c0011d0071f09d20042210ec220442300d280042a0012b0552c20e320093308c3600537001390033b0233d0263e1013f001410074203144006450054603a470324804f4c0054d00a4e0df4f0055002151002520365301a54030550185600758003590145a01e5d00b5e0055f00c60004610016401265002660116703768012690046a00a6b0056c0066d0076e0076f0027000178001790037e0097f09d810098200283007840168500e8700288003890038f003330c0011400515006190041b0031c0871d0651e0021f00920008210012200227004280032c0012d00334002350163603237002390023b0093d0063f07e400384100242005450014600e47009480044b0014f010500065100452005530035706158002590055a0065b0085d0075e0035f0096000665002660016c0037900284002220c0011500c1b0011c0021d1171f00921005280122c0032d005350013603d3b0083e0043f00f400bc4201d440014600b4800450013520015705a590045a0095b02c5d0045e016600056100376001790057a004870013e0e0021700318002190061a00d1e0821f00820025220022a0022b0082d001320023300234001360013700738005390163a00c3b0033c0163d0123e0043f0024105342003430014400f450194700a4b0054d00c4e0024f0225000351033520055301058030590115c0015d0075f008600016200b630096400e6502166006690146a0026c00f6e00172001800038100282005830038700389001900014c0f00415007190181a0041b0121c0011d0031e0041f0ad20010210192200f2300127002280012b00a2c00e320013300a35002360043700f390053a0023b0393d0263e01a3f00140003410174208d4400645005460324701c480064c0024d0014e00b4f0115002c51003520275300e5400d5500557002580075903b5a0085d0105e0215f00160004630016400f660036700968015690046a0116b0046c0076d00f6e0046f00470002720027f0018100182001830018400486001870038900446190011a00d1b0031c0011e0051f005200bf2100f22084230052b0022c0023200133009390033b0043c0033d0103e0154000141001420024400245004460084704d480044d0044e0084f00a5000551016520145302f5400c5502e5600258002590125a0105d0025e0025f00560010630036400b6500466001670086800a690156a0086b0026c01c6d00a6e0116f0067000272010730027a0018000181003820028300385001870058d0018f00190003440f002190011b02c1d0051f02620009211ac22033230ac2b0022c03f3300a3b0033d0083e0a54000342019440024603247016481014e0174f00150023520235300a5409d550265604357001590195a0bc5b0015d0035e0425f0016001c6103864005660016702e68042690016a01c6b03a6d0106e0086f01e700107100a72002730017400378001790057a0057f001840038500f860088700488005890078a0028b0018e0028f00e920022d1b002200092101c2216b230ee3e0074600147001480024e00451001520025300c540285506b560405a00a61001640026700768009690016a0096b00e6c0046e0066f017700117100772011730077400b750047b001810018500387002880018b0018d00a8e0068f008900019100192001241b00221020231e8330013e004460014800d4e004520015403e55001560c95a00a610046700a680086b02d6d0016f02271019730017400175009810018500688001890018b0018d0018e0108f00691001920029300194001950010424090280127703778007082509026016290132a00531009320014300363001320b0010c00212003130061400b1500118003190041a0021c0031e0042500e2611927033290022a01f2b0072d033310073200f330013400c35043360013700238002390013a00c3b0033c0043d0023f0054100743010440084500f470014b0044d0024f0015100253001580045c004620026300864001650016600269001390c0021100313004140041501b190041b0031c0061d0121e0011f0052400626008270cb2803f2a0022b00c2c0052d016320023400235026360223700b3a00a3b0193d0033f039400054102c4201443001440044500746003470054b0024c0094d0024f001500065200158005590065c01c5d0085f00d6000363001660036a00178006790017e0017f001820068400311150022400d27004280b52c004360013f00b4000f4201e50002570015e0015f001770047800c790028400709290e42a021430254400145001630017c1807d050800032517001180021a0031e0012900c2a1032b0312d001320053b0013c002410024301f440224502b4b0024c0014d02453002580025d0035f001620036301b640026500766003690016c002780017c0187d0eb7e0367f0018006583002900023c1b0012000221002270022a0072b0c72c0142d001320013300236001370013b0013d0013e001410044200443001440064500a4602147014480024b0014c0014d0034e0064f0035000852002530055400255001590035c0065d0045f004600036300164008650026600f6700168003690016c0016d001780017d0057e06f7f010800198101e8200683014840028500186001870049000116280012b0032c0a942008470034800550001540015a0025e0016000166001670026e00178001790037e0017f05481001830018400c850051615001190011a0011c00126001270012a0012b0012d097340013500c3600a370013b0013f002410014400945001460014d0035f00163002032e09e2f00d38001200b0010d02c0e004120031600317003180101a0011e00425002290032e0162f10d3003d3102f3200c370023802d390023a00e3c0064300b490904a0024b01a4c0014d001510015c0026200f6300182001370c0010d0030e00b120021300114003150011701a180181900d1a0221e01f1f00120005260012a00a2f006300c5310013201b3300434001350013702a3801d3905b3a0193b0043c0393d01441007430024400645007490084a0014b03e4c0024d0054e0014f00e5000151019520015300258014590016201c6300d640026501c66001690026c00380001191c0011e0012500326002290012a0012f002310df3200d3a0273d0014301545003490354a04b4b0074c00f4f001580025c0135f001620016300177001820013a0c0010e0011700318001190031a0051b0011e0031f004200032a0092b0112d0013000431002320be3300c350013600137004390093a0183b0163c0023d01841005430024401b450134600247001490014a0014b00d4c0274d02b4e00d4f00d500075100a5201253003580055c0055d0035f00662001630066400c6500d6600867001690026c004780017e00182002830011b1a0011e00121002220012b00132003330a33b0023d0063e0084400546003470014d0024e02650001520045300454007550015900164001650016600167002680016f00122100011200114006150011700218001190021a0021c0171e0032000326002270022d0043408e35014390093a0013c00c3d0023f003410014300244001450014b0034f008510065200158001590015f0016200a6c001400c002130021400c15005190021a0021b0031c0671d0071e0081f00320005250012600c27018280052a0012b00b2c0012d02a3200234017350bb3602a37007390073a0023b0093c0033d01d3e0053f07540006410084200243001440064500746007470054b0024c0024d0014f00d50002510035200153002570095800a5900e5a0025c0015d0035e0015f00760001630026400165002690026c00279001820023715009190011b0011c01a1d08c1e0011f00e2000121005220012700c280172b0032c0042d027330013501d3617d390013a0013b00f3d0053e0033f0ac400b54100a4202844005450024601f47013480034f0035001a5200353001570af58004590205a0085b0175d0125e0175f006600096100165001660016700169001780027900a7a00282001840013113001170011900f1a0011c0031e0051f007200042200126001270012b0042d00130001320023400237066380013900d3a0063b0133c0043d0083e0023f0014100b42001440054500246005470034b0034d0024f0115000151004520025300158010590065d00364002650066600168002690036c0017200187001360d0010e00212006140011600d170171802c190031a00c1e0382000426001290052a01e2b0012f0083002e31003320033300134005350013700438130390253a01a3c0813d002410064304e4400b45019490094b06b4c0014d0244e0024f0095101952001580145c003620e56302b640016502866001690046c0017c0037d0137e0028000882003460e002150011700718005190061a0131b0011c0041e0131f0092001e2100222006270012a0042b00a300053200233004340023701c38005390573a0063b0103c0253d0233e0014100942003430014400e4501946005470094b00a4c0014d00c4f0395000151033520035300f540015500158019590095c0015d0025f0016000162018630076400b650246600467001690116a0046c01a6d0026e00170001720057d0018000181001820038300187003490e00213001140011700418005190091a0091c0021e0331f00820005220012600427002290012a00a2b0132d0033000b3100e320303300134001350043700c3800d3901b3a12e3b01d3c00e3d0293f0014108742001430164402f450294600347005490064a0054b0554c0374d0134e0024f01c500055102b520095300758077590045c0445d0165f00e6200b630396400e650386601c690106a0016c0066e001780017d0037e006800018100182029840018700288001511300214001150021900f1a0021b0041c0021d0031e0061f03c20019210092200727003280052b0192c00b2d00332007330093500436003370133900a3a0073b0d63c0013d02e3e0153f002410594205f440104500b460444702d480044c0104d0024e0084f0205006751007520245300b540075500657001580235905f5a0065c0015d0365e0125f007600096300364011650066601a6700868015690106a0176b0026c0106d00f6e0096f00172002780017e004810068200b8300384005860018700d890018f001900012f1700218002190031a00a1e0142001522004290012a0052b0013200233001340023700438001390253a0043b0013c0973d0113e0014100543004440064501d470044b0134d0124f01a51066520025300f58013590055d001620836304764012650426600567001690236c0167d0018000782007900024914001190041a0111b0041e0051f00b2002b21003220272a0012b0092c001320023300d36002370073900b3a0043b0103c0093d0803e011410064300144010450134601947035480024b0024c0014d0124e0084f023500095102b5203c5304c5400a55014580245903f5a0075c0035d0075e0025f00860006620016300c64033650206601d67009680106903a6a0156c0476d00b6e0106f0017000272008730027e00380002810028200e8300587010880019000791002470f001190021a0011b0091d0011f00d2000c2105922036230122b0082c01d330213b0093d0103e10e400014200f440034500146040470254805c4c0014d0014e0114f0035000f510035201e5301a5404b550225600b57002590285a0975d0095e0165f002600176100e6300164014660046704168041690076a0266b00a6c00d6d0116e00c6f010700077100272003730017f00481002830038400385005860018700988007890038a0018f01090001910032b150011c0071d00d1f0012100127001280052b0032c00335006360253a0013b0033d0013e0023f0a64005e41002420074400145001460014700b480034f00350001530015708b580025900b5a0035b01e5d0035e0085f0086000c6700176002790087a001820038400288001141d0022800c360073f003400ce420084600148007570515a0035b0735e00f5f001600086100770001760077900d7a00d7b0011b1f0032b0022c001370013a0013b0053d002410904200b440034500246002470064f0015000152001580125900a5d0065f003630026500266003690047e00182005870031e1d001280052c006360023b0044000241001420c34600347006480045000655001590075d0015e02160003660016a0036b0016d0016e001780017900382001840028600187002880018900426180011a0011e001260012900b2a0392b00a3200338004390013a0093c00841001430f4440224503c490024b00b4c0054d0305100453001580045c0305f0066200263057640026500c66002690027c00e7d07b7e017800378200a8300190002351a001200012a0072b0192d0043200133002350013a0013b0033c0033d0023e00141007440da4501b46011470014b0014c0034d02e4e00d4f003500035100752003530105400158020590035c0035d00d5f00160002630096402a6500e6600f6700568003690086a0046c0026d0017d0017e02b7f0018001e81011820058300e84001860014d190011a0021b0011e0061f0022000d2100122005260012a00f2b01e2c001320023300135002380013900a3a0013b0023c0103d0103e0013f0014100e4300844025450a84600b4702e4b00d4c0064d01b4e00d4f02950005510225200e5302e5400255003580225900e5a0015c0065d0195e0025f04b6000562008630326404765024660236700468008690516a0096c0246d0056e0136f001720067300178001790017c0017d0067e0197f002800448100b8202783015840038700c9001991001531b0011d0011f0082000b2100e22009230012b01f2c0062d00132001330043600237001390013a0013b00e3d00d3e02341006420134401245007460c24704e4801b4d0024e0104f00c500225100352018530195401e5501856002570015800a590505a01d5d0205e0535f00f6005161005630016402565005660146702b68056690106a0346b00c6c0206d0406e01b6f00d7000d7200473001780027900a7a0087e00b7f0068000981030820038301084006850068601a8702288011890098a0018d0038f00a900059100692001950023e1b00120003210022b0022c0093d0043e0023f0014100142002450014600a4705a4800b4e0014f00650001510015200653007540015501756001580025900e5a0175d0035e0075f009600196100164006660016700268004690036a0096b0016c0046d0046e00f6f003700047200473002790017a0057e0017f00181001820088300984002850028700888006890048d0049000591002920019300130210052c0343e00640006420194600d47009480eb5000352004540045500b5602b590095a0585b0025e038600206107064001670076800b6a00b6b02d6d0016e0036f00d700137100a790097a0167f009810028400a85017860068700188005890118a00b8b0088c0028d0028e0028f004910019200295001170d0030e0012f004310123200338001390013a0084300745001490cf4a0814b0254c0144f0015c01e5f002620016300166001770297800d82002103100e320023a0054300345001490094a13e4c048520015c03d5d0015f00165001771cf780548200343140011700218002190021a0041c0011e019200132a0062b00830009310043200833001360013700338006390123a01d3b0043c0193d006410114301a4401b4503247003490084a0064b0b74c03f4d02d4e0024f051500085104c5200b5300658043590055c0975d0185e0015f0396201863028640096506d6602567001690096c00c6e006720017700578018790067d0027e0167f0038000c810028203e830048400687006900023c1e001200032a0022b00b2c001310013201c36001370013a0093b00c3d0084100b42004430014400e450124600d470084a0174b00e4c1974d00a4e0184f016500655100452072530035400258009590035c07b5d0485e0025f069600056300964002650076605d6700168001690066a0056c0036d0076e0037702078198790687e0077f0188100182043830028409a85001870078900229200022a0032b003330023900143003440104500946004470014d0874e0104f00151002520025301458005590015d0035f0026301664010650126601767003690086a0016c0036d0017d0097e07d7f0058003c8101f82006830148400285001870028d0019000a37210012200323001330073b0013d0013e002440014500146006470054c0014d00d4e1384f002500035201f5300f5401e550045d0035e0015f00460001630016401665004660146703e6800b690016a0026c0066d0086e0086f00378002790047e00d7f1538000281019820068301f8401c85035860018700488002890028a0018d0048e0018f0039200147190011a0011e0031f00220008220042b0022c00236001390053b0063c0053d0063e003410024200344001450094600c47016480014b0044c0054d0024e0044f059500505100b520165300e5500557001580085901a5a0035c0015d0265e0085f0456001d61001620016300264006650136600e67003680036901c6a0096c0236d00b6e00a70001720107300378001790267a0077f0028100382008830088400d85001860038700e880018900990008910022d150011b0012000221003220012c0023b0023e001420094600947005480044c0054e001500a9520175300154001590035a0035d0235e0285f007600166600a67003680076a0096b0016d01e6e0027000278003790747a0137f00381005820018300284014850068600387003880038900b301a0011e001200052200a2b0013300137001390023c0043d004410014400145004470064b0034c0014d0024f00c500015105f520075300d5500258003590045c0015f004620056300a640156501366008680026902e6a0026c0266d0016e0087201380003810018204383008840028700f8f0019000c91001441e001200012100322006230012b0012c002330013b0043d0093e002410014200144001450034600147003480014c0044d0024e0074f00450011510045207753011540105500c5600158001590075c0015d0055e0015f0086000363001640076500166016670056800c690086a0196b0036c0096d0266e01a6f00170001720087300378002790037e0037f00b810028200d83004840588500b8701f88006890158d0058f00490003910063f1f0012000e210022202823001330023d0063e00144001450044600447006480024d0044e0084f0035000251008520105307b540125504d5600158001590075d0026100163003640246500c6601267011680146901e6a0296b0016c03b6d0116e0336f00a7000a710017203e73014740027e0027f0068000c810118200883034840018500586001870148800b8d0378e0028f00f90053910149200295001421b002200012100c2200f2301c330023d0033e0154600c47006480164d0014e019500065201f5300e540f35502d56064590075a0135d0015e00d5f0016000a6100364007660036709c6808c6a03c6b0736c0026d0316e0156f079700297101572004730137400f790037a0017f017800018102782004830168400a8508f8603e8701388031890178a0158b0058d0258e0378f066900029101f92012930069400195006960013122001230043e001470014800552002530035400855057560435a0026100364002660016700168007690016a0036b0176d0026e00a6f0177002371010720127300f7401075003790018200183005840018500b86003870058800a890058a0098b0018d03f8e0308f00f900039100a9200d9300a95004960039800120230043e001480055400d55001560a55a00761003670016a0026b0256f019700017103b7400a750097a0017f001810018500d86001880058a0158b0038c0018e0838f00992011930019400e95001970011c1c0021d002280023600b3b0013d0013e0013f02f4007f4200646003470024800c5000457166590075a00c5b1a15d0055e00d6002061013760d7790087a01a7b00486001870014518001190021a0021b0011e0071f00320004220042b00430001330013500137004390043a0043b0053c0043d00e3e0013f0014101e4200443001440064500c460084700a4b0034d0074e0024f020500055101c5200a5300955001580845901f5c0015d0125e0045f00e600016200163004640106502c6600d67001680026902d6a0066b0016c0216d0086e00a70001720047d0017e0057f0018000681004820088300487006880028f00190002451d0011f0022000721001220092b001390013b0043d0053e0033f004410054201444002450014601a47020480094f0065000951002520065300754004550125700158005590815a0165d0115e05e5f0086003b610066300164003650026600267007680216900c6a01e6b0096c0186d01e6e0156f0067000a71001720147300a790057a01481001820038300384006850018601187019880068900c8a0028b0028e0018f002900029100995002392100322004230022c0043e0083f001420034400146003470074802b500025200153001540095500f5600b570015900b5a0b25b0015e0175f00160021610b2670016800d6a00a6b01b6c0036d0036e0056f01370012710097200673002740037500176001790017a01f7b03281001850038600388005890078a00c8b0098c0068d0018f005910019200393001950030a2c00140014570085a0015b1566000161007762a37a00e7b01430190011a001220012a0012b0052c00132005390013a004410054300e440064501946002470054a0074b0024c0264d0094f00250007510015200a580025c10e5d02d5f05a6000163017640016500266035690016a0026d0016e0017700c780607900d7e0157f00a800088100282050830048402989001900014e1a0011b0011e0031f00120003210022a0012b0072c00636001370013b0083d0043e0064101d4200e440134500d460354702d480064b0014c0094d0054e0074f0075004851002520145300f540075500856001570015800f590195a0085c0095d12b5e0355f07c6004b630046400c650046602d6700f6800d690176a01f6b0026c00f6d0356e0226f00270003720017300578007790877a0097e0087f00680006810278201d8301784030850068600b8702e8800b890178d0018f0019000791002920012b2c0013b002400034201a4600847002480125000352001540015600157004590035a00b5d00a5e0c75f0026003c6101c67002680016a00c6b00e6d00b6f0017000a750017900d7a05b7b0077f001810018400285003860258700288009890128a0078b007910039200195001331e00120002220012c001320013b0014100145007460044700a4c0024f001500055100152003530035500158003590025a0015d00f5f07a60014630036400366007690036a0036d0046e00a6f0037200173001780097905b7a0087f00481004820158300a8402685004860018700d88002890108b0018f0019000691002920014221003220012b0012c0013b0013d0013f001420044500146006470224801e4f0015000b5200153004540025500c5600557004590175a0355b0045d0075e0465f01a6010f6105b6400467003680076a0196b00b6c0036d0106e0196f00f7001c710017200c730067400176001790307a1347b0337f0038100382003830078400d85010860138701888023890488a0158b02c8c0048d0048e0018f005910069200393003950041d400014803155001560095a01b5b00d5e0126000d611876a0016b0176d0016f007700117101f7600c790037a0757b258850068600288004890088a0288b0308c0378e0018f0019500217170011e003200042b00138002390023c0153d00143001450014f00251020620c663012640036501366001690036a0016c00180001820059000130180011a001200022a0032b00135001370013a0013c00a3d0064100143006440034500d470014b0064c0014d0144e0014f008510275200353017580065c0065d0015f00462014630bd6401c650236601167001680016904e6a0046c0126d0016e004720037d0047e004800208100182043830078700a900123a190011e00120002220072b001330013b0023e00241001440024500246001470094d0044e0054f004500015100a5200353027540025500a58004590055d0015f001600016200163005640806500b660086700b68019690276a0176b0016c0296d00e6e0146f0027000172012730037e0027f002800098100b820068301c850018600187012880038d0088f009900329100d331a0011c0011e001200042200330001390023b0013c0053d0024100143001440044500b460014b0064d0054e0054f00b5101c5200453011550055800a590035a0015c0015d0085f005620056300b6400e650386600e680036902a6a0036c03b6d0026e0057200f7d0017e001800058200a8300384002870018d0019000f91005441f00120004220042b0032c0013a0023d0043e00141001440024500b4600a4700f4b0014c00a4d0224e00e4f0055000e5100752034530405500c580065900b5a0015c00f5d01b5e0025f02760008630116403965011660c5670176800c690286a0276b0036c0336d05b6e0446f00170005720107300777001780047900c7a0017e0127f009800118102e82057830398404b850078600a870478800d890118d00c8f00e9002891012930013f1e0012200823002330023e00342001450014600747001480044d0014e0204f00150002520095301654037550205600958001590035a0075d0055e0045f00160007640146600d670e668039690046a0346b01f6c0096d02b6e0186f0327000a710027200873008790017a0027e0037f01580004810418302184007850508602087018880428900c8a0028d02d8e0108f0599000b910229200a9300395005342100122001230013e001420014600147004480014e0015100152002530075400c5500956002590035a0035d0015e0015f00261001640076700868044690026a0136b0126c0056d0096e00d6f00d70006710017200b73005810018200283002850068600a8700488009890058a0018d0048e0028f00f9000491009920049300395001311f0012000122007390013d00146001470044b0014c0014f0035100c5200453018540015501058001590065a0015d0015e0015f002600026300b6401465009660046700168006690666a0106c0406d00a6e028720707300b740017f00180004820138300d850018702f88006890038d0068f007900419100b92001412200f3b0013d0013e0024100242001460064700e480014d0014e00150008510015200e5300e5401055051560035900e5a0075d0065e0045f002600086100163001640126500266005670196802a690146a0766b01d6c01f6d0496e0586f0297005b710047207073062740127f00181012820068301284008850088601a8703c8802d890458a0058b00e8d0248e0038f0429002c9105b9200d9301394001950149600231210012200123009460034800c54022550115604d5a0095e008600026100e6400167010680276a00e6b0d76d00a6e0066f06d7003a7106d720027301b7403f750117a0057b0018100284002850138605e8700788027890138a04d8b0188c00a8d00c8e0258f033900019101292037930169400f9501a9600d970012c220073b001470034f003510035300c5400355008590045a0015d0016000164004650036600367003680056900f6a00e6b0056c0346d0196e0186f00370008720537301580002810028200183005850038600e8700e88009890078a0018d0068e0018f0059000d910069300295002304100147001500015200253002540025500256002590015a0015d0015e00760001640016600168009690016a00b6b0056d0486e0086f00370005720047301274002790017a0027f0028100a830018400185003860668700b8800d8901b8a0048b0058d0058e0028f004900019100a920039300194001950053320001220043e00144001470044d0024e0015100152002530085400155026590025e0025f0056000464003660046800b6900e6a01e6b0056c0176d0136e05b6f01170043710027208673071740117a00282008830108400b85007860058703b8801a8904a8a0068b01f8d0278e0018f0189002a910379200893015950189800336200012100222006230063e0014800353002540105502e5602b590015a0095e00360003610056700d6801f6a0176b0586c0036d0196e00e6f0a770065710587200e7305174089750197a0057f00281005830048502886028870048804b890268a07e8b0398c0198d0308e04e8f0799000591041920839305e940299502f96020970049800b99002312200123001470014800154003550255601c5a00360002610066700468004690026a0046b01b6c0016d0066e00a6f0207009571075720157304374076750367a0027b00a8300185009860078700988012890228a02c8b0ca8c0678d00a8e0158f01390003910229203c930399401e95053960279700a980179900720230015603a5a00461008680016b0286f0147000671147730017403b750d87a0017b017850028600488002890018a0518b0108c11f8e02f8f00391001920379300a9405895008960279702f980079900615460015100154001550036c0066e0057000a720397301c74002870018b0018d0038f001900049100492001930089500c96001980061855001680016b0026c0016d0016e0086f0027000971001720027302d7400f88001890078b0078d0048f0049100b9200593010940029503796005980121e5400255005560046b0066f00c700167101a7200473013740a6750cf8600288005890028a0098b00c8c0118d0048e0108f00d910069202c93050940609503b960809705c980a39905f9a0140f7101174002750ad8a0018b0018c0198e0039200194025960149705d98001990179a0229b001027604d7b002034a001770487800e0a520015c0015f00277016780d37902e7f0088200284032890021346001480025a0015d0045e0055f002600096100178008790d37a0217f001840268500886001870018900e8a0018b001115e00460002610106b00170001760037900c7a0c07b046850018600388001890068a0098b0158c002920010661001760067a0037b08a8b0028c009027c0477d00e09440014d002630017c0147d0d57e02380066810029000415440014d0045c0025d0035f00163003640016600a670027d00c7e0e97f0158003e8103982002830298400685002880018d0019000e0a6600178001790047f07981002830028400a85015890018d0011e2a00144001450044d0045300a5d0025f001630066400f650026600667003690076a0016c0046d0016e003720017d01a7e02f800c681013820048303e87003880028d0068f0019007391003352b0014500146003470014d0014e003520025300b540065500458001590015d0075e0025f00160001640086600d6702d6800d690026a0176b0046c00a6d02c6e00b6f005700037200673005790037e02a7f0068001f811578200283063840048501a8608e8701288047890028a0018b0018d02b8e0028f00b9003c91020920039300395001202b001470014c0015200253001550025c0015f00b600016300864005650016600767001690076a0046c0036d0046e00c700017f0028000181001820938300b8400787019890068d0018f001900049100129470034e0025300254002550035f0046400a65001660056700768002690026a0066b0016c0016d0056e0126f0027200573006790027e0047f0048000a8101882009830a4840028501186005870108801b890088b0018d0528e0028f00b900659102993003950021d48001520015e0015f00360001660026a0026d0046e005700017300177001780057900f7f01181002820098300284091850058700c88002890248b0018d001910029200193001950022c4e00155001560045a0025d0015e0026000661003640016600167007680036a0026b0076c0016d00b6e0056f01070004710017200173004790077a0087e0017f03181016830168401885106860338700c88040890358a0218b0068d0308e0408f013910109201b930069400195009125e001670016b0036f0037000273001740017a001810058500586068880098a0038b0018e0018f0029100493001303d0014700248001500015100152001530045500958001590025e0025f00160002640096600667001680096900f6a0126b0016c00b6d01a6e02e6f0017000e720197302774001790037f001800018100482012830138400a85003860058707f8800d890198b0058d00e8f017900179102692001930079500a3047001480015400455007560025e001600046400166001670086800c690036a00d6b0096c0026d00e6e0176f02870029710047200c7303d74015750027a0028100b8301e840048501e8603c8701788089890248a02e8b0208c0088d0468e0148f03190010910519201e9303b9400495030960089700298001285200254001550015e0016000566001680016a0076b0046d00a6e0066f0037000f7100372001730087400375001790067a00a830018401585007860068700d8800b890908a0158b04f8c0018d0038e0028f005910099201093008940019502696003980022448001560035a0015e0016000261007680016a0016b0096f00f70011710277400a750097a0117b00a810018501f8602888020890178a0fc8b06e8c0908d0018e02f8f004910059203a9301d9402c9502a960259700c98002990020e6100171002750017b004890068a0058b0658c0339300194002950059600397001990010971001750017b0088a0098b0028c07294001960039700d14670016e0016f0017000172001730048300285003880048a0018d0578e0418f002900039100792006930079400595003960010b740018500a86001880038a0058c0028e0658f0029200b9400e96002234700155002660016700268007690016a0016b0026c0026d0016e0066f009700097100172002730167400d81002830018500186004870048800c890038b0018d00b8e0048f04291010920109300d940039500a96002980011844001550036000164001690046a0026c0036d0016e0046f001700027200b730057400280006810018300987004880018d0078f0049005291012930012b540025500460001660016700268003690016a00a6b0036c0016d0076e0156f00d7001c710027203f7306074019750028000181004820018300a85004860058701b880228900b8a0088b00b8c0018d0688e0048f02c90035910889201a9305a940029504896008980139900127540015500256005680036a0026b00a6d0026e0036f01a7001b7101a720027302d74077750247a001830018500a8600e8700188018890158a02c8b0278c00e8d0268e06c8f034900019102e920b5930a2940709507a960699700f9805b990129a0012455001680016a0016b0026d0016e0056f008700127100d72008730287404f7501c81001830038500288008890038a0108b0118c0088d02b8e0278f01a900029102592057930ab9406d9507c9608697025980a59901f9a0039b00117560016b0026f001710137401e7507b85001880018a0188b0078c0298e02d8f0039202d9300f941009500d9605e970be980309906f9a0199b0011070004730047400686001880018b00a8c0018d0029100292005930059400295028960099800d990011771002730037400e7502288002890018a00e8b0308c0258d0018e0038f0029100592014930269404495041960d9970b1980d0990d19a04a9b00d0a750078a0018c01994007960069706b980029902f9a0539b0171270001730027400a750078a0018b0028c0028d00192004930119401095010960219701a98092990619a0319b0100d740037501e8c00592002930039401a9500396034970a1980459914d9a1f89b189058b0019700b990109a0cf9b20b019b01d12003e5012be020bf03018040030719c080290a0430b0020d0051000112006160062e09a2f00f3000334001490011c0000301109021370303b04004060170718f0808a090030a0ca0b0180d0140e0021000412029140011602a17003180021e00125006290012e1372f028300013400238001490012c01021021d4031dd04031062e10705e081dc090ba0a1590b0a70c0080d0c20e012100ba11007120d71301614017150011608b1702818022190012519f2603e27002290322a0042e0772f0c93002431038320023402b3500138030390043a0043c00343005490204a0014b0026200445020110313804119050050604a0700308068091650a0180b0e80c0aa0d0d80e05c0f00210121110921204e130b21407b150241600c1704f1802c1900f1a0111c0151e0121f004240042501c260522701828001290032a0072b0012d00f2e0032f04a30044310b232025340273501b3701e380103900b3a0233b0033c0093d0023f00241001420014300e4400145002490744a0534b0124c00b4d0024f001580025c0055f0016200363001770014d03010041fd05055080040900e0b02d0c10b0d00a0e0d70f029100041100512002130401402f1505017032180141907e1a0371b0081c02b1d00f1e01b1f0192000f21001260132700c280012a0052b0032d0492f00330019310033204233007340093501a3600737032380013901e3a0093b0123c0023d00a3e0023f004400014100743001440084500146001490024a0014b0074c0074d0064f004500025100352001530015700158003590015c0016200363001640016500266001690016c0013004015053680c0060e04d0f215170081902a1a0291b0901c0011d0021e0031f02d2001d2101a2200f230042b0032c0022d0033200d330a63600137006390033b0053d00c3e00e3f001410014600247002480014c0024d0034e0134f0035100152004530015400155001640026500166002670017e001830010e060550901e0c0011000311004130022503d26008290062a0023100643003490014a00215030010704708005090010a0570b0010d0080e002120111602f170061800125004290012e0d12f01530005340033800a390013c0013c0200c0305e04036050030602c0700f080bd090870a02b0b0d70c01f0d05b0e01b100ee11013120e11306014069150031601e1704818030190031a00a1c00a1e013250212605027005290042a0052b0012d0082e0332f0ac300693104d3200a3407c3500f3700d38052390133a0143b0013c0133d0014100143005450014906d4a0064b0124c00251001580015c0056200d63001650013703004040030601e08002090bc0b0070c03b0d0031003b110eb120051305c1401b1501c18001190071a0011c00a1e0021f00124017250102604f2703828003290042a0062b0022d0122f00130001310823200d340033501a360053700838002390023a0193b0033c0013f003410014300e45003490254a0514b0034c00c4d001580025c00b6300277003250400107004080070a0b60b0190d0610e004100011204f1300114003160d81701b180351a0031e0012512d2602a290522a0072e0422f09f3002a310323401035001370013803c390023a0033c0064300f450014901f4b00362010630014f020010300a04029050080600208013090160a0080b0c50c06a0d01f0e0970f00d1002f110091207f13097140cd1500f16011170e518088190481a0401b0031c0461d0051e04c1f0092000725018260bd2702e290072a02d2b0092d0612e0062f029300b2310113203f33004340bc35059360073707b380423906c3a0453b0113c0493d00e3f005410134301c4400c4500a460014901c4a0014b03c4c0044d00b4f010500015101058011590015c00662024630096400165008660016c0027d0028000182001480400305002090090b0010c0600e0010f00c1000311006130231401215060170021902a1a00a1b0051c02f1d01c1e0061f01d200072100422001240052601027033280072a0042b0182c0012d0c930004310063202533005340073502336017370163900f3a00a3b0133c0013d0083e0013f00c400014100d430024400e450064600347003490014a0044b0024c0094d0044f005500015200158003590015d0035e0015f002620026300164001650016c00177001240a0050b0010d0dd0e00a160081700518037190021a0011e0032500826003290072a0052f02530016310a4320173300138011390013a01c3c0043d0014301945002490764a0314b00f4c00a4d0015c00c620026300165001820014f040060500b0b0020c00b0d00d0e0d90f026130041400415003160031703a1801a190761a09e1b0071c0081d0011e0291f02020024210012200126005270022a00a2b0162c0012d00a2f00230022310033209c3303035007360013702638007390323a01c3b0173c0163d0333e0023f0034100b420014300a44016450084600148001490044a0014b01c4c0194d02d4e0084f01c5000b51013520125300958013590055c0085d0025f0016200763006640086501066003690036a0016c0056d0026e001820023b050040c0040e0050f0cd1500217002190151a0181b0ae1d0031e0011f02420018210262200e230062b01d2c0172d0033201433101350013600337002390023a0023b00e3d0123e0303f00144003450034600b47002480024c0024d0064e0394f00250003510015200d5300354006550025600158001590035a0035d0026000164004650016600267003680016a0026c0016e0014203003040080600308004090190b0080c01c0d0010e00410074110d012009130371403e1502b1700418009190031a0031c0271d0031e0052408325001260142703d2800d2a0022b0022d0072e0012f008300083100d3200c3402f3501b360043700e38004390073a00e3b0043c0033d0013f00d4100d42001430054400145002490764a0904b0164c00f4f00251002580035c0105f00462002630036500266001770447800624090040c00b10002111141300b1400115035190011d0051f002242b927040280662a0012b0032d0023100832001350043600437004390013a0013b0033f002400014100742007490014a0974c0155c0165d0017716f780238400144040030700208009090040a0160b02e0c0090d0140e0110f0031001011004120961301e1404e150031602e1705f180751900e1a0191c00f1e0351f001200022501c2606c2700a290152a0262b0032d01e2e0032f0203006f3100f3201333001340b13502c36001370143807a3901f3a0253b0013c04d3d00341008430204400845009470014900c4a0014b02e4c0014d0074f00551005580085c0015f0016202e630096500a690017d00154040090600208001090150b0060c0420d0020e00c0f00510011110211200513099140501506c1700c180061903f1a0101b0041c0601d0181e02c1f01820008210012400825001260252709b2800d2a0042b0172c0022d04730013310083202a330023401e3507a3602937090380053902a3a0493b0463c0103d01e3e0033f02a410514200a430084400b4500f4600547008490074a0064b00c4c0104d0044f0145000551005520045700358025590025c0115d0085f0046200463009640016500666004690026e001770027e00180002820045c040030500308001090010b00d0c02a0d0010e0170f0041000c1100e1201013033140851505a17019180121902a1a0321b00e1c0f21d0211e0461f01d20020210042400726033270822802d290012a0122b0212c0022d0673001731009320193300534096350cc3604a3704a380103905b3a0233b02a3c0253d01d3e0043f070400054102f42006430094401e4501d4600947009480014900a4a0054b03b4c0164d0074e0024f038500035101952002530055700958033590135c0185d0075e0015f011600026200e63010640016501166004690076c00577003780097e001800018200a840015704001050010b0020c0360f00711019130131400b151451700118001190211a0041b0131c0521d17f1e0041f075200102100a220012404026007270a4281f72a0012b02d2c02b2d06330001310013201d33006340013503c360fc3701d390063a00c3b06c3c0013d0113e0083f0a94007c410334208b44007450094601a47018480044a00a4b0044c02e4e0014f011500385100152016550015703b58008590125a0015b0075c0185d01a5e0075f01660006620016300164003650026600568002690026c0026d0027700c780347900f7f003820048400c87003220a00c0d0080e00212009140011617d17024180491a0051e00a2500726001292542a05a2e0032f00f3000a3100732002340053806d390023a0013c011430524400245005490064b0064d0036202b6300b7c2767d05d44050030c0060d0030e0100f003120021300314006150021600417053180191901c1a0231c0051e01b1f00620007220012600327001290122a0752b0242d0052f0013001631001320103300434005350063702738009390383a00c3b0083c01e3d00c41005430164401e4501c46003470034b0134c0024d01c4e0024f0105100f530015800a590025c0045f0026200f6300c640076500c690026c0017c01d7d0587e01980019820018300248040010b0030d0110e01f0f004120011300314006160151703e180c4190141a04a1c0051e0821f0012000b25001260082902b2a0ac2b0172d0072f00b3003c310193202f330033400e3500837016380743903f3a04c3b0093c07c3d01b41015430e24403f4503746001470014900f4a0054b0754c0124d0584e0054f0165103a520035300258029590015c02e5d0035f003620716306464006650326600c690066c0017c0267d0937e01b80021810028200c870015c050020c0090e0100f01813004140041500e1701518002190971a0371b03b1c00e1d0051e0331f0852004c21011220132600127009280022a0132b0662c0092d01730005320223302f340023500836006370623903c3a0193b0673c0113d05e3e01d3f004410514200c430014408e450264602e4701f48001490024b00c4c0154d0354e01a4f031500155101f52022530265400455004580445901a5a0025c0035d01d5e0045f0036000262001630096402a6501e660146700568004690146a0046c0106d00a6e00472002790017d0047e0217f006800118100d82008830018400187004900015b0c0010e0160f00a140011500317006180091901d1a0811b01d1c0021d0011e0281f016200802100b22032230012700328002290012a0132b06a2c00c300073200e3302934001350033600137016380023901d3a00a3b0103c0223d0433e0103f0024100842003430054402445024460184701a480014a0014b00c4c00d4d0564e01d4f02e5000a510225201853028540025500758022590155a0015c0075d0055e0015f00b600046200b630196401b650386601c6700868007690166a0066c01c6d0036e00270001720027d00e7e04d7f00a80027810108200b83007870038f001900025c050010c0010e0020f04f150071900e1a0171b1631c0031d00c1f06b2003c21116220552303127002280072b0422c1c72d004320063309e350013600337001390033a0013b0203d02c3e1073f00240005410034203c4400e4500a46047470234804d4c0094d0084e0ca4f00b5002e5100552041530145404d550165600d5700258004590185a0295b0015c0025d00b5e00c5f00e60008610016300364009650036600c670336801d690066a0106b0046c0086d00a6e0026f00670001720017300178008790057a0027e0087f0ae80001810078200683007840108500f8600187004890028f005480c0020f00113001140041500f190031a0011b0011c0611d0731e0021f00b200072100322001270052800d2b0052c0022d00930001320023300134004350283604b37002390053a0013b0083c0033d0063e00b3f08140050410094201044003450084600c4700e480044b0034c0054f00d5000f51004520045707558005590155a0065b0235d0095e0065f00b60009620016500666003690036a0026c0046d0046e00177001790078200484001870028800190001290c00111001150061b0031d08e1f0022100227001280182b0022c006360213b0013e0063f00c4008f410014201446003470014800b500105200157040590015a0075b04f5d0015e01360006610036d0016e001700017600778003790097a0077b001860018f001530e0020f00115002170031800b190151a0171b0021c0061d0011e0561f00920029210012200626002270022a0092b00a2c0012d0023000532009330013400335002360023700938008390133a0113b00e3c02a3d01a3e0033f0034103442004430094400e4501e4600647011480014b00f4c0074d01a4e0064f0225000551032520085301455004580265900b5a0015c0045d0115e0015f007620136301964010650336600b67004690156a0046c01a6d0016e00772002790017d0037e0088000c810028200b83004840018700290002590c0030f0041300115007190141a0021b0281c0011d00c1e0051f072200192102a2201a2300726001270022800e2b0142c01f2d0043200333010350013600737008390043a0023b03a3d0203e02e3f0024000141015420944400a450044603b470274801d4b0014c0094e0144f00b5003f5100952027530135401555008560025800b590325a00e5b0015d0175e0295f0036001261004630016400c650066600f670116801e690096a0126b0056c00c6d0156e00d6f0047000372004790047a0037e0027f002800028100a830028400e850018600587005890018f00391002551a0041b00a1c0011d0011e0081f00f2008d2101e220af2300a2b0082c00830001320013300b3500137002390043b0053c0043d01a3e01c400014100342003440054500a4601547045480104b0014c0014d0034e00a4f01950009510115201f5303c540175502f560075700158005590265a00d5d0055e0085f0126001461004620016300364019650076600767013680106901d6a01b6b0066c02f6d00e6e01a6f004700067200e73005790057e0037f0048000481005820068300b8400385005860018701088006890038d0038f00990006910034c0f002190021a0021b0321d0061f016200052113722029230e02c05d330123b0063d0073e0934000c4201e4400146028470154810b4c0014e0284f0015002252025530085408f550235605c57005590115a0b25b0055d0095e02e5f0016002561072640066500166003670366803c690016a01f6b04d6c0026d00e6e0086f025700107101772001730047400575001790077a00f7b0047f00981005830028400b850188600a8700888007890088a0068d0028e0058f00a910039200393001372000a21015220c5230de330023d0013e00a47003480084e0054f0015000352005530105402e550595605e590015a00e5e00260002610026400166001670056800e690016a00c6b0136c0056d0066e0086f0217001d7100c7200c7300f7400c750077f00183001850068700188006890028a0028b0018d00e8e00b8f0069000491005920059300395001241b0022101122001231293e0024200146002480094e003500015401d560a65a0026100467008680056a0016b01a6d0016f012700017102a74004750097f0018500386001880018a0048c0018e0168f00391001920029300195002042401d280037701678003072502926005290052a001310013a00143001350c005110011300914005150031700118003190021a0011c001250132608c2702228001290062a0272b0082d02b310063200d33001340043501d360023700238001390033a00e3b0023c0013f0024100743010440054500847001490024a0024b0054c0034d0044f00151001580015c0055d0015f001620016300764001650027d002820013c1100613003140011500d1a0011b0021c0071d00c1f007200012100124011260062705c2804d2b00e2c00b2d01032002350143602137005390013a0043b0073d0023f0264000b410154202143002440024500446002470034a0034c00a4d0024f0015000952002530015700358004590045a0015b0025c0175d00b5e0015f009630026400166004770077800e7900380001820078400212150011d0012400d270022804a2b0012c007360013f0024001142010570025b0025e002770057800e790048400105290542a00b4300f7c0aa7d01c240f0011a0011b0012900f2a0772b017320013b0013c00141001430104401a4500b46002470014b0024c0014d00e5000153001580025c0045d002620016300d6400265001660016c0017c0227d0da7e0237f0018003982001830023d190011b0021e0012100127002280012a0032b04c2c0122d0013200133003370013b0033d0013e00141005420094300144004450074600c47011480014c0044d0024e005500045200153003540025500158001590035a0015c0055d00a5f007600016300364002650016600a67004690016d0016e0027200178002790067d0047e0597f01c8001c8100b8200383008840038500187001900011124001280022c0394200548003500015e00160001610016a0016b00278002790017e0017f0288400685004111d0012a0012c0012d0293300135003360033a0013f0014400245001460024d001510015c0015f00164001022e0352f005220a0020b0010d01f0e00212001160041800f1a0011e0022500226001290042a0022e0132f08a3002131047320063400138028390033a00d3c0064300c490814a00a4b00c4c0024d001580015c0016201163001650014a0a0010b0020c0020d0020e00e0f0021100112003130021400416003170171801e190171a0221b0011c0041e0251f0032000c250012600327004290012a0112b0042d0042e0012f00b3006531005320203300434008350023702c380213903f3a0273b0043c0343d0163f0014100a43011440144500d46001490114a0024b0374c00b4d0104e0024f01c5101a5200258015590025c0035d0015f0026202f6300c640046503066004690046c0046d0017d0027e0018000382002132500326001290022a001310453200b3a0094300645001490154a0404b0044c009510015c00c63003770067800284001410f00114001150011700218001190041a0011c0011e0021f0042000122001270012a00c2b00f2c0022d004300013100b3204d33010350013600137002390053a00d3b00c3c0013d00e3e0013f001410054300a440124500e4600447002490034a0064b0074c02e4d0194e0044f00d5000b510075200e530075800a5c00d5d0055f0056300b64008650076600b69004780027d0027e0068000181002820058400287001191e0011f001220013200133038390013d0033e0044400546002470014d0034e017520015400458001590016400266001670046c0016d0017e0017f00180001340e0021000212002130011400c15001160021700318003190021a0081c0191e0051f001200022600327003280012a0012d0063000431001320043404335012360023700838009390073a0033b0013c00e3d0053e0013f0014000143003440014500647002490014b0064f00450001510055700158004590016201363003650056c0014d0f001130051400f15008190041a0021b0021c0531d01d1e0061f0092000d2600727019280062a0062b0082c0022d0313000132006330033400e350723604d3700538001390043a0083b00e3c0023d0123e0033f07d400074101342008430034400845017460064700b480014a0014b0044c0044d0014e0014f017500095100352003530015701f5801d5900f5a0015b0025c0045d0075e0025f011600036200263007640026500466005690066c0026d00478001790027e0018000182007870033e0c0010f00114001150111b0041c0111d0771e0011f00c20003210042700b280262b0092c00c2d0183300135017360fd370013b00c3d0043e0093f08f400f54100b42039440064500546018470084800d4c0054d0014f0055001652002570e0580025901c5a0095b0325c0015d00d5e0215f00f60014610066400166002670016a0026d0027600178002790117a0067e001820038400186001870023d0e001130021400315003170041900f1a0051c0031d0011e0041f00a200052200127004280012a0022b0022c0012d00130001320073400435003360043703438001390113a0063b0183c0063d0103e0023f0054101942007440044500646006470044b0054c0024d0064e0014f01450003510075300655001580135900b5d0045f00163004640046500e66004690046c0056d0017e001820013c0d0060e003120061300114005160131700f1802a190041a00e1e01f1f001200042600227001290152a03c2b0022e0012f00d30020310073200633002340093500137003380ab3902e3a0263b0013c06d3d007410054306c440114501c490134a0014b05d4c0034d01b4f00651017530035800e5c00a5d0015f001620c26302c6502266002690036c0027c0107d0257e0048000e82003560e001130011400217009180011900d1a0201b0021c00d1d0011e0211f00c20021210012200e2600127004280012a0062b0143000832013330093400835002360013702838004390353a00d3b0173c0243d0323e0043f00241015420024300444022450284600d47014490024b0134c0084d01e4e0064f0495000c510435200d5301c550025803a590215c0075d0125e0025f00a600036202563018640156504c6600967002680056901d6a00a6c0246d0066e003720017300178001790017d0037e0027f00280007810018200b83002870039000491001500e001140041500117006180061900b1a00b1c0021e01c1f0082000d220012600527002280012a0162b0132c0012d004300033101532024330043400435006360013700f380093901e3a0b23b0173c0143d0183e0013f0014105a42004430214402e4502c46006470064900e4a01a4b0324c0474d02c4e0084f02b5000b510195200e53008580495900a5c0675d0145e0025f01b6200e630326401a6501f66024690106a0016c0086e0047200177004780067d0087e00a8000a810038202a8300584002870039000159150051900c1a0051b00e1c0021d00c1e0041f03520009210112200c2300127003280072b02f2c01e2d0053201133008350063601137010390053a00f3b0863d02b3e00f3f008400034104e4207a440134501b4604a47038480104b0024c0194d00c4e0144f0175007951009520375301f540115500c560015700658027590515a0095b0015c0075d04e5e02f5f01c600126100162001630086401265008660206700a6801a690136a0126b0036c0136d0186e00d7200478004790047a0017e00a7f001800058100d820108300384014860058700f88002890069000591001470e00112001140011700418006190041a00f1c0051e0281f0042001f210012200426001270012a00a2b0052d0013000532005330063400535001370053800d3901d3a0063b0023c0723d0203e001410084301044015450254600247007490024b0244c0034d01c4e0054f02b5106d52002530175500158029590045c0015d0035f00362072630496401a6505a6600a680026902d6a0026c01b6e003720067d0057e0048001a810018200c8300487005900075d0e0010f002140011500118001190031a0071b0081c0051e00b1f015200422100a22039230052a0012b01a2c00a2d002320083300f3500136002370063900f3a0073b0213c0073d0553e01b3f00540002410124200643001440144501c4603d470444800b4b0054c00a4d0194e0244f0345001a510395203a530575400d5501f5801d590565a00d5c0035d0115e0075f00e6000b61001620036301364056650256603c6701a680246905a6a02b6b0026c0506d0246e01b6f002700027201a73002790027e00c7f00380009810118201d8301484007850028600587015880028d0038f003900119100456150011b0161d0031f00e200072104722027230392b0062c02a3301b36001370013b00c3d0083e0aa3f00140002410014201944005450024603b47027480664d0024e02c4f0045001552017530145406d55030560235700758001590255a08e5d0055e01d5f00460022610196400c650016600a6705e68051690026a0226b01d6c0056d0246e00d6f01f7000671001720047300578002790057a0057b0017e0017f00b800028100882002830088400a85014860088700488006890078a0038b0018d0058e0048f01690003910019200393001950039600128150021c0051d00c1f00327003280132c00335007360183d0013e0033f05a4005a4100242012450014600247006480084c0044f00150002550015708c590025a00b5b0455c0015d0025e00b5f00c60009610026a00176009780027900c7a0068200584002141d002280032c001360013f00340066420044800357021580015b08b5e00660003610057601978001790087a0137b0028400131190021f002280012b0022c00132001370013a0043b0073c00140001410324200b44001450014600147003480014b0014c0014e0014f00250003520015300158003590045c0025d0065e0035f00260002630016500166003690026c0016d0046e00272001790027f0018000181001820048400287001890018f0011e24001280072b0012c00a3b0023f00140005410014203646002480054e001500015200157001590035d0045e0145f00160007610016e0017000178002790057a00184007860058700189003241a0011e0012900f2a02d2b00631004320033c003430814400c45017470014a0054b00a4c0064d022510035200153001580015c01d5d0015f0066200163023640016500666007690016a001780017c0267d07a7e0148002682007381a0011f0012a0082b00e2d0023200133002390013b0043c0023d0013e0024100843001440544500f46006470034c0014d0184e00c4f00351004520075300654001550015800a590045a0015c0035d0085e0015f003630096401665008660086700468003690056a0026c0016d0027d0097e02d7f002800118101682004830088400185001870028800190003561700118001190021a0051b0011c0021e0071f00320011220062a0192b01c2c001320033300336003390053a0083b0073c0033d00c3e0023f0044100c42003430174401a4507c46015470304a0014b00b4c00f4d0264e0124f01f50009510245200d530235400455009570015801f5900f5a0025c0175d0215e0015f0526000962006630466403c6502066029670086800b690386a0086c0276d00a6e0106f001700017200878003790067a0027c0027d0187e0287f005800588100f82038830288400c85003860028701188002890028d00290026910055c1b0041c0011d0011e0011f00f20008210162200d23004280012b0102c0113300435002360043b00e3d00f3e01b3f005400044100a420244400e4500c4606c47037480384c0034d0034e0164f00b5002c520205301c540295501356008570085800b5903f5a0345c0035d02c5e0725f012600466100f630016401e6500366019670296805d6900f6a0366b0166c0146d0476e01b6f01370010710027200773003740027600178003790217a01b7b0027e00d7f0088000b8103c82004830288401285018860368702688021890148a0028b0018d0098e0018f0139000a91008920029300295002481b00121001220032b0062c00c3b0033d0013e0043f00140001410014200744001450024600c4702c480144c0015000451001520045300554002550115600457002580015900d5a00d5d0055e00f5f00e60024610076400665001660066700568005690066a00e6b0066c0066d0076e01b6f0047000671002720057300878002790097a0037b0017f00780005810058200b830078400b850048600487012880058900a8b0018d0038e0018f0049000691004950013a21002230032b0022c01d3d0013e004400064200946002470024806d4e0015000154006550055601857004590035a0325b0055e02b5f002600176107c6700a680076a0066b0226c0016d0046e0016f0097000d7100a730027400278001790107a0257b01b7f0128100182001830028400d8501b8600a8700188007890158a0128b00a8c0038e0058f004920079500196001190e001180011e00130001310113200238003390013a0084300b4400145001490734a07b4b00f4c011510015c01662005630046500266001770397800c820020c31002320013a001490034a07f4c0195c01e66001771207802582001840014f0e004140021700318002190031a00c1c0021e01c20005220012600127001290012a0082b0082c0012d0013000a310043200e3300534002350013700a3800b390163a0213b0073c0203d00d41012430164401c4503146003470084900f4a01d4b0894c0454d03f4e0094f0365000c5103752014530075400158034590045c09c5d01c5e0015f03a600016201e6302a6400a650486601f67004690176a0026c0116d0017700d78031790087d0087e01f7f0078001081001820238300584007870028d00191001490e0011e0011f00322001280012a0032b0092c004310033200f3300336002370013a00c3b00d3d0023e0013f001400014100b420034300144008450104600b4700b490044a0224b0064c10d4d00b4e0214f00a5004251004520385300458003590035c05e5d02f5e0015f044600066300964005650046603768001690026a0016b0016c0026d0076e0036f00172002770587819d790597a0017e0087f03080004810048202b830078405f8500387002880018900390001301a001200012a0012b00332001330023d00143003440134500246001470014b0034d03f4e00a51003520035300a58002590015c0035d003620026300f64006650086601567004680016a0016c0056d0026e002720017d00e7e0617f007800418101f820038300a84002860028700488001890018d00290009382000121001220032b0013300a3e00241001440014500146003480014c0024d0014e0854f0015000452007530035400f5500156001590015d003610016400b650026600b6702b68003690016a0046b0026c0016d00a6e0056f0017300178003790027a0017e0117f0d780003810168200283014840158501e8600287001880038b0018d0048f0039000492001501a0021c0011e0021f0022000c210022200d2b0033300236002390063a0013b0063c0063d00a3e0063f00140001410044200744008450094600f47017480034b0044c0084d0024e0094f03550028510165201a5301c540065500c5800e5901c5a0085c0085d01e5e0175f03e6001e6100162002630066400f6500e6600f670066800c690136a0166b0016c0306d0146e0116f002700057201a730027800b790307a00a7b0017e0037f0088000681009820158300d84012850038600287013880058900b8f0039000c35150011b0011d0011f001210042c005330013b0033e002410014200a4600b47004480074c0014e0054f001500495200b53001540025500157001590045a0025d01a5e0285f00f6000d610046600467002680026a0036b0046d00e6e0036f001780087905b7a0187f00581002820018401685006860048700588002890088a0018b00292001431a0021e0052000422018300013200138001390033a0013b0013c0083d0083e0023f00141002440034500546005470084b0054d0084e0034f00b50004510495200c5301c5400255008580055900d5a0025c0025d0025f00b6200a6301f6401a6501a6600a6700468003690336a0096c02a6d00b6e00d6f00170001720167700178001790037e0027f00180008810048203f8300b840028500187018880018d0018f0039001591005491f00120003210032200c230032b0012c0023b0033d0053e0053f001420044400445003460074700b480024c0064d0034e00c4f002500135100352031530125401a550115600458001590085a0025c0025d00b5e0065f00660005630016400a65003660186700f680116900b6a01b6b0026c00d6d0196e0186f008700067200a7300a74001780057900d7a0047e0047f01880001810048200f8300b840618500c8701588004890218d0048f0089000a9100c9200295001501f0012000b2100222031230042a0012b0022c001330013b0013c0013d0053e00a410014300144001450044600f4700f480024b0014d00b4e0124f0035100f5201353053540185505b5600858004590095a0055d0055e0025f0076000861001630056402f65006660136702968028690316a02d6b0096c02a6d0216e03a6f0197000c71002720557301674009790027d0017e00c7f00f8001b810228200b83050840078500d8600a8702c88018890098a0038d02f8e0048f02a9005e9101a92004930039500696001461f002200022100c220122303d2c0043d0033e00f42001450014600a47005480184d0014e01a4f002500065201553007540ae5502a56077590075a0225d0015e00e5f001600146101764009660046707f6805e6a02e6b08c6c0016d0156e00d6f0787002a7102a72008730147401d75009790087a0047e0017f0308101a830098400e85097860498701188037890228a0258b00a8d02b8e0648f0609000191018920279300694003950089600298002362000121001230012c0013e001470014800252001530025400255026560365a0035e0015f00160002610036400167009680056a0066b00e6c0016d0016e0036f0107001a71016720077300c7401675008810028300485007860078700288005890088a00c8b0038c0028d0228e0318f00b900049100b920089300c94003950089600598002990011e230044800354002560455a00161006680026b0146f00a700027102d74006750107b0017f0018500686004880018a00d8c0088d0018e0418f0029100292010930029400b9600297001980011c1c0021d0071f001280023600c3f0194006f4200646002470044800a52001570f8590055a00e5b1bc5d0065e0105f002600126100c6d001761937900c7a01a7b012890028b00152190011a0021e0061f0022000d2100222001280012a0012b0062c0022d001320023300237001390023a0033b0073c0043d0113e0013f00141026420054401b450124600c4700f480014b0044c0044d00a4e0044f0195000a5101552012530145400255008570015804b590215a0035c0075d0225e0015f01260007620016300e640186501d6601d6700568005690226a0096c0336d00d6e0136f0017200978001790057a0027d0017e0117f0018000a8100782013830068400385001860018700c880058d0018f0019000a91001521b0021f00220002210032200c280022b0012c002360013b0063d0033e0063f003400034100b4201a4400145001460164702e480114c0014e0034f00750012510015200f53012540085501f5600557007580015905b5a0355b0045d0145e0665f00b600566101963001640046500166008670086801a6900e6a0276b0196c00e6d0266e0286f009700187100272019730137400376001790137a01e7b0037f004800018100d820088300c8400c85005860168701f8800a890158a0068b0088d0048e0038f007900079100b950033d2100622007230023e00840001420024600447003480224e002500015200153001540065500d5601357002590085a0745b0075e0175f00160018610936700868007690016a0096b01c6c0016d0046e0056f01570010710137200373009740047500476008790017a0397b06a7f0018300184001850078600b870048800c890058a0158b00b8c00e8d0038e0018f006910069200493004950040740006570045b07861006761d67a0067b00e2e1f001200012a0012b0033100132001360013a0043b0014100243005440074500f470034a00e4b0024c01f4d0054f0015000252005580045c0935d0145e0015f032600016300c64001660186c0016d0016e002770147807f7900c7d0017e01a7f00d80008810028202c83001840228700290001521b0011c0011d0011e0011f00420002210052b0042c00833001360023a0023b0073d0013e0023f00340002410154200a4401545004460254701b480094c00b4d0024e0074f0075003951001520185300a5400455004570035800e590175a00e5c0095d0b65e0395f0556004361001630016400d6500266024670136800c6900b6a0176b0036c0076d0236e0186f00870006720057300378021790a87a0187e00a7f011800038103282018830228404e8500c860128702c880098901a8a0018b0028d0068f0049000891005920022f21001230012c00240002410014200846003470014801250009550025600257003590025a0075b0025d0045e0575f0016001c610276600167001680036b00d6d0086f00270009710037300276001790127a06e7b016810028400585007860228700488007890178a0098b0148f0019200193001950032a200012c0014100242001450044600447007480024c0015000452001580015c0025d0085e0035f0376000f640016600267001690046a0016c0016d0026e0076f0017800a7904b7a0127f0078000281002820128300d8401b850078600287007880038900c90003910024421003220022c0033e0034000242006450014600a4700e4801b5000b5200453001540075500d5600857007580015900d5a0325b0045d00e5e0385f014600b26106d64002660046700368005690016a00f6b00d6c0036d0176e0116f00c7003071003720077300a7600878004790387a11e7b07a7e0017f0068000181005820028300e8401985016860168700d8801c890358a0278b03d8c0078d0028f00990007910099200993004950031d48010560055a0085b0125e00b60008610ca6b0036f005700047102173001740017602c790017a0487b1fe7f001840018600188001890068a0218b0288c02c8f0019200393001950021e180011e0012000122001290013000138002390033c012430024400245002460014b0024d0044f0015100d530035800262065630196500d66001690056c0057c0017d0048000482003900013c0f0011a0011e00320003220012a0012b004390013a0033b0013c0023d005410024301044008450184600547004490014b0044c0024d0144e0074f007500015101852008530115500258008590015a0015c00b5d00b5f010620146306c64021650156602068002690236a0046c00e6d0026e007720037c0037d0147e0107f00180034810048204b8300b84002850018700a8d00290017451e0012000122006230012b00233001390013b0023e0024100342001440054500346004470054b0014d0074e0094f003510075200753021540085500858001590025a0025d0025e0015f0026000563006640396500b660096700d6801c690296a0146b0016c01a6d0136e0146f007700027201b7300774002790017e0077f00580013810138200b83028840018500686002870198800a890028b0018d0118e0028f00f900339100b9200195001411b0011e0022000b220072a0012b001300013200133002390023c0033d0054100243001440014500846004470034b0034c0014d00d4e0074f0115000251023520065301554002550065800f590035c0045d0075f008600026200d6301764023650336601567002680046902a6a0076c0356d0076e00a700017201573001780017d0017e0087f0018000c8100682018830098400287006880048d0029000f910019500152190011b00220003220032a0012b0062c0013b0043c0013d0083e0013f0014100242004440094500a460094700d480014b0014c00c4d0174e0104f0055000e510095203653024540095501158009590065a0045c0135d0265e0075f0256000c62001630176402c6500e6607f6701e68016690236a02c6b0016c0206d04b6e0366f00c7000771001720117300d7400178012790197a0037d0027e0277f02080026810528205d8304a8406085014860148704988014890178b0018d0158e0018f00b90031910149200193003940014322006230013d0013e00342001440024600647007480024d0014e01c4f001500055200d5300e5402f5501856013590045a0085d0025e008600096100264013660086707968037690016a0306b0326c0096d0186e0176f03e70011710067200b7300b7400775001790037a0017e0057f01e800038104582002830258400f8505d8602f8700b88026890198a00b8b0038d0328e0248f051900089101e9200e93006940029500496001392100122002230023e001460014e0015000252003530045400e5500a56006590055a0085d0015e0036000261001630016400766003670066801d690016a00f6b01b6c0056d0106e0046f00b7001171006720087300e740037a0037f0028100582001830058400285006860148700d8800e890078a0068b0028d0078e0018f011900049101092008930059500498001421e00120005220102c0012d001330013a0023b0023c0013d0063e0024100245005460034700d4d0044e0034f0095000151016520095301e540025502058004590055d0055e0015f00b600016300c64028650086600b670086800c690596a0126b0016c0506d0106e03e6f0027000e7207f7301f790017e003800098100582014830178400285002860018703b88006890078d00f8e0018f00b900709101f9200193002950034e2100422007230012b0022c0013d0013e0024100142002460074700b4800a4d0024e0054f00150007510015201053010540155504d56015580015900e5a0145d0095e00b5f00760015610096300164015650026600b670266804a690116a0656b02d6c01a6d0456e0566f0527007f710107205473082740277500178001790037a0057b0017f003800018101b8200b830288401b8502986035870508805f8905a8a01c8b01f8c0018d0488e00d8f0649002b91062920259302b9400795037960069800334210012300e420014800b5400e5500a5604e590015a0075e00c600086101a6700a68008690016a0096b0946d0076e0026f0447002c7108472003730167403e7502d7a0137b0097f0028100283001840018501c860518700288027890248a0658b02b8c0228d0078e0418f01e9100c9203b930259402f9501d96010970049800499001412000322007230012c001350013d001440014500146004470054f0045000351002520025300f54005550155600158002590085a0025d0015e0015f001600036400865002660046700668007690176a00e6b0016c0226d0146e01e6f0067000e7205b7301a7400275001790037a0027b0017e0018000281009820078300e840048500486012870108800e8900d8d00d8e0028f00d9002a9101b92001930079500196001334200150003520025500656001590035a0015d0015e0025f00160001630016600167003680066a0056b0056d01f6e00b6f009700067100172001730117400475001790057a0018100582001830028400785009860368700988012890108a00b8b00c8c0018d0068e0028f00590002910119200393005940019500b9600198001432000122007230013d0013e001450024700448001520085300f54002550265600458001590055a0065e0035f00b6000c610016400865001660096700968016690116a02a6b00d6c0166d0146e0576f01c7003e7100972080730727401e7500378001790057a0057b0017f00280002810078200b8301984014850138601187038880298904f8a0158b0388c0058d03e8e0068f031900339103c9201e9302794004950309600c980073a220062300746001470014800752001540155502856040590015a0125e0075f001600086100e660016700e680176a0126b05b6c0016d0156e0146f0817005e710887200b73056740b4750557a0067b00c7f0028100683008840028503b860368700988050890288a08c8b0428c0568d0398e07a8f05c900059103392094930639404f9504596042970129802d990059a00139220012300142001470014800252001540055501756019590015a0065e00260005610146600167002680046a00a6b01c6c0016d0076e00a6f023700627106e720147304974058750607a00a7b0127f00181002830018400185007860128700a88017890218a0438b09c8c07c8d0238e0248f01e900089102b9204a9304794040950579603d97016980269900f9a002202300348001540015601c5a001610096b00f6e0016f00d70003710ae74022750ad7b018810018600288003890018a0338b00e8c0e88e0298f00292019930059406d9500a960219703698004990119a0011c540016600167002690016a0046b0016d0016e0026f001700077202c7301d7400787001880038a0028b0038c0018d0038e0018f0029000491008920029300e9500b9600198004215500167001680016a0016d0016e0016f001700057100272002730177400e7500184001860028700388003890078a0018b0148d0068e0018f00590001910109200b93013940029502a9600b9700298019990042156002680016b0046e0016f0047000d71024730067407c750c67b00184001850018600188003890038a0098b00f8c02c8d0068e00d8f00b910099202993044940769503c960949708a980b6990949a04d9b0060d71006740017502b8a0018c0078e001930019400a9600697038990139a0209b009017600f027700f780020a5f0016a001770107803e7900e7a0017f004820028400c870010e2c001480025d0015f001600016100178006790447a0167f0068400f85007860018900b0d5b003610057100176005790037a03d7b03685001880018a0028b00d8c007920010561001760027a0017b0168c002027c00c7d002074d001630017c0177d0577e016800239000113440015c0025f001660036e001780017d0087e0617f0118001c810158200283009840018500386001880018d00191001057f01d810018400485004890012220001470024d0074e00152002530045a0015d00362001630046400d65001660066700468002690076a0036c0016d0046e004730017c0047d0227e02a7f0028009081019820018302c84001870018d00690037910013744001470014d0034e00250001510015200153004540025500456001590015d0085e0015f0016000364006660076701768008690056a00a6b0036c0026d01c6e0076f00670002710027200173007790027a0027d0017e0207f01180012810c5820038303c84003850188605787012880358900a8a0048d02a8e0028f00f9001e91018920029300495005252b00344001450014700250001520025300155002580015d0015f0056000263004650026600a68001690056a0036d0016e0086f00178004790027e0027f0028100282047830088400f850018700c890018d0038f0019000691004920013044001450014e0015300254001550015a0015d0015e0015f001600016400365002660026700668001690016a0056c0016d00a6e0046f00470001720067300875001790017e00d7f0108000b8101382007830448400485016860098700d88015890098b0028d0288e0058f00b90024910149200593006950021847001560015e0015f003600026d0026e00178007790127f0128100182001830018403c8500a8700688002890168a0018b0018d0019100193001950012654001550015e0016000361001670046b0036d0016f0037000371003730047400175002790047a0087f0218100b8200183003840108506c86012870058801e890178a01c8b0048c0038d00f8e0418f0079100b92011930039400595005960010c6b003730018100486029880038a0048b0028c0018e0069100393001950013e22001410014200144001450014600247002480014c0014e0015000152003530025400255009590015d0035e0025f0096000964008660066700368004690056a0106b0046c0076d0146e01c6f00d700117201a7302374006790037a0037b0017e0017f0018000281007820148300f840108500e86004870448801e890248a0038b00f8d0248e0018f0169001e910309200693010950129600298001374200147001480025400455005560025a0045d0015e001600076100467006680036a0036b0116c0016d00b6e0096f021700287100d72006730347401c75002790067a0067b0027f00380001810128200183013840038501b8603d8700d880548901f8a0438b02e8c00c8d03c8e02a8f024900089103992035930429400e9503d9600e970019800e990012950002520025400155001560015d0015f00160001610036a0026b0026d0046e0036f0017000e7100273004740047900b7a00f7b0027f003840098500a860088700688005890458a00d8b04f8c0088d0048e00490001910049200e93005940029502c960079800421560025e001610036b0036f0017000471017730017400875010790017a00e7b00a830028500e86019880118900b8a0628b0398c08a8d0028e01c8f0029201b9300f9402d950179601e97017980049900a9a0010c7a0027b002890028a0018b0208c01e920019300194001950029600297004057b0028a0028c01b970069a0010f730017400185002880018d01a8e0148f00190002910069200393003940019500296001980010b750028500186001880018a0018e0119200594001960019700199001262300252001540015500167002680036a0026b0056c0016d0036e0056f00b7000b71002720047300d7400e7500181002830048500786005870028800b890058a0058b0078d0178e00a8f02290003910169200c930159400d9500c96005980062046001530035500264002650016700168002690026a0026c0036e0046f0027000472011730047400180002810018300f860018700288003890028d00f8f0059003e9101592001930069500398001990012c53001540015500a64002650016700268001690026a0076b0066d00b6e0156f0187001c710077201a730407403275008810028200283016840028500b8600c8701988027890198a0088b0158c0018d0648e0128f02e900299107992032930549400b9506496016970019802b99003245400356002680026a0016b00a6e0016f00d7001a7101a720037300e740597504b7b0037f0018500586004880128900d8a03e8b0378c0248d0148e0638f01e900019101d9207f9308a9408b9506196095970359807b990329a00121560016a0026e0046f0067000c71006720017301e7404b7502f83002860048700288008890058a0138b01e8c0128d01d8e0228f01a90001910169204493084940629505d9608197047980ce9904d9a00f9b001137100c74008750698a00e8b0068c0298d0018e0208f0019201093008940869500296049970b9980179906e9a0359b00a14700037100174003750027a00188001890018a0028b00f8c0018e0018f0019200293005940049500c9600c98015990069a0021571004730017400875019880018a0078b0138c02c8d0018e00191002920119300c940329501996075970bd9806c990cc9a0a29b03507750018c0079600197026990159a04f9b0250e74006750068c0029000192003930089400b95008960259702498060990809a05b9b0440a750058c008920019400a96016970609801e990b09a1b79b24e0497001990029a02b9b0e0019b00214003d9012c5020e70301f0400306001071b6080340a0610b00b0d00c1200a140011600f18001250012e0c92f01030001380011b0000e010da021230303a040030601f071310806f090020a0b40b0130d0180e0021000a12028140011603e1700318002250112e10a2f0303000734003380033c00149003310000101047021a8031b50404a050010627e070a7081b5090a20a16b0b0ad0c00e0d0d80e018100ba1100c120d5130141402d1609e1702218034190041a0031c0021e005251a02603927001290462a0022e0d92f0e63003831042320033401a350033803a3a0063c0044300845001490364a0024b0064c001620044d02019031280410f050090609c0700708085091570a0280b0ec0c0970d0cc0e05e0f006100fe110d01206e130be14063150251601c170511804a190111a00d1c01e1d0011e0111f00420003240082503d2605b2702028002290112a00e2b0022d0112e00e2f05530054310d83202733001340493501b360023701c38021390173a02c3b0053c00a3d0043f00341001430124400445002490784a0714b0114c0094d0034f0015100152001580025c0036200463004650016900177007780018200150030170416f050a80600208004090150b03b0c0f90d0090e0b20f05b100171101f12005130461402c1505d160021703f180161905f1a0401b01d1c0291d0171e01d1f0232000f21001240042601e2701b280032a0062b0092d03f2f002300183100c3204c3300e3400f35026360113702d38008390163a00b3b0123c00a3d0133e0013f00441004420024300344004450024600247001490084a0054b0094c0074d0034e0034f00b510075200458009590015c0015f001620036300265002660026700177001820013704020052d80b0010c0100e02d0f1d9110011300414001150031600117008190291a0261b0911c0011d0031e0041f029200142102f2200a230072b0072c0062d007300013200b330af3500137003390033b0053c0023d00a3e012410024400146001470024c0014d0064e0154f0045000452007530045400258001590016400466002670016a0027e0010b06018090081000111003250152600327001290042a001310064300114020010600107026080080a0350b0060d00a0e0011200e1602017001180032500526001290032e0632f01730002340023800444010020201c030700405905004060730701f080c9090d90a0560b0cc0c0380d07a0e03c0f002100f411034120e113075140721500a160531705818048190091a0121c0111d0011e01224002250652607d270112901b2a0142b0012d00d2e0572f0b530075310733201c340853500e370163805d390163a0143b0023c01e3d0013f001410024300d44002450014908c4a0124b01e4c0034d0024f00151005580035c0016201163002650013c030050400206013080020905a0b0030c0220d0050f00110022110b1120031302a1400b1501a17004190031a0031b0011c0081f002240322501426031270232800b290062a0082b0042d00c2f0013000231042320073400635009360033700d390033a0103b0043c0023d0013f002410054300444001450034901d4a0664b0044c00e4d0014f001580015c00b5f001770137800382001280300104001060010700808005090010a0680b0090d0580e002100021203714003160b71700e180291a0011e002250c226013290522a0092e0362f061300103101f320013400c37001380313a0023c0064300b440014900d4a0014b00551001620077c00554030180406505011060040700308017090360a0170b0a80c08f0d04d0e0b30f0171004c1101b12087130d3140e41502c16030170e31809f190601a06e1b0051c0711d0091e0691f00e20012210012400125037260be2704528003290202a0402b0152d08b2e00b2f047300cc310373206433009340cc3506d360123708b38070390843a0573b0123c0533d0203e0023f00e4101142001430334401645017470034903e4a0114b04e4c0104d0114e0024f02351019530015801e590035c00a5d0015f00162031630116400465011660067d00353040020900d0b0010c0440d0010e0040f00a100021101c120021301c140191504c1700618004190281a00e1b0121c01f1d0231e0081f01a200062100124008250012601327034280112a0072b0112c0022d05630005310043201b3300934006350263602437016390053a00f3b0183c0023d00b3e0043f00f400014100b42004430034400f45006460044700348001490034a0064b0034c00b4d0074e0014f0035000951001520055700458004590045c0095d0055f002630016400365001660036c0017700178004800018400187001260a0030d05c0e00312001140021600c170011801a190021a0031e00125009260022900e2a0092e0012f0233000a310553200534002370013800d3a00d3c00641001430144903d4a02e4b0094c0054d0015c00a6200463001770047c002820015d030010400a05026090010a0010b0040c0100d0110e0970f03d1000112003130061401015010160031703018029190651a0691b01e1c0091d0031e0291f0192002c210052200f23001260072701128002290022a0172b0222c0062d00c2f0053002b310093207f330403400b3500b3600237036380093902e3a0233b0243c01b3d02d3e0063f003410104300e4401f4501646006470054900b4a00e4b0134c0324d0324e00b4f01a500085101e520125300458014590025c0105d0045f0016200e6300e6400b650116600667001690026c0016d0016e001780017d0057e0037f0018000382001840014704001050030c0020e0030f07415005190191a0111b07f1c0011d0031e0031f0282000f2102e2200e2300b280012a0032b0142c01b2d00a320093309e340013600137002390023b00c3d00b3e0263f00141005420034400c450044600847002480044c0024d0054e03f4f00650004510035200b530095400755002590035a0045c0015e0025f00160002630016400565002660036700568002690016c0026d0016e00172002780017e0047f0038100184002450300304009060040800a090220a0010b00b0c0150d0040e00710056110b11200a130291403c1502f1700618006190081a0021c0241d0071e00b1f00120001240b82601c270392801a2a0052b0022d00f2e0012f0083000831028320083401b350143600a3700738005390063a00a3b0033c0063f00841009420014300744002450034905b4a09a4b00d4c0194d0014f00251001580025c0165d001620026300165002690017706b780087900122090030c0061000111068130041501f1c0021d00a1f001241c8260042701e2803c2b00131002320023500636006370013a0043f003400014100442003450014a0514c00f570015c0135d0016300177134780168200148030060400605003070030800b0900a0a01e0b02a0c0120d0250e02b0f0021001c1100712071130241404a150021604917057180611901c1a01a1c00e1e0261f002200052502d2606f270122902d2a03b2b0082d0232e0112f03c300693101f32023340a23502e360013701438090390323a03a3b0033c0473d0063f0034100a4302a4400a4500d470014903c4a0084b0324c0024d0064f00a5100d53001580125c0066204963011640016500f660017c0037d00c5e0400e0500306003080010901f0b00c0c04f0d0010e0120f00a100141105a12006130701404215082160011700e18007190451a0151b00e1c05e1d0281e01a1f01f2000d210032402e250022603b270a728031290012a0112b0252c0042d0772f0033000c3100c32032330043401f350803603a37070380043902a3a04b3b02f3c00a3d01b3e0023f04240007410454200a4300e4401445013460054700d4900e4a01f4b01d4c0264d00a4f0135000b5100652004530025700858022590075c01c5d0085f005620036300e64001650056600568001690066a0016c00377009780057d0017e00182007840016a03001040070500906001090070b0100c0390d0040e0150f010100151102012013130431406f150681702c18026190431a04d1b00e1c0fa1d0411e0691f0322002721004220072402025004260352709428047290042a02a2b0352c00d2d0982f004300263100c3203c3300c3408a350d83606d37065380113904d3a0363b0473c03b3d0353e0063f089400114104c42011430214401f45027460094700d48001490184a00c4b0504c0224d00f4e0044f03e5000b5101f52008530085400155002570115803d590195a0015b0025c0335d0125e0055f010600016202e6301864009650236600a6900c6b0016c0096e0017700f78012790027d0037e003800038100182008840038700258050010b0010c01a0e0020f00810001110231300b14007150ce190151a0021b01e1c03d1d1451e0051f0452000d2100b220022408f2600427072281ae2a0022b02b2c03c2d04b30001310033200c330053400135034360e337014390033a00e3b0453d0113e00c3f098400964102342086430014400d45008460204700c4800c4a00f4b0024c0344d0024e0024f00c5003d510025201053002550015705858009590155a0055b00d5c0155d01f5e01a5f0176000963003640036600867001690036b0016c0016d0057702a7804f790107a0037f003820048400d870032904001080010a00e0b0030d00f0e00312007160d0170161802a1a0011e003200022500d26005291b42a02c2e0062f015300063100c3200234003350013803c390033a0053c0094304b44001450024900c4b00b4c0014d002620176300165002690017c2367d035570300104003050020a0020b0040c0050d0030e0220f00710001120041300414008150031600b17042180181901c1a0231b0011c0041d0021e0291f0092001122001250012600b2700328001290112a0592b0172d00a30018310013201b330083400c35007360023702c3800d390243a00d3b0133c01c3d0133f0024100e420014301e4401b450114600447005490044a0014b0174c0014d0144e0034f010500025101352005530095800b590015c0055f0016201d63011640086501466004690026c002780017c02a7d0527e01980019810028200283001870015b0400108001090010a0010b0030c0020d0130e0220f0061000112006130061400b160261703c18083190181a0351b0021c0091e06b1f0062001022001250052600a27008290472a0912b0162d00a2f019300373102732026330073401e35005360023701c38070390363a0623b00f3c07a3d01941015430ba440334503b4600147006490294a0114b0734c0134d0524e0034f022500015102d520085300658022590035c0375d0035f008600016206d63041640136503566009680016900d6a0016c0036d00170001770027c0777d0b67e01b80032810028200c830018400187002900016a03001040020500a0b0010c0110d0010e0120f02412002130051400a150201700d180051906c1a0451b0561c01d1d0151e02a1f07b20048210222201c2300224002260042700e2800d2a01e2b0702c0192d01d3000731001320383304e340013501a360143705c38001390373a01e3b0733c0103d0593e02c3f01040006410494202a430074406d450354604947025480074b0144c0374d03d4e0364f03850026510185202e530285400d5500257006580385902a5a0065c0135d02d5e0055f00e600046200e6301464028650256602a6700768011690186a00c6b0016c01a6d00e6e0046f00172002780057d00c7e03d7f00d8001c810128200e8300a8400586002870058f002900036f050020d0010e0180f01b13002140081500717007180081902c1a04f1b0281c00a1d0041e0291f02720083210112203323007260022700328001290012a01a2b0562c0182d0092f001300063100132019330313400135006360043701238002390323a00b3b01a3c0193d0463e01d3f00640001410174200a4300a4402d4502b4601c47021480094a0014b0124c0144d05e4e0314f0365000f5102f520265302c540095500c570015801f590185a0075c0085d00d5e0045f013600076100162007630236402965034660226700f6800b6901a6a0106b0036c01b6d00b6e00d6f002700017200478002790047c0027d0157e0477f0158002981011820138300f8400485001860028700588001890018f0019000591001610e0010f0381500c170011900d1a0061b10a1c0011d01a1f05c2002321100220442306b24001270032800d2b0332c1472d0053200933085350023600d37005390043a0023b0193d0163e0d03f0094000c4100c4204444010450054603e4702f480634a0014b0014c00c4d0074e0ac4f00950034510025204753016540535501a5601757002580075901a5a0355b0025c0035d00b5e0115f0076000f6100c6400d6500166010670356801b690016a0186b0106c0076d00e6e0056f0057000171001720037800a7900b7a0037e0037f09b800028100c820058300b8401085014860038700b88003890048a0018d0018f002900015b0b0010e0010f0031000111001140031501617002190061a0051b0041c03b1d0551e0081f013200092100322006260012701028017290012a0012b0052c00d2d0093200234003350273605537005390053a0033b00c3c0023d00c3e0093f07a400654100942021430014400a45009460114700e480084b0074c0074d0014e0014f00b5000c51003520065300554001550015709958005590125a00c5b03f5c0015d0115e00f5f0116000b61004620026300264002650066600467001690026a0046b0016c0076d0046e002720027600b780037900c7a0097d0018100284004870048900127150031b0011d03b1f0052100223001240012800f2b0012c0023600f370013b0013f0044005c4200b460024800d50009520015702a590025a0045b0635c0015d0025e00b5f00160007610046d0016e001700017601578001790077a00b7b00484001650b0010c0010e0020f004140041500117005180091900b1a0161b0021c0091d0031e02e1f01120023210042200d2600427005290042a00d2b0162c0042d00530005310023200b330113400335002360063700a3800d3901c3a0173b00a3c0243d01d3e00e3f005400024102c4200f430064401f450274600a4701448002490014a0014b01c4c0084d0204e00d4f020500085102e5200e5301a540015500456001570025802f590135c00e5d00f5e0035f010600036201d63024640156502b6600d680046901a6a0076c0116d0046e0056f00170001720067700178001790037d00b7e0057f002800148100882012830038400585001870069000791003670f008150081900f1a0051b0281c0071d0201e0051f04d2001b210432201b230122400327007280122a0012b0142c0372d0063200233023350033601537009390043a0023b0363c0023d0243e04f3f00b400194101942074440164500a46043470244801e4b0014c00c4d0084e0234f00c5003851008520295301254021550105600557009580085902f5a01f5b0065c0015d01d5e0325f00e6001061007630066400b650066600b6701a68021690076a0216b00b6c0086d00d6e00c6f00870002710017200773001760017800a790097a0087b0027e0077f006800048100c82007830048400c85007860088700788004890038b0018d0018f005900049100192001680f004190021a00b1b00e1c0031d0011e0091f0162005021032220ab2302727001280012b0102c0132d00232004330123600337002390053a0043b0063c0063d01d3e0363f00240002410054200e44008450144602147033480154b0024c0074d00b4e01c4f01550010510165201a5303b5402c550405600f57007580085902f5a02d5b0025c0045d0105e00f5f01b600216100c62005630056401d65017660166702068029690366a0296b00c6c02b6d0186e0236f009700137100172023730117400178001790077a0037b0017e00a7f00e800078100882011830128400b850078600487012880098900b8a0028b0018d0078e0038f0089001091009920059300295001550f001190011b0201c0011d0081f01820003210c42201d2310d27001280022b0012c04f33006360023b0063d0023e0653f0024000f42017460284700d480bb4e01c4f00150014520195300754076550195608557008590095a0815b0075d0095e03d5f005600266107964003660056703d68033690016a0106b0506c0046d0156e0036f02a700117101772001730057400576003780017900e7a0167b00a7f0108100f820018300284005850238601387005880068900e8a00c8b0048c0038d0038e00c8f0119000391001920069300195002960013f1b001200092101122078230b92c002330023b0013d0013e0034600247003480094d0014e0045000151001520055300c540175503b560735a00e5f001600036100764002660026700b68008690056a00c6b0216c0056d00f6e00a6f026700127101c720067300c7401f7500b7b0017f002810038500986002870038800a8a0028b0038c0038d00a8e0158f00e900059100992004930049400695002960021f1b00121006230723e001480084e0015400f560475a0035e0016100467002680016b00d6d0016f0127102a7300174006750127b0018500286002890018a0088b0018c0018e00d9200194004950020324004280027700505250052600129005310024300338090010c00210001110011200113001140031500217001190031a0011b0011c004200012500c2602f2701a280012900d2a0192b0032d0173100a3200833001340023500e360023700538001390013a0083b0023c0023d0013f003410084300d4400745005460014a0074b0044c0034d00352001580025c0085f001620016300664001770027d0017e00180001361100913002140011500e190021c0041d00e1e0011f006200012402727024280512a0012b0082c0082d006310013500336016370013a0023b0063d0023f018400154100c420184300144005450024600247003480014a0054c00d4e001500045700958003590025c00e5d0085f00664001660036c001770177801e790027e0048100182005840010c1d002240052800b2c0013f001400034200157002770037800879002840020429013430037c0347d004221800119003260042900a2a02c2b00f2c0013200135001390013a001430084400645006470024b0014d00755001580015c0045d0025f003620016300164001650047c0337d0607e00c8000f82002830018400190001351b0011c0012100122001270022b0152c01332001390013b0043e0023f00240002410024200a4300344005450034600247005480064c0014e00250002520015300154001550015700158002590025b0015c0055d0075e0025f00560001630016500166004670026a0036d00278006790047d0047e01c7f01880010810088200383008840070a2c0063600142003480016a00178001790017f0078400185001131a0011d00124001270012d0093300135002360013d0013f00140001440015000152001590015d0016c001780017e001012e008230a0010b0010d0150e003120021400116006170021800b1e0012500426003290062a0082e00b2f02d300083102d3200534001380193a00a3c0064300c45001490434a0084b0074c0014d0014f0015c0036200b7c0017d00153040010b0030c0070d0060e0140f00412004130071400915004160011701f1802b190141a0241b0011c0071d0011e02c1f0042000d22001250032600b27005290052a0202b0082d00b2e0042f013300463100e3202633003340153500e36001370223802f3903a3a02c3b0083c0433d0113f0014100a420014301a440144501547001490214a00d4b04d4c0104d01b4e0024f0125000451022520045300558023590045c00e5d0045f0016203963016640026502666005690076a0016c0046e0017c0057d0067e002800028100382002122500326002290032f001310173a0014300845001490104a02b4b0014c0055c00a5d0016300177008780027c001490c0011400115001190041a0051b0021c0021e0021f008200042600127001290022a0092b0102c0012d00731006320143300c34001350013600237004390033a0093b00d3d0093e0013f0024100542003430084400a45017460024700148002490034a00d4b0034c01d4d0104e00e4f007500065100552006530025800c590035c0175d0045f0036200263009640046500766007690016b0016c0026d0017700378006790017d0037e00a80001810018200b83003840021b1b001220023300e3e00741001440044500146002470014e00b5200353002540035a001640026600167005680026d0016f0017d0017e0057f002800028100183002880014705001090010b0010e003100031100112003130041400c15006160041700418009190011a0081b0021c0101d0011e00c1f0012000321001220022600727005290022a0082b0042d00c2f0033000b310023200133002340263501436005370063800c390123a0063b0023c0133d0043f0054100543006440024500747001490054a0014b00c4c0014d0024f00a500035100752001530015700158009620146300665006690066c0037c0017d00180001820025a0b0020c004110041300314007150101700118003190061a0031b0031c0451d02d1e0061f0072000c2100122004240032600c2702228012290032a0062b00b2c0052d028310023200833005340093504d360513700938001390063a00f3b0123c0013d00b3e0053f08a4001b4101e420104300444011450114600b47011480024a0014b0044c00c4d0064f01a5000c51005520035300355001570475800f590175a0045b0075c00a5d0135e0045f016600086300e640036500a6600768002690076c0086d0016e002720037300178007790037e0027f00180002820078400187004440c0011500a1a0011b0021c0051d0621f00e20002210042200124001270042803d2b00b2c00c2d00b330013500636079370023b0093e0043f04f400e5410044203e4400145001460154700a480144a0014c0044e0014f002500135200255001570ca580035900e5a00d5b0825c0015d00b5e0165f00b6001d61010640026600267001690026a0016b0016d0056e0017000176009770027800d790137a00c7b001820018400389001910015b0c0010e0010f001100011300114001150051700218001190111a0071b0041c0061d0031e00c1f0102000a2100122003240012600227009280022a0032b00d2c0012d0073000432008330033500a3600437023390093a0063b01b3c0033d0123e0023f00440002410234200b430014400c450094600b4700a48001490014a0014b0084c0044d0094e0014f00c500095100f520025300654001570015801c5900e5b0015c0085d00d5e0025f0066000262004630026400565011660066700268004690066a0016c0066d0036e0017200178002790017e0027f00280002810018200190001430a0010b0010d0080e005100021200513001140011601c1701218022190081a0151c0011e022200042600327001290362a0382b0052d0012e0032f00a3001d3100f3200533003340063500437004380623901c3a0253b0013c0413d0063f0014100743082440114501c47001490174a0024b0424c0084d0214e0014f00b500015101353002580185c0115f00462071630296501866001690017c0487d0467e00b800118100182007670b0030c0020d0010e0060f0081300214006150041700e1800a1900e1a0241b00c1c00d1d0081e0281f0232003d210072201924001260022700528001290012a01c2b0252c0042d0073000632019330133400435006360083702c380073903d3a0183b02b3c0393d0303e00e3f01041026420034300f4402a450464602247023490024a0014b0234c0154d02e4e0124f0525001c510565201d5302954002550045700558045590285a0055c0105d0135e0055f01460006620296302f6402965050660146700368008690386a00f6c02e6d0086e00972006780037d00c7e0137f00180020810078200e830078400685002860018700788001890018f0039000a91001550e0021500217001180041900a1a0031b0021c0041e00d1f0062000624001250012600a27006290042a0202b0252c0012d003300053101d3201b330043400135008360043700b380093900d3a04b3b0103c00b3d0163e0013f0044103d420044301944023450254600947009490104a0204b01e4c0514d0224e00d4f01350011510185200d5300a58031590055a0015c0505d01d5e0015f01c620136302e640146501c6601e6900a6a0016c00d6d0026e006720027700d7801c790017d0187e0127f003800108100582023830038400587002900016b0c0010f00413001140021500718002190061a0061b0091c0031d0171e0021f02720013210142200c2300324001270082801a2a0042b0262c0332d007310013200c3300834002350033601637007390053a0053b0583d02b3e02f3f00d40013410484206f430024401b4500e4604c470364801e4a0044b0014c01f4d00b4e01e4f01c50076510035202e5300e540165500e5700f58026590535a00d5b0025c0135d0555e0355f0266001c61002630096401b65009660346701b6802c6900f6a0176b0066c0126d0206e0116f00170003720067300277001780137902e7a0047d0027e00c7f00a8000a810118201e830068402385006860098701488005890048f005900019100192001950015f050010e0020f0031200113001140011500316002170091800a190051a0141b0011c0051d0011e0201f00120022210012200e250012600227002290052a0132b00c2f00330008310033200933003340043500436002370103801b390273a00e3b0083c0543d0243e0033f0054100d4200143018440214503f4600a4700f490044a0024b0294c0074d0334e0074f03650001510705200a530185400155001580295900b5c00c5d00c5f00d60001620836305b64027650646601467003680036902a6a0026b0016c01e6d0076e0097200f7c0017d02f7e0107f0028002581002820228300a84002870038f001900086c0f00515001170021900c1a0111b00e1c0041d0071e00b1f02220040210172204c2300727002280012a0012b0192c0102d00630001310023200a330283400135005360063700938001390113a0093b0273c00a3d0463e0323f00840004410274201243002440294502846046470474800d4a0014b00d4c0154d0194e0434f033500265103352063530565402955036560055700858035590505a0215c0075d02b5e01f5f0266001f6100462008630236406565026660456702668047690516a0386b0086c0626d02d6e02e6f00e7000b71001720257300f780027900b7a0017d0017e0157f0118001b810258202a83026840138500a8600a8702988008890098a0028b0018d0048f00e9001e910095a0f002150021b0151d0011f0072000a21048220242304c280012b0042c03f33019360043b0083d0083e05d3f00140008410034201f440044602a47022480724c0044d0034e0234f0035001c520145301d540655503a5603c57007580045901f5a07f5b0045d00b5e0315f00b6002261022630016400c660086706368047690046a0236b02f6c0056d01b6e0146f0207001171007720017300874004780017900e7a00d7b0037e0037f00f81016820018300c8400f85021860148700c880128900f8a0078b0018c0018d0048e0038f0259000791007920059300295003970019800232150011c0041d00d1f001210012400127001280112b0012c00335003360153b0063e0013f0354004d410034200c450024600447008480034c0014f00150005540015706558003590085a0035b06b5d0035e00c5f005600086100863001660016a0016d0047601d78006790107a00f7b0028100182001840028700188001102c00136001400204200448001570115b05f5d0015e00560001610027603e78001790017a0047b0052c150011d0012100224001270012b0012c001360013a0013b0043d0033f0014000141016420084500346002470034e0014f0025000552002550025700158001590025c0015d0075e0055f00860001640036600167001690016d0016e002790067e00281001820048400487001900011e27001280022c00a3f002400024201148006500015200156001570015a0015c0015e00b5f0016000561002680016a0016f001780037900a7a00182001840028600287002880028900193001251800126001290162a0122b0022c0012d0013100132003380013a0064302d4400645002490014a0044b0044c0054d0104f001520015c0145d0025f0026200163010640016600177002780057c0357d0577e00f7f0018000f82005830013a17001180011d00120001220012a0052b0062d0013300336001390013a0023b0013d0013f00241002440164500546004470034b0014c0034d00d4e0054f00150003510015200553008580045c0015d00d5f0036300364007650066600c6700268001690016c0016d0046f001780017d00d7e0327f00280013810188200383004840018500186001870018d00190004910016415002180011a0031b0061c0011e0031f00220012210042200f230012700228001290022a0192b01b2c0092d0023100132004330063500136001390053a0063b00b3c0083d00f3e00b3f0064100c4200343014440244503e4600f47030480064a0044b0044c0134d0234e0154f01f500115101452012530255400a55011570045801d590175a0065c02b5d0345e0085f05260012610016200a6303964029650246602c670126800b690306a00b6b0016c0206d0136e0156f002700017200b730057800c7900d7a0027c0057d03e7e04f7f00a8006d810228203b830248401985003860028701888002890058b0018d0028f007900189100493001610f00115001190011b0021d0041e0011f00820007210142200d2300a280052a0012b00c2c01e2d0013300136006390013b0063d00a3e0193f0024001341008420224400a45001460464702e4804a4c0024d0024e00f4f0095003a51001520185301a5402e5501c560095700f58007590305a0415b0075d0285e0735f0126005561032630016401f65003660166702e68041690076a0396b0286c00d6d03f6e0196f01d7001c710097200f73010740037600278003790357a0377b0057e01a7f01f800058103e820088301e84030850218603c8702088027890178a0078b00a8d0108e0038f013900099100c9200695003960014c1b0012000221003230022c010320013b0013d0023e0063f00140003410044200744002460074700c480174e0024f002500085200453001540095500d560065700358003590055a01b5b0065d00a5e0105f00e600156100d6400465002660056700368004690026a0106b0056c0046d0066e00b6f0077000d71003720017300774002780037900a7a0127b0027e0067f00680005810028200d8300d8401085009860088700f8800a8900d8a0078b0038d0048f005900079100c920039400134210012c007400034200446003480214c0014e0015300154003550045601457001590015a0125b00d5d0025e0106000e6104d67003680036a0036b0186e0016f008700037101874001750047600a78001790087a0237b03a7f00981001830028400785010860098700588005890088a0108b0118c0038e0038f00392002930019500212290012f002300013100e32002380013a0043c00143006490294a04d4b0044c0075c0085f001620017704a78007054a0284c0065c0097706c780125a0d0020e0031200114001160011700618001190041a0081b0011c0021d0011e01f1f0022000f210012200626003290012a00f2b00b2d0022f0013000f310073201633001340033500536001370053800c390133a01e3b00b3c02b3d00a3e0013f0024101042002430254401f4502246004470094901f4a0254b0464c0424d0394e0094f02b500075102b520075300b5400258026590055c0775d0145f03f600056202d6303c64014650336601b68003690186a0016c0136d0046e002720037701a78051790097a0017d01f7e0307f0088002d810058202c830048400d8700690008431b0011d0011e0011f0022a0012b00b2c001320073300536001370013a0073b0033d0054000141007430024400a45010460074700a48001490014a02a4b0044c0794d00a4e0134f00650021510035201e540025700158003590045c03d5d0285e0015f025600036300464001650046601a67001690076a0056b0016c0016d0026e002730017708d7811b7903e7a0027e00d7f02d800048100582022830068403a850028700a8900232200012a0013200133001350013d00142001430054400f45002460014b0014c0014d0114e0095100152002530055400158002590025c0035e0015f00262001630046400465001660086700268003690036c0026d0016e003780017d01a7e03f7f009800188100d8200483007840018500186001870018d0039000591001341f0012000122001230022c001330023e002440014600347003480014c0014d0024e02b4f0015000152004530055400b55002560025a0025f001640036600767013680086a0026b0036d0026e001700017200178003790027d0017e0117f05e800038100b830058400b8500c8600188003890028d0018e0018f002900019100192002670e001190021a0041b0011c0021d0021e0081f0042000f210062201423002290012a0022b0052c0023000232001330063400136001390073a0023b00d3c0053d00c3e0093f0014000241007420054300244009450114601347021480064b0024c00d4d0034e00c4f0285001e5101a5201e5301f540065500e5600357004580145901f5a00e5b0025c00d5d0225e0175f02e600206100362003630106401965017660216700b68010690236a00f6b0046c03d6d01a6e0196f00670005710027201a73005760017800a790367a00d7b0017d0017e00b7f00e8000e81010820198301a8401f850058600a870168800d890108a0018b0028d0038f0029000f9100a920023c1f0012100323001280012c0023b0013d001400044100142007440014600147001480064c0014d0014e001500205200453001540065600357004590045a0045b0015d00c5e01f5f006600056100b64001660046700268003690016a0036b0036d00a6e0046f002700037300178003790307a01a7b0027f0098300184010850048600587002880048900a8a0018b0038e0018f00190001531a0051b0011c0011e0051f0022000b210042200f230012b0023300338001390053a0033b0053c00e3d0073e00341003430034400545011460064700e48001490024b0074c0034d00e4e0064f00f500015103a520165302654002550145600158011590055a0025c0095d0085e0015f010600046200c630266402b650206601567003680056903f6a0116c0396d0116e01a6f003700037202a7300878003790027d0057e00c7f0088000f8100682031830148400685003860018701488002890058d0068f00490023910069200195001591a0021b0011f003200042100422011230022b0032c002330043b0043d0063e0083f001410044200444002450014600b47009480064c0044d0044e0114f0045000c51001520225301054012550185600957001590095a00c5c0055d0095e0095f00b6000f61003630016400d650016601b67017680176900b6a01a6b00a6c0096d0156e01a6f00f70009710037200d7300e740027700178016790147a0037b0017e0037f01980003810098200a830098403485010860078701a8800e890198a0048b0028c0018d00a8e0028f00e900079100a92001930019400295003960015b1e0021f00120004210062203f2300b2b0032c00133006390013b0033c0013d0053e004410014300144005450044600b47014480054b0014d00e4e0164f00a5000a5100b5201e530435402a550535600b58005590105a00f5c0015d0085e00a5f0066000d6100562002630056402765011660196703e680346902d6a0326b01b6c0406d02a6e0506f02770027710077204e73038740087500178002790017a0017b0017d0017e0107f01080019810318201683049840128501b8601987036880268901a8a0038b0048d0508e0148f0389006b910339200b93013940029500c96003980014b200012100922010230432b0012c005330023e00f42001450014600c470064801c4d0014e01250009520095300354074550205609958001590085a02a5d0025e0185f0016000f6101a6400666003670446803b690016a01f6b0736c0036d0196e00d6f06c70028710487200273015740307500d790077a00a7b0077e0027f0208101f8300e840098506f860448700788030890248a02f8b0158c0048d01b8e06b8f036900029101292030930139400f9500b960069700198004990013423002470034800552001530015400155012560225a0055d0015f001600026100665001680066a0046b0136d0056e0066f011700157101772004730137400c750117b0027f001800018100483006840048500d860088700488008890078a0118b00b8c0098d0188e01d8f010900049100c920139300d94008950099600697002980021d230022c0014800354001560125a00161005680026b0066f001700037101b74004750187b0037f0018500588004890018a0058b0018c0088e012920079400c95002960059700199001221d003280022b0012c00135001360063f00c40053420074600247001480044c001500025200157089590015a0065b1395d0025e00d5f004600096100d6a0016b0016e00170001761fb7900b7a01c7b012840038a001641400118001190031a0051c0031d0011e0091f0062000c210032200127001280012a0022b0052c0052d002320023300235002360033700238001390083a0063b00b3c0063d0103e0053f00440001410214200443001440104500f4600e47014480024a0014b0044c0074d00c4e0024f0185000e510165201053019540015500c57002580235901f5a0055c00b5d0215e0045f0206000e62004630176401d65020660226700768008690206a00c6b0016c02b6d0146e0196f001700047201173007740017700178005790087a0017d0057e0127f00680013810148201e830108400e85001860018701988001890038d0048f0049000e91004930015b1a0021b0031d0021e0011f00720006210062200a23003280022b0032c00736005390013b0073d0053e00c3f0074000a410094201e44003450044601747024480204a0014e0084f006500125100452011530135400f55018560075700b580035903e5a0255b00f5d00f5e05c5f0156004361029630016400b65001660126701868024690116a0276b0186c0116d0356e0236f01a7002171003720207301b74006760037901c7a0457b0177e0077f009800058101382009830148400e850118602a87028880218901a8a0088b0138c0028d00b8e0018f00b9000f91012920059300695005452100222003230062c0023d0013e0053f002400024200146003470044801e50002520045300354008550085601457003590025a03b5b0135d0025e0115f0016001c610806400166001670056800d6a00a6b0156c0016d0076e0046f00e70011710197200373009740077601e790037a02e7b09c7f004810038300484002850118600d870038800b890058a0138b01a8c00f8d0048e0058f009900019100492007930049400395003960039800106400015b02061001760d67a0017b00a272b0022c001310013a0014000141004430014400145008490014a00a4b0014c00e4d0024e0014f00150001580015c0325d0085f013600016300266005680016a001770377804a790107d0047e0097f00a8000a810018200e83001840168500189002581c0011f00321004220012300127001280022b0022c006360023a0013b0023e0033f0024000641009420104300144009450034601747014480084c00a4d0024e0074f00350028510015200c530035400355007560015700758003590105a00b5b0065c0145d0535e0275f0366003861007630016400a650016601b67014680066900a6a00f6b0056c0056d0186e00a6f00870007720057300676001780337909d7a0267b0027d0017e0197f0268000b810268201683017840408500b860108701a8800e890248a0038b0058d0088f0029000d9100692002930029500132230012c0013f0024000242003460014800e52001550015600157003590015a0015b0095e01b6000e6101e680016a0016b00a6d0026f004700037100473001740017600c790117a0437b0227f00381001830018400285006860158700188008890098a0068b00a8c0078e0018f001910019200293001940019500198001292c002450044600147003480025000453001550035a0025c0045d0045e0025f01560006660056700268001690016a0016c0016e004700027700378012790277a00b7b0027e0037f00681003820098300584019850058600187004890048b0018d00290001910024221001230012c006360013e001420064600647009480124e00250004520015400655008560075700a590055a0265b0165d0045e02d5f0036005961076630016600167007680086a00a6b0156c0016d0076e0096f00e700237100b7200673006740027601f78002790457a0f07b0ae7f00a810078200383006840138501386023870128801f8903a8a02a8b0268c0148d0058e0028f009900069100c92008930069501096003194000148002560035b00c5e00360002610496b0036f0017000371013750027602b790027a0267b0f9840018500286003890018a0108b0108c02192002950032b1400117003180021a0021e0052000222002290022a0052b0013000138001390023a0023b0013c00a3d003430034500446001490014b0054c0014d0074f0055100b5300455001580015c002620316301a640076500966002690056c0047d0058000b820028300187001900014413001180012000422002290022a0072b0032c00132001330023a0033b0013c0023d0054100142001430114400745011470034b0024c0044d0164e0064f004500035100d520045300b5500358005590015c00c5d0035f00f600016200763039640166500f6601567003680026901f6a0076c00e6d0016e008720087300178002790017c0067d01d7e0177f0068003a810078203f8301384006850048701188002890028d0019001a910034a1e0012000421003220062b004390013c0013d0043e0094100144005450064600547006480034d0064e00c4f00150007510045200d530185400b55009580045900a5a0015c0015d00f5e0035f00360006610016300864024650046600c6701768019690156a0176b0026c0126d0146e01b6f0037000c71001720267300574002790027d0047e0157f0068002181019820138302c8400b85006860078701f8800f890088b0018d0158e0018f010900399100f920049300294001510f001190011a0011e0031f002200092200b2a0022b003320023300438001390053a0033b0043c0093d0093e0033f00141004430044400845008460054700c490014a0014b0054c0044d00f4e0084f00d50005510315200c5301c5500b570015800c5900a5a0015c0065d0055f0086000262007630126401b65020660176700b680096902f6a0086b0016c0306d00f6e013720247300578001790077d0087e0157f0048001e8100e820298301a8400585001860048701188002890018d0068f00490023910069200193002561b0032000221003220042b0022c00733002390023a0013b0043d0043e002410054200244008450024600847012480064a0014b0014c00c4d0084e0134f0065000d510045201e530185400d550165600157001580085900b5a0065c0155d01a5e00a5f02b6000e630106401f650086603d6701a68016690126a0296b0086c0156d03b6e0306f00e7000971001720137301274003770037801b790237a0037d0037e0317f03f8001d8104982036830338405c85026860198703f88018890218a0038b0018d0198e0028f0159002e91017920049300395004472100422003230062c002330013b0013e004410014600447005480094d0024e00e4f001520065300a54027550135601d590045a0085d0035e0065f00260009610096300164007660076703d68018690036a0196b0246c0026d0166e0146f0347000b7100c720087300b7400f76001790027a0097b0027e0047f041800058102c830188400e85051860278700c88025890148a00d8b0058c0018d0388e0358f033900059101f920169300c940049500696002432100122003230062c0013300140001420014600247001480074e00350003520025400f5500c5600d590015a0075c0015d0025e0075f00260008610046300164003660016700f68010690026a0136b0146c0016d00d6e0076f0207001371009720047300c7400a750037b0017f0058100e82003830078400185010860198700a88014890118a0098b0078c0048d0118e00e8f01890006910109200a93005940029500b9600498001541b0011f00120003220102b0022d001390013d0043e001410014200244005450074600547015480024a0014b0014c0014d0044e0044f005500035100b5201653022540065502a560025700158004590105a0025c0025d00c5e0055f01260011610026200663011640266500a6601c6700d68015690466a0276b0046c03d6d0216e04b6f0117001b7208a730357400578002790047a0047d0047e0027f0058000f81012820388303684018850048600887046880158900f8a0018b0048d0348f0189006d91031920049300b9500996003980025220002210042200b230092c0053b0013d0013e0044100242008460084700a480114c0024d0014e0025000c510015200e530165402055059560285700158001590115a0225c0015d0035e0155f00460029610176400d650026600b67028680356900f6a04a6b0496c0126d03c6e0486f05c70081710207204773089740527500b7900f7a00f7b0097e0027f00e80003810328200b83037840238503786049870458806c890608a0528b0528c00d8d0538e02a8f07d90033910759204c9305394014950589600f970019800d9900330230064800d500015400655004560375a0075e006600056102b670086800c6a0036b05a6d0086f02a700237107d720017300c7403c7503e790017a0157b01c7f0048100483001850228602f8700388013890178a0668b0278c0588d00d8e05a8f0189100a9203e93020940359501896027970089800e990024b1f0012000321002220092b0013e0024100244002450014600347001480024d0034e0034f00150002510085200653013540075501c560035900a5a0055c0025d0025e0055f006600076100164008650046600a6700a6800e690246a0176b0046c0296d0216e01f6f0087001871002720597302174008790047a0047b0017e0047f0018000a8100d82008830168400385007860188701588014890128a0068b0058c0018d01d8e0038f01890038910199200b9300d940019500b980053a1f00121001420014600147002480014e0015000152001530015400355003560015a0025e0056000261001640016600167001680026a0076b0056d0096e0076f00670005710037200673008740067500178002790067a0037b0017f005800018100583001840048500986019870048800b890118a00b8b00b8c0028d0068e0078f00b9100892003930069400495007960034c2000122004230023d0013e002440024500147004480044c0014e0014f00150002510025200553007540095501b5600958001590065a00a5d0035e0035f00c60014610096400665001660036700b680126900a6a01e6b0176c0116d01f6e0346f0207004a7100f7204c7307f740347500878001790077a00a7b0057f00a800028100c8200d830288401d8501e8601e8703b8803d890508a01e8b0428c0078d04e8e00e8f046900409106192027930479400b9504b96013970029801e990013c22002230052c00146001470014800550001530015400c5501956045590015a00a5e004600086100e640016700d680166a00b6b0476d00c6e0096f06570048710997200873031740977508b76001790017a00f7b0217f008810088300784002850388604387005880428902b8a07a8b0548c07a8d02f8e0a88f053900059102c920a29306a940859505b9606897023980489901b9a0023623003460024800653001540015500a5600a5a0065e001600056101367003680026a0086b0196d0036e0096f01e7002e7105472008730207405875082790037a0057b0237f0028100183004840038500d8600f8700b88012890238a04d8b0848c0848d0178e0228f014900059102b920469304794053950569604c970359803c9901a9a0089b0011b48001560075a001610026b0076f0037000171046740087507e7b0178500186003880028a01d8b0038c07b8e0138f0029200d9403e95004960139702f98004990149a01127220014700155004600026600167002680016a0016b0026c0066e0046f002700057100272019730147400a750038300184001860028700388005890028a0068b0058d0098e0018f003900059100e920039300f94004950169600b97001980119900221600016b0026d0016e0016f0017000671002720037300c7400e750058400185002860068700188003890068a0078b0128c0028d0058e0038f001910059200e9300f940099502096017970039802f9900f9a003204800156005610016b0036f0037000771017720017300474044750807b0018500288001890028a00a8b0198c0258d0028e0148f0059100192021930219405f950289606b970ad98079990cb9a0929b02a0c710027500a7b0018a0018c0028e001940019700f98001990069a0199b01901760010177002057700778013790057f001840010c5d002600016100176001780067900c7a0137b0017f00184005890018b001096100276005790017a00e7b01f890028a0028b0038c00202760017b00100047c00a7d0117e003800050d4d0015c0016d0017d0027e00a7f00780003810048300184001850018600188001037f0038400185001224d0014e001500015300155002590015f00164003650016600367002690026a0046c0026d0016e0016f004730017c0037d01e7e01c7f0038004881010820028301a84001850018700188001890018d0079001d910043522001420014d0014e0015300154003550015a0015d0055e0026000364002660026700c680016a0036b0066c0026d0096e0026f004700087100273003740017500178001790017a0047b0017d0027e00d7f00f80008810458200183016840048501386031870058801d890068a0068b0028d0168e00b8f0089000a9101292002930019500122390013a001460014e0015c0015d0025f005600036300166004670026a0016c0026e003720017700178007790047a0017f004800028100282013830028400e85002860018700988003890078b0018f00190002910012f44001470014d0014e00254003560015a0025d0015f00360001640016600167004680016c0016d0046e0076f00370002720067300878001790017a0017e0047f00a800088100a820048301d840088501286004870058800c890068a0028b0028d01d8e0068f007900109100e92005930069400295002142c001520015f00160004660016e0027300278006790057a0047e0017f004820018400f850038900a8b0049100193001950011f610016b0016f0027000471003730017400175003790037a0087b0017f0098100383004840048501b8600c890098a0118b0078c0038d0068e01d8f001910029200993002940049500296001980030e560016f00171001740017a001810018500186006880028a0028b0018c0018e002970024422002230012c0013d0013e002420014600247001480024d0015200353005540055500a56001590045a0055b0015d0025e0045f0056000a6100264007660046700668006690026a0146b0016c0036d00f6e0206f00b7001b7201273024740127500278001790097a0077b0037e0017f00a800028100b8200d830188401a850138600a8702a8801c890318a0068b0158c0028d01e8e0058f01c90017910299200c9300d95019960039800235220013d0015400155002560055a0045e0046000561004660016700568003690016a0046b00c6d0046e0086f01370023710157200673018740227500e790047a00f7b0057f005800018100c8300c840058501c860348700b880338901a8a03c8b0468c0278d02b8e03a8f0249000b910279202e9303e940219503e9601c97008980159900726560025b001610056b0026d0016e0016f0017000271002730047400575004790047a00c7b0078300184007850058600287001880028901d8a0178b0338c00c8d0018e0058f002910059200993003940079501b9600f9700498004990019a0011b60001610036b0017100a7300274002750117a0077b014850058600388001890078a01b8b0208c0708d0028e00a92010930029402795008960209702b980019900a9a004087a0017b0058a0028b0048c00e930019600298001057b0018c001970019a0029b0010a7400182001880018b0018d0048e0069100292003930049400104740018e00194004960012a550015600159001640016700268001690016b0086d0016e0026f00a7000c7100c720047300f7400f750087a0018000181002830018500286003870028800e890088a0098b0088c0048d0148e00e8f011900049100e92016930229400a9500f9600c970029800999002235100154001550025f002640026600268001690016a0036c0036d0036e0046f001700017200a73008740028000281003820018300d85002860018700788005890038b0038d00e8f006900169100c92002930099400195008324700255003560035a0015d0016000167001680026a0046b0056c0016d00b6e0086f0137002a7100f720127303a740467500d7a0017e0017f00380002810068200283012840018500e860178700888028890138a0158b01e8c0058d0508e0218f0289001b910519203b930719401b950489603c97003980529900b9a001215600561002680016b0066f0057000671021730097403f7505b7a0017b0018500d860078800a890088a02e8b0248c03e8d00c8e03f8f0089100b9205093042940879503f960949707a9807b990719a0219b0052354001550016a0016b0026d0016f0037000b7101573012740317504a8300284001850068600488006890028a0178b0168c0258d00f8e01e8f00a90001910129202d930669406895061960919708e980d6990949a03b9b00c137100374002750257b001880018a0038b0018c0188e005920039300394028950019600e9707a9800a990419a05c9b01a1074001750017b0018b0068c0058e0029200493005940079500b9600897008980149900a9a0059b002147000171005740047500c7b001880018a0058b0048c01d8d0018e003930039401f950059603297080980369909a9a0c39b084059400197003990049a01f9b0280f7300174004750048c0039100292001930069400e95007960179701998036990599a0679b09209750038c00194003960029702c980049904f9a1099b1fc029a0089b03c00";
-
-    const N_LIST: [usize; 3] = [11, 34, 156];
-    const MAX_EPS: usize = 40;
-    let mut result = vec![];
-    let mut cursor = 0;
-
-    for &n in N_LIST.iter() {
-        let mut v = vec![];
-
-        for _ in 0..=MAX_EPS {
-            let mut matrix = vec![vec![0; n]; n];
-
-            for col in 0..n {
-                // 最初に非零要素の数が来る
-                let size = decode_hex_to_u64(&HEX_DATA[cursor..(cursor + 2)]) as u32;
-                cursor += 2;
-
-                // そこからsize回(行, 数)のペアが来る
-                for _ in 0..size {
-                    let row = decode_hex_to_u64(&HEX_DATA[cursor..(cursor + 2)]) as usize;
-                    cursor += 2;
-                    let count = decode_hex_to_u64(&HEX_DATA[cursor..(cursor + 3)]) as u32;
-                    cursor += 3;
-                    matrix[row][col] = count;
-                }
+        for (i, g1) in graphs.iter().enumerate() {
+            let permuted = g1.permute(&permutation);
+            let vf2 = Vf2Checker::new(&permuted);
+            let wl = WlChecker::new(&permuted);
+
+            // 見つかった写像で並べ替えると元に戻る
+            let mapping = vf2.find_isomorphism(g1).unwrap();
+            assert_eq!(g1.permute(&mapping), permuted);
+            let mapping = wl.find_isomorphism(g1).unwrap();
+            assert_eq!(g1.permute(&mapping), permuted);
+
+            if let Some(g2) = graphs.get(i + 1) {
+                assert_eq!(vf2.find_isomorphism(g2), None);
             }
+        }
+    }
 
-            v.push(matrix);
+    #[test]
+    fn automorphisms_test() {
+        // 5頂点のサイクルの自己同型群は位数10の二面体群
+        let mut cycle = Graph::new(5);
+
+        for i in 0..5 {
+            cycle.connect(i, (i + 1) % 5);
         }
 
-        result.push(v);
-    }
+        let automorphisms = Vf2Checker::new(&cycle).automorphisms();
+        assert_eq!(automorphisms.len(), 10);
+        assert!(automorphisms.contains(&(0..5).collect()));
 
-    result
+        for automorphism in automorphisms.iter() {
+            assert_eq!(cycle.permute(automorphism), cycle);
+        }
+
+        // 空グラフは全ての並べ替え
+        assert_eq!(Vf2Checker::new(&Graph::new(4)).automorphisms().len(), 24);
+    }
 }
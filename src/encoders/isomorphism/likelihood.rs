@@ -0,0 +1,320 @@
+//! 焼きなましで求めたグループ分けから、各候補グラフが送られた対数尤度を求める
+//!
+//! 送信側ではグループ内は全て辺あり、グループ間は元のグラフの辺に従って全てあり・なしになっている。
+//! 各頂点対は独立に確率epsで反転するので、グループ対ごとの辺の本数は二項分布に従う
+
+use super::annealing::state::State;
+use crate::graph::Graph;
+use itertools::Itertools;
+use std::cmp::Reverse;
+
+/// log(0)を避けるためのepsの下限
+const MIN_ERROR_RATIO: f64 = 1e-3;
+
+#[derive(Debug, Clone)]
+pub struct Likelihood {
+    group_count: usize,
+    /// グループ間の (辺のある頂点対の数 - ない頂点対の数)
+    cross_counts: Vec<Vec<i32>>,
+    /// グループ間に辺が1本もないとしたときの対数尤度
+    base: f64,
+    /// log((1 - eps) / eps)
+    log_ratio: f64,
+}
+
+impl Likelihood {
+    pub fn new(state: &State, error_ratio: f64) -> Self {
+        let eps = error_ratio.max(MIN_ERROR_RATIO);
+        let log_ok = (1.0 - eps).ln();
+        let log_ng = eps.ln();
+        let group_count = state.group_count();
+        let sizes = state.groups().iter().map(|g| g.len() as i32).collect_vec();
+
+        let mut cross_counts = vec![vec![0; group_count]; group_count];
+        let mut base = 0.0;
+
+        // 辺の本数は (count + 頂点対の数) / 2 で求まる
+        for i in 0..group_count {
+            let pairs = sizes[i] * (sizes[i] - 1) / 2;
+            let edges = (state.self_count(i) + pairs) / 2;
+            base += edges as f64 * log_ok + (pairs - edges) as f64 * log_ng;
+
+            for j in (i + 1)..group_count {
+                let count = state.cross_count(i, j);
+                let pairs = sizes[i] * sizes[j];
+                let edges = (count + pairs) / 2;
+                base += edges as f64 * log_ng + (pairs - edges) as f64 * log_ok;
+                cross_counts[i][j] = count;
+                cross_counts[j][i] = count;
+            }
+        }
+
+        Self {
+            group_count,
+            cross_counts,
+            base,
+            log_ratio: log_ok - log_ng,
+        }
+    }
+
+    /// graphsの中で対数尤度の最も高いものの添字と、その対数尤度を返す
+    ///
+    /// 候補グラフの頂点とグループの対応は最も尤もらしく選ぶ。グループ対(i, j)を辺ありとすると、
+    /// 対数尤度は count(i, j) * log((1 - eps) / eps) だけ増える。
+    /// 辺の数だけで決まる上界の大きい候補から調べ、それまでの最良を上回れない候補は探索を打ち切る
+    pub fn most_likely(&self, graphs: &[Graph]) -> (usize, f64) {
+        let mut sorted_counts = self
+            .cross_counts
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| row[(i + 1)..].iter().copied())
+            .collect_vec();
+        sorted_counts.sort_unstable_by_key(|&c| Reverse(c));
+
+        // prefix[e] = 辺がe本のときの上界
+        let mut prefix = vec![0];
+
+        for c in sorted_counts {
+            prefix.push(prefix.last().unwrap() + c);
+        }
+
+        let order = (0..graphs.len())
+            .sorted_by_key(|&i| Reverse(prefix[graphs[i].edge_count()]))
+            .collect_vec();
+        let mut best = i32::MIN;
+        let mut best_index = order[0];
+
+        for i in order {
+            if best != i32::MIN && prefix[graphs[i].edge_count()] <= best {
+                break;
+            }
+
+            let (score, _) = self.best_assignment(&graphs[i], best);
+
+            if best == i32::MIN || score > best {
+                best = score;
+                best_index = i;
+            }
+        }
+
+        (best_index, self.base + best as f64 * self.log_ratio)
+    }
+
+    /// 辺に対応するグループ対のcountの和の最大値と、探索した節点の数を求める。floor以下であればfloorを返す
+    ///
+    /// 次数の大きい頂点から順にグループを割り当てる分枝限定法で解く。k!通り全てを調べるとk = 8で1クエリ数秒かかる
+    fn best_assignment(&self, graph: &Graph, floor: i32) -> (i32, usize) {
+        assert_eq!(graph.n, self.group_count);
+        let order = (0..graph.n)
+            .sorted_by_key(|&v| Reverse(graph.degree(v)))
+            .collect_vec();
+
+        let mut search = Search {
+            graph,
+            cross_counts: &self.cross_counts,
+            order: &order,
+            assigned: vec![None; graph.n],
+            used: vec![false; graph.n],
+            best: floor,
+            nodes: 0,
+        };
+        search.dfs(0, 0);
+        (search.best, search.nodes)
+    }
+}
+
+struct Search<'a> {
+    graph: &'a Graph,
+    cross_counts: &'a [Vec<i32>],
+    /// 割り当てる頂点の順
+    order: &'a [usize],
+    /// assigned[v] = 頂点vに割り当てたグループ
+    assigned: Vec<Option<usize>>,
+    used: Vec<bool>,
+    best: i32,
+    nodes: usize,
+}
+
+impl Search<'_> {
+    fn dfs(&mut self, depth: usize, score: i32) {
+        self.nodes += 1;
+
+        if depth == self.graph.n {
+            self.best = self.best.max(score);
+            return;
+        }
+
+        if score + self.upper_bound(depth) <= self.best {
+            return;
+        }
+
+        let v = self.order[depth];
+
+        // 増分の大きいグループから試す
+        let candidates = (0..self.graph.n)
+            .filter(|&g| !self.used[g])
+            .map(|g| (self.gain(v, g), g))
+            .sorted_by_key(|&(gain, _)| Reverse(gain))
+            .collect_vec();
+
+        for (gain, g) in candidates {
+            self.assigned[v] = Some(g);
+            self.used[g] = true;
+            self.dfs(depth + 1, score + gain);
+            self.used[g] = false;
+            self.assigned[v] = None;
+        }
+    }
+
+    /// 頂点vをグループgに割り当てたときの、割り当て済みの頂点との辺による増分
+    fn gain(&self, v: usize, g: usize) -> i32 {
+        (0..self.graph.n)
+            .filter(|&u| self.graph.has_edge(u, v))
+            .filter_map(|u| self.assigned[u])
+            .map(|h| self.cross_counts[g][h])
+            .sum()
+    }
+
+    /// 残りの辺による増分の上界
+    ///
+    /// 割り当て済みの頂点との辺は頂点ごとに空いているグループの中で最大の増分、
+    /// 未割り当ての頂点同士の辺は空いているグループ対のcountの大きい方から順に取れるとする
+    fn upper_bound(&self, depth: usize) -> i32 {
+        let rest = &self.order[depth..];
+        let free = (0..self.graph.n).filter(|&g| !self.used[g]).collect_vec();
+
+        let outer = rest
+            .iter()
+            .map(|&v| free.iter().map(|&g| self.gain(v, g)).max().unwrap_or(0))
+            .sum::<i32>();
+
+        let inner_edges = rest
+            .iter()
+            .tuple_combinations()
+            .filter(|&(&u, &v)| self.graph.has_edge(u, v))
+            .count();
+        let inner = free
+            .iter()
+            .tuple_combinations()
+            .map(|(&g, &h)| self.cross_counts[g][h])
+            .sorted_by_key(|&c| Reverse(c))
+            .take(inner_edges)
+            .sum::<i32>();
+
+        outer + inner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Likelihood, MIN_ERROR_RATIO};
+    use crate::{encoders::isomorphism::annealing::state::State, graph::Graph};
+    use itertools::Itertools;
+    use rand::prelude::*;
+    use rand_pcg::Pcg64Mcg;
+
+    /// k頂点のグラフの各頂点をsize頂点のクリークに置き換える
+    fn expand(graph: &Graph, perm: &[usize], size: usize) -> Graph {
+        let n = graph.n * size;
+        let mut expanded = Graph::new(n);
+
+        for u in 0..n {
+            for v in (u + 1)..n {
+                let (gu, gv) = (perm[u / size], perm[v / size]);
+
+                if gu == gv || graph.has_edge(gu, gv) {
+                    expanded.connect(u, v);
+                }
+            }
+        }
+
+        expanded
+    }
+
+    fn groups(k: usize, size: usize) -> Vec<Vec<usize>> {
+        (0..k)
+            .map(|i| (i * size..(i + 1) * size).collect())
+            .collect()
+    }
+
+    fn random_graph(k: usize, rng: &mut Pcg64Mcg) -> Graph {
+        let mut graph = Graph::new(k);
+
+        for (u, v) in (0..k).tuple_combinations() {
+            if rng.gen_bool(0.5) {
+                graph.connect(u, v);
+            }
+        }
+
+        graph
+    }
+
+    #[test]
+    fn most_likely_test() {
+        let mut path = Graph::new(4);
+        let mut star = Graph::new(4);
+
+        for i in 0..3 {
+            path.connect(i, i + 1);
+            star.connect(0, i + 1);
+        }
+
+        // 頂点の対応がずれていても、ノイズで一致しなくなっていても元のグラフを選ぶ
+        let mut received = expand(&path, &[2, 0, 3, 1], 3);
+        received.flip(0, 3);
+        received.flip(4, 10);
+        let state = State::new(&received, groups(4, 3), 1.0);
+        let likelihood = Likelihood::new(&state, 0.1);
+        assert_eq!(likelihood.most_likely(&[star.clone(), path.clone()]).0, 1);
+
+        // ノイズがなければ全ての頂点対が反転しなかったときの対数尤度になる
+        let received = expand(&star, &[1, 3, 0, 2], 3);
+        let state = State::new(&received, groups(4, 3), 1.0);
+        let likelihood = Likelihood::new(&state, 0.0);
+        let expected = 66.0 * (1.0 - MIN_ERROR_RATIO).ln();
+        let (index, log_likelihood) = likelihood.most_likely(&[path.clone(), star.clone()]);
+        assert_eq!(index, 1);
+        assert!((log_likelihood - expected).abs() < 1e-9);
+        assert!(likelihood.most_likely(&[path]).1 < -1.0);
+    }
+
+    #[test]
+    fn best_assignment_test() {
+        // k = 8でも全順列を調べた場合と一致し、探索する節点は全順列の木 (109601個) の1/5未満
+        let k = 8;
+        let mut rng = Pcg64Mcg::new(42);
+
+        for _ in 0..5 {
+            let original = random_graph(k, &mut rng);
+            let mut perm = (0..k).collect_vec();
+            perm.shuffle(&mut rng);
+            let mut received = expand(&original, &perm, 4);
+
+            for (u, v) in (0..received.n).tuple_combinations() {
+                if rng.gen_bool(0.2) {
+                    received.flip(u, v);
+                }
+            }
+
+            let state = State::new(&received, groups(k, 4), 1.0);
+            let likelihood = Likelihood::new(&state, 0.2);
+
+            for graph in [original, random_graph(k, &mut rng)] {
+                let (best, nodes) = likelihood.best_assignment(&graph, i32::MIN);
+                let expected = (0..k)
+                    .permutations(k)
+                    .map(|perm| {
+                        (0..k)
+                            .tuple_combinations()
+                            .filter(|&(u, v)| graph.has_edge(u, v))
+                            .map(|(u, v)| likelihood.cross_counts[perm[u]][perm[v]])
+                            .sum::<i32>()
+                    })
+                    .max()
+                    .unwrap();
+                assert_eq!(best, expected);
+                assert!(nodes < 20000);
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! tables以下のCSVからビルド時に生成した戦略表・精度表・混同行列
+//!
+//! 生成と検証はbuild.rsで行う
+
+include!(concat!(env!("OUT_DIR"), "/isomorphism_tables.rs"));
@@ -1,38 +1,206 @@
-use std::ops::Index;
+mod canonical;
+mod graph6;
 
-#[derive(Debug, Clone)]
+pub use self::canonical::CanonicalForm;
+pub use self::graph6::{parse_graph6_list, Graph6Error};
+
+use std::ops::RangeInclusive;
+
+/// 1ワードあたりのビット数
+pub const WORD_BITS: usize = 128;
+
+/// グラフ文字列のパースに失敗したときのエラー
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseGraphError {
+    /// 頂点数が範囲外
+    SizeOutOfRange { n: usize, min: usize, max: usize },
+    /// 文字列長がN(N-1)/2と一致しない
+    WrongLength { expected: usize, actual: usize },
+    /// '0', '1'以外の文字が含まれている
+    InvalidCharacter { position: usize, found: char },
+}
+
+impl std::fmt::Display for ParseGraphError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseGraphError::SizeOutOfRange { n, min, max } => {
+                write!(f, "N = {} is out of range [{}, {}]", n, min, max)
+            }
+            ParseGraphError::WrongLength { expected, actual } => {
+                write!(f, "expected {} characters, found {}", expected, actual)
+            }
+            ParseGraphError::InvalidCharacter { position, found } => {
+                write!(f, "invalid character {:?} at position {}", found, position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseGraphError {}
+
+/// コンテストの入出力形式で書かれたn頂点のグラフを読む。nはlimitの範囲になければならない
+pub fn parse_contest_graph(
+    str: &str,
+    n: usize,
+    limit: RangeInclusive<usize>,
+) -> Result<Graph, ParseGraphError> {
+    if !limit.contains(&n) {
+        return Err(ParseGraphError::SizeOutOfRange {
+            n,
+            min: *limit.start(),
+            max: *limit.end(),
+        });
+    }
+
+    Graph::deserialize(str, n)
+}
+
+/// 隣接行列を行ごとにビット列として詰めて持つグラフ
+///
+/// 各行は`words`個のu128で表され、次数や近傍の共通部分などはpopcountで計算できる
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Graph {
     pub n: usize,
-    edges: Vec<Vec<bool>>,
+    /// 1行あたりのワード数
+    words: usize,
+    /// n * words 個のワードを行優先で並べたもの
+    bits: Vec<u128>,
 }
 
 impl Graph {
     pub fn new(n: usize) -> Self {
+        let words = Self::words_for(n);
+
         Self {
             n,
-            edges: vec![vec![false; n]; n],
+            words,
+            bits: vec![0; n * words],
         }
     }
 
+    /// n頂点の頂点集合を表すのに必要なワード数
+    pub fn words_for(n: usize) -> usize {
+        n.div_ceil(WORD_BITS)
+    }
+
     pub fn connect(&mut self, u: usize, v: usize) {
-        self.edges[u][v] = true;
-        self.edges[v][u] = true;
+        self.set_bit(u, v);
+        self.set_bit(v, u);
+    }
+
+    pub fn disconnect(&mut self, u: usize, v: usize) {
+        self.unset_bit(u, v);
+        self.unset_bit(v, u);
     }
 
-    pub fn deserialize(str: &str, n: usize) -> Self {
-        let mut edges = vec![vec![false; n]; n];
-        let mut chars = str.chars();
+    pub fn flip(&mut self, u: usize, v: usize) {
+        self.flip_bit(u, v);
+        self.flip_bit(v, u);
+    }
+
+    pub fn has_edge(&self, u: usize, v: usize) -> bool {
+        let word = self.bits[u * self.words + v / WORD_BITS];
+        ((word >> (v % WORD_BITS)) & 1) > 0
+    }
+
+    /// 頂点uの隣接頂点集合をビット列で返す
+    pub fn row(&self, u: usize) -> &[u128] {
+        &self.bits[u * self.words..(u + 1) * self.words]
+    }
+
+    /// 頂点uの次数
+    pub fn degree(&self, u: usize) -> usize {
+        self.row(u).iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// 全頂点の次数
+    pub fn degrees(&self) -> Vec<usize> {
+        (0..self.n).map(|u| self.degree(u)).collect()
+    }
+
+    /// 辺の本数
+    pub fn edge_count(&self) -> usize {
+        let sum: usize = self.bits.iter().map(|w| w.count_ones() as usize).sum();
+        sum / 2
+    }
+
+    /// 頂点uと頂点vに共通する隣接頂点の数
+    pub fn common_neighbors(&self, u: usize, v: usize) -> usize {
+        self.count_neighbors_in(u, self.row(v))
+    }
+
+    /// 頂点uの隣接頂点のうち、ビット列で表された頂点集合に含まれるものの数
+    pub fn count_neighbors_in(&self, u: usize, set: &[u128]) -> usize {
+        self.row(u)
+            .iter()
+            .zip(set.iter())
+            .map(|(a, b)| (a & b).count_ones() as usize)
+            .sum()
+    }
+
+    /// 頂点uと頂点vの隣接頂点集合の対称差の大きさ
+    pub fn row_distance(&self, u: usize, v: usize) -> usize {
+        self.row(u)
+            .iter()
+            .zip(self.row(v).iter())
+            .map(|(a, b)| (a ^ b).count_ones() as usize)
+            .sum()
+    }
+
+    /// 頂点番号をそのまま対応させたときに異なる辺の本数
+    pub fn distance(&self, other: &Graph) -> usize {
+        assert_eq!(self.n, other.n);
+        let sum: usize = self
+            .bits
+            .iter()
+            .zip(other.bits.iter())
+            .map(|(a, b)| (a ^ b).count_ones() as usize)
+            .sum();
+        sum / 2
+    }
+
+    /// 頂点iが元のグラフの頂点permutation[i]となるよう頂点番号を付け替える
+    pub fn permute(&self, permutation: &[usize]) -> Self {
+        assert_eq!(self.n, permutation.len());
+        let mut graph = Self::new(self.n);
+
+        for i in 0..self.n {
+            for j in (i + 1)..self.n {
+                if self.has_edge(permutation[i], permutation[j]) {
+                    graph.connect(i, j);
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// 上三角部分を行優先で並べた0/1文字列からグラフを復元する
+    pub fn deserialize(str: &str, n: usize) -> Result<Self, ParseGraphError> {
+        let expected = n * n.saturating_sub(1) / 2;
+        let actual = str.chars().count();
+
+        if expected != actual {
+            return Err(ParseGraphError::WrongLength { expected, actual });
+        }
+
+        let mut graph = Self::new(n);
+        let mut chars = str.chars().enumerate();
 
         for row in 0..n {
             for col in (row + 1)..n {
-                if chars.next().unwrap() == '1' {
-                    edges[row][col] = true;
-                    edges[col][row] = true;
+                match chars.next() {
+                    Some((_, '1')) => graph.connect(row, col),
+                    Some((_, '0')) => {}
+                    Some((position, found)) => {
+                        return Err(ParseGraphError::InvalidCharacter { position, found })
+                    }
+                    None => unreachable!(),
                 }
             }
         }
 
-        Self { n, edges }
+        Ok(graph)
     }
 
     pub fn serialize(&self) -> String {
@@ -40,20 +208,32 @@ impl Graph {
 
         for row in 0..self.n {
             for col in (row + 1)..self.n {
-                let c = if self.edges[row][col] { '1' } else { '0' };
+                let c = if self.has_edge(row, col) { '1' } else { '0' };
                 s.push(c);
             }
         }
 
         s.iter().collect()
     }
+
+    fn set_bit(&mut self, u: usize, v: usize) {
+        self.bits[u * self.words + v / WORD_BITS] |= 1 << (v % WORD_BITS);
+    }
+
+    fn unset_bit(&mut self, u: usize, v: usize) {
+        self.bits[u * self.words + v / WORD_BITS] &= !(1 << (v % WORD_BITS));
+    }
+
+    fn flip_bit(&mut self, u: usize, v: usize) {
+        self.bits[u * self.words + v / WORD_BITS] ^= 1 << (v % WORD_BITS);
+    }
 }
 
 impl std::fmt::Display for Graph {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for row in 0..self.n {
             for col in 0..self.n {
-                let c = if self.edges[row][col] { '#' } else { '.' };
+                let c = if self.has_edge(row, col) { '#' } else { '.' };
                 write!(f, "{}", c)?;
             }
 
@@ -64,31 +244,74 @@ impl std::fmt::Display for Graph {
     }
 }
 
-impl Index<usize> for Graph {
-    type Output = [bool];
-
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.edges[index]
-    }
-}
-
 #[cfg(test)]
 mod test {
-    use super::Graph;
+    use super::{parse_contest_graph, Graph, ParseGraphError};
 
     #[test]
     fn desearialize_test() {
         let n = 4;
         let s = "100101";
-        let actual = Graph::deserialize(&s, n);
-        let expected = vec![
-            vec![false, true, false, false],
-            vec![true, false, true, false],
-            vec![false, true, false, true],
-            vec![false, false, true, false],
+        let actual = Graph::deserialize(s, n).unwrap();
+        let expected = [
+            [false, true, false, false],
+            [true, false, true, false],
+            [false, true, false, true],
+            [false, false, true, false],
         ];
 
-        assert_eq!(expected, actual.edges);
+        for (u, row) in expected.iter().enumerate() {
+            for (v, &edge) in row.iter().enumerate() {
+                assert_eq!(edge, actual.has_edge(u, v));
+            }
+        }
+    }
+
+    #[test]
+    fn desearialize_error_test() {
+        assert_eq!(
+            Graph::deserialize("10010", 4),
+            Err(ParseGraphError::WrongLength {
+                expected: 6,
+                actual: 5
+            })
+        );
+        assert_eq!(
+            Graph::deserialize("1001011", 4),
+            Err(ParseGraphError::WrongLength {
+                expected: 6,
+                actual: 7
+            })
+        );
+        assert_eq!(
+            Graph::deserialize("10x101", 4),
+            Err(ParseGraphError::InvalidCharacter {
+                position: 2,
+                found: 'x'
+            })
+        );
+    }
+
+    #[test]
+    fn parse_contest_graph_test() {
+        assert_eq!(
+            parse_contest_graph("100", 3, 4..=100),
+            Err(ParseGraphError::SizeOutOfRange {
+                n: 3,
+                min: 4,
+                max: 100
+            })
+        );
+        assert_eq!(
+            parse_contest_graph("100101", 4, 4..=100),
+            Graph::deserialize("100101", 4)
+        );
+
+        // Graph自体は頂点数を制限しない
+        let n = 150;
+        let mut graph = Graph::new(n);
+        graph.connect(0, n - 1);
+        assert_eq!(Graph::deserialize(&graph.serialize(), n), Ok(graph));
     }
 
     #[test]
@@ -104,4 +327,31 @@ mod test {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn popcount_test() {
+        // 複数ワードにまたがる場合も確認する
+        let n = 200;
+        let mut graph = Graph::new(n);
+
+        for v in 1..n {
+            graph.connect(0, v);
+        }
+
+        graph.connect(1, 150);
+        graph.connect(2, 150);
+        graph.flip(1, 2);
+        graph.flip(1, 2);
+
+        assert_eq!(graph.degree(0), n - 1);
+        assert_eq!(graph.degree(150), 3);
+        assert_eq!(graph.edge_count(), n + 1);
+        assert_eq!(graph.common_neighbors(1, 2), 2);
+        assert_eq!(graph.row_distance(1, 2), 0);
+
+        let mut other = graph.clone();
+        other.disconnect(0, 199);
+        other.connect(3, 4);
+        assert_eq!(graph.distance(&other), 2);
+    }
 }
@@ -0,0 +1,296 @@
+//! 頂点番号の付け方によらないグラフの標準形
+//!
+//! 各頂点を「各セルに含まれる隣接頂点の数」で分類し直す分割の細分化と、セルから頂点を1つ選んで
+//! 単独のセルに分ける個別化で探索木を作り、葉で得られる隣接行列のビット列のうち最大のものを標準形とする。
+//! 探索木の形は頂点番号によらないので、同型なグラフは同じ標準形を持つ
+
+use super::{Graph, WORD_BITS};
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+/// 1ワードあたりのビット数
+const CODE_BITS: usize = 64;
+
+/// グラフの標準形
+///
+/// 同型なグラフ同士でのみ一致するので、HashMapのキーとして同型なグラフを引ける
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CanonicalForm {
+    n: usize,
+    /// 上三角部分を (0, 1), (0, 2), (1, 2), (0, 3), ... の順に並べ、先頭ほど上位のビットに詰めたもの
+    code: Vec<u64>,
+}
+
+impl CanonicalForm {
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// 標準形のビット列を'0', '1'の文字列で返す
+    pub fn bitstring(&self) -> String {
+        (0..pair_count(self.n))
+            .map(|pos| if self.get(pos) { '1' } else { '0' })
+            .collect()
+    }
+
+    /// 標準形のハッシュ値（同じビルドであれば実行ごとに変わらない）
+    pub fn hash_value(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// 標準的な頂点番号を付けたグラフを返す
+    pub fn to_graph(&self) -> Graph {
+        let mut graph = Graph::new(self.n);
+
+        for j in 0..self.n {
+            for i in 0..j {
+                if self.get(position(i, j)) {
+                    graph.connect(i, j);
+                }
+            }
+        }
+
+        graph
+    }
+
+    fn get(&self, pos: usize) -> bool {
+        (self.code[pos / CODE_BITS] >> (CODE_BITS - 1 - pos % CODE_BITS)) & 1 > 0
+    }
+}
+
+impl Graph {
+    /// 標準形を求める
+    pub fn canonical_form(&self) -> CanonicalForm {
+        self.canonical_labeling().1
+    }
+
+    /// (labeling, 標準形) を求める。標準形の頂点iは元のグラフの頂点labeling[i]に対応する
+    ///
+    /// 自己同型写像が多いと探索木が大きくなるが、互いに入れ替えても変わらない頂点の集合は1つしか調べない
+    pub fn canonical_labeling(&self) -> (Vec<usize>, CanonicalForm) {
+        let partition = if self.n > 0 {
+            vec![(0..self.n).collect()]
+        } else {
+            vec![]
+        };
+        let mut best = None;
+        search(self, partition, &mut best);
+        let (form, labeling) = best.unwrap();
+        (labeling, form)
+    }
+}
+
+fn pair_count(n: usize) -> usize {
+    n * n.saturating_sub(1) / 2
+}
+
+/// 頂点対(i, j) (i < j) のビット列中の位置
+fn position(i: usize, j: usize) -> usize {
+    j * (j - 1) / 2 + i
+}
+
+fn search(
+    graph: &Graph,
+    partition: Vec<Vec<usize>>,
+    best: &mut Option<(CanonicalForm, Vec<usize>)>,
+) {
+    let partition = refine(graph, partition);
+
+    let target = match partition.iter().position(|cell| cell.len() > 1) {
+        Some(target) => target,
+        None => {
+            let labeling = partition.into_iter().flatten().collect::<Vec<_>>();
+            let form = encode(graph, &labeling);
+
+            if best.as_ref().is_none_or(|(b, _)| form > *b) {
+                *best = Some((form, labeling));
+            }
+
+            return;
+        }
+    };
+
+    // セル内の頂点が互いに入れ替え可能なら、どれを個別化しても同じ葉の集合が得られる
+    let cell = &partition[target];
+    let candidates = if are_twins(graph, cell) {
+        &cell[..1]
+    } else {
+        &cell[..]
+    };
+
+    for &v in candidates.iter() {
+        let mut next = Vec::with_capacity(partition.len() + 1);
+        next.extend(partition[..target].iter().cloned());
+        next.push(vec![v]);
+        next.push(cell.iter().copied().filter(|&u| u != v).collect());
+        next.extend(partition[(target + 1)..].iter().cloned());
+        search(graph, next, best);
+    }
+}
+
+/// 同じセルの頂点の「各セルに含まれる隣接頂点の数」が全て一致するまで分割を細かくする
+fn refine(graph: &Graph, mut partition: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+    loop {
+        let masks = partition
+            .iter()
+            .map(|cell| {
+                let mut mask = vec![0; Graph::words_for(graph.n)];
+
+                for &v in cell.iter() {
+                    mask[v / WORD_BITS] |= 1 << (v % WORD_BITS);
+                }
+
+                mask
+            })
+            .collect::<Vec<_>>();
+
+        let mut refined = Vec::with_capacity(partition.len());
+
+        for cell in partition.iter() {
+            if cell.len() == 1 {
+                refined.push(cell.clone());
+                continue;
+            }
+
+            let mut keyed = cell
+                .iter()
+                .map(|&v| {
+                    let key = masks
+                        .iter()
+                        .map(|mask| graph.count_neighbors_in(v, mask))
+                        .collect::<Vec<_>>();
+                    (key, v)
+                })
+                .collect::<Vec<_>>();
+            keyed.sort();
+
+            // キーの小さい順に新しいセルを作る
+            let mut start = 0;
+
+            for end in 1..=keyed.len() {
+                if end == keyed.len() || keyed[end].0 != keyed[start].0 {
+                    refined.push(keyed[start..end].iter().map(|&(_, v)| v).collect());
+                    start = end;
+                }
+            }
+        }
+
+        if refined.len() == partition.len() {
+            return refined;
+        }
+
+        partition = refined;
+    }
+}
+
+/// 頂点集合の任意の2頂点u, vについて、N(u) \ {v} = N(v) \ {u} が成り立つか
+fn are_twins(graph: &Graph, cell: &[usize]) -> bool {
+    let first = cell[0];
+
+    cell[1..].iter().all(|&v| {
+        (0..graph.n)
+            .filter(|&w| w != first && w != v)
+            .all(|w| graph.has_edge(first, w) == graph.has_edge(v, w))
+    })
+}
+
+/// 頂点iを元のグラフの頂点labeling[i]としたときのビット列を求める
+fn encode(graph: &Graph, labeling: &[usize]) -> CanonicalForm {
+    let n = graph.n;
+    let mut code = vec![0; pair_count(n).div_ceil(CODE_BITS)];
+
+    for j in 0..n {
+        for i in 0..j {
+            if graph.has_edge(labeling[i], labeling[j]) {
+                let pos = position(i, j);
+                code[pos / CODE_BITS] |= 1 << (CODE_BITS - 1 - pos % CODE_BITS);
+            }
+        }
+    }
+
+    CanonicalForm { n, code }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::graph::Graph;
+    use rand::prelude::*;
+    use rand_pcg::Pcg64Mcg;
+
+    fn random_graph(n: usize, rng: &mut Pcg64Mcg) -> Graph {
+        let mut graph = Graph::new(n);
+
+        for u in 0..n {
+            for v in (u + 1)..n {
+                if rng.gen_bool(0.5) {
+                    graph.connect(u, v);
+                }
+            }
+        }
+
+        graph
+    }
+
+    #[test]
+    fn canonical_form_test() {
+        let mut rng = Pcg64Mcg::new(42);
+
+        for n in 1..=12 {
+            let graph = random_graph(n, &mut rng);
+            let form = graph.canonical_form();
+
+            let mut permutation = (0..n).collect::<Vec<_>>();
+            permutation.shuffle(&mut rng);
+            let permuted = graph.permute(&permutation);
+            assert_eq!(permuted.canonical_form(), form);
+            assert_eq!(permuted.canonical_form().hash_value(), form.hash_value());
+
+            // 辺の数が変わるので別物
+            if n >= 2 {
+                let mut flipped = graph.clone();
+                flipped.flip(0, 1);
+                assert_ne!(flipped.canonical_form(), form);
+            }
+        }
+    }
+
+    #[test]
+    fn canonical_labeling_test() {
+        let mut rng = Pcg64Mcg::new(0);
+        let graph = random_graph(10, &mut rng);
+        let (labeling, form) = graph.canonical_labeling();
+
+        assert_eq!(graph.permute(&labeling), form.to_graph());
+        assert_eq!(form.bitstring().len(), 45);
+    }
+
+    #[test]
+    fn symmetric_graph_test() {
+        // 空グラフ・完全グラフ・サイクルでも探索木が爆発しない
+        let empty = Graph::new(30);
+        assert_eq!(empty.canonical_form().to_graph(), empty);
+
+        let mut complete = Graph::new(30);
+        let mut cycle = Graph::new(30);
+
+        for u in 0..30 {
+            cycle.connect(u, (u + 1) % 30);
+
+            for v in (u + 1)..30 {
+                complete.connect(u, v);
+            }
+        }
+
+        assert_eq!(complete.canonical_form().to_graph(), complete);
+        assert_eq!(
+            cycle
+                .permute(&(0..30).rev().collect::<Vec<_>>())
+                .canonical_form(),
+            cycle.canonical_form()
+        );
+    }
+}
@@ -0,0 +1,394 @@
+//! nautyやnetworkxで使われるgraph6 / sparse6形式の読み書き
+//!
+//! 参考: https://users.cecs.anu.edu.au/~bdm/data/formats.txt
+
+use super::Graph;
+
+const GRAPH6_HEADER: &str = ">>graph6<<";
+const SPARSE6_HEADER: &str = ">>sparse6<<";
+const BIAS: u8 = 63;
+
+/// graph6 / sparse6文字列のパースに失敗したときのエラー
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Graph6Error {
+    /// 空文字列
+    Empty,
+    /// 63..=126の範囲外の文字が含まれている
+    InvalidCharacter { position: usize, found: char },
+    /// 途中で文字列が終わっている
+    UnexpectedEnd,
+    /// 辺データの長さが頂点数と合わない
+    WrongLength { expected: usize, actual: usize },
+}
+
+impl std::fmt::Display for Graph6Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Graph6Error::Empty => write!(f, "empty string"),
+            Graph6Error::InvalidCharacter { position, found } => {
+                write!(f, "invalid character {:?} at position {}", found, position)
+            }
+            Graph6Error::UnexpectedEnd => write!(f, "unexpected end of string"),
+            Graph6Error::WrongLength { expected, actual } => {
+                write!(f, "expected {} data bytes, found {}", expected, actual)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Graph6Error {}
+
+impl Graph {
+    /// graph6形式の文字列に変換する（ヘッダ・改行は付けない）
+    pub fn to_graph6(&self) -> String {
+        let mut bytes = encode_size(self.n);
+        let mut bits = vec![];
+
+        // 上三角部分を列優先で並べる
+        for col in 1..self.n {
+            for row in 0..col {
+                bits.push(self.has_edge(row, col));
+            }
+        }
+
+        bytes.extend(pack_bits(&bits, false));
+        bytes.into_iter().map(char::from).collect()
+    }
+
+    /// graph6形式の文字列からグラフを復元する
+    pub fn from_graph6(str: &str) -> Result<Self, Graph6Error> {
+        let str = str.trim();
+        let str = str.strip_prefix(GRAPH6_HEADER).unwrap_or(str);
+        let bytes = to_six_bits(str)?;
+        let (n, data) = decode_size(&bytes)?;
+
+        let bit_count = n * n.saturating_sub(1) / 2;
+        let expected = bit_count.div_ceil(6);
+
+        if data.len() != expected {
+            return Err(Graph6Error::WrongLength {
+                expected,
+                actual: data.len(),
+            });
+        }
+
+        let mut graph = Graph::new(n);
+        let mut index = 0;
+
+        for col in 1..n {
+            for row in 0..col {
+                let word = data[index / 6];
+                if (word >> (5 - index % 6)) & 1 > 0 {
+                    graph.connect(row, col);
+                }
+
+                index += 1;
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// sparse6形式の文字列に変換する（ヘッダ・改行は付けない）
+    ///
+    /// 辺の数が少ないグラフではgraph6よりも短くなる
+    pub fn to_sparse6(&self) -> String {
+        let k = sparse6_width(self.n);
+        let mut bits = vec![];
+        let mut current = 0;
+
+        let push_value = |bits: &mut Vec<bool>, x: usize| {
+            for i in (0..k).rev() {
+                bits.push((x >> i) & 1 > 0);
+            }
+        };
+
+        // 大きい方の頂点の昇順に辺を並べる
+        for v in 0..self.n {
+            for u in 0..=v {
+                if u == v || !self.has_edge(u, v) {
+                    continue;
+                }
+
+                if v == current {
+                    bits.push(false);
+                    push_value(&mut bits, u);
+                } else if v == current + 1 {
+                    current = v;
+                    bits.push(true);
+                    push_value(&mut bits, u);
+                } else {
+                    current = v;
+                    bits.push(true);
+                    push_value(&mut bits, v);
+                    bits.push(false);
+                    push_value(&mut bits, u);
+                }
+            }
+        }
+
+        // パディングの1がn-1への辺と解釈されないようにする
+        let padding = (6 - bits.len() % 6) % 6;
+        if k < 6 && self.n == (1 << k) && padding >= k && current + 1 < self.n {
+            bits.push(false);
+        }
+
+        let mut bytes = vec![b':'];
+        bytes.extend(encode_size(self.n));
+        bytes.extend(pack_bits(&bits, true));
+        bytes.into_iter().map(char::from).collect()
+    }
+
+    /// sparse6形式の文字列からグラフを復元する
+    ///
+    /// 自己ループと多重辺は無視される
+    pub fn from_sparse6(str: &str) -> Result<Self, Graph6Error> {
+        let str = str.trim();
+        let str = str.strip_prefix(SPARSE6_HEADER).unwrap_or(str);
+        let str = match (str.strip_prefix(':'), str.chars().next()) {
+            (Some(str), _) => str,
+            (None, Some(found)) => {
+                return Err(Graph6Error::InvalidCharacter { position: 0, found })
+            }
+            (None, None) => return Err(Graph6Error::Empty),
+        };
+        let bytes = to_six_bits(str)?;
+        let (n, data) = decode_size(&bytes)?;
+        let k = sparse6_width(n);
+
+        let bits = data
+            .iter()
+            .flat_map(|&b| (0..6).rev().map(move |i| (b >> i) & 1 > 0))
+            .collect::<Vec<_>>();
+
+        let mut graph = Graph::new(n);
+        let mut v = 0;
+        let mut cursor = 0;
+
+        while cursor + 1 + k <= bits.len() {
+            let b = bits[cursor];
+            let x = bits[(cursor + 1)..(cursor + 1 + k)]
+                .iter()
+                .fold(0, |acc, &bit| (acc << 1) | bit as usize);
+            cursor += 1 + k;
+
+            if b {
+                v += 1;
+            }
+
+            // 末尾のパディングで範囲外になったら終了
+            if x >= n || v >= n {
+                break;
+            }
+
+            if x > v {
+                v = x;
+            } else if x < v {
+                graph.connect(x, v);
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// 先頭の文字を見てgraph6 / sparse6のどちらかとしてパースする
+    pub fn from_graph6_or_sparse6(str: &str) -> Result<Self, Graph6Error> {
+        let str = str.trim();
+
+        if str.starts_with(':') || str.starts_with(SPARSE6_HEADER) {
+            Self::from_sparse6(str)
+        } else {
+            Self::from_graph6(str)
+        }
+    }
+}
+
+/// 1行1グラフのgraph6 / sparse6ファイルを読み込む
+///
+/// 空行は読み飛ばす
+pub fn parse_graph6_list(str: &str) -> Result<Vec<Graph>, Graph6Error> {
+    str.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(Graph::from_graph6_or_sparse6)
+        .collect()
+}
+
+/// sparse6で頂点番号1つを表すのに使うビット数
+fn sparse6_width(n: usize) -> usize {
+    let mut k = 1;
+
+    while (1 << k) < n {
+        k += 1;
+    }
+
+    k
+}
+
+fn encode_size(n: usize) -> Vec<u8> {
+    if n <= 62 {
+        return vec![n as u8 + BIAS];
+    }
+
+    let (mut bytes, digits) = if n <= 258047 {
+        (vec![126], 3)
+    } else {
+        (vec![126, 126], 6)
+    };
+
+    for i in (0..digits).rev() {
+        bytes.push(((n >> (6 * i)) & 0b111111) as u8 + BIAS);
+    }
+
+    bytes
+}
+
+/// 頂点数と残りのデータ部分を返す
+fn decode_size(bytes: &[u8]) -> Result<(usize, &[u8]), Graph6Error> {
+    let digits = match bytes {
+        [] => return Err(Graph6Error::Empty),
+        [63, 63, ..] => 6,
+        [63, ..] => 3,
+        [b, ..] => return Ok((*b as usize, &bytes[1..])),
+    };
+
+    let begin = if digits == 6 { 2 } else { 1 };
+    let end = begin + digits;
+
+    if bytes.len() < end {
+        return Err(Graph6Error::UnexpectedEnd);
+    }
+
+    let n = bytes[begin..end]
+        .iter()
+        .fold(0, |acc, &b| (acc << 6) | b as usize);
+
+    Ok((n, &bytes[end..]))
+}
+
+/// 文字列を6bitずつの値に変換する
+fn to_six_bits(str: &str) -> Result<Vec<u8>, Graph6Error> {
+    if str.is_empty() {
+        return Err(Graph6Error::Empty);
+    }
+
+    str.chars()
+        .enumerate()
+        .map(|(position, c)| {
+            if (63..=126).contains(&(c as u32)) {
+                Ok(c as u8 - BIAS)
+            } else {
+                Err(Graph6Error::InvalidCharacter { position, found: c })
+            }
+        })
+        .collect()
+}
+
+/// ビット列を上位ビットから6bitずつ詰める
+///
+/// 端数はgraph6では0、sparse6では1で埋める
+fn pack_bits(bits: &[bool], pad_with_one: bool) -> Vec<u8> {
+    bits.chunks(6)
+        .map(|chunk| {
+            let mut value = 0;
+
+            for i in 0..6 {
+                let bit = chunk.get(i).copied().unwrap_or(pad_with_one);
+                value = (value << 1) | bit as u8;
+            }
+
+            value + BIAS
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_graph6_list, Graph6Error};
+    use crate::graph::Graph;
+    use rand::Rng;
+    use rand_pcg::Pcg64Mcg;
+
+    fn path(n: usize) -> Graph {
+        let mut graph = Graph::new(n);
+
+        for i in 1..n {
+            graph.connect(i - 1, i);
+        }
+
+        graph
+    }
+
+    #[test]
+    fn graph6_known_test() {
+        assert_eq!(path(2).to_graph6(), "A_");
+
+        let mut complete = Graph::new(4);
+        for u in 0..4 {
+            for v in (u + 1)..4 {
+                complete.connect(u, v);
+            }
+        }
+
+        assert_eq!(complete.to_graph6(), "C~");
+        assert_eq!(Graph::from_graph6(">>graph6<<C~\n").unwrap(), complete);
+    }
+
+    #[test]
+    fn sparse6_known_test() {
+        assert_eq!(path(2).to_sparse6(), ":An");
+
+        // フォーマット仕様書に載っている例
+        let mut graph = Graph::new(7);
+        graph.connect(0, 1);
+        graph.connect(0, 2);
+        graph.connect(1, 2);
+        graph.connect(5, 6);
+        assert_eq!(graph.to_sparse6(), ":Fa@x^");
+        assert_eq!(Graph::from_sparse6(":Fa@x^").unwrap(), graph);
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let mut rng = Pcg64Mcg::new(42);
+
+        for &n in [1, 2, 4, 8, 16, 31, 62, 63, 100, 200].iter() {
+            for &p in [0.05, 0.5].iter() {
+                let mut graph = Graph::new(n);
+
+                for u in 0..n {
+                    for v in (u + 1)..n {
+                        if rng.gen_bool(p) {
+                            graph.connect(u, v);
+                        }
+                    }
+                }
+
+                assert_eq!(Graph::from_graph6(&graph.to_graph6()).unwrap(), graph);
+                assert_eq!(Graph::from_sparse6(&graph.to_sparse6()).unwrap(), graph);
+            }
+        }
+    }
+
+    #[test]
+    fn parse_list_test() {
+        let list = parse_graph6_list("A_\n\n:An\nC~\n").unwrap();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list[0], list[1]);
+
+        assert_eq!(
+            Graph::from_graph6("C~~"),
+            Err(Graph6Error::WrongLength {
+                expected: 1,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            Graph::from_graph6("C!"),
+            Err(Graph6Error::InvalidCharacter {
+                position: 1,
+                found: '!'
+            })
+        );
+    }
+}
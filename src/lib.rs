@@ -0,0 +1,9 @@
+pub mod annealing;
+pub mod encoders;
+pub mod graph;
+pub mod logger;
+pub mod noise;
+pub mod simulator;
+pub mod time_manager;
+pub mod utils;
+pub mod visualizer;
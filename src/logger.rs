@@ -0,0 +1,88 @@
+//! 標準エラー出力またはファイルへのログ出力
+//!
+//! `init`を呼ぶまではDEBUGレベルまで全て標準エラー出力に書き出す
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    sync::Mutex,
+};
+
+/// 復号の失敗など、常に出力したいもの
+pub const ERROR: u8 = 0;
+/// 選んだ戦略や実行時間などの概要
+pub const INFO: u8 = 1;
+/// クエリごとの経過など
+pub const DEBUG: u8 = 2;
+
+struct Logger {
+    verbosity: u8,
+    file: Option<BufWriter<File>>,
+}
+
+static LOGGER: Mutex<Logger> = Mutex::new(Logger {
+    verbosity: DEBUG,
+    file: None,
+});
+
+/// verbosity以下のレベルのログだけを出力するようにする
+///
+/// pathを指定した場合は標準エラー出力の代わりにファイルに書き出す
+pub fn init(verbosity: u8, path: Option<&Path>) -> io::Result<()> {
+    let file = match path {
+        Some(path) => Some(BufWriter::new(File::create(path)?)),
+        None => None,
+    };
+
+    let mut logger = LOGGER.lock().unwrap();
+    logger.verbosity = verbosity;
+    logger.file = file;
+    Ok(())
+}
+
+pub fn log(level: u8, args: std::fmt::Arguments) {
+    let mut logger = LOGGER.lock().unwrap();
+
+    if level > logger.verbosity {
+        return;
+    }
+
+    // ログの書き込みに失敗しても解答は続けたいので無視する
+    match logger.file.as_mut() {
+        Some(file) => {
+            let _ = writeln!(file, "{}", args);
+        }
+        None => {
+            let _ = writeln!(io::stderr(), "{}", args);
+        }
+    }
+}
+
+/// ファイルに書き出している場合、バッファの内容を書き出す
+pub fn flush() {
+    if let Some(file) = LOGGER.lock().unwrap().file.as_mut() {
+        let _ = file.flush();
+    }
+}
+
+#[macro_export]
+macro_rules! log_error {
+    ($($arg:tt)*) => {
+        $crate::logger::log($crate::logger::ERROR, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! log_info {
+    ($($arg:tt)*) => {
+        $crate::logger::log($crate::logger::INFO, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! log_debug {
+    ($($arg:tt)*) => {
+        $crate::logger::log($crate::logger::DEBUG, format_args!($($arg)*))
+    };
+}
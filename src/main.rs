@@ -1,57 +1,72 @@
-mod encoders;
-mod graph;
-mod utils;
-
-use crate::{encoders::isomorphism::IsomorphismEncoder, graph::Graph};
-use encoders::Encoder;
+use ahc016::{
+    encoders::{select_encoder, EncoderKind, EncoderOptions, MAX_N, MIN_N, QUERY_COUNT},
+    graph::parse_contest_graph,
+    log_debug, log_error, log_info, logger,
+    time_manager::TimeManager,
+};
+use clap::Parser;
 use proconio::source::line::LineSource;
 use proconio::*;
 use std::{
     io::{self, BufReader, BufWriter, Stdin, Write as _},
+    path::PathBuf,
     time::Instant,
 };
 
-const DEFAULT_QUERY_COUNT: usize = 100;
+/// 入力の読み込み・出力などのための余裕（秒）
+const TIME_MARGIN: f64 = 0.2;
 
-#[derive(Debug, Clone, Copy)]
+/// 引数なしで実行するとコンテストと同じ設定で動く
+#[derive(Parser, Debug)]
 struct AppArgs {
+    /// 回答するクエリの数
+    #[clap(short = 'q', long = "query-count", default_value_t = QUERY_COUNT)]
     query_count: usize,
+    /// 使うエンコーダ（isomorphism, barcode, binomial, clique）。省略時は予測スコアで選ぶ
+    #[clap(short = 'e', long = "encoder")]
+    encoder: Option<EncoderKind>,
+    /// 冗長性考慮前のグラフの大きさ
+    #[clap(short = 'b', long = "bits")]
     bits: Option<usize>,
+    /// 冗長性
+    #[clap(short = 'r', long = "redundancy")]
     redundancy: Option<usize>,
+    /// 焼きなましスコアのグループ内:グループ外の重みの比
+    #[clap(short = 's', long = "score-coef")]
     score_coef: Option<f64>,
+    /// クエリ100回分の制限時間（秒）
+    #[clap(short = 't', long = "time-limit", default_value_t = 5.0)]
+    time_limit: f64,
+    /// 1クエリあたりの焼きなましの回数
+    #[clap(long = "trial-count")]
+    trial_count: Option<usize>,
+    /// 焼きなまし1回あたりの反復回数。指定すると実行速度によらず結果が再現できる
+    #[clap(long = "iterations")]
+    iterations: Option<usize>,
+    /// 焼きなましを並列に行うスレッド数
+    #[clap(long = "threads")]
+    threads: Option<usize>,
+    /// 焼きなましの乱数シード
+    #[clap(long = "seed")]
+    seed: Option<u64>,
+    /// ログの詳しさ（0: エラーのみ, 1: 概要, 2: クエリごと）
+    #[clap(short = 'v', long = "verbosity", default_value_t = logger::DEBUG)]
+    verbosity: u8,
+    /// ログの出力先。省略時は標準エラー出力
+    #[clap(short = 'l', long = "log")]
+    log_path: Option<PathBuf>,
 }
 
 impl AppArgs {
-    fn read() -> Self {
-        let query_count = if std::env::args().len() < 2 {
-            DEFAULT_QUERY_COUNT
-        } else {
-            std::env::args().nth(1).unwrap().parse().unwrap()
-        };
-
-        let bits = if std::env::args().len() < 3 {
-            None
-        } else {
-            Some(std::env::args().nth(2).unwrap().parse().unwrap())
-        };
-
-        let redundancy = if std::env::args().len() < 4 {
-            None
-        } else {
-            Some(std::env::args().nth(3).unwrap().parse().unwrap())
-        };
-
-        let score_coef = if std::env::args().len() < 5 {
-            None
-        } else {
-            Some(std::env::args().nth(4).unwrap().parse().unwrap())
-        };
-
-        Self {
-            query_count,
-            bits,
-            redundancy,
-            score_coef,
+    fn encoder_options(&self) -> EncoderOptions {
+        EncoderOptions {
+            bits: self.bits,
+            redundancy: self.redundancy,
+            score_coef: self.score_coef,
+            trial_count: self.trial_count,
+            iterations: self.iterations,
+            threads: self.threads,
+            seed: self.seed,
         }
     }
 }
@@ -82,19 +97,39 @@ impl Input {
 }
 
 fn main() {
-    let app_args = AppArgs::read();
+    let app_args = AppArgs::parse();
+
+    if let Err(err) = logger::init(app_args.verbosity, app_args.log_path.as_deref()) {
+        eprintln!("failed to open the log file: {}", err);
+        std::process::exit(1);
+    }
+
     let mut stdin = LineSource::new(BufReader::new(io::stdin()));
     let stdout = io::stdout();
     let stdout = &mut BufWriter::new(stdout.lock());
     let input = Input::read(&mut stdin);
 
+    for kind in EncoderKind::ALL.iter() {
+        let prediction = kind.predict(input.graph_count, input.error_ratio);
+        log_info!(
+            "{:<12}: N = {:>3}, accuracy = {:.4}, score = {:.0}",
+            kind.name(),
+            prediction.graph_size,
+            prediction.accuracy,
+            prediction.expected_score()
+        );
+    }
+
+    let kind = app_args
+        .encoder
+        .unwrap_or_else(|| select_encoder(input.graph_count, input.error_ratio));
+    log_info!("encoder   : {}", kind);
+
     // グラフ生成
-    let encoder = IsomorphismEncoder::new(
+    let encoder = kind.create(
         input.graph_count,
         input.error_ratio,
-        app_args.bits,
-        app_args.redundancy,
-        app_args.score_coef,
+        &app_args.encoder_options(),
     );
 
     writeln!(stdout, "{}", encoder.graph_size()).unwrap();
@@ -104,10 +139,13 @@ fn main() {
         writeln!(stdout, "{}", graph.serialize()).unwrap();
     }
 
-    let elapsed = Instant::now() - input.since;
-    let each_duration = (5.0 - (elapsed.as_secs_f64() + 0.2)) / DEFAULT_QUERY_COUNT as f64;
     stdout.flush().unwrap();
 
+    // クエリ数が多い場合でも1クエリあたりの持ち時間はコンテストと同じにする
+    let time_limit =
+        app_args.time_limit * app_args.query_count as f64 / QUERY_COUNT as f64 - TIME_MARGIN;
+    let mut time_manager = TimeManager::new(input.since, time_limit, app_args.query_count);
+
     // クエリ回答
     for q in 0..app_args.query_count {
         input! {
@@ -115,12 +153,52 @@ fn main() {
             graph: String
         }
 
-        eprintln!("query: {}", q);
-        let graph = Graph::deserialize(&graph, encoder.graph_size());
-        writeln!(stdout, "{}", encoder.decode(&graph, each_duration)).unwrap();
+        let mut budget = time_manager.allocate();
+        log_debug!("query: {} ({:.4}s)", q, budget.allotted());
+
+        // 壊れた入力が来ても落ちずに適当な値を返す
+        let answer = match parse_contest_graph(&graph, encoder.graph_size(), MIN_N..=MAX_N) {
+            Ok(graph) => encoder.decode(&graph, &mut budget),
+            Err(err) => {
+                log_error!("failed to parse query {}: {}", q, err);
+                0
+            }
+        };
+
+        time_manager.finish(&budget);
+
+        writeln!(stdout, "{}", answer).unwrap();
         stdout.flush().unwrap();
     }
 
-    let elapsed = Instant::now() - input.since;
-    eprintln!("elapsed: {:.3}s", elapsed.as_secs_f64());
+    let (extended, extended_count) = time_manager.extensions();
+    log_info!("elapsed: {:.3}s", time_manager.elapsed());
+    log_info!("used   : {:.3}s", time_manager.used());
+    log_info!("saved  : {:.3}s", time_manager.saved());
+    log_info!("extend : {:.3}s ({} queries)", extended, extended_count);
+    logger::flush();
+}
+
+#[cfg(test)]
+mod test {
+    use super::AppArgs;
+    use ahc016::{encoders::EncoderKind, logger};
+    use clap::{CommandFactory, Parser};
+
+    #[test]
+    fn app_args_test() {
+        AppArgs::command().debug_assert();
+
+        // 引数なしならコンテストと同じ設定
+        let args = AppArgs::parse_from(["ahc016"]);
+        assert_eq!(args.query_count, 100);
+        assert_eq!(args.encoder, None);
+        assert_eq!(args.time_limit, 5.0);
+        assert_eq!(args.verbosity, logger::DEBUG);
+
+        let args = AppArgs::parse_from(["ahc016", "-s", "1.5", "--encoder", "barcode"]);
+        assert_eq!(args.score_coef, Some(1.5));
+        assert_eq!(args.bits, None);
+        assert_eq!(args.encoder, Some(EncoderKind::BarCode));
+    }
 }
@@ -0,0 +1,244 @@
+//! 送信グラフにノイズを加えるチャネル
+//!
+//! `NoiseChannel`はジャッジと同じ「頂点のシャッフル → 各辺の反転」を再現する。
+//! それ以外のモデルはジャッジとは異なるノイズに対する頑健性を測るためのもの
+
+mod models;
+
+pub use self::models::{
+    AsymmetricChannel, BurstChannel, VertexDeletionChannel, VertexDuplicationChannel,
+};
+
+use crate::graph::Graph;
+use itertools::Itertools;
+use rand::prelude::*;
+
+/// 頂点のシャッフルの後にノイズを加えるモデル
+pub trait NoiseModel {
+    /// 頂点の並べ替えをせず、ノイズだけを加える
+    ///
+    /// 頂点数は変えない（問題の形式上、受信側のグラフの頂点数はNで固定されているため）
+    fn corrupt(&self, graph: &Graph, rng: &mut dyn RngCore) -> Graph;
+
+    /// グラフを送信し、(受信されたグラフ, 隠された順列)を返す
+    ///
+    /// 受信されたグラフの頂点iは送信したグラフの頂点permutation[i]に対応する
+    fn transmit(&self, graph: &Graph, rng: &mut dyn RngCore) -> (Graph, Vec<usize>) {
+        let mut permutation = (0..graph.n).collect_vec();
+        permutation.shuffle(rng);
+        let received = self.corrupt(&graph.permute(&permutation), rng);
+        (received, permutation)
+    }
+}
+
+/// ノイズモデルの指定文字列のパースに失敗したときのエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseNoiseModelError {
+    /// 知らないモデル名
+    UnknownModel(String),
+    /// パラメータの数や値が不正
+    InvalidParameter(String),
+}
+
+impl std::fmt::Display for ParseNoiseModelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseNoiseModelError::UnknownModel(name) => write!(f, "unknown noise model {:?}", name),
+            ParseNoiseModelError::InvalidParameter(param) => {
+                write!(f, "invalid noise parameter {:?}", param)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseNoiseModelError {}
+
+/// `名前:パラメータ1,パラメータ2`形式の文字列からノイズモデルを作る
+///
+/// epsは入力で与えられる反転確率で、パラメータで上書きしない限り各モデルのベースのノイズとして使われる
+///
+/// - `iid` : ジャッジと同じノイズ
+/// - `asym:p01,p10` : 辺のない所は確率p01、辺のある所は確率p10で反転
+/// - `burst:ratio,burst_eps` : 確率ratioで選ばれた頂点に接続する辺だけ確率burst_epsで反転
+/// - `delete:count` : count個の頂点の情報を失わせる
+/// - `dup:count` : count個の頂点を他の頂点の複製で置き換える
+pub fn parse_noise_model(
+    spec: &str,
+    eps: f64,
+) -> Result<Box<dyn NoiseModel>, ParseNoiseModelError> {
+    let (name, params) = match spec.trim().split_once(':') {
+        Some((name, params)) => (name, params.split(',').map(|p| p.trim()).collect_vec()),
+        None => (spec.trim(), vec![]),
+    };
+
+    let parse_f64 = |i: usize| -> Result<f64, ParseNoiseModelError> {
+        let param = params.get(i).copied().unwrap_or("");
+        match param.parse::<f64>() {
+            Ok(p) if (0.0..=1.0).contains(&p) => Ok(p),
+            _ => Err(ParseNoiseModelError::InvalidParameter(param.to_string())),
+        }
+    };
+    let parse_usize = |i: usize| -> Result<usize, ParseNoiseModelError> {
+        let param = params.get(i).copied().unwrap_or("");
+        param
+            .parse::<usize>()
+            .map_err(|_| ParseNoiseModelError::InvalidParameter(param.to_string()))
+    };
+    let check_len = |len: usize| -> Result<(), ParseNoiseModelError> {
+        if params.len() == len {
+            Ok(())
+        } else {
+            Err(ParseNoiseModelError::InvalidParameter(params.join(",")))
+        }
+    };
+
+    match name {
+        "iid" => {
+            check_len(0)?;
+            Ok(Box::new(NoiseChannel::new(eps)))
+        }
+        "asym" => {
+            check_len(2)?;
+            Ok(Box::new(AsymmetricChannel::new(
+                parse_f64(0)?,
+                parse_f64(1)?,
+            )))
+        }
+        "burst" => {
+            check_len(2)?;
+            Ok(Box::new(BurstChannel::new(
+                eps,
+                parse_f64(0)?,
+                parse_f64(1)?,
+            )))
+        }
+        "delete" => {
+            check_len(1)?;
+            Ok(Box::new(VertexDeletionChannel::new(eps, parse_usize(0)?)))
+        }
+        "dup" => {
+            check_len(1)?;
+            Ok(Box::new(VertexDuplicationChannel::new(
+                eps,
+                parse_usize(0)?,
+            )))
+        }
+        _ => Err(ParseNoiseModelError::UnknownModel(name.to_string())),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NoiseChannel {
+    /// 各辺が反転する確率
+    eps: f64,
+}
+
+impl NoiseChannel {
+    pub fn new(eps: f64) -> Self {
+        Self { eps }
+    }
+
+    pub fn eps(&self) -> f64 {
+        self.eps
+    }
+
+    /// 頂点の並べ替えをせず、各辺を確率epsで反転させる
+    pub fn flip_edges<R: Rng + ?Sized>(&self, graph: &Graph, rng: &mut R) -> Graph {
+        let mut flipped = graph.clone();
+
+        for i in 0..graph.n {
+            for j in (i + 1)..graph.n {
+                if rng.gen_bool(self.eps) {
+                    flipped.flip(i, j);
+                }
+            }
+        }
+
+        flipped
+    }
+}
+
+/// 乱数の消費順はジャッジと同じなので、同じシードを使えば同じグラフが得られる
+impl NoiseModel for NoiseChannel {
+    fn corrupt(&self, graph: &Graph, rng: &mut dyn RngCore) -> Graph {
+        self.flip_edges(graph, rng)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_noise_model, NoiseChannel, NoiseModel, ParseNoiseModelError};
+    use crate::graph::Graph;
+    use rand::prelude::*;
+
+    #[test]
+    fn same_as_judge_test() {
+        const N: usize = 20;
+        const EPS: f64 = 0.2;
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(42);
+        let mut graph = Graph::new(N);
+
+        for i in 0..N {
+            for j in (i + 1)..N {
+                if rng.gen_bool(0.5) {
+                    graph.connect(i, j);
+                }
+            }
+        }
+
+        // ジャッジ側の実装をそのまま写したもの
+        let mut judge_rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+        let mut vs = (0..N).collect::<Vec<_>>();
+        vs.shuffle(&mut judge_rng);
+        let mut h = String::new();
+        for i in 0..N {
+            for j in i + 1..N {
+                if graph.has_edge(vs[i], vs[j]) ^ judge_rng.gen_bool(EPS) {
+                    h.push('1');
+                } else {
+                    h.push('0');
+                }
+            }
+        }
+
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+        let (received, permutation) = NoiseChannel::new(EPS).transmit(&graph, &mut rng);
+
+        assert_eq!(received.serialize(), h);
+        assert_eq!(permutation, vs);
+    }
+
+    #[test]
+    fn no_noise_test() {
+        let mut graph = Graph::new(5);
+        graph.connect(0, 1);
+        graph.connect(1, 4);
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+        let (received, permutation) = NoiseChannel::new(0.0).transmit(&graph, &mut rng);
+
+        assert_eq!(received, graph.permute(&permutation));
+        assert_eq!(received.edge_count(), 2);
+    }
+
+    #[test]
+    fn parse_noise_model_test() {
+        assert!(parse_noise_model("iid", 0.1).is_ok());
+        assert!(parse_noise_model("asym:0.05, 0.3", 0.1).is_ok());
+        assert!(parse_noise_model("burst:0.1,0.5", 0.1).is_ok());
+        assert!(parse_noise_model("delete:3", 0.1).is_ok());
+        assert!(parse_noise_model("dup:3", 0.1).is_ok());
+
+        assert_eq!(
+            parse_noise_model("gauss", 0.1).err(),
+            Some(ParseNoiseModelError::UnknownModel("gauss".to_string()))
+        );
+        assert_eq!(
+            parse_noise_model("asym:0.05,1.5", 0.1).err(),
+            Some(ParseNoiseModelError::InvalidParameter("1.5".to_string()))
+        );
+        assert_eq!(
+            parse_noise_model("delete", 0.1).err(),
+            Some(ParseNoiseModelError::InvalidParameter("".to_string()))
+        );
+    }
+}
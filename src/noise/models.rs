@@ -0,0 +1,241 @@
+//! ジャッジとは異なるノイズモデル
+
+use super::{NoiseChannel, NoiseModel};
+use crate::graph::Graph;
+use itertools::Itertools;
+use rand::prelude::*;
+
+/// 辺のない所とある所で反転確率が異なるチャネル
+#[derive(Debug, Clone, Copy)]
+pub struct AsymmetricChannel {
+    /// 0 → 1 に反転する確率
+    p01: f64,
+    /// 1 → 0 に反転する確率
+    p10: f64,
+}
+
+impl AsymmetricChannel {
+    pub fn new(p01: f64, p10: f64) -> Self {
+        Self { p01, p10 }
+    }
+}
+
+impl NoiseModel for AsymmetricChannel {
+    fn corrupt(&self, graph: &Graph, rng: &mut dyn RngCore) -> Graph {
+        let mut flipped = graph.clone();
+
+        for i in 0..graph.n {
+            for j in (i + 1)..graph.n {
+                let p = if graph.has_edge(i, j) {
+                    self.p10
+                } else {
+                    self.p01
+                };
+
+                if rng.gen_bool(p) {
+                    flipped.flip(i, j);
+                }
+            }
+        }
+
+        flipped
+    }
+}
+
+/// 一部の頂点の行だけが強く壊れるチャネル
+///
+/// 各頂点は確率burst_ratioで「壊れた頂点」となり、壊れた頂点に接続する辺は確率burst_eps、
+/// それ以外の辺は確率epsで反転する
+#[derive(Debug, Clone, Copy)]
+pub struct BurstChannel {
+    eps: f64,
+    burst_ratio: f64,
+    burst_eps: f64,
+}
+
+impl BurstChannel {
+    pub fn new(eps: f64, burst_ratio: f64, burst_eps: f64) -> Self {
+        Self {
+            eps,
+            burst_ratio,
+            burst_eps,
+        }
+    }
+}
+
+impl NoiseModel for BurstChannel {
+    fn corrupt(&self, graph: &Graph, rng: &mut dyn RngCore) -> Graph {
+        let bursts = (0..graph.n)
+            .map(|_| rng.gen_bool(self.burst_ratio))
+            .collect_vec();
+        let mut flipped = graph.clone();
+
+        for i in 0..graph.n {
+            for j in (i + 1)..graph.n {
+                let p = if bursts[i] || bursts[j] {
+                    self.burst_eps
+                } else {
+                    self.eps
+                };
+
+                if rng.gen_bool(p) {
+                    flipped.flip(i, j);
+                }
+            }
+        }
+
+        flipped
+    }
+}
+
+/// count個の頂点を削除し、代わりに辺が確率1/2で張られた頂点を挿入するチャネル
+///
+/// 頂点数を変えられないので、削除された頂点の位置にランダムな頂点が入ったものとして扱う。
+/// その後、全体に確率epsの反転ノイズを加える
+#[derive(Debug, Clone, Copy)]
+pub struct VertexDeletionChannel {
+    eps: f64,
+    count: usize,
+}
+
+impl VertexDeletionChannel {
+    pub fn new(eps: f64, count: usize) -> Self {
+        Self { eps, count }
+    }
+}
+
+impl NoiseModel for VertexDeletionChannel {
+    fn corrupt(&self, graph: &Graph, rng: &mut dyn RngCore) -> Graph {
+        let count = self.count.min(graph.n);
+        let deleted = rand::seq::index::sample(rng, graph.n, count).into_vec();
+        let mut replaced = graph.clone();
+
+        for &u in deleted.iter() {
+            for v in 0..graph.n {
+                if u != v && rng.gen_bool(0.5) {
+                    replaced.flip(u, v);
+                }
+            }
+        }
+
+        NoiseChannel::new(self.eps).flip_edges(&replaced, rng)
+    }
+}
+
+/// count個の頂点を別の頂点の複製で置き換えるチャネル
+///
+/// 複製された頂点は元の頂点と同じ隣接関係を持つ（互いの間の辺は元のまま）。
+/// その後、全体に確率epsの反転ノイズを加える
+#[derive(Debug, Clone, Copy)]
+pub struct VertexDuplicationChannel {
+    eps: f64,
+    count: usize,
+}
+
+impl VertexDuplicationChannel {
+    pub fn new(eps: f64, count: usize) -> Self {
+        Self { eps, count }
+    }
+}
+
+impl NoiseModel for VertexDuplicationChannel {
+    fn corrupt(&self, graph: &Graph, rng: &mut dyn RngCore) -> Graph {
+        // 複製元と上書き先が重ならないよう、2 * count 頂点を選んで前半を上書き先にする
+        let count = self.count.min(graph.n / 2);
+        let chosen = rand::seq::index::sample(rng, graph.n, count * 2).into_vec();
+        let (targets, sources) = chosen.split_at(count);
+        let mut duplicated = graph.clone();
+
+        for (&target, &source) in targets.iter().zip(sources.iter()) {
+            for v in 0..graph.n {
+                if v == target || v == source {
+                    continue;
+                }
+
+                if duplicated.has_edge(target, v) != graph.has_edge(source, v) {
+                    duplicated.flip(target, v);
+                }
+            }
+        }
+
+        NoiseChannel::new(self.eps).flip_edges(&duplicated, rng)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AsymmetricChannel, BurstChannel, VertexDeletionChannel, VertexDuplicationChannel};
+    use crate::{graph::Graph, noise::NoiseModel};
+    use rand::prelude::*;
+
+    fn half_graph(n: usize) -> Graph {
+        let mut graph = Graph::new(n);
+
+        for u in 0..(n / 2) {
+            for v in (u + 1)..n {
+                graph.connect(u, v);
+            }
+        }
+
+        graph
+    }
+
+    #[test]
+    fn asymmetric_test() {
+        let graph = half_graph(40);
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+
+        // 辺は消えるが増えない
+        let received = AsymmetricChannel::new(0.0, 0.5).corrupt(&graph, &mut rng);
+        assert!(received.edge_count() < graph.edge_count());
+
+        for u in 0..graph.n {
+            for v in 0..graph.n {
+                assert!(!received.has_edge(u, v) || graph.has_edge(u, v));
+            }
+        }
+    }
+
+    #[test]
+    fn burst_test() {
+        let graph = half_graph(40);
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+
+        assert_eq!(
+            BurstChannel::new(0.0, 0.0, 1.0).corrupt(&graph, &mut rng),
+            graph
+        );
+
+        // 全頂点が壊れていれば全ての辺が反転する
+        let received = BurstChannel::new(0.0, 1.0, 1.0).corrupt(&graph, &mut rng);
+        assert_eq!(received.distance(&graph), graph.n * (graph.n - 1) / 2);
+    }
+
+    #[test]
+    fn vertex_test() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+        let mut graph = Graph::new(40);
+
+        for u in 0..graph.n {
+            for v in (u + 1)..graph.n {
+                if rng.gen_bool(0.5) {
+                    graph.connect(u, v);
+                }
+            }
+        }
+
+        let count_twins = |graph: &Graph| {
+            (0..graph.n)
+                .filter(|&u| (0..graph.n).any(|v| u != v && graph.row_distance(u, v) <= 2))
+                .count()
+        };
+        assert_eq!(count_twins(&graph), 0);
+
+        let deleted = VertexDeletionChannel::new(0.0, 3).corrupt(&graph, &mut rng);
+        assert_eq!(deleted.n, graph.n);
+        assert!(deleted.distance(&graph) <= 3 * (graph.n - 1));
+
+        let duplicated = VertexDuplicationChannel::new(0.0, 3).corrupt(&graph, &mut rng);
+        assert_eq!(count_twins(&duplicated), 6);
+    }
+}
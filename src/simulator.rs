@@ -0,0 +1,308 @@
+//! ジャッジと同じ通信路をプロセス内で再現し、エンコーダを採点する
+//!
+//! 子プロセスと標準入出力でやりとりしないので、シリアライズの手間がなくデバッガでそのまま追える。
+//! 乱数の消費順はジャッジと同じなので、同じ入力ならtesterと同じグラフを受信する
+
+use crate::{
+    encoders::{Encoder, MAX_N, MIN_N, QUERY_COUNT},
+    noise::{NoiseChannel, NoiseModel},
+    time_manager::TimeManager,
+};
+use rand::prelude::*;
+use rand_chacha::ChaCha20Rng;
+use std::time::Instant;
+
+/// 1ケース分の入力
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputSpec {
+    /// グラフの種類数M
+    pub graph_count: usize,
+    /// 辺が反転する確率ε
+    pub error_ratio: f64,
+    /// 各クエリで送るグラフの番号
+    pub queries: Vec<usize>,
+    /// 頂点の並べ替えとノイズの乱数シード
+    pub seed: u64,
+}
+
+impl InputSpec {
+    pub fn new(graph_count: usize, error_ratio: f64, queries: Vec<usize>, seed: u64) -> Self {
+        Self {
+            graph_count,
+            error_ratio,
+            queries,
+            seed,
+        }
+    }
+
+    /// 送るグラフをseedから一様ランダムにquery_count個選ぶ
+    pub fn random(graph_count: usize, error_ratio: f64, query_count: usize, seed: u64) -> Self {
+        let mut rng = ChaCha20Rng::seed_from_u64(seed);
+        let queries = (0..query_count)
+            .map(|_| rng.gen_range(0, graph_count))
+            .collect();
+        Self::new(graph_count, error_ratio, queries, rng.gen())
+    }
+
+    /// 公式の入力生成器と同じ入力をseedから作る
+    pub fn generate(seed: u64) -> Self {
+        let mut rng = ChaCha20Rng::seed_from_u64(seed ^ 275473);
+        let graph_count = rng.gen_range(10, 101i32) as usize;
+        let error_ratio = rng.gen_range(0, 41i32) as f64 * 0.01;
+        let queries = (0..QUERY_COUNT)
+            .map(|_| rng.gen_range(0, graph_count as i32) as usize)
+            .collect();
+        Self::new(graph_count, error_ratio, queries, rng.gen())
+    }
+
+    /// 入力ファイルの形式（M ε, 送るグラフの番号, シード）を読む
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut tokens = input.split_whitespace();
+        let mut next = |name: &str| tokens.next().ok_or(format!("missing {}", name));
+
+        let graph_count = next("M")?
+            .parse::<usize>()
+            .map_err(|e| format!("invalid M: {}", e))?;
+        let error_ratio = next("eps")?
+            .parse::<f64>()
+            .map_err(|e| format!("invalid eps: {}", e))?;
+        let mut queries = Vec::with_capacity(QUERY_COUNT);
+
+        for q in 0..QUERY_COUNT {
+            let s = next("s")?
+                .parse::<usize>()
+                .map_err(|e| format!("invalid s_{}: {}", q, e))?;
+            queries.push(s);
+        }
+
+        let seed = next("seed")?
+            .parse::<u64>()
+            .map_err(|e| format!("invalid seed: {}", e))?;
+
+        Ok(Self::new(graph_count, error_ratio, queries, seed))
+    }
+}
+
+/// 1クエリの結果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QueryOutcome {
+    /// 送ったグラフの番号
+    pub sent: usize,
+    /// エンコーダの答えた番号
+    pub answered: usize,
+    /// 復号にかかった時間（秒）
+    pub elapsed: f64,
+}
+
+impl QueryOutcome {
+    pub fn is_correct(&self) -> bool {
+        self.sent == self.answered
+    }
+}
+
+/// 1ケースの結果
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulationResult {
+    /// グラフの大きさN
+    pub graph_size: usize,
+    /// 誤答数E
+    pub errors: usize,
+    /// round(1e9 * 0.9^E / N)
+    pub score: i64,
+    pub outcomes: Vec<QueryOutcome>,
+}
+
+impl SimulationResult {
+    /// 正解なら'o', 不正解なら'x'を並べた文字列（testerの出力と同じ形式）
+    pub fn summary(&self) -> String {
+        self.outcomes
+            .iter()
+            .map(|o| if o.is_correct() { 'o' } else { 'x' })
+            .collect()
+    }
+}
+
+/// ジャッジが不正な出力とみなすもの
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulationError {
+    /// Nが範囲外
+    InvalidGraphSize(usize),
+    /// index番目のグラフの頂点数がNと異なる
+    InvalidGraph { index: usize, n: usize },
+    /// query番目のクエリの答えが範囲外
+    InvalidAnswer { query: usize, answer: usize },
+}
+
+impl std::fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SimulationError::InvalidGraphSize(n) => write!(f, "Illegal output (N): {}", n),
+            SimulationError::InvalidGraph { index, n } => {
+                write!(f, "Illegal output (g_{}): {} vertices", index, n)
+            }
+            SimulationError::InvalidAnswer { query, answer } => {
+                write!(f, "Illegal output (t_{}): {}", query, answer)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SimulationError {}
+
+/// ジャッジと同じノイズでspecを実行する
+///
+/// time_limitはクエリ100回分の制限時間で、クエリ数に比例して伸び縮みさせる
+pub fn simulate(
+    encoder: &dyn Encoder,
+    spec: &InputSpec,
+    time_limit: f64,
+) -> Result<SimulationResult, SimulationError> {
+    let noise = NoiseChannel::new(spec.error_ratio);
+    simulate_with_noise(encoder, spec, &noise, time_limit)
+}
+
+/// ジャッジとは異なるノイズでspecを実行する
+pub fn simulate_with_noise(
+    encoder: &dyn Encoder,
+    spec: &InputSpec,
+    noise: &dyn NoiseModel,
+    time_limit: f64,
+) -> Result<SimulationResult, SimulationError> {
+    let graph_size = encoder.graph_size();
+
+    if !(MIN_N..=MAX_N).contains(&graph_size) {
+        return Err(SimulationError::InvalidGraphSize(graph_size));
+    }
+
+    let mut graphs = Vec::with_capacity(spec.graph_count);
+
+    for index in 0..spec.graph_count {
+        let graph = encoder.encode(index);
+
+        if graph.n != graph_size {
+            return Err(SimulationError::InvalidGraph { index, n: graph.n });
+        }
+
+        graphs.push(graph);
+    }
+
+    let query_count = spec.queries.len();
+    let time_limit = time_limit * query_count as f64 / QUERY_COUNT as f64;
+    let mut time_manager = TimeManager::new(Instant::now(), time_limit, query_count);
+    let mut rng = ChaCha20Rng::seed_from_u64(spec.seed);
+    let mut outcomes = Vec::with_capacity(query_count);
+
+    for (query, &sent) in spec.queries.iter().enumerate() {
+        let (received, _) = noise.transmit(&graphs[sent], &mut rng);
+        let mut budget = time_manager.allocate();
+        let answer = encoder.decode(&received, &mut budget);
+        time_manager.finish(&budget);
+
+        if answer >= spec.graph_count {
+            return Err(SimulationError::InvalidAnswer { query, answer });
+        }
+
+        outcomes.push(QueryOutcome {
+            sent,
+            answered: answer,
+            elapsed: budget.elapsed(),
+        });
+    }
+
+    let errors = outcomes.iter().filter(|o| !o.is_correct()).count();
+    let score = (1e9 * 0.9f64.powi(errors as i32) / graph_size as f64).round() as i64;
+
+    Ok(SimulationResult {
+        graph_size,
+        errors,
+        score,
+        outcomes,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{simulate, InputSpec, SimulationError};
+    use crate::{
+        encoders::{Encoder, EncoderKind, EncoderOptions, QUERY_COUNT},
+        graph::Graph,
+        time_manager::QueryBudget,
+    };
+
+    #[test]
+    fn generate_test() {
+        // 公式の入力生成器でseed = 0としたときの入力 (0000.txt)
+        let spec = InputSpec::generate(0);
+        assert_eq!(spec.graph_count, 10);
+        assert_eq!(spec.error_ratio, 0.0);
+        assert_eq!(spec.queries.len(), QUERY_COUNT);
+        assert_eq!(spec.queries[0], 5);
+        assert_eq!(spec.seed, 6098571402459332402);
+
+        let text = format!(
+            "10 0.00\n{}\n6098571402459332402\n",
+            spec.queries
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+        assert_eq!(InputSpec::parse(&text), Ok(spec));
+        assert!(InputSpec::parse("10 0.00\n1\n").is_err());
+    }
+
+    #[test]
+    fn simulate_test() {
+        // ノイズがなければ全問正解
+        let spec = InputSpec::random(20, 0.0, 30, 0);
+        let options = EncoderOptions {
+            iterations: Some(1000),
+            ..Default::default()
+        };
+        let encoder = EncoderKind::Isomorphism.create(20, 0.0, &options);
+        let result = simulate(encoder.as_ref(), &spec, 5.0).unwrap();
+
+        assert_eq!(result.graph_size, encoder.graph_size());
+        assert_eq!(result.errors, 0);
+        assert_eq!(
+            result.score,
+            (1e9 / result.graph_size as f64).round() as i64
+        );
+        assert_eq!(result.outcomes.len(), 30);
+        assert_eq!(result.summary(), "o".repeat(30));
+        assert!(result
+            .outcomes
+            .iter()
+            .zip(spec.queries.iter())
+            .all(|(o, &s)| o.sent == s));
+    }
+
+    /// 常に範囲外の番号を答えるエンコーダ
+    struct BrokenEncoder;
+
+    impl Encoder for BrokenEncoder {
+        fn graph_size(&self) -> usize {
+            4
+        }
+
+        fn encode(&self, _index: usize) -> Graph {
+            Graph::new(4)
+        }
+
+        fn decode_ranked(&self, _graph: &Graph, _budget: &mut QueryBudget) -> Vec<f64> {
+            vec![0.0, 0.0, 1.0]
+        }
+    }
+
+    #[test]
+    fn invalid_answer_test() {
+        let spec = InputSpec::new(2, 0.1, vec![0, 1], 0);
+        assert_eq!(
+            simulate(&BrokenEncoder, &spec, 5.0),
+            Err(SimulationError::InvalidAnswer {
+                query: 0,
+                answer: 2
+            })
+        );
+    }
+}
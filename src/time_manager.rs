@@ -0,0 +1,209 @@
+//! 全体の制限時間を各クエリに割り振る
+//!
+//! 各クエリには「残り時間 / 残りクエリ数」を割り当てるので、早く終わったクエリの余りは
+//! 自動的に後のクエリに回される。復号がうまく行かないときは、後のクエリの取り分を
+//! 一定以上残す範囲で延長を受けられる
+
+use std::time::Instant;
+
+/// 1クエリが延長で得られる時間の上限（割り当てに対する比）
+const MAX_EXTENSION_RATIO: f64 = 1.0;
+/// 延長しても後のクエリに残しておく時間（均等割りに対する比）
+const RESERVE_RATIO: f64 = 0.5;
+
+#[derive(Debug, Clone)]
+pub struct TimeManager {
+    since: Instant,
+    /// 全体の制限時間（秒）
+    time_limit: f64,
+    /// 全体のクエリ数
+    query_count: usize,
+    /// 回答済みのクエリ数
+    answered: usize,
+    /// 回答済みのクエリで実際に使った時間の合計
+    used: f64,
+    /// 回答済みのクエリで使わずに余らせた時間の合計
+    saved: f64,
+    /// 延長された時間の合計
+    extended: f64,
+    /// 延長を受けたクエリ数
+    extended_count: usize,
+}
+
+impl TimeManager {
+    /// sinceから数えてtime_limit秒以内にquery_count個のクエリに回答する
+    pub fn new(since: Instant, time_limit: f64, query_count: usize) -> Self {
+        Self {
+            since,
+            time_limit,
+            query_count,
+            answered: 0,
+            used: 0.0,
+            saved: 0.0,
+            extended: 0.0,
+            extended_count: 0,
+        }
+    }
+
+    pub fn elapsed(&self) -> f64 {
+        (Instant::now() - self.since).as_secs_f64()
+    }
+
+    /// 残り時間（秒）
+    pub fn remaining(&self) -> f64 {
+        (self.time_limit - self.elapsed()).max(0.0)
+    }
+
+    /// 次のクエリの持ち時間を割り当てる
+    pub fn allocate(&self) -> QueryBudget {
+        self.budget_at(self.elapsed())
+    }
+
+    /// 回答が終わったクエリの使用時間を記録する
+    pub fn finish(&mut self, budget: &QueryBudget) {
+        self.answered += 1;
+        self.used += budget.elapsed();
+        self.saved += budget.remaining();
+
+        if budget.extended > 0.0 {
+            self.extended += budget.extended;
+            self.extended_count += 1;
+        }
+    }
+
+    /// 回答済みのクエリ数
+    pub fn answered(&self) -> usize {
+        self.answered
+    }
+
+    /// 回答済みのクエリで実際に使った時間の合計（秒）
+    pub fn used(&self) -> f64 {
+        self.used
+    }
+
+    /// 回答済みのクエリで使わずに余らせた時間の合計（秒）
+    ///
+    /// 余った時間は後のクエリの割り当てに回っている
+    pub fn saved(&self) -> f64 {
+        self.saved
+    }
+
+    /// (延長された時間の合計, 延長を受けたクエリ数)
+    pub fn extensions(&self) -> (f64, usize) {
+        (self.extended, self.extended_count)
+    }
+
+    fn budget_at(&self, elapsed: f64) -> QueryBudget {
+        let remaining = (self.time_limit - elapsed).max(0.0);
+        let queries = self.query_count.saturating_sub(self.answered).max(1);
+        let allotted = remaining / queries as f64;
+
+        // 後のクエリには均等割りの一定割合を残す
+        let even_share = self.time_limit / self.query_count.max(1) as f64;
+        let reserve = (queries - 1) as f64 * even_share * RESERVE_RATIO;
+        let extendable = (allotted * MAX_EXTENSION_RATIO)
+            .min(remaining - allotted - reserve)
+            .max(0.0);
+
+        QueryBudget {
+            since: Instant::now(),
+            allotted,
+            extendable,
+            extended: 0.0,
+        }
+    }
+}
+
+/// 1クエリ分の持ち時間
+#[derive(Debug, Clone)]
+pub struct QueryBudget {
+    since: Instant,
+    /// 最初に割り当てられた時間（秒）
+    allotted: f64,
+    /// 延長できる時間の上限（秒）
+    extendable: f64,
+    /// 延長された時間（秒）
+    extended: f64,
+}
+
+impl QueryBudget {
+    /// 延長のできない固定の持ち時間
+    pub fn fixed(duration: f64) -> Self {
+        Self {
+            since: Instant::now(),
+            allotted: duration,
+            extendable: 0.0,
+            extended: 0.0,
+        }
+    }
+
+    /// 最初に割り当てられた時間（秒）
+    pub fn allotted(&self) -> f64 {
+        self.allotted
+    }
+
+    /// 延長分を含めた持ち時間（秒）
+    pub fn total(&self) -> f64 {
+        self.allotted + self.extended
+    }
+
+    /// このクエリで使った時間（秒）
+    pub fn elapsed(&self) -> f64 {
+        (Instant::now() - self.since).as_secs_f64()
+    }
+
+    /// 延長分を含めた残り時間（秒）
+    pub fn remaining(&self) -> f64 {
+        (self.total() - self.elapsed()).max(0.0)
+    }
+
+    /// 持ち時間の延長を求め、実際に延長された時間を返す
+    pub fn extend(&mut self, duration: f64) -> f64 {
+        let granted = duration.min(self.extendable - self.extended).max(0.0);
+        self.extended += granted;
+        granted
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{QueryBudget, TimeManager};
+    use std::time::Instant;
+
+    #[test]
+    fn allocate_test() {
+        let mut manager = TimeManager::new(Instant::now(), 10.0, 10);
+
+        let budget = manager.budget_at(0.0);
+        assert!((budget.allotted() - 1.0).abs() < 1e-9);
+
+        // 早く終わった分は後のクエリに回る
+        manager.answered = 5;
+        let budget = manager.budget_at(2.5);
+        assert!((budget.allotted() - 1.5).abs() < 1e-9);
+
+        // 最後のクエリは残り全てを使える
+        manager.answered = 9;
+        let mut budget = manager.budget_at(9.0);
+        assert!((budget.allotted() - 1.0).abs() < 1e-9);
+        assert_eq!(budget.extend(1.0), 0.0);
+    }
+
+    #[test]
+    fn extend_test() {
+        let manager = TimeManager::new(Instant::now(), 10.0, 10);
+
+        // 延長は割り当てと同じだけまで
+        let mut budget = manager.budget_at(0.0);
+        assert!((budget.extend(0.6) - 0.6).abs() < 1e-9);
+        assert!((budget.extend(0.6) - 0.4).abs() < 1e-9);
+        assert_eq!(budget.extend(0.6), 0.0);
+        assert!((budget.total() - 2.0).abs() < 1e-9);
+
+        // 後のクエリの取り分を削りすぎない
+        let mut budget = manager.budget_at(5.0);
+        assert_eq!(budget.extend(1.0), 0.0);
+
+        assert_eq!(QueryBudget::fixed(1.0).extend(1.0), 0.0);
+    }
+}
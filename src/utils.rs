@@ -19,20 +19,22 @@ impl<T: PartialOrd> ChangeMinMax for T {
     }
 }
 
-pub fn decode_hex_to_u64(data: &[u8]) -> u64 {
-    let mut value = 0;
+/// 標準正規分布の累積分布関数
+///
+/// 誤差関数はAbramowitz and Stegun 7.1.26で近似している（誤差1.5e-7程度）
+pub fn normal_cdf(x: f64) -> f64 {
+    let z = x.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.3275911 * z);
+    let poly = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let erf = 1.0 - poly * (-z * z).exp();
 
-    for &d in data {
-        value *= 16;
-        let v = if d <= b'9' {
-            d as u64 - 48
-        } else {
-            d as u64 - 97 + 10
-        };
-        value += v;
+    if x >= 0.0 {
+        0.5 * (1.0 + erf)
+    } else {
+        0.5 * (1.0 - erf)
     }
-
-    value
 }
 
 #[allow(dead_code)]
@@ -0,0 +1,269 @@
+//! グラフをSVGで描画する
+
+use crate::graph::Graph;
+use itertools::Itertools;
+use svg::{
+    node::element::{Circle, Group, Line, Rectangle, Title},
+    Document,
+};
+
+/// 隣接行列1マスの大きさ
+const CELL_SIZE: usize = 8;
+/// ノードリンク図の大きさ
+const LAYOUT_SIZE: usize = 600;
+const NODE_RADIUS: f64 = 5.0;
+
+/// 隣接行列を頂点番号順に描画する
+pub fn draw_matrix(graph: &Graph) -> Document {
+    let order = (0..graph.n).collect_vec();
+    draw_matrix_with_order(graph, &order)
+}
+
+/// 隣接行列を行・列をorderの順に並べ替えて描画する
+///
+/// order[i]番目の頂点がi行目・i列目に来る
+pub fn draw_matrix_with_order(graph: &Graph, order: &[usize]) -> Document {
+    assert_eq!(graph.n, order.len());
+    let size = graph.n * CELL_SIZE;
+
+    let mut doc = new_document(size, size);
+    doc = doc.add(background(size, size));
+    doc = doc.add(draw_cells(graph, order));
+    doc
+}
+
+/// 隣接行列をグループ順に並べ替えて描画する
+///
+/// グループ同士のブロックは辺の密度に応じて色付けされ、境界には線が引かれる
+pub fn draw_matrix_with_groups(graph: &Graph, groups: &[Vec<usize>]) -> Document {
+    let order = groups.iter().flatten().copied().collect_vec();
+    assert_eq!(graph.n, order.len());
+    let size = graph.n * CELL_SIZE;
+
+    let mut doc = new_document(size, size);
+    doc = doc.add(background(size, size));
+
+    // ブロックごとの辺密度のヒートマップ
+    let mut blocks = Group::new();
+    let mut offset0 = 0;
+
+    for (g0, group0) in groups.iter().enumerate() {
+        let mut offset1 = 0;
+
+        for (g1, group1) in groups.iter().enumerate() {
+            let mut count = 0;
+            let mut pairs = 0;
+
+            for &u in group0.iter() {
+                for &v in group1.iter() {
+                    if u != v {
+                        pairs += 1;
+                        count += graph.has_edge(u, v) as usize;
+                    }
+                }
+            }
+
+            let density = if pairs > 0 {
+                count as f64 / pairs as f64
+            } else {
+                0.0
+            };
+
+            let rect = Rectangle::new()
+                .set("x", offset1 * CELL_SIZE)
+                .set("y", offset0 * CELL_SIZE)
+                .set("width", group1.len() * CELL_SIZE)
+                .set("height", group0.len() * CELL_SIZE)
+                .set("fill", heat_color(density))
+                .add(Title::new().add(svg::node::Text::new(format!(
+                    "groups ({}, {}): {}/{} = {:.3}",
+                    g0, g1, count, pairs, density
+                ))));
+            blocks = blocks.add(rect);
+            offset1 += group1.len();
+        }
+
+        offset0 += group0.len();
+    }
+
+    doc = doc.add(blocks);
+    doc = doc.add(draw_cells(graph, &order).set("fill-opacity", 0.6));
+
+    // グループの境界線
+    let mut lines = Group::new().set("stroke", "#1f77b4").set("stroke-width", 1);
+    let mut offset = 0;
+
+    for group in groups.iter().take(groups.len().saturating_sub(1)) {
+        offset += group.len();
+        let p = offset * CELL_SIZE;
+        lines = lines
+            .add(
+                Line::new()
+                    .set("x1", p)
+                    .set("y1", 0)
+                    .set("x2", p)
+                    .set("y2", size),
+            )
+            .add(
+                Line::new()
+                    .set("x1", 0)
+                    .set("y1", p)
+                    .set("x2", size)
+                    .set("y2", p),
+            );
+    }
+
+    doc.add(lines)
+}
+
+/// 頂点を円周上に並べたノードリンク図を描画する
+///
+/// groupsを渡すと同じグループの頂点を隣り合わせに並べ、グループごとに色分けする
+pub fn draw_node_link(graph: &Graph, groups: Option<&[Vec<usize>]>) -> Document {
+    let (order, group_of) = match groups {
+        Some(groups) => {
+            let mut group_of = vec![0; graph.n];
+
+            for (g, group) in groups.iter().enumerate() {
+                for &v in group.iter() {
+                    group_of[v] = g;
+                }
+            }
+
+            (groups.iter().flatten().copied().collect_vec(), group_of)
+        }
+        None => ((0..graph.n).collect_vec(), vec![0; graph.n]),
+    };
+
+    assert_eq!(graph.n, order.len());
+    let group_count = group_of.iter().max().map_or(1, |g| g + 1);
+
+    // 頂点の座標を決める
+    let center = LAYOUT_SIZE as f64 / 2.0;
+    let radius = center - NODE_RADIUS * 4.0;
+    let mut positions = vec![(0.0, 0.0); graph.n];
+
+    for (i, &v) in order.iter().enumerate() {
+        let theta = 2.0 * std::f64::consts::PI * i as f64 / graph.n as f64;
+        positions[v] = (center + radius * theta.cos(), center + radius * theta.sin());
+    }
+
+    let mut doc = new_document(LAYOUT_SIZE, LAYOUT_SIZE);
+    doc = doc.add(background(LAYOUT_SIZE, LAYOUT_SIZE));
+
+    let mut edges = Group::new()
+        .set("stroke", "black")
+        .set("stroke-opacity", 0.2)
+        .set("stroke-width", 1);
+
+    for u in 0..graph.n {
+        for v in (u + 1)..graph.n {
+            if graph.has_edge(u, v) {
+                let (x1, y1) = positions[u];
+                let (x2, y2) = positions[v];
+                edges = edges.add(
+                    Line::new()
+                        .set("x1", x1)
+                        .set("y1", y1)
+                        .set("x2", x2)
+                        .set("y2", y2),
+                );
+            }
+        }
+    }
+
+    doc = doc.add(edges);
+
+    let mut nodes = Group::new().set("stroke", "black");
+
+    for (v, &(x, y)) in positions.iter().enumerate() {
+        let circle = Circle::new()
+            .set("cx", x)
+            .set("cy", y)
+            .set("r", NODE_RADIUS)
+            .set("fill", group_color(group_of[v], group_count))
+            .add(Title::new().add(svg::node::Text::new(format!(
+                "v = {}, group = {}, deg = {}",
+                v,
+                group_of[v],
+                graph.degree(v)
+            ))));
+        nodes = nodes.add(circle);
+    }
+
+    doc.add(nodes)
+}
+
+fn new_document(width: usize, height: usize) -> Document {
+    Document::new()
+        .set("viewBox", (0, 0, width, height))
+        .set("width", width)
+        .set("height", height)
+}
+
+fn background(width: usize, height: usize) -> Rectangle {
+    Rectangle::new()
+        .set("x", 0)
+        .set("y", 0)
+        .set("width", width)
+        .set("height", height)
+        .set("fill", "white")
+}
+
+/// 辺のあるマスを塗りつぶす
+fn draw_cells(graph: &Graph, order: &[usize]) -> Group {
+    let mut cells = Group::new().set("fill", "black");
+
+    for (row, &u) in order.iter().enumerate() {
+        for (col, &v) in order.iter().enumerate() {
+            if u != v && graph.has_edge(u, v) {
+                let rect = Rectangle::new()
+                    .set("x", col * CELL_SIZE)
+                    .set("y", row * CELL_SIZE)
+                    .set("width", CELL_SIZE)
+                    .set("height", CELL_SIZE);
+                cells = cells.add(rect);
+            }
+        }
+    }
+
+    cells
+}
+
+/// 密度0で白、密度1で赤
+fn heat_color(density: f64) -> String {
+    let density = density.clamp(0.0, 1.0);
+    let gb = (255.0 * (1.0 - density)).round() as u8;
+    format!("rgb(255,{},{})", gb, gb)
+}
+
+fn group_color(group: usize, group_count: usize) -> String {
+    let hue = 360.0 * group as f64 / group_count as f64;
+    format!("hsl({:.0},70%,55%)", hue)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{draw_matrix, draw_matrix_with_groups, draw_node_link};
+    use crate::graph::Graph;
+
+    #[test]
+    fn draw_test() {
+        let mut graph = Graph::new(4);
+        graph.connect(0, 1);
+        graph.connect(2, 3);
+        let groups = vec![vec![0, 1], vec![2, 3]];
+
+        // 背景 + 辺の数 * 2マス
+        let matrix = draw_matrix(&graph).to_string();
+        assert_eq!(matrix.matches("<rect").count(), 1 + 2 * 2);
+
+        // 背景 + ブロック4つ + 辺の数 * 2マス
+        let grouped = draw_matrix_with_groups(&graph, &groups).to_string();
+        assert_eq!(grouped.matches("<rect").count(), 1 + 4 + 2 * 2);
+
+        let node_link = draw_node_link(&graph, Some(&groups)).to_string();
+        assert_eq!(node_link.matches("<circle").count(), 4);
+        assert_eq!(node_link.matches("<line").count(), 2);
+    }
+}
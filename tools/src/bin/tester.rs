@@ -1,9 +1,16 @@
 #![allow(non_snake_case)]
 
+use ahc016::{
+    encoders::{select_encoder, EncoderKind, EncoderOptions, MAX_N, QUERY_COUNT},
+    graph::Graph,
+    logger,
+    noise::{parse_noise_model, NoiseModel},
+    simulator::{simulate_with_noise, InputSpec},
+};
 use rand::prelude::*;
 use std::io::{prelude::*, BufReader};
 use std::process::{ChildStdout, Stdio};
-use tools::*;
+use tools::score;
 
 fn read(stdout: &mut BufReader<ChildStdout>) -> Result<String, String> {
     loop {
@@ -32,53 +39,44 @@ fn read_usize(stdout: &mut BufReader<ChildStdout>, lb: usize, ub: usize) -> Resu
     Ok(v)
 }
 
-fn exec(p: &mut std::process::Child) -> Result<i64, String> {
+/// 制限時間（秒）
+const TIME_LIMIT: f64 = 5.0;
+
+fn read_input() -> Result<InputSpec, String> {
     let mut input = String::new();
     std::io::stdin().read_to_string(&mut input).unwrap();
-    let input = parse_input(&input);
+    InputSpec::parse(&input)
+}
+
+/// 環境変数NOISE_MODELでジャッジとは異なるノイズを指定できる（省略時はジャッジと同じ）
+fn noise_model(eps: f64) -> Result<Box<dyn NoiseModel>, String> {
+    let noise_model = std::env::var("NOISE_MODEL").unwrap_or_else(|_| "iid".to_owned());
+    parse_noise_model(&noise_model, eps).map_err(|err| format!("NOISE_MODEL: {}", err))
+}
+
+fn exec(p: &mut std::process::Child) -> Result<i64, String> {
+    let input = read_input()?;
     let mut stdin = std::io::BufWriter::new(p.stdin.take().unwrap());
     let mut stdout = std::io::BufReader::new(p.stdout.take().unwrap());
-    let _ = writeln!(stdin, "{} {:.2}", input.M, input.eps);
+    let _ = writeln!(stdin, "{} {:.2}", input.graph_count, input.error_ratio);
     let _ = stdin.flush();
     let N = read_usize(&mut stdout, 4, MAX_N)?;
     let mut gs = vec![];
-    for k in 0..input.M {
+    for k in 0..input.graph_count {
         let g = read(&mut stdout)?;
-        let cs = g.chars().collect::<Vec<_>>();
-        if cs.len() != N * (N - 1) / 2 || cs.iter().any(|&c| c != '0' && c != '1') {
-            return Err(format!("Illegal output (g_{}): {}", k, g));
-        }
-        let mut g = mat![false; N; N];
-        let mut p = 0;
-        for i in 0..N {
-            for j in i + 1..N {
-                g[i][j] = cs[p] == '1';
-                g[j][i] = g[i][j];
-                p += 1;
-            }
-        }
+        let g = Graph::deserialize(&g, N).map_err(|err| format!("Illegal output (g_{}): {}", k, err))?;
         gs.push(g);
     }
     let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(input.seed);
+    let channel = noise_model(input.error_ratio)?;
     let mut E = 0;
     let mut result = String::new();
-    for k in 0..Q {
-        let mut vs = (0..N).collect::<Vec<_>>();
-        vs.shuffle(&mut rng);
-        let s = input.ss[k];
-        let mut h = String::new();
-        for i in 0..N {
-            for j in i + 1..N {
-                if gs[s][vs[i]][vs[j]] ^ rng.gen_bool(input.eps) {
-                    h.push('1');
-                } else {
-                    h.push('0');
-                }
-            }
-        }
-        let _ = writeln!(stdin, "{}", h);
+    for k in 0..QUERY_COUNT {
+        let s = input.queries[k];
+        let (h, _) = channel.transmit(&gs[s], &mut rng);
+        let _ = writeln!(stdin, "{}", h.serialize());
         let _ = stdin.flush();
-        let t = read_usize(&mut stdout, 0, input.M - 1)?;
+        let t = read_usize(&mut stdout, 0, input.graph_count - 1)?;
         if s != t {
             E += 1;
             result.push('x');
@@ -93,9 +91,41 @@ fn exec(p: &mut std::process::Child) -> Result<i64, String> {
     Ok(score(E, N))
 }
 
+/// 子プロセスを起動せず、エンコーダをプロセス内で実行する。encoderがNoneなら予測スコアで選ぶ
+fn exec_in_process(encoder: Option<EncoderKind>) -> Result<i64, String> {
+    let input = read_input()?;
+    let kind = encoder.unwrap_or_else(|| select_encoder(input.graph_count, input.error_ratio));
+    let encoder = kind.create(input.graph_count, input.error_ratio, &EncoderOptions::default());
+    let channel = noise_model(input.error_ratio)?;
+    let result = simulate_with_noise(encoder.as_ref(), &input, channel.as_ref(), TIME_LIMIT).map_err(|err| err.to_string())?;
+    eprintln!("N = {}", result.graph_size);
+    eprintln!("E = {}", result.errors);
+    eprintln!("{}", result.summary());
+    Ok(result.score)
+}
+
 fn main() {
     if std::env::args().len() < 2 {
         eprintln!("Usage: {} <command> [<args>...]", std::env::args().nth(0).unwrap());
+        eprintln!("       {} --encoder <isomorphism|barcode|binomial|clique|auto>", std::env::args().nth(0).unwrap());
+        return;
+    }
+    if std::env::args().nth(1).unwrap() == "--encoder" {
+        let encoder = match std::env::args().nth(2).as_deref() {
+            None | Some("auto") => None,
+            Some(name) => Some(name.parse::<EncoderKind>().unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                std::process::exit(1)
+            })),
+        };
+        logger::init(logger::ERROR, None).unwrap();
+        match exec_in_process(encoder) {
+            Ok(score) => eprintln!("Score = {}", score),
+            Err(err) => {
+                eprintln!("{}", err);
+                eprintln!("Score = 0");
+            }
+        }
         return;
     }
     let (command, args) = (std::env::args().nth(1).unwrap(), std::env::args().skip(2).collect::<Vec<_>>());
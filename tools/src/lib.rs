@@ -1,5 +1,6 @@
 #![allow(non_snake_case, unused_macros)]
 
+use ahc016::graph::Graph;
 use proconio::input;
 use rand::prelude::*;
 
@@ -95,10 +96,7 @@ pub fn parse_output(input: &Input, f: &str) -> Result<Output, String> {
                 return Err(format!("Illegal output (N): {}", v));
             }
         } else if gs.len() < input.M {
-            let cs = v.chars().collect::<Vec<_>>();
-            if cs.len() != N * (N - 1) / 2 || cs.iter().any(|&c| c != '0' && c != '1') {
-                return Err(format!("Illegal output (g_{}): {}", gs.len(), v));
-            }
+            Graph::deserialize(v, N).map_err(|err| format!("Illegal output (g_{}): {}", gs.len(), err))?;
             gs.push(v.to_owned());
             comments_g.push(comment);
             comment = String::new();